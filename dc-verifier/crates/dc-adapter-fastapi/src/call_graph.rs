@@ -1,41 +1,85 @@
+use crate::PyProjectLayout;
 use anyhow::Result;
 use dc_core::call_graph::{CallGraph, CallGraphBuilder};
 use std::path::{Path, PathBuf};
 
 /// Построитель графа вызовов для FastAPI приложения
+///
+/// Хранит только конфигурацию: каждый вызов `build_graph` создает новый
+/// `CallGraphBuilder`, поэтому один экземпляр можно переиспользовать
 pub struct FastApiCallGraphBuilder {
-    core_builder: CallGraphBuilder,
     app_path: PathBuf,
+    /// Maximum recursion depth (None = unlimited)
+    max_depth: Option<usize>,
+    /// Анализировать тестовые модули (по умолчанию пропускаются)
+    include_tests: bool,
+    /// Корень для абсолютных импортов (None - каталог точки входа)
+    project_root: Option<PathBuf>,
 }
 
 impl FastApiCallGraphBuilder {
     /// Creates a new builder
     pub fn new(app_path: PathBuf) -> Self {
         Self {
-            core_builder: CallGraphBuilder::new(),
             app_path,
+            max_depth: None,
+            include_tests: false,
+            project_root: None,
         }
     }
 
+    /// Создает построитель по `pyproject.toml`: корень исходников задает корень
+    /// абсолютных импортов, а точка входа ищется в пакете проекта
+    pub fn from_pyproject(pyproject: &Path) -> Result<Self> {
+        let layout = PyProjectLayout::discover(pyproject)?;
+        Ok(Self::new(layout.entry_point()?).with_project_root(Some(layout.source_root)))
+    }
+
+    /// Задает корень, от которого разрешаются абсолютные импорты (`from app.models import ...`)
+    pub fn with_project_root(mut self, project_root: Option<PathBuf>) -> Self {
+        self.project_root = project_root;
+        self
+    }
+
     /// Sets the maximum recursion depth
     pub fn with_max_depth(mut self, max_depth: Option<usize>) -> Self {
-        self.core_builder = self.core_builder.with_max_depth(max_depth);
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Включает анализ тестовых модулей (`test_*.py`, `conftest.py`)
+    pub fn with_include_tests(mut self, include_tests: bool) -> Self {
+        self.include_tests = include_tests;
         self
     }
 
     /// Строит граф для FastAPI приложения
-    /// Потребляет self, так как вызывает into_graph() на core_builder
-    pub fn build_graph(self) -> Result<CallGraph> {
+    pub fn build_graph(&self) -> Result<CallGraph> {
+        self.build_graph_for(&self.app_path)
+    }
+
+    /// Строит граф для указанного приложения с текущей конфигурацией
+    pub fn build_graph_for(&self, app_path: &Path) -> Result<CallGraph> {
+        let mut core_builder = CallGraphBuilder::new()
+            .with_max_depth(self.max_depth)
+            .with_include_tests(self.include_tests);
+        if let Some(project_root) = &self.project_root {
+            core_builder = core_builder.with_project_root(project_root.clone());
+        }
+
         // Определяем корень проекта
-        let project_root = Self::find_project_root(&self.app_path);
+        let project_root = self
+            .project_root
+            .clone()
+            .unwrap_or_else(|| Self::find_project_root(app_path));
 
         // Находим точку входа
-        let entry_point = if self.app_path.exists() && self.app_path.is_file() {
+        let entry_point = if app_path.exists() && app_path.is_file() {
             // Если app_path указывает на конкретный файл, используем его
-            self.app_path.clone()
+            app_path.to_path_buf()
         } else {
             // Иначе ищем стандартную точку входа
-            self.core_builder.find_entry_point(&project_root)?
+            core_builder.find_entry_point(&project_root)?
         };
 
         // Строим граф вызовов от точки входа
@@ -44,7 +88,6 @@ impl FastApiCallGraphBuilder {
         // - Функции и классы
         // - Вызовы функций
         // - Декораторы FastAPI (@app.get, @app.post и т.д.)
-        let mut core_builder = self.core_builder;
         core_builder.build_from_entry(&entry_point)?;
 
         // Возвращаем построенный граф
@@ -129,4 +172,36 @@ mod tests {
         // Должен вернуть родителя app_path
         assert_eq!(found_root, app_path.parent().unwrap());
     }
+
+    #[test]
+    fn test_builder_reused_for_consecutive_builds() {
+        let first_dir = TempDir::new().unwrap();
+        let first_app = first_dir.path().join("main.py");
+        fs::write(&first_app, "def list_users():\n    return []\n").unwrap();
+
+        let second_dir = TempDir::new().unwrap();
+        let second_app = second_dir.path().join("main.py");
+        fs::write(&second_app, "def list_orders():\n    return []\n").unwrap();
+
+        let builder = FastApiCallGraphBuilder::new(first_app.clone());
+        let function_names = |graph: &CallGraph| -> Vec<String> {
+            graph
+                .node_weights()
+                .filter_map(|node| match node {
+                    dc_core::call_graph::CallNode::Function { name, .. } => Some(name.clone()),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let first = builder.build_graph().unwrap();
+        let second = builder.build_graph_for(&second_app).unwrap();
+        let again = builder.build_graph().unwrap();
+
+        assert_eq!(function_names(&first), vec!["list_users".to_string()]);
+        assert_eq!(function_names(&second), vec!["list_orders".to_string()]);
+        // Повторная сборка не должна зависеть от предыдущих
+        assert_eq!(first.node_count(), again.node_count());
+        assert_eq!(first.edge_count(), again.edge_count());
+    }
 }
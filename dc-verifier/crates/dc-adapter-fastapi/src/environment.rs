@@ -0,0 +1,25 @@
+use anyhow::Result;
+use pyo3::prelude::*;
+
+/// Версия встроенного интерпретатора Python (например, "3.11.4")
+pub fn python_version() -> Result<String> {
+    Python::attach(|py| {
+        let version = py.version_info();
+        Ok(format!(
+            "{}.{}.{}",
+            version.major, version.minor, version.patch
+        ))
+    })
+}
+
+/// Импортирует Python-модуль и возвращает его `__version__` (или "unknown")
+pub fn python_module_version(module: &str) -> Result<String> {
+    Python::attach(|py| {
+        let imported = py.import(module)?;
+        let version = imported
+            .getattr("__version__")
+            .and_then(|version| version.extract::<String>())
+            .unwrap_or_else(|_| "unknown".to_string());
+        Ok(version)
+    })
+}
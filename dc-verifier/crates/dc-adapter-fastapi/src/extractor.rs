@@ -3,15 +3,31 @@ use pyo3::prelude::*;
 use pyo3::types::PyAny;
 use std::path::PathBuf;
 
+/// Имя атрибута модуля с приложением по умолчанию
+pub const DEFAULT_APP_ATTR: &str = "app";
+
 /// Извлекает FastAPI приложение и routes
 pub struct FastApiExtractor {
     app_path: PathBuf,
+    app_attr: String,
 }
 
 impl FastApiExtractor {
     /// Создает новый экстрактор
     pub fn new(app_path: PathBuf) -> Self {
-        Self { app_path }
+        Self {
+            app_path,
+            app_attr: DEFAULT_APP_ATTR.to_string(),
+        }
+    }
+
+    /// Задает атрибут модуля с приложением (`application`)
+    ///
+    /// Суффикс `()` означает фабрику: `create_app()` вызывается без аргументов,
+    /// и приложением считается ее результат.
+    pub fn with_app_attr(mut self, app_attr: impl Into<String>) -> Self {
+        self.app_attr = app_attr.into();
+        self
     }
 
     /// Загружает FastAPI app через PyO3
@@ -35,8 +51,22 @@ impl FastApiExtractor {
             // Загружаем модуль
             loader.call_method1("exec_module", (module.clone(),))?;
 
-            // Получаем app
-            let app = module.getattr("app")?;
+            // Получаем app (или вызываем фабрику)
+            let (attr_name, is_factory) = match self.app_attr.strip_suffix("()") {
+                Some(factory) => (factory.trim(), true),
+                None => (self.app_attr.trim(), false),
+            };
+            if !module.hasattr(attr_name)? {
+                anyhow::bail!(
+                    "Module {} has no attribute '{}'; top-level callables: [{}]. \
+                     Set the app attribute (e.g. `application` or `create_app()`)",
+                    self.app_path.display(),
+                    attr_name,
+                    top_level_callables(&module)?.join(", ")
+                );
+            }
+            let app = module.getattr(attr_name)?;
+            let app = if is_factory { app.call0()? } else { app };
             Ok(app.into())
         })
     }
@@ -119,6 +149,20 @@ impl FastApiExtractor {
     }
 }
 
+/// Публичные вызываемые объекты верхнего уровня модуля, по имени
+fn top_level_callables(module: &Bound<'_, PyAny>) -> Result<Vec<String>> {
+    let namespace = module.getattr("__dict__")?;
+    let mut names = Vec::new();
+    for item in namespace.call_method0("items")?.try_iter()? {
+        let (name, value): (String, Bound<'_, PyAny>) = item?.extract()?;
+        if !name.starts_with('_') && value.is_callable() {
+            names.push(name);
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
 /// FastAPI route
 #[derive(Debug, Clone)]
 pub struct FastApiRoute {
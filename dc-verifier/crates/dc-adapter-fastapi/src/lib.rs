@@ -1,14 +1,17 @@
 use dc_core::models::DataChain;
 use pyo3::prelude::*;
-use serde_json;
 
 mod call_graph;
+mod environment;
 mod extractor;
 mod pydantic;
+mod pyproject;
 
 pub use call_graph::*;
+pub use environment::*;
 pub use extractor::*;
 pub use pydantic::*;
+pub use pyproject::*;
 
 /// Python модуль для FastAPI адаптера
 #[pymodule]
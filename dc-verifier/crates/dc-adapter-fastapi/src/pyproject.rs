@@ -0,0 +1,148 @@
+use anyhow::{Context, Result};
+use dc_core::call_graph::CallGraphBuilder;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Структура Python проекта, описанная в `pyproject.toml`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PyProjectLayout {
+    /// Имя проекта (`[project].name` или `[tool.poetry].name`)
+    pub name: Option<String>,
+    /// Корень исходников: каталог, от которого разрешаются абсолютные импорты
+    pub source_root: PathBuf,
+    /// Каталог пакета проекта, если он найден
+    pub package_dir: Option<PathBuf>,
+}
+
+impl PyProjectLayout {
+    /// Читает `pyproject.toml` и определяет корень исходников и каталог пакета
+    ///
+    /// Корень исходников берется из `[tool.poetry].packages` (`from = "src"`) или
+    /// `[tool.setuptools.package-dir]` (`"" = "src"`); без них используется каталог
+    /// `src/`, если он есть, иначе каталог `pyproject.toml`.
+    pub fn discover(pyproject: &Path) -> Result<Self> {
+        let content = fs::read_to_string(pyproject)
+            .with_context(|| format!("Failed to read {}", pyproject.display()))?;
+        let manifest: toml::Table = content
+            .parse()
+            .with_context(|| format!("Failed to parse {}", pyproject.display()))?;
+        let project_dir = pyproject
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+
+        let poetry = manifest
+            .get("tool")
+            .and_then(|tool| tool.get("poetry"))
+            .and_then(|poetry| poetry.as_table());
+        let name = manifest
+            .get("project")
+            .and_then(|project| project.get("name"))
+            .or_else(|| poetry.and_then(|poetry| poetry.get("name")))
+            .and_then(|name| name.as_str())
+            .map(str::to_string);
+
+        // Первый пакет poetry: `packages = [{ include = "app", from = "src" }]`
+        let poetry_package = poetry
+            .and_then(|poetry| poetry.get("packages"))
+            .and_then(|packages| packages.as_array())
+            .and_then(|packages| packages.first());
+        let setuptools_dir = manifest
+            .get("tool")
+            .and_then(|tool| tool.get("setuptools"))
+            .and_then(|setuptools| setuptools.get("package-dir"))
+            .and_then(|package_dir| package_dir.get(""))
+            .and_then(|dir| dir.as_str());
+
+        let source_root = match poetry_package
+            .and_then(|package| package.get("from"))
+            .and_then(|from| from.as_str())
+            .or(setuptools_dir)
+        {
+            Some(dir) => project_dir.join(dir),
+            None if project_dir.join("src").is_dir() => project_dir.join("src"),
+            None => project_dir,
+        };
+
+        // Имя пакета: `include` poetry или имя проекта (`my-app` → `my_app`)
+        let package_name = poetry_package
+            .and_then(|package| package.get("include"))
+            .and_then(|include| include.as_str())
+            .map(str::to_string)
+            .or_else(|| name.as_ref().map(|name| name.replace(['-', '.'], "_")));
+        let package_dir = package_name
+            .map(|package| source_root.join(package))
+            .filter(|dir| dir.is_dir());
+
+        Ok(Self {
+            name,
+            source_root,
+            package_dir,
+        })
+    }
+
+    /// Точка входа приложения (`main.py`, `app.py`) в каталоге пакета или корне исходников
+    pub fn entry_point(&self) -> Result<PathBuf> {
+        let builder = CallGraphBuilder::new();
+        self.package_dir
+            .iter()
+            .chain(std::iter::once(&self.source_root))
+            .find_map(|dir| builder.find_entry_point(dir).ok())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Entry point (main.py, app.py) not found in {}; set app_path explicitly",
+                    self.package_dir
+                        .as_ref()
+                        .unwrap_or(&self.source_root)
+                        .display()
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_src_layout_sets_source_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_dir = temp_dir.path();
+        let package_dir = project_dir.join("src").join("my_service");
+        fs::create_dir_all(&package_dir).unwrap();
+        fs::write(package_dir.join("main.py"), "app = None\n").unwrap();
+        fs::write(
+            project_dir.join("pyproject.toml"),
+            "[project]\nname = \"my-service\"\n",
+        )
+        .unwrap();
+
+        let layout = PyProjectLayout::discover(&project_dir.join("pyproject.toml")).unwrap();
+
+        assert_eq!(layout.name.as_deref(), Some("my-service"));
+        assert_eq!(layout.source_root, project_dir.join("src"));
+        assert_eq!(layout.package_dir, Some(package_dir.clone()));
+        assert_eq!(layout.entry_point().unwrap(), package_dir.join("main.py"));
+    }
+
+    #[test]
+    fn test_poetry_packages_from() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_dir = temp_dir.path();
+        fs::create_dir_all(project_dir.join("lib").join("api")).unwrap();
+        fs::write(
+            project_dir.join("pyproject.toml"),
+            "[tool.poetry]\nname = \"service\"\npackages = [{ include = \"api\", from = \"lib\" }]\n",
+        )
+        .unwrap();
+
+        let layout = PyProjectLayout::discover(&project_dir.join("pyproject.toml")).unwrap();
+
+        assert_eq!(layout.source_root, project_dir.join("lib"));
+        assert_eq!(
+            layout.package_dir,
+            Some(project_dir.join("lib").join("api"))
+        );
+    }
+}
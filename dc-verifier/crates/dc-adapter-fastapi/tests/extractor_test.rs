@@ -0,0 +1,54 @@
+use dc_adapter_fastapi::FastApiExtractor;
+use pyo3::prelude::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn write_module(source: &str) -> (TempDir, std::path::PathBuf) {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("main.py");
+    fs::write(&path, source).unwrap();
+    (temp_dir, path)
+}
+
+#[test]
+fn test_load_app_with_custom_attribute_name() {
+    let (_temp_dir, path) =
+        write_module("class App:\n    name = 'custom'\n\napplication = App()\n");
+
+    let app = FastApiExtractor::new(path)
+        .with_app_attr("application")
+        .load_app()
+        .unwrap();
+
+    Python::attach(|py| {
+        let name: String = app.bind(py).getattr("name").unwrap().extract().unwrap();
+        assert_eq!(name, "custom");
+    });
+}
+
+#[test]
+fn test_load_app_calls_factory() {
+    let (_temp_dir, path) =
+        write_module("class App:\n    name = 'factory'\n\ndef create_app():\n    return App()\n");
+
+    let app = FastApiExtractor::new(path)
+        .with_app_attr("create_app()")
+        .load_app()
+        .unwrap();
+
+    Python::attach(|py| {
+        let name: String = app.bind(py).getattr("name").unwrap().extract().unwrap();
+        assert_eq!(name, "factory");
+    });
+}
+
+#[test]
+fn test_missing_app_lists_callables() {
+    let (_temp_dir, path) = write_module("def create_app():\n    return None\n");
+
+    let error = FastApiExtractor::new(path).load_app().unwrap_err();
+
+    let message = error.to_string();
+    assert!(message.contains("'app'"));
+    assert!(message.contains("create_app"));
+}
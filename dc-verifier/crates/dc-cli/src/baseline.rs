@@ -0,0 +1,247 @@
+//! Baseline comparison for CI
+//!
+//! A baseline is a previous JSON report (`--format json`). Findings are matched
+//! by a location-independent fingerprint, so moving code around does not turn
+//! existing findings into new ones.
+
+use crate::reporters::JsonReporter;
+use anyhow::{Context, Result};
+use dc_core::models::{DataChain, MismatchType};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Stable identity of a single finding
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Finding {
+    /// Chain name (e.g. "POST /users")
+    pub chain: String,
+    /// Source schema name of the contract
+    pub from_schema: String,
+    /// Receiving schema name of the contract
+    pub to_schema: String,
+    /// Field path
+    pub path: String,
+    /// Mismatch type
+    pub mismatch_type: MismatchType,
+}
+
+/// Findings of a previous run
+///
+/// Identical findings (e.g. the same field in two contracts of a chain) are
+/// counted, so fixing one of them shows up as fixed.
+#[derive(Debug)]
+pub struct Baseline {
+    findings: BTreeMap<Finding, usize>,
+}
+
+/// Result of comparing a run against the baseline
+#[derive(Debug, Default)]
+pub struct BaselineComparison {
+    /// Findings absent from the baseline
+    pub new: Vec<Finding>,
+    /// Baseline findings that no longer occur
+    pub fixed: Vec<Finding>,
+    /// Findings present in both
+    pub existing: Vec<Finding>,
+}
+
+#[derive(Deserialize)]
+struct ReportFile {
+    chains: Vec<DataChain>,
+}
+
+/// Loads the chains of a JSON report (`--format json`)
+pub fn load_report_chains(path: &Path) -> Result<Vec<DataChain>> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read report: {}", path.display()))?;
+    let report: ReportFile = serde_json::from_str(&content).with_context(|| {
+        format!(
+            "Failed to parse {} (expected a JSON report)",
+            path.display()
+        )
+    })?;
+    Ok(report.chains)
+}
+
+impl Baseline {
+    /// Loads a baseline from a JSON report
+    pub fn load(path: &Path) -> Result<Self> {
+        Ok(Self::from_chains(&load_report_chains(path)?))
+    }
+
+    /// Fails when `path` already exists and overwriting was not forced
+    ///
+    /// Guards against silently accepting new findings into an existing baseline.
+    pub fn ensure_writable(path: &Path, force: bool) -> Result<()> {
+        if path.exists() && !force {
+            anyhow::bail!(
+                "Baseline {} already exists; pass --force to overwrite it",
+                path.display()
+            );
+        }
+        Ok(())
+    }
+
+    /// Writes checked chains as a baseline (a JSON report that [`Baseline::load`] reads)
+    pub fn write(path: &Path, chains: &[DataChain]) -> Result<()> {
+        JsonReporter
+            .generate(chains, &path.to_string_lossy())
+            .with_context(|| format!("Failed to write baseline: {}", path.display()))
+    }
+
+    /// Collects the findings of checked chains
+    pub fn from_chains(chains: &[DataChain]) -> Self {
+        let mut findings = BTreeMap::new();
+        let all = chains.iter().flat_map(|chain| {
+            chain.contracts.iter().flat_map(move |contract| {
+                contract.mismatches.iter().map(move |mismatch| Finding {
+                    chain: chain.name.clone(),
+                    from_schema: contract.from_schema.name.clone(),
+                    to_schema: contract.to_schema.name.clone(),
+                    path: mismatch.path.clone(),
+                    mismatch_type: mismatch.mismatch_type,
+                })
+            })
+        });
+        for finding in all {
+            *findings.entry(finding).or_insert(0) += 1;
+        }
+        Self { findings }
+    }
+
+    /// Splits the findings of `chains` into new, fixed and existing ones
+    pub fn compare(&self, chains: &[DataChain]) -> BaselineComparison {
+        let current = Self::from_chains(chains).findings;
+        let mut comparison = BaselineComparison::default();
+        for (finding, &count) in &current {
+            let known = self.findings.get(finding).copied().unwrap_or(0);
+            let existing = count.min(known);
+            comparison
+                .existing
+                .extend(std::iter::repeat_n(finding.clone(), existing));
+            comparison
+                .new
+                .extend(std::iter::repeat_n(finding.clone(), count - existing));
+        }
+        for (finding, &known) in &self.findings {
+            let count = current.get(finding).copied().unwrap_or(0);
+            comparison.fixed.extend(std::iter::repeat_n(
+                finding.clone(),
+                known.saturating_sub(count),
+            ));
+        }
+        comparison
+    }
+}
+
+impl BaselineComparison {
+    /// One-line CI summary: `NEW: n, FIXED: m, EXISTING: k`
+    pub fn summary_line(&self) -> String {
+        format!(
+            "NEW: {}, FIXED: {}, EXISTING: {}",
+            self.new.len(),
+            self.fixed.len(),
+            self.existing.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dc_core::models::{BaseType, Location, Mismatch, Severity, TypeInfo};
+    use dc_core::test_support::{chain, contract, pydantic_schema};
+
+    fn mismatch(path: &str, mismatch_type: MismatchType, line: usize) -> Mismatch {
+        let type_info = TypeInfo {
+            base_type: BaseType::String,
+            schema_ref: None,
+            constraints: Vec::new(),
+            optional: false,
+        };
+        Mismatch {
+            mismatch_type,
+            rule: String::new(),
+            path: path.to_string(),
+            expected: type_info.clone(),
+            actual: type_info,
+            location: Location {
+                file: "main.py".to_string(),
+                line,
+                column: None,
+            },
+            message: format!("Problem with '{}'", path),
+        }
+    }
+
+    fn chain_with(mismatches: Vec<Mismatch>) -> DataChain {
+        let mut contract = contract(pydantic_schema("UserForm"), pydantic_schema("UserCreate"));
+        contract.mismatches = mismatches;
+        contract.severity = Severity::Warning;
+        chain("POST /users", vec![contract])
+    }
+
+    #[test]
+    fn test_existing_baseline_requires_force() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("baseline.json");
+        Baseline::ensure_writable(&path, false).unwrap();
+
+        Baseline::write(&path, &[chain_with(Vec::new())]).unwrap();
+        let error = Baseline::ensure_writable(&path, false).unwrap_err();
+        assert!(error.to_string().contains("--force"));
+        Baseline::ensure_writable(&path, true).unwrap();
+
+        // The written file is a loadable baseline
+        Baseline::load(&path).unwrap();
+    }
+
+    #[test]
+    fn test_compare_counts_new_fixed_and_existing() {
+        let baseline = Baseline::from_chains(&[chain_with(vec![
+            mismatch("email", MismatchType::MissingField, 10),
+            mismatch("age", MismatchType::TypeMismatch, 12),
+        ])]);
+
+        // `age` moved to another line but is the same finding; `email` was fixed
+        let current = [chain_with(vec![
+            mismatch("age", MismatchType::TypeMismatch, 20),
+            mismatch("role", MismatchType::TypeMismatch, 21),
+            mismatch("name", MismatchType::MissingField, 22),
+        ])];
+        let comparison = baseline.compare(&current);
+
+        assert_eq!(comparison.new.len(), 2);
+        assert_eq!(comparison.fixed.len(), 1);
+        assert_eq!(comparison.fixed[0].path, "email");
+        assert_eq!(comparison.existing.len(), 1);
+        assert_eq!(comparison.existing[0].path, "age");
+        assert_eq!(comparison.summary_line(), "NEW: 2, FIXED: 1, EXISTING: 1");
+    }
+
+    #[test]
+    fn test_duplicate_findings_are_counted() {
+        let baseline = Baseline::from_chains(&[chain_with(vec![
+            mismatch("email", MismatchType::MissingField, 10),
+            mismatch("email", MismatchType::MissingField, 30),
+        ])]);
+
+        let current = [chain_with(vec![mismatch(
+            "email",
+            MismatchType::MissingField,
+            10,
+        )])];
+        let comparison = baseline.compare(&current);
+        assert_eq!(comparison.summary_line(), "NEW: 0, FIXED: 1, EXISTING: 1");
+
+        let current = [chain_with(vec![
+            mismatch("email", MismatchType::MissingField, 10),
+            mismatch("email", MismatchType::MissingField, 30),
+            mismatch("email", MismatchType::MissingField, 50),
+        ])];
+        let comparison = baseline.compare(&current);
+        assert_eq!(comparison.summary_line(), "NEW: 1, FIXED: 0, EXISTING: 2");
+    }
+}
@@ -0,0 +1,137 @@
+use crate::commands::check::{typescript_builder, typescript_cache};
+use crate::config::Config;
+use crate::profiling::profile_span;
+use anyhow::Result;
+use dc_core::analyzers::{find_http_calls, match_api_usage, ApiSpec};
+use dc_core::parsers::{OpenApiParser, ProtoParser};
+use std::path::{Path, PathBuf};
+
+/// Matches frontend HTTP calls against the endpoints of several OpenAPI specs (or `.proto` files)
+///
+/// Returns `false` when a call matches no endpoint or an endpoint is never called.
+pub fn execute_api_usage(config_path: &str, spec_paths: &[PathBuf]) -> Result<bool> {
+    profile_span!("api_usage");
+    let config = Config::load(config_path)?;
+
+    let specs = spec_paths
+        .iter()
+        .map(|spec_path| load_spec(spec_path))
+        .collect::<Result<Vec<_>>>()?;
+
+    let cache = typescript_cache(&config);
+    let mut calls = Vec::new();
+    let mut typescript_adapters = 0;
+    for adapter_config in &config.adapters {
+        if adapter_config.adapter_type != "typescript" {
+            continue;
+        }
+        let src_paths = adapter_config
+            .src_paths
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("TypeScript adapter requires src_paths"))?;
+        let graph = {
+            profile_span!("build_graph");
+            typescript_builder(
+                adapter_config,
+                src_paths.iter().map(PathBuf::from).collect(),
+                cache.as_ref(),
+            )
+            .with_max_depth(config.max_recursion_depth)
+            .build_graph()?
+        };
+        calls.extend(find_http_calls(&graph));
+        typescript_adapters += 1;
+    }
+
+    if typescript_adapters == 0 {
+        anyhow::bail!(
+            "api-usage requires at least one typescript adapter in {}",
+            config_path
+        );
+    }
+
+    let usage = match_api_usage(&calls, &specs);
+    let endpoints: usize = specs.iter().map(|spec| spec.endpoints.len()).sum();
+    if usage.is_clean() {
+        println!(
+            "All {} call(s) match the {} endpoint(s) of {} spec(s)",
+            calls.len(),
+            endpoints,
+            specs.len()
+        );
+        return Ok(true);
+    }
+
+    if !usage.unmatched_calls.is_empty() {
+        println!("Calls matching no endpoint:");
+        for call in &usage.unmatched_calls {
+            println!(
+                "  - {} {} ({}:{})",
+                call.method, call.path, call.location.file, call.location.line
+            );
+        }
+    }
+    if !usage.unused_endpoints.is_empty() {
+        println!("Endpoints no frontend code calls:");
+        for endpoint in &usage.unused_endpoints {
+            println!(
+                "  - {} {} ({})",
+                endpoint.method, endpoint.path, endpoint.spec
+            );
+        }
+    }
+    println!(
+        "{} unmatched call(s), {} unused endpoint(s)",
+        usage.unmatched_calls.len(),
+        usage.unused_endpoints.len()
+    );
+
+    Ok(false)
+}
+
+/// Loads the endpoints of an OpenAPI spec, or of the gRPC services of a `.proto` file
+fn load_spec(spec_path: &Path) -> Result<ApiSpec> {
+    let is_proto = spec_path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("proto"));
+    let endpoints = if is_proto {
+        ProtoParser::new().from_path(spec_path)?.endpoints()
+    } else {
+        OpenApiParser::from_path(spec_path)?.extract_endpoints()
+    };
+    Ok(ApiSpec {
+        source: spec_path.display().to_string(),
+        endpoints,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proto_file_is_loaded_as_spec() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let proto_path = temp_dir.path().join("users.proto");
+        std::fs::write(
+            &proto_path,
+            r#"
+syntax = "proto3";
+package users.v1;
+
+service UserService {
+  rpc GetUser(GetUserRequest) returns (User);
+}
+
+message GetUserRequest { string id = 1; }
+message User { string name = 1; }
+"#,
+        )
+        .unwrap();
+
+        let spec = load_spec(&proto_path).unwrap();
+        assert_eq!(spec.endpoints.len(), 1);
+        assert_eq!(spec.endpoints[0].path, "/users.v1.UserService/GetUser");
+        assert_eq!(spec.endpoints[0].method, "POST");
+    }
+}
@@ -1,22 +1,119 @@
-use crate::config::Config;
-use crate::reporters::{JsonReporter, MarkdownReporter};
-use crate::ReportFormat;
-use anyhow::Result;
-use dc_adapter_fastapi::FastApiCallGraphBuilder;
-use dc_core::analyzers::{ChainBuilder, ContractChecker};
+use crate::baseline::Baseline;
+use crate::commands::visualize::DEFAULT_CACHE_DIR;
+use crate::config::{AdapterConfig, Config, OutputConfig, RulesConfig};
+use crate::profiling::profile_span;
+use crate::reporters::{JsonReporter, LspReporter, MarkdownReporter};
+use crate::{FailOn, ReportFormat, ReportGrouping};
+use anyhow::{Context, Result};
+use dc_adapter_fastapi::{FastApiCallGraphBuilder, PyProjectLayout};
+use dc_core::analyzers::{
+    route_coverage, ChainBuilder, ContractChecker, RouteCoverage, DEFAULT_MAX_CHAIN_LENGTH,
+    DEFAULT_SENSITIVE_FIELDS,
+};
+use dc_core::cache::CacheStore;
+use dc_core::call_graph::CallGraph;
 use dc_core::data_flow::DataFlowTracker;
-use dc_core::models::Severity;
+use dc_core::models::{dedupe_schema_refs, Contract, DataChain, SchemaReference, Severity};
+use dc_core::parsers::JsonSchemaParser;
 use dc_typescript::TypeScriptCallGraphBuilder;
 use indicatif::{ProgressBar, ProgressStyle};
-use std::path::PathBuf;
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Options of the `check` command beyond the config file
+#[derive(Debug, Default)]
+pub struct CheckOptions {
+    /// Previous JSON report to compare findings against
+    pub baseline: Option<PathBuf>,
+    /// Exit code condition
+    pub fail_on: Option<FailOn>,
+    /// Worker threads for building graphs (overrides `workers` from the config)
+    pub workers: Option<usize>,
+    /// File to save this run's findings to as a new baseline
+    pub write_baseline: Option<PathBuf>,
+    /// Overwrite an existing `write_baseline` file
+    pub force: bool,
+    /// Only write the baseline when the run passes the `fail_on` condition
+    pub baseline_update_on_pass: bool,
+    /// Analyze test files, which are skipped by default
+    pub include_tests: bool,
+    /// Grouping of findings in the report
+    pub group_by: ReportGrouping,
+    /// Only report chains with a link under this file or directory
+    /// (canonicalized when it exists, see `is_within`)
+    pub path: Option<PathBuf>,
+    /// Print only `OK` and write no report when no chain has findings
+    pub quiet_success: bool,
+    /// File to write the route typing coverage map to
+    pub coverage_map: Option<PathBuf>,
+}
 
 /// Executes data chain verification
-pub fn execute_check(config_path: &str, format: ReportFormat) -> Result<()> {
+///
+/// Returns `false` when the `--fail-on` condition is met.
+pub fn execute_check(
+    config_path: &str,
+    format: Option<ReportFormat>,
+    options: &CheckOptions,
+) -> Result<bool> {
+    profile_span!("check");
+
     // 1. Load configuration
     let config = Config::load(config_path)?;
+    if options.fail_on == Some(FailOn::New) && options.baseline.is_none() {
+        anyhow::bail!("--fail-on new requires --baseline");
+    }
+    let baseline = options
+        .baseline
+        .as_deref()
+        .map(Baseline::load)
+        .transpose()?;
+    if let Some(path) = &options.write_baseline {
+        Baseline::ensure_writable(path, options.force)?;
+    }
 
-    // 2. Initialize adapters and build graphs
+    // 2. Initialize adapters, build graphs and check contracts at all junctions
+    //    (chains are checked as they are produced, one at a time)
     let mut all_chains = Vec::new();
+    let mut coverage = Vec::new();
+
+    let sensitive_fields = config.sensitive_fields.clone().unwrap_or_else(|| {
+        DEFAULT_SENSITIVE_FIELDS
+            .iter()
+            .map(|field| field.to_string())
+            .collect()
+    });
+    let rule_severities = config
+        .rules
+        .as_ref()
+        .map(RulesConfig::severities)
+        .transpose()?
+        .unwrap_or_default();
+    let mut checker = ContractChecker::new()
+        .with_allowlist(config.allow.clone())
+        .with_sensitive_fields(sensitive_fields)
+        .with_rule_severities(rule_severities);
+    if config
+        .rules
+        .as_ref()
+        .is_some_and(|rules| rules.is_enabled("invalid_example"))
+    {
+        checker = checker.with_example_validation();
+    }
+    if config
+        .rules
+        .as_ref()
+        .is_some_and(|rules| rules.is_enabled("flag_any"))
+    {
+        checker = checker.with_any_flagging();
+    }
+    for rule in config.rules.iter().flat_map(|rules| &rules.disable) {
+        checker = checker.without_rule(rule);
+    }
+
+    // Standalone JSON Schema contracts are checked against same-named code schemas
+    let json_schema_contracts = load_json_schema_contracts(&config)?;
 
     // Create progress bar
     let pb = ProgressBar::new(config.adapters.len() as u64);
@@ -28,118 +125,757 @@ pub fn execute_check(config_path: &str, format: ReportFormat) -> Result<()> {
     );
     pb.set_message("Building graphs...");
 
-    for (idx, adapter_config) in config.adapters.iter().enumerate() {
-        pb.set_message(format!(
-            "Processing adapter {} ({})...",
-            idx + 1,
-            adapter_config.adapter_type
-        ));
-        match adapter_config.adapter_type.as_str() {
-            "fastapi" => {
-                let app_path = adapter_config
-                    .app_path
-                    .as_ref()
-                    .ok_or_else(|| anyhow::anyhow!("FastAPI adapter requires app_path"))?;
-                let app_path = PathBuf::from(app_path);
-
-                // Build call graph for FastAPI
-                let mut builder = FastApiCallGraphBuilder::new(app_path);
-                // Set max recursion depth from config
-                if let Some(max_depth) = config.max_recursion_depth {
-                    builder = builder.with_max_depth(Some(max_depth));
-                }
-                let graph = builder.build_graph()?;
+    let cache = typescript_cache(&config);
 
-                // Create DataFlowTracker and ChainBuilder
-                let tracker = DataFlowTracker::new(&graph);
-                let chain_builder = ChainBuilder::new(&graph, &tracker);
+    // Graphs of independent adapters are built in parallel on a bounded pool
+    let pool = build_thread_pool(options.workers.or(config.workers))?;
+    let graphs = {
+        profile_span!("build_graph");
+        pool.install(|| {
+            config
+                .adapters
+                .par_iter()
+                .enumerate()
+                .map(|(idx, adapter_config)| {
+                    pb.set_message(format!(
+                        "Processing adapter {} ({})...",
+                        idx + 1,
+                        adapter_config.adapter_type
+                    ));
+                    let graph = build_adapter_graph(
+                        &config,
+                        adapter_config,
+                        cache.as_ref(),
+                        options.include_tests,
+                    );
+                    pb.inc(1);
+                    graph
+                })
+                .collect::<Result<Vec<_>>>()
+        })?
+    };
 
-                // Find all chains
-                let chains = chain_builder.find_all_chains()?;
-                all_chains.extend(chains);
-            }
-            "typescript" => {
-                let src_paths = adapter_config
-                    .src_paths
-                    .as_ref()
-                    .ok_or_else(|| anyhow::anyhow!("TypeScript adapter requires src_paths"))?;
-                let src_paths: Vec<PathBuf> = src_paths.iter().map(PathBuf::from).collect();
-
-                // Build call graph for TypeScript
-                let builder = TypeScriptCallGraphBuilder::new(src_paths)
-                    .with_max_depth(config.max_recursion_depth);
-                let graph = builder.build_graph()?;
-
-                // Create DataFlowTracker and ChainBuilder
-                let tracker = DataFlowTracker::new(&graph);
-                let chain_builder = ChainBuilder::new(&graph, &tracker);
-
-                // Find all chains
-                let chains = chain_builder.find_all_chains()?;
-                all_chains.extend(chains);
-            }
-            _ => {
-                eprintln!("Unknown adapter type: {}", adapter_config.adapter_type);
-            }
-        }
-        pb.inc(1);
-    }
-
-    pb.set_message("Finding chains...");
     pb.finish_with_message("Graphs built");
 
-    // 3. Check contracts at all junctions
-    let pb = ProgressBar::new(all_chains.len() as u64);
+    // Chains are built lazily and checked one at a time, so their total is unknown
+    let pb = ProgressBar::new_spinner();
     pb.set_style(
-        ProgressStyle::default_bar()
-            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} chains {msg}")
-            .unwrap()
-            .progress_chars("#>-"),
+        ProgressStyle::default_spinner()
+            .template("{spinner:.green} [{elapsed_precise}] {pos} chains {msg}")
+            .unwrap(),
     );
     pb.set_message("Checking contracts...");
+    pb.enable_steady_tick(std::time::Duration::from_millis(100));
 
-    let checker = ContractChecker::new();
-    for chain in &mut all_chains {
-        for contract in &mut chain.contracts {
-            let mismatches = checker.check_contract(contract);
-            contract.mismatches = mismatches.clone();
+    for graph in graphs.into_iter().flatten() {
+        if options.coverage_map.is_some() {
+            coverage.extend(route_coverage(&graph));
+        }
 
-            // Determine severity based on Mismatch types
-            contract.severity = if mismatches
-                .iter()
-                .any(|m| matches!(m.mismatch_type, dc_core::models::MismatchType::TypeMismatch))
-            {
-                Severity::Critical
-            } else if !mismatches.is_empty() {
-                Severity::Warning
-            } else {
-                Severity::Info
+        // Create DataFlowTracker and ChainBuilder
+        let tracker = DataFlowTracker::new(&graph);
+        let chain_builder = ChainBuilder::new(&graph, &tracker)
+            .with_response_envelope(config.response_envelope.clone())
+            .with_max_chain_length(config.max_chain_length.unwrap_or(DEFAULT_MAX_CHAIN_LENGTH));
+
+        // Find and check chains; a chain that fails to build is reported and skipped
+        profile_span!("check_chains");
+        for chain in chain_builder.iter_chains() {
+            let mut chain = match chain {
+                Ok(chain) => chain,
+                Err(error) => {
+                    pb.println(format!("Warning: skipped a chain: {:#}", error));
+                    continue;
+                }
             };
+            if options
+                .path
+                .as_deref()
+                .is_some_and(|scope| !chain_in_scope(&chain, scope))
+            {
+                continue;
+            }
+            add_json_schema_contracts(&mut chain, &json_schema_contracts);
+            check_chain(&checker, &mut chain);
+            all_chains.push(chain);
+            pb.inc(1);
         }
-        pb.inc(1);
     }
 
     pb.finish_with_message("Contracts checked");
 
-    // 4. Generate report
+    // 3. Generate report
+    profile_span!("report");
+    if write_report(&all_chains, &config.output, format, options)? {
+        println!(
+            "Verification completed. Report saved to {}",
+            config.output.path
+        );
+    } else {
+        println!("OK");
+    }
+
+    if let Some(path) = &options.coverage_map {
+        write_coverage_map(path, &coverage)?;
+        println!("Coverage map saved to {}", path.display());
+    }
+
+    // 4. Compare with the baseline and apply the exit code condition
+    let comparison = baseline.map(|baseline| baseline.compare(&all_chains));
+    if let Some(comparison) = &comparison {
+        println!("{}", comparison.summary_line());
+    }
+    let passed = match options.fail_on {
+        Some(FailOn::New) => comparison.is_none_or(|comparison| comparison.new.is_empty()),
+        Some(FailOn::Critical) => !has_severity(&all_chains, Severity::Critical),
+        Some(FailOn::Error) => !has_severity(&all_chains, Severity::Error),
+        Some(FailOn::Warning) => !has_severity(&all_chains, Severity::Warning),
+        None => true,
+    };
+
+    // 5. Save the baseline for the next runs
+    if let Some(path) = &options.write_baseline {
+        if options.baseline_update_on_pass && !passed {
+            println!(
+                "Baseline {} not updated: the run did not pass --fail-on",
+                path.display()
+            );
+        } else {
+            Baseline::write(path, &all_chains)?;
+            println!("Baseline saved to {}", path.display());
+        }
+    }
+
+    Ok(passed)
+}
+
+/// Writes the report of all chains
+///
+/// With `quiet_success` and no findings, nothing is written and a report left
+/// by an earlier run is removed; returns whether the report was written.
+fn write_report(
+    chains: &[DataChain],
+    output: &OutputConfig,
+    format: Option<ReportFormat>,
+    options: &CheckOptions,
+) -> Result<bool> {
+    if options.quiet_success && !has_findings(chains) {
+        match std::fs::remove_file(&output.path) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+                return Err(err)
+                    .with_context(|| format!("Failed to remove stale report: {}", output.path));
+            }
+            _ => {}
+        }
+        return Ok(false);
+    }
+
     let pb = ProgressBar::new_spinner();
     pb.set_message("Generating report...");
     pb.enable_steady_tick(std::time::Duration::from_millis(100));
-    match format {
+    match output.resolve_format(format) {
         ReportFormat::Json => {
-            JsonReporter.generate(&all_chains, &config.output.path)?;
+            JsonReporter.generate_grouped(chains, &output.path, options.group_by)?;
         }
         ReportFormat::Markdown => {
-            MarkdownReporter.generate(&all_chains, &config.output.path)?;
+            MarkdownReporter.generate(chains, &output.path, options.group_by)?;
+        }
+        ReportFormat::Lsp => {
+            LspReporter.generate(chains, &output.path)?;
         }
     }
-
     pb.finish_with_message("Report generated");
 
-    println!(
-        "Verification completed. Report saved to {}",
-        config.output.path
-    );
+    Ok(true)
+}
 
-    Ok(())
+/// Writes the route typing coverage map as JSON
+fn write_coverage_map(path: &Path, coverage: &[RouteCoverage]) -> Result<()> {
+    let fully_typed = coverage
+        .iter()
+        .filter(|route| route.is_fully_typed())
+        .count();
+    let map = serde_json::json!({
+        "summary": {"routes": coverage.len(), "fully_typed": fully_typed},
+        "routes": coverage,
+    });
+    std::fs::write(path, serde_json::to_string_pretty(&map)?)
+        .with_context(|| format!("Failed to write coverage map: {}", path.display()))
+}
+
+/// Builds the thread pool for graph building
+///
+/// `None` uses one thread per logical CPU.
+pub fn build_thread_pool(workers: Option<usize>) -> Result<rayon::ThreadPool> {
+    let workers = workers.unwrap_or_else(|| {
+        std::thread::available_parallelism()
+            .map(|parallelism| parallelism.get())
+            .unwrap_or(1)
+    });
+    Ok(rayon::ThreadPoolBuilder::new()
+        .num_threads(workers)
+        .build()?)
+}
+
+/// Builds the call graph of one adapter (`None` for unknown adapter types)
+fn build_adapter_graph(
+    config: &Config,
+    adapter_config: &AdapterConfig,
+    cache: Option<&CacheStore>,
+    include_tests: bool,
+) -> Result<Option<CallGraph>> {
+    match adapter_config.adapter_type.as_str() {
+        "fastapi" => {
+            // Build call graph for FastAPI
+            let mut builder = fastapi_builder(adapter_config)?.with_include_tests(include_tests);
+            // Set max recursion depth from config
+            if let Some(max_depth) = config.max_recursion_depth {
+                builder = builder.with_max_depth(Some(max_depth));
+            }
+            Ok(Some(builder.build_graph()?))
+        }
+        "typescript" => {
+            let src_paths = adapter_config
+                .src_paths
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("TypeScript adapter requires src_paths"))?;
+            let src_paths: Vec<PathBuf> = src_paths.iter().map(PathBuf::from).collect();
+
+            // Build call graph for TypeScript
+            let builder = typescript_builder(adapter_config, src_paths, cache)
+                .with_max_depth(config.max_recursion_depth)
+                .with_include_tests(include_tests);
+            Ok(Some(builder.build_graph()?))
+        }
+        // Schema contracts without code: loaded by `load_json_schema_contracts`
+        "jsonschema" => Ok(None),
+        _ => {
+            eprintln!("Unknown adapter type: {}", adapter_config.adapter_type);
+            Ok(None)
+        }
+    }
+}
+
+/// Opens the incremental cache in the config's `cache_dir` (`.dc-verifier-cache` by default)
+pub(crate) fn open_cache(config: &Config) -> Result<CacheStore> {
+    CacheStore::new(config.cache_dir.as_deref().unwrap_or(DEFAULT_CACHE_DIR))
+}
+
+/// Per-file cache of the TypeScript adapters (`None` without TypeScript adapters)
+///
+/// A cache that cannot be opened (e.g. locked by a concurrent run) only disables caching.
+pub(crate) fn typescript_cache(config: &Config) -> Option<CacheStore> {
+    if !config
+        .adapters
+        .iter()
+        .any(|adapter| adapter.adapter_type == "typescript")
+    {
+        return None;
+    }
+    open_cache(config)
+        .map_err(|err| eprintln!("Cache disabled: {}", err))
+        .ok()
+}
+
+/// Creates the TypeScript graph builder of an adapter
+///
+/// With `tsconfig`, aliased imports (`@/components/Button`) resolve through its `paths`;
+/// with a cache, files whose content is unchanged are not re-parsed.
+pub(crate) fn typescript_builder(
+    adapter_config: &AdapterConfig,
+    src_paths: Vec<PathBuf>,
+    cache: Option<&CacheStore>,
+) -> TypeScriptCallGraphBuilder {
+    let mut builder = TypeScriptCallGraphBuilder::new(src_paths);
+    if let Some(tsconfig) = &adapter_config.tsconfig {
+        builder = builder.with_tsconfig(PathBuf::from(tsconfig));
+    }
+    if let Some(cache) = cache {
+        builder = builder.with_cache(cache.clone());
+    }
+    builder
+}
+
+/// Creates the FastAPI graph builder of an adapter
+///
+/// With `pyproject`, absolute imports resolve from the discovered source root and
+/// the entry point is found in the project package unless `app_path` is set.
+pub(crate) fn fastapi_builder(adapter_config: &AdapterConfig) -> Result<FastApiCallGraphBuilder> {
+    match (&adapter_config.app_path, &adapter_config.pyproject) {
+        (Some(app_path), Some(pyproject)) => {
+            let layout = PyProjectLayout::discover(Path::new(pyproject))?;
+            Ok(FastApiCallGraphBuilder::new(PathBuf::from(app_path))
+                .with_project_root(Some(layout.source_root)))
+        }
+        (None, Some(pyproject)) => FastApiCallGraphBuilder::from_pyproject(Path::new(pyproject)),
+        (Some(app_path), None) => Ok(FastApiCallGraphBuilder::new(PathBuf::from(app_path))),
+        (None, None) => anyhow::bail!("FastAPI adapter requires app_path or pyproject"),
+    }
+}
+
+/// Loads the `*.schema.json` contracts of all `jsonschema` adapters
+fn load_json_schema_contracts(config: &Config) -> Result<Vec<SchemaReference>> {
+    let parser = JsonSchemaParser::new();
+    let mut contracts = Vec::new();
+    for adapter_config in &config.adapters {
+        if adapter_config.adapter_type != "jsonschema" {
+            continue;
+        }
+        if let Some(schema_dir) = &adapter_config.schema_dir {
+            contracts.extend(parser.load_dir(Path::new(schema_dir))?);
+        }
+    }
+    // Adapters sharing a schema directory load the same contracts
+    Ok(dedupe_schema_refs(contracts))
+}
+
+/// Adds a contract from each link schema to the JSON Schema contract of the same name
+///
+/// The code-derived schema is the source and the standalone contract the receiver,
+/// so the regular rules report what the code fails to provide. A schema shared by
+/// several links is compared once. Names are compared case-insensitively, so a
+/// contract named after its file (`user.schema.json` → `user`) matches `User`.
+fn add_json_schema_contracts(chain: &mut DataChain, contracts: &[SchemaReference]) {
+    let mut compared = HashSet::new();
+    let mut added = Vec::new();
+    for link in &chain.links {
+        let schema = &link.schema_ref;
+        for contract_schema in contracts
+            .iter()
+            .filter(|contract| contract.name.eq_ignore_ascii_case(&schema.name))
+        {
+            let key = (
+                &schema.location.file,
+                schema.location.line,
+                &contract_schema.location.file,
+            );
+            if !compared.insert(key) {
+                continue;
+            }
+            added.push(Contract {
+                from_link_id: link.id.clone(),
+                to_link_id: format!("jsonschema:{}", contract_schema.location.file),
+                from_schema: schema.clone(),
+                to_schema: contract_schema.clone(),
+                mismatches: Vec::new(),
+                severity: Severity::Info,
+                status_code: None,
+            });
+        }
+    }
+    chain.contracts.extend(added);
+}
+
+/// Checks whether any link of a chain is located under `scope` (a file or directory)
+fn chain_in_scope(chain: &DataChain, scope: &Path) -> bool {
+    chain
+        .links
+        .iter()
+        .any(|link| is_within(Path::new(&link.location.file), scope))
+}
+
+/// Checks whether `path` is `scope` or lies under it
+///
+/// `scope` is canonicalized once by the caller; an existing `path` is canonicalized
+/// here, so relative and absolute forms match.
+fn is_within(path: &Path, scope: &Path) -> bool {
+    path.canonicalize()
+        .unwrap_or_else(|_| path.to_path_buf())
+        .starts_with(scope)
+}
+
+/// Checks whether any contract has mismatches
+fn has_findings(chains: &[DataChain]) -> bool {
+    chains
+        .iter()
+        .flat_map(|chain| &chain.contracts)
+        .any(|contract| !contract.mismatches.is_empty())
+}
+
+/// Checks whether any contract reaches `severity`
+fn has_severity(chains: &[DataChain], severity: Severity) -> bool {
+    chains
+        .iter()
+        .flat_map(|chain| &chain.contracts)
+        .any(|contract| contract.severity >= severity)
+}
+
+/// Checks contracts at every junction of a chain and sets their severity and the chain status
+fn check_chain(checker: &ContractChecker, chain: &mut DataChain) {
+    for contract in &mut chain.contracts {
+        let mismatches = checker.check_chain_contract(&chain.name, contract);
+        // Determine severity based on Mismatch types and sensitive fields
+        contract.severity = checker.contract_severity(&mismatches);
+        contract.mismatches = mismatches;
+    }
+    chain.update_status();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dc_core::models::{
+        BaseType, ChainStatus, Contract, Link, LinkType, Location, Mismatch, MismatchType, TypeInfo,
+    };
+    use dc_core::test_support::{chain, contract, schema};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_chain_status_follows_contract_severity() {
+        let checker = ContractChecker::new();
+        let number_age = schema(
+            "User",
+            json!({
+                "type": "object",
+                "properties": {"age": {"type": "number"}},
+                "required": ["age"]
+            }),
+        );
+        let string_age = schema(
+            "User",
+            json!({
+                "type": "object",
+                "properties": {"age": {"type": "string"}},
+                "required": ["age"]
+            }),
+        );
+
+        let mut failing = chain(
+            "POST /users",
+            vec![contract(string_age, number_age.clone())],
+        );
+        check_chain(&checker, &mut failing);
+        assert_eq!(failing.contracts[0].severity, Severity::Critical);
+        assert_eq!(failing.status, ChainStatus::Fail);
+
+        let mut clean = chain(
+            "POST /users",
+            vec![contract(number_age.clone(), number_age)],
+        );
+        check_chain(&checker, &mut clean);
+        assert_eq!(clean.status, ChainStatus::Pass);
+        let report = serde_json::to_value(&clean).unwrap();
+        assert_eq!(report["status"], "pass");
+    }
+
+    /// Reports every contract as having an extra `debug` field
+    struct ExtraFieldRule;
+
+    impl dc_core::analyzers::ContractRule for ExtraFieldRule {
+        fn check(&self, contract: &Contract) -> Vec<Mismatch> {
+            let type_info = TypeInfo {
+                base_type: BaseType::String,
+                schema_ref: None,
+                constraints: Vec::new(),
+                optional: true,
+            };
+            vec![Mismatch {
+                mismatch_type: MismatchType::ExtraField,
+                rule: String::new(),
+                path: "debug".to_string(),
+                expected: type_info.clone(),
+                actual: type_info,
+                location: contract.from_schema.location.clone(),
+                message: "Extra field 'debug'".to_string(),
+            }]
+        }
+
+        fn name(&self) -> &str {
+            "extra_field"
+        }
+
+        fn description(&self) -> &str {
+            "Flags extra fields."
+        }
+
+        fn mismatch_types(&self) -> &[MismatchType] {
+            &[MismatchType::ExtraField]
+        }
+
+        fn default_severity(&self) -> Severity {
+            Severity::Error
+        }
+    }
+
+    #[test]
+    fn test_rule_default_severity_flows_into_contract() {
+        let user = schema("User", json!({"type": "object", "properties": {}}));
+
+        let mut checker = ContractChecker::new();
+        checker.add_rule(Box::new(ExtraFieldRule));
+        let mut checked = chain("POST /users", vec![contract(user.clone(), user.clone())]);
+        check_chain(&checker, &mut checked);
+        assert_eq!(checked.contracts[0].severity, Severity::Error);
+        assert_eq!(checked.status, ChainStatus::Fail);
+
+        // The config overrides the declared severity
+        let mut checker = ContractChecker::new()
+            .with_rule_severities(HashMap::from([("extra_field".to_string(), Severity::Info)]));
+        checker.add_rule(Box::new(ExtraFieldRule));
+        let mut checked = chain("POST /users", vec![contract(user.clone(), user)]);
+        check_chain(&checker, &mut checked);
+        assert_eq!(checked.contracts[0].severity, Severity::Info);
+        assert_eq!(checked.status, ChainStatus::Pass);
+    }
+
+    fn chain_through(name: &str, files: &[&str]) -> DataChain {
+        let number = schema("User", json!({"type": "object"}));
+        let mut chain = chain("POST /users", vec![contract(number.clone(), number)]);
+        chain.name = name.to_string();
+        chain.links = files
+            .iter()
+            .enumerate()
+            .map(|(idx, file)| Link {
+                id: format!("link-{}", idx),
+                link_type: LinkType::Transformer,
+                location: Location {
+                    file: file.to_string(),
+                    line: 1,
+                    column: None,
+                },
+                node_id: serde_json::from_value(json!(idx)).unwrap(),
+                schema_ref: schema("User", json!({"type": "object"})),
+            })
+            .collect();
+        chain
+    }
+
+    #[test]
+    fn test_path_scope_keeps_chains_touching_subtree() {
+        let scope = Path::new("services/billing");
+        let chains = [
+            chain_through(
+                "POST /invoices",
+                &["gateway/routes.py", "services/billing/invoices.py"],
+            ),
+            chain_through("POST /users", &["services/users/api.py"]),
+            chain_through("GET /billing-report", &["services/billing-report/api.py"]),
+        ];
+
+        let names: Vec<_> = chains
+            .iter()
+            .filter(|chain| chain_in_scope(chain, scope))
+            .map(|chain| chain.name.as_str())
+            .collect();
+
+        assert_eq!(names, vec!["POST /invoices"]);
+    }
+
+    #[test]
+    fn test_link_path_is_canonicalized_against_canonical_scope() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let billing = temp_dir.path().join("billing");
+        std::fs::create_dir_all(&billing).unwrap();
+        std::fs::write(billing.join("api.py"), "").unwrap();
+        let scope = billing.canonicalize().unwrap();
+
+        // The link's file is spelled with `..`, the scope is already canonical
+        let link_file = billing.join("..").join("billing").join("api.py");
+        assert!(is_within(&link_file, &scope));
+        assert!(!is_within(&temp_dir.path().join("users.py"), &scope));
+    }
+
+    #[test]
+    fn test_quiet_success_writes_report_only_with_findings() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let report_path = temp_dir.path().join("report.md");
+        let output = OutputConfig {
+            format: ReportFormat::Markdown,
+            path: report_path.to_string_lossy().to_string(),
+        };
+        let options = CheckOptions {
+            quiet_success: true,
+            ..CheckOptions::default()
+        };
+        let checker = ContractChecker::new();
+        let number_age = schema(
+            "User",
+            json!({
+                "type": "object",
+                "properties": {"age": {"type": "number"}},
+                "required": ["age"]
+            }),
+        );
+        let string_age = schema(
+            "User",
+            json!({
+                "type": "object",
+                "properties": {"age": {"type": "string"}},
+                "required": ["age"]
+            }),
+        );
+
+        let mut clean = chain(
+            "POST /users",
+            vec![contract(number_age.clone(), number_age.clone())],
+        );
+        check_chain(&checker, &mut clean);
+        assert!(!write_report(&[clean.clone()], &output, None, &options).unwrap());
+        assert!(!report_path.exists());
+
+        let mut failing = chain("POST /users", vec![contract(string_age, number_age)]);
+        check_chain(&checker, &mut failing);
+        assert!(write_report(&[failing], &output, None, &options).unwrap());
+        assert!(report_path.exists());
+
+        // A clean run does not leave the previous report behind
+        assert!(!write_report(&[clean], &output, None, &options).unwrap());
+        assert!(!report_path.exists());
+    }
+
+    #[test]
+    fn test_json_schema_contract_is_checked_against_code_schema() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let app_path = temp_dir.path().join("app").join("main.py");
+        std::fs::create_dir_all(app_path.parent().unwrap()).unwrap();
+        std::fs::write(
+            &app_path,
+            r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class User(BaseModel):
+    name: str
+
+@app.post("/users")
+def create_user(user: User):
+    return user
+"#,
+        )
+        .unwrap();
+        // Without `title` the contract is named `user` after its file
+        let schema_dir = temp_dir.path().join("schemas");
+        std::fs::create_dir_all(&schema_dir).unwrap();
+        std::fs::write(
+            schema_dir.join("user.schema.json"),
+            json!({
+                "type": "object",
+                "properties": {"name": {"type": "string"}, "email": {"type": "string"}},
+                "required": ["name", "email"]
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let report_path = temp_dir.path().join("report.json");
+        let config_path = temp_dir.path().join("dc-verifier.toml");
+        std::fs::write(
+            &config_path,
+            format!(
+                "project_name = \"demo\"\n\n[[adapters]]\ntype = \"fastapi\"\napp_path = {:?}\n\n[[adapters]]\ntype = \"jsonschema\"\nschema_dir = {:?}\n\n[output]\nformat = \"json\"\npath = {:?}\n",
+                app_path, schema_dir, report_path,
+            ),
+        )
+        .unwrap();
+
+        execute_check(
+            config_path.to_str().unwrap(),
+            None,
+            &CheckOptions::default(),
+        )
+        .unwrap();
+
+        let report: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+        let contracts: Vec<&serde_json::Value> = report["chains"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .flat_map(|chain| chain["contracts"].as_array().unwrap())
+            .filter(|contract| {
+                contract["to_link_id"]
+                    .as_str()
+                    .is_some_and(|id| id.starts_with("jsonschema:"))
+            })
+            .collect();
+        assert!(!contracts.is_empty());
+        assert!(contracts.iter().all(|contract| {
+            contract["from_schema"]["name"] == "User" && contract["to_schema"]["name"] == "user"
+        }));
+        assert!(contracts.iter().any(|contract| {
+            contract["mismatches"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|mismatch| mismatch["path"] == "email")
+        }));
+    }
+
+    #[test]
+    fn test_shared_schema_dir_loads_contracts_once() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let schema_dir = temp_dir.path().join("schemas");
+        std::fs::create_dir_all(&schema_dir).unwrap();
+        std::fs::write(
+            schema_dir.join("user.schema.json"),
+            json!({"type": "object", "properties": {"name": {"type": "string"}}}).to_string(),
+        )
+        .unwrap();
+        let config_path = temp_dir.path().join("dc-verifier.toml");
+        let adapter = format!(
+            "[[adapters]]\ntype = \"jsonschema\"\nschema_dir = {:?}\n\n",
+            schema_dir
+        );
+        std::fs::write(
+            &config_path,
+            format!(
+                "project_name = \"demo\"\n\n{}{}[output]\nformat = \"json\"\npath = \"report.json\"\n",
+                adapter, adapter
+            ),
+        )
+        .unwrap();
+        let config = Config::load(config_path.to_str().unwrap()).unwrap();
+
+        let contracts = load_json_schema_contracts(&config).unwrap();
+
+        assert_eq!(contracts.len(), 1);
+        assert_eq!(contracts[0].name, "user");
+    }
+
+    #[test]
+    fn test_typescript_adapter_reuses_cached_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let src = temp_dir.path().join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::write(
+            src.join("api.ts"),
+            "export function getUser(id: string) { return fetch(`/users/${id}`); }\n",
+        )
+        .unwrap();
+        let config_path = temp_dir.path().join("dc-verifier.toml");
+        std::fs::write(
+            &config_path,
+            format!(
+                "project_name = \"demo\"\ncache_dir = {:?}\n\n[[adapters]]\ntype = \"typescript\"\nsrc_paths = [{:?}]\n\n[output]\nformat = \"json\"\npath = {:?}\n",
+                temp_dir.path().join("cache"),
+                src,
+                temp_dir.path().join("report.json"),
+            ),
+        )
+        .unwrap();
+        let config = Config::load(config_path.to_str().unwrap()).unwrap();
+
+        let cache = typescript_cache(&config).unwrap();
+        for _ in 0..2 {
+            build_adapter_graph(&config, &config.adapters[0], Some(&cache), false)
+                .unwrap()
+                .unwrap();
+        }
+        // The second build takes the unchanged file from `cache_dir`
+        assert_eq!(cache.stats().misses, 1);
+        assert_eq!(cache.stats().hits, 1);
+    }
+
+    #[test]
+    fn test_worker_count_sets_pool_size() {
+        assert_eq!(build_thread_pool(Some(3)).unwrap().current_num_threads(), 3);
+
+        let default_threads = std::thread::available_parallelism().unwrap().get();
+        assert_eq!(
+            build_thread_pool(None).unwrap().current_num_threads(),
+            default_threads
+        );
+    }
 }
@@ -0,0 +1,113 @@
+use crate::config::Config;
+use anyhow::Result;
+use dc_adapter_fastapi::{python_module_version, python_version};
+
+/// Outcome of a single environment check
+#[derive(Debug)]
+pub struct DoctorCheck {
+    /// What was checked
+    pub name: String,
+    /// Whether the check passed
+    pub passed: bool,
+    /// Version, path or error message
+    pub detail: String,
+}
+
+impl DoctorCheck {
+    fn from_result(name: impl Into<String>, result: Result<String>) -> Self {
+        match result {
+            Ok(detail) => Self {
+                name: name.into(),
+                passed: true,
+                detail,
+            },
+            Err(err) => Self {
+                name: name.into(),
+                passed: false,
+                detail: format!("{:#}", err),
+            },
+        }
+    }
+}
+
+/// Sanity-checks the configuration and the Python environment of the FastAPI adapter
+///
+/// Returns `false` when any check failed.
+pub fn execute_doctor(config_path: &str) -> Result<bool> {
+    let mut checks = vec![check_config(config_path)];
+    checks.extend(check_python());
+
+    for check in &checks {
+        let status = if check.passed { "ok" } else { "FAIL" };
+        println!("[{:>4}] {}: {}", status, check.name, check.detail);
+    }
+
+    let passed = checks.iter().all(|check| check.passed);
+    if passed {
+        println!("Environment looks good.");
+    }
+    Ok(passed)
+}
+
+/// Loads and validates the configuration file
+pub fn check_config(config_path: &str) -> DoctorCheck {
+    let result = Config::load(config_path)
+        .map(|config| format!("{} ({} adapter(s))", config_path, config.adapters.len()));
+    DoctorCheck::from_result("config", result)
+}
+
+/// Checks that the embedded Python is reachable and `fastapi`/`pydantic` import
+pub fn check_python() -> Vec<DoctorCheck> {
+    let interpreter = DoctorCheck::from_result("python", python_version());
+    if !interpreter.passed {
+        return vec![interpreter];
+    }
+
+    let mut checks = vec![interpreter];
+    for module in ["fastapi", "pydantic"] {
+        checks.push(DoctorCheck::from_result(
+            module,
+            python_module_version(module),
+        ));
+    }
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_check_config_reports_valid_and_invalid_configs() {
+        let temp_dir = TempDir::new().unwrap();
+        let app_path = temp_dir.path().join("main.py");
+        std::fs::write(&app_path, "app = None\n").unwrap();
+
+        let valid_path = temp_dir.path().join("valid.toml");
+        std::fs::write(
+            &valid_path,
+            format!(
+                "project_name = \"demo\"\n\n[output]\nformat = \"json\"\npath = \"report.json\"\n\n[[adapters]]\ntype = \"fastapi\"\napp_path = {:?}\n",
+                app_path
+            ),
+        )
+        .unwrap();
+        let check = check_config(valid_path.to_str().unwrap());
+        assert!(check.passed, "{}", check.detail);
+        assert!(check.detail.contains("1 adapter"));
+
+        let invalid_path = temp_dir.path().join("invalid.toml");
+        std::fs::write(
+            &invalid_path,
+            "project_name = \"demo\"\nadapters = []\n\n[output]\nformat = \"json\"\npath = \"report.json\"\n",
+        )
+        .unwrap();
+        let check = check_config(invalid_path.to_str().unwrap());
+        assert!(!check.passed);
+        assert!(check.detail.contains("At least one adapter"));
+
+        let missing = check_config(temp_dir.path().join("missing.toml").to_str().unwrap());
+        assert!(!missing.passed);
+    }
+}
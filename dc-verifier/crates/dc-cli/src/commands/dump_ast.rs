@@ -0,0 +1,162 @@
+use anyhow::{Context, Result};
+use dc_core::call_graph::Decorator;
+use dc_core::models::SchemaReference;
+use dc_core::parsers::{
+    is_component_file, Call, Import, LocationConverter, PythonParser, TypeScriptParser,
+};
+use rustpython_parser::{ast, parse, Mode};
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+/// Everything the extractors saw in one source file
+#[derive(Debug, Serialize)]
+pub struct ExtractedFile {
+    /// Source language ("python" or "typescript")
+    pub language: &'static str,
+    pub imports: Vec<Import>,
+    pub calls: Vec<Call>,
+    /// Top-level functions and classes
+    pub functions: Vec<serde_json::Value>,
+    /// Pydantic models, TypeScript interfaces/types and Zod schemas
+    pub schemas: Vec<SchemaReference>,
+    /// Python decorators (always empty for TypeScript)
+    pub decorators: Vec<Decorator>,
+}
+
+/// Prints what the parsers extract from a `.py` or `.ts` file as JSON
+///
+/// Meant for bug reports about parser gaps: the output shows exactly which
+/// imports, calls, definitions, schemas and decorators the analysis works with.
+pub fn execute_dump_ast(path: &Path) -> Result<()> {
+    let extracted = dump_file(path)?;
+    println!("{}", serde_json::to_string_pretty(&extracted)?);
+    Ok(())
+}
+
+/// Runs the parser matching the file extension over `path`
+pub fn dump_file(path: &Path) -> Result<ExtractedFile> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("py") => dump_python(path),
+        Some("ts" | "tsx" | "js" | "jsx") => dump_typescript(path),
+        _ if is_component_file(path) => dump_typescript(path),
+        _ => anyhow::bail!(
+            "Unsupported file: {} (expected .py, .ts, .tsx, .js, .jsx, .vue or .svelte)",
+            path.display()
+        ),
+    }
+}
+
+fn dump_python(path: &Path) -> Result<ExtractedFile> {
+    let source =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let file_path = path.to_string_lossy();
+    let module = parse(&source, Mode::Module, &file_path)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+    let converter = LocationConverter::new(source);
+    let parser = PythonParser::new();
+
+    Ok(ExtractedFile {
+        language: "python",
+        imports: parser.extract_imports(&module, &file_path, &converter),
+        calls: parser.extract_calls(&module, &file_path, &converter),
+        functions: python_definitions(&module, &converter),
+        schemas: parser.extract_pydantic_models(&module, &file_path, &converter),
+        decorators: parser.extract_decorators(&module, &file_path, &converter),
+    })
+}
+
+/// Top-level Python functions and classes (with their methods)
+fn python_definitions(module: &ast::Mod, converter: &LocationConverter) -> Vec<serde_json::Value> {
+    let ast::Mod::Module(module) = module else {
+        return Vec::new();
+    };
+    module
+        .body
+        .iter()
+        .filter_map(|stmt| python_definition(stmt, converter))
+        .collect()
+}
+
+fn python_definition(stmt: &ast::Stmt, converter: &LocationConverter) -> Option<serde_json::Value> {
+    let line_of = |offset: ast::TextSize| converter.byte_offset_to_location(offset.into()).0;
+    match stmt {
+        ast::Stmt::FunctionDef(func) => Some(serde_json::json!({
+            "Function": {"name": func.name.as_str(), "line": line_of(func.range.start()), "is_async": false}
+        })),
+        ast::Stmt::AsyncFunctionDef(func) => Some(serde_json::json!({
+            "Function": {"name": func.name.as_str(), "line": line_of(func.range.start()), "is_async": true}
+        })),
+        ast::Stmt::ClassDef(class) => {
+            let methods: Vec<_> = class
+                .body
+                .iter()
+                .filter_map(|stmt| python_definition(stmt, converter))
+                .collect();
+            Some(serde_json::json!({
+                "Class": {"name": class.name.as_str(), "line": line_of(class.range.start()), "methods": methods}
+            }))
+        }
+        _ => None,
+    }
+}
+
+fn dump_typescript(path: &Path) -> Result<ExtractedFile> {
+    let parser = TypeScriptParser::new();
+    let (module, _, converter) = parser
+        .parse_file(path)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+    let file_path = path.to_string_lossy();
+
+    let functions = parser
+        .extract_functions_and_classes(&module, &file_path, &converter)
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<Result<_, _>>()?;
+    let mut schemas = parser.extract_typescript_schemas(&module, &file_path, &converter);
+    schemas.extend(parser.extract_zod_schemas(&module, &file_path, &converter));
+
+    Ok(ExtractedFile {
+        language: "typescript",
+        imports: parser.extract_imports(&module, &file_path, &converter),
+        calls: parser.extract_calls(&module, &file_path, &converter),
+        functions,
+        schemas,
+        decorators: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dump_python_file_lists_calls() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("main.py");
+        fs::write(
+            &path,
+            r#"
+from db import crud
+
+def create_user(data):
+    user = crud.create(data)
+    notify(user)
+    return user
+"#,
+        )
+        .unwrap();
+
+        let extracted = dump_file(&path).unwrap();
+
+        let names: Vec<&str> = extracted
+            .calls
+            .iter()
+            .map(|call| call.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["crud.create", "notify"]);
+        assert_eq!(extracted.language, "python");
+        assert_eq!(extracted.imports.len(), 1);
+        assert_eq!(extracted.functions[0]["Function"]["name"], "create_user");
+    }
+}
@@ -0,0 +1,108 @@
+use anyhow::Result;
+use dc_core::analyzers::ContractChecker;
+
+/// Prints documentation for a contract rule
+pub fn execute_explain(rule: &str) -> Result<()> {
+    let checker = ContractChecker::new()
+        .with_example_validation()
+        .with_any_flagging();
+    match checker.explain(rule) {
+        Some(doc) => {
+            println!("{}", doc);
+            Ok(())
+        }
+        None => {
+            let known: Vec<&str> = checker.rules().map(|r| r.name()).collect();
+            anyhow::bail!(
+                "Unknown rule: {}. Available rules: {}",
+                rule,
+                known.join(", ")
+            )
+        }
+    }
+}
+
+/// Prints every registered contract rule with a one-line summary
+pub fn execute_list_rules() -> Result<()> {
+    print!(
+        "{}",
+        rule_listing(
+            &ContractChecker::new()
+                .with_example_validation()
+                .with_any_flagging()
+        )
+    );
+    Ok(())
+}
+
+/// One `name  summary` line per rule, summary being the first line of its description
+fn rule_listing(checker: &ContractChecker) -> String {
+    let width = checker
+        .rules()
+        .map(|rule| rule.name().len())
+        .max()
+        .unwrap_or(0);
+    checker
+        .rules()
+        .map(|rule| {
+            let summary = rule.description().lines().next().unwrap_or_default();
+            format!("{:width$}  {}\n", rule.name(), summary, width = width)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_listing_contains_builtin_rules() {
+        let listing = rule_listing(
+            &ContractChecker::new()
+                .with_example_validation()
+                .with_any_flagging(),
+        );
+        for name in [
+            "type_mismatch",
+            "enum_mismatch",
+            "missing_field",
+            "naming_mismatch",
+            "unnormalized_data",
+            "array_size",
+            "invalid_example",
+            "flag_any",
+        ] {
+            assert!(
+                listing.lines().any(|line| line.starts_with(name)),
+                "{} missing from:\n{}",
+                name,
+                listing
+            );
+        }
+        assert!(listing.contains("Checks that every required field"));
+    }
+
+    #[test]
+    fn test_explain_help_names_every_rule() {
+        use clap::CommandFactory;
+
+        let command = crate::Cli::command();
+        let help = command
+            .find_subcommand("explain")
+            .and_then(|explain| explain.get_arguments().find(|arg| arg.get_id() == "rule"))
+            .and_then(|rule| rule.get_help())
+            .unwrap()
+            .to_string();
+        let checker = ContractChecker::new()
+            .with_example_validation()
+            .with_any_flagging();
+        for rule in checker.rules() {
+            assert!(
+                help.contains(rule.name()),
+                "{} missing from: {}",
+                rule.name(),
+                help
+            );
+        }
+    }
+}
@@ -10,6 +10,15 @@ entry_point = "backend/api/main.py"
 # Maximum recursion depth for graph building (optional, None = unlimited)
 # max_recursion_depth = 100
 
+# Maximum number of links in a chain, longer chains are truncated (optional, default = 64)
+# max_chain_length = 32
+
+# Worker threads for building graphs (optional, default = number of logical CPUs)
+# workers = 4
+
+# Payload field of enveloped API responses, e.g. {"data": ..., "meta": ...} (optional)
+# response_envelope = "data"
+
 [[adapters]]
 type = "fastapi"
 app_path = "backend/api/main.py"
@@ -1,3 +1,9 @@
+pub mod api_usage;
 pub mod check;
+pub mod doctor;
+pub mod dump_ast;
+pub mod explain;
 pub mod init;
+pub mod schema_diff;
+pub mod spec_diff;
 pub mod visualize;
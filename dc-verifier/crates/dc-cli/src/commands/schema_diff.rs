@@ -0,0 +1,62 @@
+use crate::baseline::load_report_chains;
+use anyhow::Result;
+use dc_core::analyzers::{schema_compatibility_report, Compatibility, ContractChecker};
+use dc_core::models::{dedupe_schema_refs, DataChain, SchemaReference};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Reports whether each schema of the `new` run is backward-compatible with the `old` run
+///
+/// Both runs are JSON reports (`check --format json`). Returns `false` when any
+/// schema has a breaking change.
+pub fn execute_schema_diff(old_report: &Path, new_report: &Path) -> Result<bool> {
+    let old_schemas = collect_schemas(&load_report_chains(old_report)?);
+    let new_schemas = collect_schemas(&load_report_chains(new_report)?);
+
+    let report = schema_compatibility_report(&old_schemas, &new_schemas, &ContractChecker::new());
+
+    let mut breaking = 0;
+    for schema in &report {
+        let label = match schema.compatibility {
+            Compatibility::Unchanged => continue,
+            Compatibility::Compatible => "compatible",
+            Compatibility::Breaking => {
+                breaking += 1;
+                "BREAKING"
+            }
+        };
+        println!("{} [{}]", schema.name, label);
+        for change in &schema.changes {
+            println!("  - {}", change.description);
+        }
+    }
+
+    let unchanged = report
+        .iter()
+        .filter(|schema| schema.compatibility == Compatibility::Unchanged)
+        .count();
+    println!(
+        "{} schema(s) compared: {} breaking, {} unchanged",
+        report.len(),
+        breaking,
+        unchanged
+    );
+
+    Ok(breaking == 0)
+}
+
+/// Unique schemas (by name) used in the contracts of a run
+///
+/// Duplicate references to one model are collapsed first, so the most complete
+/// one (with a JSON schema) is compared.
+fn collect_schemas(chains: &[DataChain]) -> Vec<SchemaReference> {
+    let references = chains
+        .iter()
+        .flat_map(|chain| &chain.contracts)
+        .flat_map(|contract| [contract.from_schema.clone(), contract.to_schema.clone()]);
+    let mut schemas = BTreeMap::new();
+    for schema in dedupe_schema_refs(references) {
+        schemas.entry(schema.name.clone()).or_insert(schema);
+    }
+    schemas.into_values().collect()
+}
@@ -0,0 +1,62 @@
+use crate::commands::check::fastapi_builder;
+use crate::config::Config;
+use crate::profiling::profile_span;
+use anyhow::Result;
+use dc_core::analyzers::{compare_with_spec, ContractChecker, SpecDrift};
+use dc_core::parsers::OpenApiParser;
+use std::path::Path;
+
+/// Compares FastAPI routes against a committed OpenAPI spec (contract-first)
+///
+/// Returns `false` when the implementation drifted from the spec.
+pub fn execute_spec_diff(config_path: &str, spec_path: &Path) -> Result<bool> {
+    profile_span!("spec_diff");
+    let config = Config::load(config_path)?;
+
+    let endpoints = OpenApiParser::from_path(spec_path)?.extract_endpoints();
+
+    let checker = ContractChecker::new();
+    let mut drifts: Vec<SpecDrift> = Vec::new();
+    let mut fastapi_adapters = 0;
+
+    for adapter_config in &config.adapters {
+        if adapter_config.adapter_type != "fastapi" {
+            continue;
+        }
+        let graph = {
+            profile_span!("build_graph");
+            fastapi_builder(adapter_config)?.build_graph()?
+        };
+        drifts.extend(compare_with_spec(&graph, &endpoints, &checker));
+        fastapi_adapters += 1;
+    }
+
+    if fastapi_adapters == 0 {
+        anyhow::bail!(
+            "spec-diff requires at least one fastapi adapter in {}",
+            config_path
+        );
+    }
+
+    if drifts.is_empty() {
+        println!(
+            "Implementation matches {} ({} endpoints)",
+            spec_path.display(),
+            endpoints.len()
+        );
+        return Ok(true);
+    }
+
+    println!("Drift from {}:", spec_path.display());
+    for drift in &drifts {
+        println!("  - {}", drift);
+        if let SpecDrift::SchemaDrift { mismatches, .. } = drift {
+            for mismatch in mismatches {
+                println!("      {}", mismatch.message);
+            }
+        }
+    }
+    println!("{} drift(s) found", drifts.len());
+
+    Ok(false)
+}
@@ -1,18 +1,59 @@
-use crate::config::Config;
+use crate::commands::check::{fastapi_builder, open_cache, typescript_builder};
+use crate::config::{AdapterConfig, Config, OutputConfig};
+use crate::profiling::profile_span;
 use anyhow::Result;
-use dc_adapter_fastapi::FastApiCallGraphBuilder;
-use dc_core::call_graph::{CallEdge, CallGraph, CallNode};
-use dc_typescript::TypeScriptCallGraphBuilder;
+use dc_adapter_fastapi::PyProjectLayout;
+use dc_core::cache::CacheStore;
+use dc_core::call_graph::{imports_only as filter_imports, CallEdge, CallGraph, CallNode};
+use dc_core::models::normalize_route_path;
 use indicatif::{ProgressBar, ProgressStyle};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Default directory of the incremental cache (see `cache_dir` in the config)
+pub const DEFAULT_CACHE_DIR: &str = ".dc-verifier-cache";
+
+/// Source extensions whose changes invalidate an adapter's DOT file
+const FASTAPI_SOURCE_EXTENSIONS: &[&str] = &["py"];
+const TYPESCRIPT_SOURCE_EXTENSIONS: &[&str] =
+    &["ts", "tsx", "js", "jsx", "mjs", "cjs", "vue", "svelte"];
+
+/// Graphs written and reused by one `visualize` run
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct VisualizeSummary {
+    /// Adapters whose DOT file was (re)generated
+    pub regenerated: Vec<String>,
+    /// Adapters whose sources are unchanged, so the existing DOT file was kept
+    pub skipped: Vec<String>,
+}
 
 /// Visualizes call graphs (optional function)
-pub fn execute_visualize(config_path: &str) -> Result<()> {
+///
+/// With `imports_only`, only modules and import edges are written.
+/// Test files are skipped unless `include_tests` is set.
+///
+/// DOT files are incremental: an adapter whose config and source files (the discovered
+/// ones and every file parsed by the previous run, by content hash in the cache) are
+/// unchanged keeps its existing file.
+pub fn execute_visualize(
+    config_path: &str,
+    imports_only: bool,
+    include_tests: bool,
+) -> Result<VisualizeSummary> {
+    profile_span!("visualize");
     let config = Config::load(config_path)?;
+    let cache = open_cache(&config)?;
+    visualize_with_cache(&config, &cache, imports_only, include_tests)
+}
 
-    // Build graphs for all adapters
-    let mut all_graphs = Vec::new();
+/// Writes the DOT files of `config`'s adapters, reusing unchanged ones recorded in `cache`
+fn visualize_with_cache(
+    config: &Config,
+    cache: &CacheStore,
+    imports_only: bool,
+    include_tests: bool,
+) -> Result<VisualizeSummary> {
+    let mut summary = VisualizeSummary::default();
 
     let pb = ProgressBar::new(config.adapters.len() as u64);
     pb.set_style(
@@ -21,83 +62,88 @@ pub fn execute_visualize(config_path: &str) -> Result<()> {
             .unwrap()
             .progress_chars("#>-"),
     );
-    pb.set_message("Building graphs...");
-
-    for (idx, adapter_config) in config.adapters.iter().enumerate() {
-        pb.set_message(format!(
-            "Processing adapter {} ({})...",
-            idx + 1,
-            adapter_config.adapter_type
-        ));
-        match adapter_config.adapter_type.as_str() {
-            "fastapi" => {
-                let app_path = adapter_config
-                    .app_path
-                    .as_ref()
-                    .ok_or_else(|| anyhow::anyhow!("FastAPI adapter requires app_path"))?;
-                let app_path = PathBuf::from(app_path);
+    pb.set_message("Generating graphs...");
 
-                let builder = FastApiCallGraphBuilder::new(app_path);
-                let graph = builder.build_graph()?;
-                let unique_id = format!("{}_{}", adapter_config.adapter_type, idx);
-                all_graphs.push((unique_id, graph));
-            }
-            "typescript" => {
-                let src_paths = adapter_config
+    let adapter_count = config.adapters.len();
+    for (index, adapter_config) in config.adapters.iter().enumerate() {
+        let adapter_type = adapter_config.adapter_type.as_str();
+        let adapter_name = format!("{}_{}", adapter_type, index);
+        pb.set_message(format!("Processing adapter {}...", adapter_name));
+
+        let (source_dirs, extensions) = match adapter_type {
+            "fastapi" => (
+                fastapi_source_dirs(adapter_config)?,
+                FASTAPI_SOURCE_EXTENSIONS,
+            ),
+            "typescript" => (
+                adapter_config
                     .src_paths
                     .as_ref()
-                    .ok_or_else(|| anyhow::anyhow!("TypeScript adapter requires src_paths"))?;
-                let src_paths: Vec<PathBuf> = src_paths.iter().map(PathBuf::from).collect();
-
-                let builder = TypeScriptCallGraphBuilder::new(src_paths);
-                let graph = builder.build_graph()?;
-                let unique_id = format!("{}_{}", adapter_config.adapter_type, idx);
-                all_graphs.push((unique_id, graph));
+                    .ok_or_else(|| anyhow::anyhow!("TypeScript adapter requires src_paths"))?
+                    .iter()
+                    .map(PathBuf::from)
+                    .collect(),
+                TYPESCRIPT_SOURCE_EXTENSIONS,
+            ),
+            // Standalone schema contracts have no call graph
+            "jsonschema" => {
+                pb.inc(1);
+                continue;
             }
             _ => {
                 eprintln!("Unknown adapter type: {}", adapter_config.adapter_type);
+                pb.inc(1);
+                continue;
             }
-        }
-        pb.inc(1);
-    }
-
-    pb.finish_with_message("Graphs built");
+        };
 
-    // Generate DOT for each graph
-    let pb = ProgressBar::new(all_graphs.len() as u64);
-    pb.set_style(
-        ProgressStyle::default_bar()
-            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} graphs {msg}")
-            .unwrap()
-            .progress_chars("#>-"),
-    );
-    pb.set_message("Generating DOT files...");
+        let output_path =
+            resolve_output_path(&config.output, adapter_type, index, adapter_count, "dot");
+        // The key covers the adapter settings; the hashed files are the discovered
+        // sources, the config files and every file parsed by the previous build
+        let cache_key = format!(
+            "dot:{}:{}:imports_only={}:include_tests={}",
+            output_path.display(),
+            adapter_cache_key(adapter_config),
+            imports_only,
+            include_tests
+        );
+        let mut sources = adapter_config_files(adapter_config);
+        for dir in &source_dirs {
+            collect_source_files(dir, extensions, &mut sources)?;
+        }
+        let mut previous_sources = sources.clone();
+        previous_sources.extend(cache.saved_files(&cache_key)?);
+        previous_sources.sort();
+        previous_sources.dedup();
+        if output_path.exists() && !cache.is_changed_files(&cache_key, &previous_sources)? {
+            summary.skipped.push(adapter_name);
+            pb.inc(1);
+            continue;
+        }
 
-    let adapter_count = config.adapters.len();
-    for (adapter_name, graph) in all_graphs {
-        pb.set_message(format!("Generating DOT for {}...", adapter_name));
-        let dot_content = generate_dot(&graph, &adapter_name)?;
-
-        // Determine output path
-        let output_path = if config.output.path.ends_with(".dot") {
-            let base_path = PathBuf::from(&config.output.path);
-            if adapter_count > 1 {
-                let stem = base_path
-                    .file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("graph");
-                let safe_adapter_name =
-                    adapter_name.replace(|c: char| !c.is_ascii_alphanumeric(), "_");
-                let parent = base_path
-                    .parent()
-                    .map(PathBuf::from)
-                    .unwrap_or_else(|| PathBuf::from("."));
-                parent.join(format!("{}-{}.dot", stem, safe_adapter_name))
-            } else {
-                base_path
+        let graph = {
+            profile_span!("build_graph");
+            match adapter_type {
+                "fastapi" => fastapi_builder(adapter_config)?
+                    .with_include_tests(include_tests)
+                    .build_graph()?,
+                _ => typescript_builder(adapter_config, source_dirs, Some(cache))
+                    .with_include_tests(include_tests)
+                    .build_graph()?,
             }
+        };
+        sources.extend(parsed_files(&graph));
+        sources.sort();
+        sources.dedup();
+        let graph = if imports_only {
+            filter_imports(&graph)
         } else {
-            PathBuf::from(&config.output.path).join(format!("{}.dot", adapter_name))
+            graph
+        };
+        let dot_content = {
+            profile_span!("generate_dot");
+            generate_dot(&graph, &adapter_name)?
         };
 
         // Create directory if needed
@@ -107,16 +153,155 @@ pub fn execute_visualize(config_path: &str) -> Result<()> {
 
         // Save DOT file
         fs::write(&output_path, dot_content)?;
+        cache.save_files_hash(&cache_key, &sources)?;
+        summary.regenerated.push(adapter_name);
         pb.inc(1);
     }
 
     pb.finish_with_message("DOT files generated");
 
-    println!("Visualization completed. DOT files saved.");
+    for adapter_name in &summary.regenerated {
+        println!("Regenerated: {}", adapter_name);
+    }
+    for adapter_name in &summary.skipped {
+        println!("Skipped (sources unchanged): {}", adapter_name);
+    }
+    println!(
+        "Visualization completed: {} regenerated, {} skipped.",
+        summary.regenerated.len(),
+        summary.skipped.len()
+    );
+
+    Ok(summary)
+}
+
+/// Directories with the Python sources of a FastAPI adapter
+fn fastapi_source_dirs(adapter_config: &AdapterConfig) -> Result<Vec<PathBuf>> {
+    if let Some(pyproject) = &adapter_config.pyproject {
+        return Ok(vec![
+            PyProjectLayout::discover(Path::new(pyproject))?.source_root,
+        ]);
+    }
+    let app_path = adapter_config
+        .app_path
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("FastAPI adapter requires app_path or pyproject"))?;
+    let app_dir = Path::new(app_path)
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    Ok(vec![app_dir.to_path_buf()])
+}
+
+/// Files named in an adapter's config (`app_path`, `pyproject`, `tsconfig`)
+fn adapter_config_files(adapter_config: &AdapterConfig) -> Vec<PathBuf> {
+    [
+        &adapter_config.app_path,
+        &adapter_config.pyproject,
+        &adapter_config.tsconfig,
+    ]
+    .into_iter()
+    .flatten()
+    .map(PathBuf::from)
+    .collect()
+}
+
+/// Adapter settings that shape its graph, as part of the DOT cache key
+fn adapter_cache_key(adapter_config: &AdapterConfig) -> String {
+    format!(
+        "type={};app_path={};pyproject={};src_paths={};tsconfig={}",
+        adapter_config.adapter_type,
+        adapter_config.app_path.as_deref().unwrap_or_default(),
+        adapter_config.pyproject.as_deref().unwrap_or_default(),
+        adapter_config
+            .src_paths
+            .as_deref()
+            .unwrap_or_default()
+            .join(","),
+        adapter_config.tsconfig.as_deref().unwrap_or_default()
+    )
+}
+
+/// Files of the modules parsed into `graph`
+fn parsed_files(graph: &CallGraph) -> Vec<PathBuf> {
+    graph
+        .node_weights()
+        .filter_map(|node| match node {
+            CallNode::Module { path } if path.is_file() => Some(path.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Collects source files with the given extensions, skipping hidden and dependency directories
+fn collect_source_files(path: &Path, extensions: &[&str], files: &mut Vec<PathBuf>) -> Result<()> {
+    if path.is_file() {
+        let matches = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| extensions.contains(&ext));
+        if matches {
+            files.push(path.to_path_buf());
+        }
+        return Ok(());
+    }
+
+    if path.is_dir() {
+        for entry in fs::read_dir(path)? {
+            let entry_path = entry?.path();
+            let name = entry_path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or_default();
+            if entry_path.is_dir()
+                && (name.starts_with('.') || name == "node_modules" || name == "__pycache__")
+            {
+                continue;
+            }
+            collect_source_files(&entry_path, extensions, files)?;
+        }
+    }
 
     Ok(())
 }
 
+/// Output file of one adapter's graph
+///
+/// Rules, in order:
+/// - `output.path` containing `{adapter}`: the placeholder is replaced with the
+///   adapter id (`fastapi_0`), e.g. `graphs/{adapter}.dot`
+/// - `output.path` ending with `.<ext>`: used as is for a single adapter; with several
+///   adapters the id is appended to the file stem (`graph.dot` → `graph-fastapi_0.dot`)
+/// - otherwise `output.path` is a directory holding `<adapter id>.<ext>`
+pub fn resolve_output_path(
+    output: &OutputConfig,
+    adapter_name: &str,
+    index: usize,
+    count: usize,
+    ext: &str,
+) -> PathBuf {
+    let adapter_id = format!("{}_{}", adapter_name, index)
+        .replace(|c: char| !c.is_ascii_alphanumeric() && c != '_', "_");
+
+    if output.path.contains("{adapter}") {
+        return PathBuf::from(output.path.replace("{adapter}", &adapter_id));
+    }
+
+    let base_path = Path::new(&output.path);
+    if base_path.extension().and_then(|e| e.to_str()) == Some(ext) {
+        if count <= 1 {
+            return base_path.to_path_buf();
+        }
+        let stem = base_path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("graph");
+        return base_path.with_file_name(format!("{}-{}.{}", stem, adapter_id, ext));
+    }
+
+    base_path.join(format!("{}.{}", adapter_id, ext))
+}
+
 /// Generates DOT format from graph
 fn generate_dot(graph: &CallGraph, graph_name: &str) -> Result<String> {
     let mut dot = String::new();
@@ -140,11 +325,18 @@ fn generate_dot(graph: &CallGraph, graph_name: &str) -> Result<String> {
             let label = format_node_label(node);
             // Escape special characters for DOT
             let escaped_label = escape_dot_string(&label);
-            dot.push_str(&format!("  {} [label=\"{}\"];\n", node_id, escaped_label));
+            if matches!(node, CallNode::ExternalCall { .. }) {
+                dot.push_str(&format!(
+                    "  {} [label=\"{}\", style=dashed, color=gray];\n",
+                    node_id, escaped_label
+                ));
+            } else {
+                dot.push_str(&format!("  {} [label=\"{}\"];\n", node_id, escaped_label));
+            }
         }
     }
 
-    dot.push_str("\n");
+    dot.push('\n');
 
     // Add edges
     for edge_idx in graph.edge_indices() {
@@ -186,6 +378,9 @@ fn format_node_label(node: &CallNode) -> String {
         CallNode::Method { name, .. } => {
             format!("Method: {}", name)
         }
+        CallNode::ExternalCall { name } => {
+            format!("External: {}", name)
+        }
         CallNode::Route { path, method, .. } => {
             let method_str = match method {
                 dc_core::call_graph::HttpMethod::Get => "GET",
@@ -196,7 +391,7 @@ fn format_node_label(node: &CallNode) -> String {
                 dc_core::call_graph::HttpMethod::Options => "OPTIONS",
                 dc_core::call_graph::HttpMethod::Head => "HEAD",
             };
-            format!("Route: {} {}", method_str, path)
+            format!("Route: {} {}", method_str, normalize_route_path(path))
         }
     }
 }
@@ -230,3 +425,172 @@ fn escape_dot_string(s: &str) -> String {
         .replace("\r", "\\r")
         .replace("\t", "\\t")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn output(path: &str) -> OutputConfig {
+        OutputConfig {
+            format: crate::ReportFormat::Markdown,
+            path: path.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_single_adapter_writes_configured_file() {
+        let path = resolve_output_path(&output("out/graph.dot"), "fastapi", 0, 1, "dot");
+        assert_eq!(path, PathBuf::from("out/graph.dot"));
+    }
+
+    #[test]
+    fn test_multiple_adapters_get_distinct_files() {
+        let file = output("out/graph.dot");
+        assert_eq!(
+            resolve_output_path(&file, "fastapi", 0, 2, "dot"),
+            PathBuf::from("out/graph-fastapi_0.dot")
+        );
+        assert_eq!(
+            resolve_output_path(&file, "typescript", 1, 2, "dot"),
+            PathBuf::from("out/graph-typescript_1.dot")
+        );
+
+        let dir = output("graphs");
+        assert_eq!(
+            resolve_output_path(&dir, "typescript", 1, 2, "dot"),
+            PathBuf::from("graphs/typescript_1.dot")
+        );
+    }
+
+    /// Config of one FastAPI adapter at `app/main.py` writing DOT files into `graphs/`
+    fn fastapi_config(temp_dir: &Path) -> (Config, PathBuf) {
+        let app_path = temp_dir.join("app").join("main.py");
+        fs::create_dir_all(app_path.parent().unwrap()).unwrap();
+        fs::write(&app_path, "def list_users():\n    return []\n").unwrap();
+        let config_path = temp_dir.join("dc-verifier.toml");
+        fs::write(
+            &config_path,
+            format!(
+                "project_name = \"demo\"\ncache_dir = {:?}\n\n[[adapters]]\ntype = \"fastapi\"\napp_path = {:?}\n\n[output]\nformat = \"markdown\"\npath = {:?}\n",
+                temp_dir.join("cache"),
+                app_path,
+                temp_dir.join("graphs"),
+            ),
+        )
+        .unwrap();
+        (
+            Config::load(config_path.to_str().unwrap()).unwrap(),
+            app_path,
+        )
+    }
+
+    #[test]
+    fn test_unchanged_adapter_is_not_regenerated() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let (config, app_path) = fastapi_config(temp_dir.path());
+        let cache = CacheStore::new(config.cache_dir.as_deref().unwrap()).unwrap();
+
+        let first = visualize_with_cache(&config, &cache, false, false).unwrap();
+        assert_eq!(first.regenerated, ["fastapi_0"]);
+
+        let second = visualize_with_cache(&config, &cache, false, false).unwrap();
+        assert!(second.regenerated.is_empty());
+        assert_eq!(second.skipped, ["fastapi_0"]);
+
+        fs::write(&app_path, "def list_orders():\n    return []\n").unwrap();
+        let third = visualize_with_cache(&config, &cache, false, false).unwrap();
+        assert_eq!(third.regenerated, ["fastapi_0"]);
+    }
+
+    #[test]
+    fn test_adapter_config_change_regenerates() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let (mut config, app_path) = fastapi_config(temp_dir.path());
+        let cache = CacheStore::new(config.cache_dir.as_deref().unwrap()).unwrap();
+
+        let first = visualize_with_cache(&config, &cache, false, false).unwrap();
+        assert_eq!(first.regenerated, ["fastapi_0"]);
+
+        // Another entry point in the same directory is a different graph
+        let other_app = app_path.with_file_name("other.py");
+        fs::write(&other_app, "def list_orders():\n    return []\n").unwrap();
+        config.adapters[0].app_path = Some(other_app.to_string_lossy().to_string());
+        let second = visualize_with_cache(&config, &cache, false, false).unwrap();
+        assert_eq!(second.regenerated, ["fastapi_0"]);
+    }
+
+    #[test]
+    fn test_parsed_file_outside_src_paths_regenerates() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let src = temp_dir.path().join("src");
+        let shared = temp_dir.path().join("shared");
+        fs::create_dir_all(&src).unwrap();
+        fs::create_dir_all(&shared).unwrap();
+        let format_file = shared.join("format.ts");
+        fs::write(
+            &format_file,
+            "export function format(s: string) { return s; }\n",
+        )
+        .unwrap();
+        fs::write(
+            src.join("app.ts"),
+            "import { format } from '@shared/format';\n\nexport function main() { return format('x'); }\n",
+        )
+        .unwrap();
+        let tsconfig = temp_dir.path().join("tsconfig.json");
+        fs::write(
+            &tsconfig,
+            r#"{"compilerOptions": {"baseUrl": ".", "paths": {"@shared/*": ["shared/*"]}}}"#,
+        )
+        .unwrap();
+        let config_path = temp_dir.path().join("dc-verifier.toml");
+        fs::write(
+            &config_path,
+            format!(
+                "project_name = \"demo\"\ncache_dir = {:?}\n\n[[adapters]]\ntype = \"typescript\"\nsrc_paths = [{:?}]\ntsconfig = {:?}\n\n[output]\nformat = \"markdown\"\npath = {:?}\n",
+                temp_dir.path().join("cache"),
+                src,
+                tsconfig,
+                temp_dir.path().join("graphs"),
+            ),
+        )
+        .unwrap();
+        let config = Config::load(config_path.to_str().unwrap()).unwrap();
+        let cache = CacheStore::new(config.cache_dir.as_deref().unwrap()).unwrap();
+
+        let first = visualize_with_cache(&config, &cache, false, false).unwrap();
+        assert_eq!(first.regenerated, ["typescript_0"]);
+        let second = visualize_with_cache(&config, &cache, false, false).unwrap();
+        assert_eq!(second.skipped, ["typescript_0"]);
+
+        // `shared/format.ts` is only reached through the tsconfig alias
+        fs::write(
+            &format_file,
+            "export function format(s: string) { return s.trim(); }\n",
+        )
+        .unwrap();
+        let third = visualize_with_cache(&config, &cache, false, false).unwrap();
+        assert_eq!(third.regenerated, ["typescript_0"]);
+    }
+
+    #[test]
+    fn test_directory_output_names_file_after_adapter() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let (config, _) = fastapi_config(temp_dir.path());
+        let cache = CacheStore::new(config.cache_dir.as_deref().unwrap()).unwrap();
+
+        visualize_with_cache(&config, &cache, false, false).unwrap();
+        let graphs = temp_dir.path().join("graphs");
+        let files: Vec<_> = fs::read_dir(&graphs)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(files, ["fastapi_0.dot"]);
+    }
+
+    #[test]
+    fn test_adapter_placeholder_is_replaced() {
+        let path = resolve_output_path(&output("graphs/{adapter}.dot"), "fastapi", 0, 1, "dot");
+        assert_eq!(path, PathBuf::from("graphs/fastapi_0.dot"));
+    }
+}
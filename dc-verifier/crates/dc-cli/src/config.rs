@@ -1,7 +1,11 @@
+use crate::ReportFormat;
 use anyhow::{Context, Result};
+use dc_core::analyzers::ContractChecker;
+use dc_core::models::{AllowRule, Severity};
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Project configuration
 #[derive(Debug, Deserialize)]
@@ -14,6 +18,20 @@ pub struct Config {
     pub output: OutputConfig,
     /// Maximum recursion depth for graph building (None = unlimited)
     pub max_recursion_depth: Option<usize>,
+    /// Maximum number of links in a chain; longer chains are truncated (None = 64)
+    pub max_chain_length: Option<usize>,
+    /// Number of worker threads for building graphs (None = number of logical CPUs)
+    pub workers: Option<usize>,
+    /// Security-relevant field names whose mismatches are always critical
+    /// (None = built-in list: password, token, secret, ssn, ...)
+    pub sensitive_fields: Option<Vec<String>>,
+    /// Path to the payload inside response envelopes (e.g. `data` for `{"data": ..., "meta": ...}`)
+    pub response_envelope: Option<String>,
+    /// Directory of the incremental cache (None = `.dc-verifier-cache`)
+    pub cache_dir: Option<String>,
+    /// Intentional transformations that are not reported (`[[allow]]` tables)
+    #[serde(default)]
+    pub allow: Vec<AllowRule>,
 }
 
 /// Adapter configuration
@@ -23,31 +41,112 @@ pub struct AdapterConfig {
     pub adapter_type: String,
     pub app_path: Option<String>,
     pub src_paths: Option<Vec<String>>,
+    /// FastAPI: `pyproject.toml` to discover the source root and entry point from
+    pub pyproject: Option<String>,
+    /// JSON Schema: directory of `*.schema.json` contracts
+    pub schema_dir: Option<String>,
+    /// TypeScript: `tsconfig.json` whose `compilerOptions.paths` resolve aliased imports
+    pub tsconfig: Option<String>,
 }
 
-/// Rules configuration
+/// Rules configuration: severity per rule (`info`, `warning`, `error`, `critical`)
 #[derive(Debug, Deserialize)]
-#[allow(dead_code)]
 pub struct RulesConfig {
     pub type_mismatch: Option<String>,
+    pub enum_mismatch: Option<String>,
     pub missing_field: Option<String>,
+    pub naming_mismatch: Option<String>,
     pub unnormalized_data: Option<String>,
+    pub array_size: Option<String>,
+    /// Optional: validates schema examples against their own schema when enabled
+    pub invalid_example: Option<String>,
+    /// Optional: flags `Any`/`unknown`/`dict` fields opposite typed ones when enabled
+    pub flag_any: Option<String>,
+    /// Optional rules to run in addition to the default ones
+    #[serde(default)]
+    pub enable: Vec<String>,
+    /// Default rules to turn off (`naming_mismatch`: renamed fields become missing ones)
+    #[serde(default)]
+    pub disable: Vec<String>,
+}
+
+/// Rules that only run when listed in `rules.enable`
+pub const OPTIONAL_RULES: &[&str] = &["invalid_example", "flag_any"];
+
+impl RulesConfig {
+    /// Whether the optional rule `name` is listed in `enable`
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.enable.iter().any(|rule| rule == name)
+    }
+
+    /// Checks that `enable` only lists optional rules and `disable` only default ones
+    pub fn validate(&self) -> Result<()> {
+        let checker = ContractChecker::new();
+        for rule in &self.disable {
+            if checker.find_rule(rule).is_none() {
+                anyhow::bail!("rules.disable: unknown rule '{}'", rule);
+            }
+        }
+        for rule in &self.enable {
+            if !OPTIONAL_RULES.contains(&rule.as_str()) {
+                anyhow::bail!(
+                    "rules.enable: unknown optional rule '{}'. Supported: {}",
+                    rule,
+                    OPTIONAL_RULES.join(", ")
+                );
+            }
+        }
+        self.severities().map(|_| ())
+    }
+
+    /// Configured severities by rule name; rules that are not set keep their default
+    pub fn severities(&self) -> Result<HashMap<String, Severity>> {
+        [
+            ("type_mismatch", &self.type_mismatch),
+            ("enum_mismatch", &self.enum_mismatch),
+            ("missing_field", &self.missing_field),
+            ("naming_mismatch", &self.naming_mismatch),
+            ("unnormalized_data", &self.unnormalized_data),
+            ("array_size", &self.array_size),
+            ("invalid_example", &self.invalid_example),
+            ("flag_any", &self.flag_any),
+        ]
+        .into_iter()
+        .filter_map(|(rule, severity)| severity.as_ref().map(|severity| (rule, severity)))
+        .map(|(rule, severity)| {
+            let severity = severity
+                .parse()
+                .with_context(|| format!("rules.{}", rule))?;
+            Ok((rule.to_string(), severity))
+        })
+        .collect()
+    }
 }
 
 /// Output configuration
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
 pub struct OutputConfig {
-    pub format: String,
+    pub format: ReportFormat,
     pub path: String,
 }
 
+impl OutputConfig {
+    /// Report format of a run: the `--format` flag takes precedence over `output.format`
+    pub fn resolve_format(&self, flag: Option<ReportFormat>) -> ReportFormat {
+        flag.unwrap_or(self.format)
+    }
+}
+
 impl Config {
     /// Loads configuration from a file
+    ///
+    /// An optional `extends = "../base.toml"` key (relative to the config's
+    /// directory) merges the base config first; see [`merge_tables`].
     pub fn load(path: &str) -> Result<Self> {
-        let content = fs::read_to_string(Path::new(path))
-            .with_context(|| format!("Failed to read config file: {}", path))?;
-        let config: Config = toml::from_str(&content)
+        let table = load_table(Path::new(path), false, &mut Vec::new())?;
+        let config: Config = table
+            .try_into()
             .with_context(|| format!("Failed to parse config file: {}", path))?;
         config.validate()?;
         Ok(config)
@@ -69,16 +168,27 @@ impl Config {
             // Validate adapter_type
             match adapter.adapter_type.as_str() {
                 "fastapi" => {
-                    // For FastAPI, app_path is required
-                    let app_path = adapter.app_path.as_ref().ok_or_else(|| {
-                        anyhow::anyhow!("Adapter {}: FastAPI adapter requires app_path", idx)
-                    })?;
-                    let path = Path::new(app_path);
-                    if !path.exists() {
-                        anyhow::bail!("Adapter {}: app_path does not exist: {}", idx, app_path);
+                    // For FastAPI, app_path or pyproject is required
+                    if adapter.app_path.is_none() && adapter.pyproject.is_none() {
+                        anyhow::bail!(
+                            "Adapter {}: FastAPI adapter requires app_path or pyproject",
+                            idx
+                        );
                     }
-                    if !path.is_file() {
-                        anyhow::bail!("Adapter {}: app_path must be a file: {}", idx, app_path);
+                    for (key, value) in [
+                        ("app_path", &adapter.app_path),
+                        ("pyproject", &adapter.pyproject),
+                    ] {
+                        let Some(value) = value else {
+                            continue;
+                        };
+                        let path = Path::new(value);
+                        if !path.exists() {
+                            anyhow::bail!("Adapter {}: {} does not exist: {}", idx, key, value);
+                        }
+                        if !path.is_file() {
+                            anyhow::bail!("Adapter {}: {} must be a file: {}", idx, key, value);
+                        }
                     }
                 }
                 "typescript" => {
@@ -108,10 +218,27 @@ impl Config {
                             );
                         }
                     }
+                    if let Some(tsconfig) = &adapter.tsconfig {
+                        if !Path::new(tsconfig).is_file() {
+                            anyhow::bail!("Adapter {}: tsconfig must be a file: {}", idx, tsconfig);
+                        }
+                    }
+                }
+                "jsonschema" => {
+                    let schema_dir = adapter.schema_dir.as_ref().ok_or_else(|| {
+                        anyhow::anyhow!("Adapter {}: JSON Schema adapter requires schema_dir", idx)
+                    })?;
+                    if !Path::new(schema_dir).is_dir() {
+                        anyhow::bail!(
+                            "Adapter {}: schema_dir must be a directory: {}",
+                            idx,
+                            schema_dir
+                        );
+                    }
                 }
                 _ => {
                     anyhow::bail!(
-                        "Adapter {}: Unknown adapter type: {}. Supported types: fastapi, typescript",
+                        "Adapter {}: Unknown adapter type: {}. Supported types: fastapi, typescript, jsonschema",
                         idx,
                         adapter.adapter_type
                     );
@@ -119,13 +246,20 @@ impl Config {
             }
         }
 
-        // Validate output format
-        match self.output.format.as_str() {
-            "markdown" | "json" => {}
-            _ => {
+        if let Some(rules) = &self.rules {
+            rules.validate()?;
+        }
+
+        if self.workers == Some(0) {
+            anyhow::bail!("workers must be at least 1");
+        }
+
+        // Validate allowlist entries
+        for (idx, rule) in self.allow.iter().enumerate() {
+            if rule.route.is_none() && rule.path.is_none() && rule.mismatch_type.is_none() {
                 anyhow::bail!(
-                    "Invalid output format: {}. Supported formats: markdown, json",
-                    self.output.format
+                    "allow[{}]: at least one of route, path or mismatch_type must be set",
+                    idx
                 );
             }
         }
@@ -138,3 +272,243 @@ impl Config {
         Ok(())
     }
 }
+
+/// Reads a config file as a TOML table with its `extends` chain merged in
+///
+/// Relative paths of a base config (`is_base`) are rebased onto its directory.
+fn load_table(path: &Path, is_base: bool, visited: &mut Vec<PathBuf>) -> Result<toml::Table> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+    let mut table: toml::Table = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse config file: {}", path.display()))?;
+    if is_base {
+        rebase_paths(&mut table, path.parent().unwrap_or(Path::new(".")));
+    }
+
+    let Some(extends) = table.remove("extends") else {
+        return Ok(table);
+    };
+    let extends = extends
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("{}: extends must be a path", path.display()))?;
+
+    let canonical = path
+        .canonicalize()
+        .with_context(|| format!("Failed to resolve config path: {}", path.display()))?;
+    if visited.contains(&canonical) {
+        anyhow::bail!("Cyclic extends: {} is extended again", path.display());
+    }
+    visited.push(canonical);
+
+    let base_path = path.parent().unwrap_or(Path::new(".")).join(extends);
+    let mut base = load_table(&base_path, true, visited)?;
+    merge_tables(&mut base, table);
+    Ok(base)
+}
+
+/// Joins relative path values of a config table onto `dir`
+fn rebase_paths(table: &mut toml::Table, dir: &Path) {
+    fn rebase(value: &mut toml::Value, dir: &Path) {
+        match value {
+            toml::Value::String(path) if Path::new(path.as_str()).is_relative() => {
+                *path = dir.join(path.as_str()).to_string_lossy().to_string();
+            }
+            toml::Value::Array(paths) => paths.iter_mut().for_each(|path| rebase(path, dir)),
+            _ => {}
+        }
+    }
+
+    for key in ["entry_point", "cache_dir"] {
+        if let Some(value) = table.get_mut(key) {
+            rebase(value, dir);
+        }
+    }
+    if let Some(path) = table
+        .get_mut("output")
+        .and_then(|output| output.get_mut("path"))
+    {
+        rebase(path, dir);
+    }
+    let adapters = table
+        .get_mut("adapters")
+        .and_then(toml::Value::as_array_mut)
+        .into_iter()
+        .flatten()
+        .filter_map(toml::Value::as_table_mut);
+    for adapter in adapters {
+        for key in [
+            "app_path",
+            "src_paths",
+            "pyproject",
+            "schema_dir",
+            "tsconfig",
+        ] {
+            if let Some(value) = adapter.get_mut(key) {
+                rebase(value, dir);
+            }
+        }
+    }
+}
+
+/// Merges a child config into its base
+///
+/// Tables are merged key by key, `adapters` and `allow` lists are concatenated
+/// (base entries first), and any other child value overrides the base one.
+fn merge_tables(base: &mut toml::Table, child: toml::Table) {
+    for (key, child_value) in child {
+        match (base.get_mut(&key), child_value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(child_table)) => {
+                merge_tables(base_table, child_table);
+            }
+            (Some(toml::Value::Array(base_list)), toml::Value::Array(child_list))
+                if key == "adapters" || key == "allow" =>
+            {
+                base_list.extend(child_list);
+            }
+            (_, child_value) => {
+                base.insert(key, child_value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_child_config_inherits_base_adapters() {
+        let temp_dir = TempDir::new().unwrap();
+        let app_path = temp_dir.path().join("main.py");
+        fs::write(&app_path, "").unwrap();
+        let frontend = temp_dir.path().join("frontend");
+        fs::create_dir(&frontend).unwrap();
+
+        fs::write(
+            temp_dir.path().join("base.toml"),
+            format!(
+                "project_name = \"base\"\nmax_recursion_depth = 50\n\n[output]\nformat = \"json\"\npath = \"report.json\"\n\n[[adapters]]\ntype = \"fastapi\"\napp_path = {:?}\n",
+                app_path
+            ),
+        )
+        .unwrap();
+        let package_dir = temp_dir.path().join("package");
+        fs::create_dir(&package_dir).unwrap();
+        let child_path = package_dir.join("dc-verifier.toml");
+        fs::write(
+            &child_path,
+            format!(
+                "extends = \"../base.toml\"\nproject_name = \"package\"\n\n[output]\npath = \"package.json\"\n\n[[adapters]]\ntype = \"typescript\"\nsrc_paths = [{:?}]\n",
+                frontend
+            ),
+        )
+        .unwrap();
+
+        let config = Config::load(child_path.to_str().unwrap()).unwrap();
+
+        assert_eq!(config.project_name, "package");
+        assert_eq!(config.max_recursion_depth, Some(50));
+        assert_eq!(config.output.format, ReportFormat::Json);
+        assert_eq!(config.output.path, "package.json");
+        let adapter_types: Vec<_> = config
+            .adapters
+            .iter()
+            .map(|adapter| adapter.adapter_type.as_str())
+            .collect();
+        assert_eq!(adapter_types, vec!["fastapi", "typescript"]);
+    }
+
+    #[test]
+    fn test_base_config_paths_are_relative_to_base() {
+        let temp_dir = TempDir::new().unwrap();
+        let backend = temp_dir.path().join("backend");
+        fs::create_dir(&backend).unwrap();
+        fs::write(backend.join("main.py"), "").unwrap();
+        fs::write(
+            temp_dir.path().join("base.toml"),
+            "project_name = \"base\"\n\n[output]\nformat = \"json\"\npath = \"report.json\"\n\n[[adapters]]\ntype = \"fastapi\"\napp_path = \"backend/main.py\"\n",
+        )
+        .unwrap();
+        let package_dir = temp_dir.path().join("package");
+        fs::create_dir(&package_dir).unwrap();
+        let child_path = package_dir.join("dc-verifier.toml");
+        fs::write(&child_path, "extends = \"../base.toml\"\n").unwrap();
+
+        let config = Config::load(child_path.to_str().unwrap()).unwrap();
+
+        let base_dir = package_dir.join("..");
+        assert_eq!(
+            config.adapters[0].app_path.as_deref().map(Path::new),
+            Some(base_dir.join("backend/main.py").as_path())
+        );
+        assert_eq!(
+            Path::new(&config.output.path),
+            base_dir.join("report.json").as_path()
+        );
+    }
+
+    #[test]
+    fn test_cyclic_extends_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("a.toml"), "extends = \"b.toml\"\n").unwrap();
+        fs::write(temp_dir.path().join("b.toml"), "extends = \"a.toml\"\n").unwrap();
+
+        let error = Config::load(temp_dir.path().join("a.toml").to_str().unwrap()).unwrap_err();
+        assert!(error.to_string().contains("Cyclic extends"));
+    }
+
+    #[test]
+    fn test_invalid_output_format_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let app_path = temp_dir.path().join("main.py");
+        fs::write(&app_path, "").unwrap();
+        let config_path = temp_dir.path().join("dc-verifier.toml");
+        fs::write(
+            &config_path,
+            format!(
+                "project_name = \"app\"\n\n[output]\nformat = \"xml\"\npath = \"report.xml\"\n\n[[adapters]]\ntype = \"fastapi\"\napp_path = {:?}\n",
+                app_path
+            ),
+        )
+        .unwrap();
+
+        let error = Config::load(config_path.to_str().unwrap()).unwrap_err();
+        let message = format!("{:#}", error);
+        assert_eq!(message.matches("Invalid output format: xml").count(), 1);
+        assert!(message.contains("Supported formats: markdown, json, lsp"));
+    }
+
+    #[test]
+    fn test_rules_are_enabled_and_disabled_explicitly() {
+        let rules: RulesConfig =
+            toml::from_str("invalid_example = \"error\"\nenable = [\"invalid_example\"]\n")
+                .unwrap();
+        assert!(rules.is_enabled("invalid_example"));
+        assert!(rules.validate().is_ok());
+
+        // A severity alone does not enable an optional rule
+        let rules: RulesConfig = toml::from_str("invalid_example = \"error\"\n").unwrap();
+        assert!(!rules.is_enabled("invalid_example"));
+
+        let rules: RulesConfig = toml::from_str("enable = [\"type_mismatch\"]\n").unwrap();
+        let error = rules.validate().unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("unknown optional rule 'type_mismatch'"));
+    }
+
+    #[test]
+    fn test_format_flag_overrides_config() {
+        let output = OutputConfig {
+            format: ReportFormat::Json,
+            path: "report.json".to_string(),
+        };
+
+        assert_eq!(output.resolve_format(None), ReportFormat::Json);
+        assert_eq!(
+            output.resolve_format(Some(ReportFormat::Lsp)),
+            ReportFormat::Lsp
+        );
+    }
+}
@@ -1,22 +1,90 @@
 use anyhow::Result;
-use clap::Parser;
+use clap::{CommandFactory, Parser};
+use std::path::PathBuf;
 
+mod baseline;
 mod commands;
 mod config;
+mod profiling;
 mod reporters;
 
 #[derive(Parser)]
 #[command(name = "dc-verifier")]
 #[command(about = "Data Chains Verifier - data chain integrity verification")]
 struct Cli {
+    /// Print documentation for a rule (e.g. type_mismatch) and exit
+    #[arg(long, value_name = "RULE")]
+    explain: Option<String>,
+    /// List the available contract rules and exit
+    #[arg(long)]
+    list_rules: bool,
+    /// Write a folded-stack profile of the run phases (requires the `profiling` feature)
+    #[arg(long, value_name = "FILE", global = true)]
+    profile: Option<PathBuf>,
     #[command(subcommand)]
-    command: Commands,
+    command: Option<Commands>,
 }
 
-#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+/// Report format, set by `output.format` in the config or the `--format` flag
+#[derive(clap::ValueEnum, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(try_from = "String")]
 pub enum ReportFormat {
     Markdown,
     Json,
+    /// LSP-style diagnostics grouped by file (for editor plugins)
+    Lsp,
+}
+
+impl std::str::FromStr for ReportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        <Self as clap::ValueEnum>::from_str(value, false).map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid output format: {}. Supported formats: markdown, json, lsp",
+                value
+            )
+        })
+    }
+}
+
+impl TryFrom<&str> for ReportFormat {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for ReportFormat {
+    type Error = anyhow::Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+/// How findings are grouped in the report
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReportGrouping {
+    /// Per chain (default)
+    #[default]
+    Chain,
+    /// Per (from schema, to schema) pair, listing the affected chains
+    Schema,
+}
+
+/// Condition under which `check` exits with a non-zero code
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FailOn {
+    /// Findings that are not in the baseline (requires --baseline)
+    New,
+    /// Any contract with critical severity
+    Critical,
+    /// Any contract with error or critical severity
+    Error,
+    /// Any contract with warning severity or above
+    Warning,
 }
 
 #[derive(clap::Subcommand)]
@@ -26,9 +94,42 @@ enum Commands {
         /// Path to configuration file
         #[arg(short, long, default_value = "dc-verifier.toml")]
         config: String,
-        /// Report format (markdown or json)
-        #[arg(short, long, value_enum, default_value_t = ReportFormat::Markdown)]
-        format: ReportFormat,
+        /// Report format (markdown, json or lsp); overrides output.format of the config
+        #[arg(short, long, value_enum)]
+        format: Option<ReportFormat>,
+        /// Group findings per chain or per schema pair (markdown and json)
+        #[arg(long, value_enum, default_value_t = ReportGrouping::Chain)]
+        group_by: ReportGrouping,
+        /// Previous JSON report to compare findings against
+        #[arg(long, value_name = "FILE")]
+        baseline: Option<PathBuf>,
+        /// Exit with code 1 when findings of this kind are present
+        #[arg(long, value_enum)]
+        fail_on: Option<FailOn>,
+        /// Save this run's findings as a baseline (JSON report) to FILE
+        #[arg(long, value_name = "FILE")]
+        write_baseline: Option<PathBuf>,
+        /// Overwrite an existing --write-baseline file
+        #[arg(long, requires = "write_baseline")]
+        force: bool,
+        /// Only write the baseline when the run passes --fail-on
+        #[arg(long, requires = "write_baseline")]
+        baseline_update_on_pass: bool,
+        /// Number of worker threads for building graphs (default: logical CPUs)
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+        workers: Option<u64>,
+        /// Analyze test files (test_*.py, *.test.ts, __tests__/), skipped by default
+        #[arg(long)]
+        include_tests: bool,
+        /// Only report chains with a link under this file or directory
+        #[arg(long, value_name = "DIR_OR_FILE")]
+        path: Option<PathBuf>,
+        /// Print only "OK" and write no report when there are no findings
+        #[arg(long)]
+        quiet_success: bool,
+        /// Write a JSON map of how fully each route is typed to FILE
+        #[arg(long, value_name = "FILE")]
+        emit_coverage_map: Option<PathBuf>,
     },
     /// Create configuration file
     Init {
@@ -41,22 +142,162 @@ enum Commands {
         /// Path to configuration file
         #[arg(short, long, default_value = "dc-verifier.toml")]
         config: String,
+        /// Only output the module import graph (no functions or calls)
+        #[arg(long)]
+        imports_only: bool,
+        /// Analyze test files (test_*.py, *.test.ts, __tests__/), skipped by default
+        #[arg(long)]
+        include_tests: bool,
+    },
+    /// Report backward compatibility of schemas between two JSON reports
+    SchemaDiff {
+        /// JSON report of the previous revision
+        #[arg(long, value_name = "FILE")]
+        old: PathBuf,
+        /// JSON report of the new revision
+        #[arg(long, value_name = "FILE")]
+        new: PathBuf,
+    },
+    /// Compare FastAPI routes and schemas against a committed OpenAPI spec
+    SpecDiff {
+        /// Path to configuration file
+        #[arg(short, long, default_value = "dc-verifier.toml")]
+        config: String,
+        /// Reference OpenAPI spec (JSON or YAML, by extension)
+        #[arg(long, value_name = "FILE")]
+        spec: PathBuf,
+    },
+    /// Match frontend HTTP calls against the endpoints of one or more OpenAPI specs
+    ApiUsage {
+        /// Path to configuration file
+        #[arg(short, long, default_value = "dc-verifier.toml")]
+        config: String,
+        /// OpenAPI spec (JSON/YAML) or `.proto` file; repeat for several services
+        #[arg(long = "spec", value_name = "FILE", required = true)]
+        specs: Vec<PathBuf>,
+    },
+    /// Check the configuration and the Python environment of the FastAPI adapter
+    Doctor {
+        /// Path to configuration file
+        #[arg(short, long, default_value = "dc-verifier.toml")]
+        config: String,
+    },
+    /// Explain what a rule checks and how to fix its findings
+    Explain {
+        /// Rule name (type_mismatch, enum_mismatch, missing_field, naming_mismatch, unnormalized_data, array_size, invalid_example, flag_any)
+        rule: String,
+    },
+    /// Dump what the parsers extract from a .py or .ts file as JSON (for bug reports)
+    #[command(hide = true)]
+    DumpAst {
+        /// Source file to parse
+        file: PathBuf,
     },
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    match cli.command {
-        Commands::Check { config, format } => {
-            commands::check::execute_check(&config, format)?;
+    if let Some(rule) = cli.explain {
+        return commands::explain::execute_explain(&rule);
+    }
+    if cli.list_rules {
+        return commands::explain::execute_list_rules();
+    }
+
+    let Some(command) = cli.command else {
+        Cli::command().print_help()?;
+        return Ok(());
+    };
+
+    let profiler = profiling::Profiler::start(cli.profile.as_deref())?;
+
+    match command {
+        Commands::Check {
+            config,
+            format,
+            group_by,
+            baseline,
+            fail_on,
+            workers,
+            write_baseline,
+            force,
+            baseline_update_on_pass,
+            include_tests,
+            path,
+            quiet_success,
+            emit_coverage_map,
+        } => {
+            let options = commands::check::CheckOptions {
+                baseline,
+                fail_on,
+                workers: workers.map(|workers| workers as usize),
+                write_baseline,
+                force,
+                baseline_update_on_pass,
+                include_tests,
+                group_by,
+                // Canonicalized once: chain links are compared against it
+                path: path.map(|path| path.canonicalize().unwrap_or(path)),
+                quiet_success,
+                coverage_map: emit_coverage_map,
+            };
+            if !commands::check::execute_check(&config, format, &options)? {
+                if let Some(profiler) = profiler {
+                    profiler.finish()?;
+                }
+                std::process::exit(1);
+            }
         }
         Commands::Init { path } => {
             commands::init::execute_init(&path)?;
         }
-        Commands::Visualize { config } => {
-            commands::visualize::execute_visualize(&config)?;
+        Commands::Visualize {
+            config,
+            imports_only,
+            include_tests,
+        } => {
+            commands::visualize::execute_visualize(&config, imports_only, include_tests)?;
+        }
+        Commands::SchemaDiff { old, new } => {
+            if !commands::schema_diff::execute_schema_diff(&old, &new)? {
+                if let Some(profiler) = profiler {
+                    profiler.finish()?;
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::SpecDiff { config, spec } => {
+            if !commands::spec_diff::execute_spec_diff(&config, &spec)? {
+                if let Some(profiler) = profiler {
+                    profiler.finish()?;
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::ApiUsage { config, specs } => {
+            if !commands::api_usage::execute_api_usage(&config, &specs)? {
+                if let Some(profiler) = profiler {
+                    profiler.finish()?;
+                }
+                std::process::exit(1);
+            }
         }
+        Commands::Doctor { config } => {
+            if !commands::doctor::execute_doctor(&config)? {
+                std::process::exit(1);
+            }
+        }
+        Commands::Explain { rule } => {
+            commands::explain::execute_explain(&rule)?;
+        }
+        Commands::DumpAst { file } => {
+            commands::dump_ast::execute_dump_ast(&file)?;
+        }
+    }
+
+    if let Some(profiler) = profiler {
+        profiler.finish()?;
     }
 
     Ok(())
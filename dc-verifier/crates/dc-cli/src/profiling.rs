@@ -0,0 +1,242 @@
+//! Phase profiling for `--profile`
+//!
+//! With the `profiling` feature, CLI phases are recorded as `tracing` spans and
+//! written as folded stacks (`check;build_graph 1234`, self time in microseconds),
+//! the input format of `flamegraph.pl` and `inferno-flamegraph`.
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+/// Enters a profiling span until the end of the current scope
+///
+/// Compiles to nothing without the `profiling` feature.
+macro_rules! profile_span {
+    ($name:literal) => {
+        #[cfg(feature = "profiling")]
+        let _profile_span = tracing::info_span!($name).entered();
+    };
+}
+
+pub(crate) use profile_span;
+
+/// Active profiler; call [`Profiler::finish`] to write the trace
+pub struct Profiler {
+    output: PathBuf,
+    #[cfg(feature = "profiling")]
+    collector: std::sync::Arc<std::sync::Mutex<imp::Collector>>,
+    #[cfg(feature = "profiling")]
+    _guard: tracing::subscriber::DefaultGuard,
+}
+
+impl Profiler {
+    /// Starts recording spans when an output file is requested
+    pub fn start(output: Option<&Path>) -> Result<Option<Self>> {
+        let Some(output) = output else {
+            return Ok(None);
+        };
+
+        #[cfg(feature = "profiling")]
+        {
+            let collector = std::sync::Arc::new(std::sync::Mutex::new(imp::Collector::default()));
+            let subscriber = imp::FoldedStackSubscriber::new(collector.clone());
+            let guard = tracing::subscriber::set_default(subscriber);
+            Ok(Some(Self {
+                output: output.to_path_buf(),
+                collector,
+                _guard: guard,
+            }))
+        }
+
+        #[cfg(not(feature = "profiling"))]
+        {
+            anyhow::bail!(
+                "--profile {} requires dc-verifier to be built with the `profiling` feature \
+                 (cargo build --features profiling)",
+                output.display()
+            )
+        }
+    }
+
+    /// Writes collected folded stacks to the output file
+    pub fn finish(self) -> Result<()> {
+        #[cfg(feature = "profiling")]
+        {
+            use anyhow::Context;
+
+            let folded = self
+                .collector
+                .lock()
+                .map_err(|_| anyhow::anyhow!("Profiler state is poisoned"))?
+                .folded();
+            if let Some(parent) = self.output.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&self.output, folded)
+                .with_context(|| format!("Failed to write profile to {}", self.output.display()))?;
+            println!("Profile saved to {}", self.output.display());
+        }
+
+        #[cfg(not(feature = "profiling"))]
+        let _ = &self.output;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "profiling")]
+mod imp {
+    use std::collections::{BTreeMap, HashMap};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Open span on the stack: id, start time and time spent in children
+    struct Frame {
+        id: u64,
+        started: Instant,
+        children: Duration,
+    }
+
+    /// Accumulated self time per stack
+    #[derive(Default)]
+    pub struct Collector {
+        names: HashMap<u64, &'static str>,
+        stack: Vec<Frame>,
+        samples: BTreeMap<String, u128>,
+    }
+
+    impl Collector {
+        fn enter(&mut self, id: u64) {
+            self.stack.push(Frame {
+                id,
+                started: Instant::now(),
+                children: Duration::ZERO,
+            });
+        }
+
+        fn exit(&mut self, id: u64) {
+            if self.stack.last().map(|frame| frame.id) != Some(id) {
+                return;
+            }
+
+            let stack_key = self
+                .stack
+                .iter()
+                .map(|frame| self.names.get(&frame.id).copied().unwrap_or("unknown"))
+                .collect::<Vec<_>>()
+                .join(";");
+
+            let frame = self.stack.pop().expect("stack checked above");
+            let elapsed = frame.started.elapsed();
+            let self_time = elapsed.saturating_sub(frame.children);
+            *self.samples.entry(stack_key).or_default() += self_time.as_micros();
+
+            if let Some(parent) = self.stack.last_mut() {
+                parent.children += elapsed;
+            }
+        }
+
+        /// Folded stacks: one `frame;frame;frame <micros>` line per stack
+        pub fn folded(&self) -> String {
+            self.samples
+                .iter()
+                .map(|(stack, micros)| format!("{} {}\n", stack, micros))
+                .collect()
+        }
+    }
+
+    /// Minimal `tracing` subscriber that records span timings
+    pub struct FoldedStackSubscriber {
+        next_id: AtomicU64,
+        collector: Arc<Mutex<Collector>>,
+    }
+
+    impl FoldedStackSubscriber {
+        pub fn new(collector: Arc<Mutex<Collector>>) -> Self {
+            Self {
+                next_id: AtomicU64::new(1),
+                collector,
+            }
+        }
+
+        fn with_collector(&self, f: impl FnOnce(&mut Collector)) {
+            if let Ok(mut collector) = self.collector.lock() {
+                f(&mut collector);
+            }
+        }
+    }
+
+    impl Subscriber for FoldedStackSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+            let name = span.metadata().name();
+            self.with_collector(|collector| {
+                collector.names.insert(id, name);
+            });
+            Id::from_u64(id)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, span: &Id) {
+            self.with_collector(|collector| collector.enter(span.into_u64()));
+        }
+
+        fn exit(&self, span: &Id) {
+            self.with_collector(|collector| collector.exit(span.into_u64()));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "profiling"))]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_profile_written_for_small_check() {
+        let temp_dir = TempDir::new().unwrap();
+        let app_path = temp_dir.path().join("main.py");
+        std::fs::write(
+            &app_path,
+            "from fastapi import FastAPI\n\napp = FastAPI()\n\n@app.get(\"/ping\")\ndef ping():\n    return {\"ok\": True}\n",
+        )
+        .unwrap();
+        let config_path = temp_dir.path().join("dc-verifier.toml");
+        std::fs::write(
+            &config_path,
+            format!(
+                "project_name = \"demo\"\n\n[output]\nformat = \"json\"\npath = {:?}\n\n[[adapters]]\ntype = \"fastapi\"\napp_path = {:?}\n",
+                temp_dir.path().join("report.json"),
+                app_path
+            ),
+        )
+        .unwrap();
+
+        let profile_path = temp_dir.path().join("profile.folded");
+        let profiler = Profiler::start(Some(&profile_path)).unwrap().unwrap();
+        crate::commands::check::execute_check(
+            config_path.to_str().unwrap(),
+            Some(crate::ReportFormat::Json),
+            &Default::default(),
+        )
+        .unwrap();
+        profiler.finish().unwrap();
+
+        let folded = std::fs::read_to_string(&profile_path).unwrap();
+        assert!(!folded.is_empty());
+        assert!(folded
+            .lines()
+            .any(|line| line.starts_with("check;build_graph ")));
+    }
+}
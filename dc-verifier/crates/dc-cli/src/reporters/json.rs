@@ -1,4 +1,7 @@
+use crate::reporters::group_by_schema_pair;
+use crate::ReportGrouping;
 use anyhow::Result;
+use dc_core::analyzers::find_deprecated_usages;
 use dc_core::models::DataChain;
 use std::fs;
 use std::path::Path;
@@ -9,7 +12,17 @@ pub struct JsonReporter;
 impl JsonReporter {
     /// Generates a JSON report
     pub fn generate(&self, chains: &[DataChain], output_path: &str) -> Result<()> {
-        let report = serde_json::json!({
+        self.generate_grouped(chains, output_path, ReportGrouping::Chain)
+    }
+
+    /// Generates a JSON report; with schema grouping it also lists `schema_pairs`
+    pub fn generate_grouped(
+        &self,
+        chains: &[DataChain],
+        output_path: &str,
+        grouping: ReportGrouping,
+    ) -> Result<()> {
+        let mut report = serde_json::json!({
             "version": "1.0.0",
             "timestamp": chrono::Utc::now().to_rfc3339(),
             "summary": {
@@ -18,13 +31,21 @@ impl JsonReporter {
                     .flat_map(|c| &c.contracts)
                     .filter(|c| c.severity == dc_core::models::Severity::Critical)
                     .count(),
+                "errors": chains.iter()
+                    .flat_map(|c| &c.contracts)
+                    .filter(|c| c.severity == dc_core::models::Severity::Error)
+                    .count(),
                 "warnings": chains.iter()
                     .flat_map(|c| &c.contracts)
                     .filter(|c| c.severity == dc_core::models::Severity::Warning)
                     .count(),
             },
             "chains": chains,
+            "deprecated": find_deprecated_usages(chains),
         });
+        if grouping == ReportGrouping::Schema {
+            report["schema_pairs"] = serde_json::to_value(group_by_schema_pair(chains))?;
+        }
 
         let json_string = serde_json::to_string_pretty(&report)?;
         fs::write(Path::new(output_path), json_string)?;
@@ -0,0 +1,135 @@
+use anyhow::Result;
+use dc_core::models::{DataChain, Location, MismatchType, Severity};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// LSP-style diagnostics generator for editor plugins
+///
+/// Findings are grouped by file and shaped like LSP `Diagnostic` objects
+/// (0-based `range`, numeric `severity`, `code`), ready for inline rendering.
+pub struct LspReporter;
+
+impl LspReporter {
+    /// Generates a `{ "file": [diagnostic, ...] }` JSON document
+    pub fn generate(&self, chains: &[DataChain], output_path: &str) -> Result<()> {
+        let diagnostics = self.diagnostics(chains);
+        let json_string = serde_json::to_string_pretty(&diagnostics)?;
+        fs::write(Path::new(output_path), json_string)?;
+        Ok(())
+    }
+
+    /// Collects diagnostics for all mismatches, keyed by file
+    pub fn diagnostics(&self, chains: &[DataChain]) -> BTreeMap<String, Vec<Value>> {
+        let mut by_file: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+
+        for chain in chains {
+            for contract in &chain.contracts {
+                for mismatch in &contract.mismatches {
+                    by_file
+                        .entry(mismatch.location.file.clone())
+                        .or_default()
+                        .push(json!({
+                            "range": Self::range(&mismatch.location),
+                            "severity": Self::severity(contract.severity),
+                            "code": Self::code(mismatch.mismatch_type),
+                            "source": "dc-verifier",
+                            "message": format!("{} ({})", mismatch.message, chain.name),
+                        }));
+                }
+            }
+        }
+
+        by_file
+    }
+
+    /// Converts a 1-based location into a 0-based LSP range covering the line
+    fn range(location: &Location) -> Value {
+        let line = location.line.saturating_sub(1);
+        let character = location.column.unwrap_or(1).saturating_sub(1);
+        json!({
+            "start": { "line": line, "character": character },
+            "end": { "line": line + 1, "character": 0 },
+        })
+    }
+
+    /// Maps severity to LSP `DiagnosticSeverity` (1 = Error, 2 = Warning, 3 = Information)
+    fn severity(severity: Severity) -> u8 {
+        match severity {
+            Severity::Critical | Severity::Error => 1,
+            Severity::Warning => 2,
+            Severity::Info => 3,
+        }
+    }
+
+    /// Diagnostic code (rule-style mismatch type name)
+    fn code(mismatch_type: MismatchType) -> &'static str {
+        match mismatch_type {
+            MismatchType::TypeMismatch => "type_mismatch",
+            MismatchType::MissingField => "missing_field",
+            MismatchType::ExtraField => "extra_field",
+            MismatchType::ValidationMismatch => "validation_mismatch",
+            MismatchType::UnnormalizedData => "unnormalized_data",
+            MismatchType::NamingMismatch => "naming_mismatch",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dc_core::models::{BaseType, Mismatch, TypeInfo};
+    use dc_core::test_support::{chain, contract, pydantic_schema};
+
+    fn type_info(base_type: BaseType) -> TypeInfo {
+        TypeInfo {
+            base_type,
+            schema_ref: None,
+            constraints: Vec::new(),
+            optional: false,
+        }
+    }
+
+    fn mismatch(mismatch_type: MismatchType, file: &str, line: usize, column: usize) -> Mismatch {
+        Mismatch {
+            mismatch_type,
+            rule: String::new(),
+            path: "age".to_string(),
+            expected: type_info(BaseType::Integer),
+            actual: type_info(BaseType::String),
+            location: Location {
+                file: file.to_string(),
+                line,
+                column: Some(column),
+            },
+            message: "Type mismatch for field 'age'".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_diagnostics_grouped_by_file() {
+        let mut contract = contract(pydantic_schema("UserCreate"), pydantic_schema("User"));
+        contract.mismatches = vec![
+            mismatch(MismatchType::TypeMismatch, "api/users.py", 10, 5),
+            mismatch(MismatchType::MissingField, "api/users.py", 20, 1),
+            mismatch(MismatchType::MissingField, "web/api.ts", 3, 1),
+        ];
+        contract.severity = Severity::Critical;
+        let chain = chain("POST /users", vec![contract]);
+
+        let diagnostics = LspReporter.diagnostics(&[chain]);
+
+        assert_eq!(diagnostics.len(), 2);
+        let users = &diagnostics["api/users.py"];
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0]["range"]["start"]["line"], 9);
+        assert_eq!(users[0]["range"]["start"]["character"], 4);
+        assert_eq!(users[0]["range"]["end"]["line"], 10);
+        assert_eq!(users[0]["code"], "type_mismatch");
+        assert_eq!(users[0]["severity"], 1);
+        assert_eq!(users[1]["range"]["start"]["line"], 19);
+        assert_eq!(users[1]["code"], "missing_field");
+        assert_eq!(diagnostics["web/api.ts"].len(), 1);
+    }
+}
@@ -1,6 +1,10 @@
+use crate::reporters::group_by_schema_pair;
+use crate::ReportGrouping;
 use anyhow::Result;
-use dc_core::models::DataChain;
+use dc_core::analyzers::{find_deprecated_usages, JsonSchema, SchemaParser};
+use dc_core::models::{Constraint, ConstraintValue, Contract, DataChain};
 use std::fs;
+use std::io::{BufWriter, Write};
 use std::path::Path;
 
 /// Markdown report generator
@@ -8,116 +12,459 @@ pub struct MarkdownReporter;
 
 impl MarkdownReporter {
     /// Generates report in .chain_verification_report.md format
-    pub fn generate(&self, chains: &[DataChain], output_path: &str) -> Result<()> {
-        let mut report = String::new();
+    ///
+    /// Findings are listed per chain or, with [`ReportGrouping::Schema`], per schema pair.
+    /// Convenience wrapper over [`MarkdownReporter::write_report`] writing to `output_path`.
+    pub fn generate(
+        &self,
+        chains: &[DataChain],
+        output_path: &str,
+        grouping: ReportGrouping,
+    ) -> Result<()> {
+        let mut writer = BufWriter::new(fs::File::create(Path::new(output_path))?);
+        self.write_report(chains, &mut writer, grouping)
+    }
+
+    /// Streams the report to `writer` and flushes it once at the end
+    ///
+    /// `chains` is walked a few times (statistics, sections, deprecated usages)
+    /// instead of being collected, so chains are rendered one section at a time.
+    pub fn write_report<'a, I, W>(
+        &self,
+        chains: I,
+        writer: &mut W,
+        grouping: ReportGrouping,
+    ) -> Result<()>
+    where
+        I: IntoIterator<Item = &'a DataChain>,
+        I::IntoIter: Clone,
+        W: Write,
+    {
+        let date = chrono::Utc::now().date_naive();
+        Self::write_report_dated(chains.into_iter(), writer, grouping, date)
+    }
+
+    /// Writes the report with the given verification date
+    fn write_report_dated<'a, W: Write>(
+        chains: impl Iterator<Item = &'a DataChain> + Clone,
+        writer: &mut W,
+        grouping: ReportGrouping,
+        date: chrono::NaiveDate,
+    ) -> Result<()> {
+        let mut section = String::new();
 
         // Header
-        report.push_str("# Data Chain Verification Report\n\n");
-        report.push_str(&format!(
+        section.push_str("# Data Chain Verification Report\n\n");
+        section.push_str(&format!(
             "## Verification Date\n{}\n\n",
-            chrono::Utc::now().format("%Y-%m-%d")
+            date.format("%Y-%m-%d")
         ));
 
         // Statistics - count chains, not contracts
-        let total_chains = chains.len();
-        let chains_with_critical = chains
-            .iter()
-            .filter(|chain| {
-                chain
-                    .contracts
-                    .iter()
-                    .any(|c| c.severity == dc_core::models::Severity::Critical)
-            })
-            .count();
-        let chains_with_warnings = chains
-            .iter()
-            .filter(|chain| {
-                // Chains without Critical, but with at least one Warning
-                !chain
-                    .contracts
-                    .iter()
-                    .any(|c| c.severity == dc_core::models::Severity::Critical)
-                    && chain
-                        .contracts
-                        .iter()
-                        .any(|c| c.severity == dc_core::models::Severity::Warning)
-            })
-            .count();
+        let has_errors = |chain: &DataChain| {
+            chain
+                .contracts
+                .iter()
+                .any(|c| c.severity >= dc_core::models::Severity::Error)
+        };
+        let mut total_chains = 0;
+        let mut chains_with_critical = 0;
+        let mut chains_with_warnings = 0;
+        for chain in chains.clone() {
+            total_chains += 1;
+            if has_errors(chain) {
+                chains_with_critical += 1;
+            } else if chain
+                .contracts
+                .iter()
+                .any(|c| c.severity == dc_core::models::Severity::Warning)
+            {
+                // Chains without Critical or Error, but with at least one Warning
+                chains_with_warnings += 1;
+            }
+        }
         let valid_chains = total_chains - chains_with_critical - chains_with_warnings;
 
-        report.push_str("## Verification Statistics\n");
-        report.push_str(&format!("- **Total Chains**: {}\n", total_chains));
-        report.push_str(&format!(
-            "- **Critical Issues**: {}\n",
+        section.push_str("## Verification Statistics\n");
+        section.push_str(&format!("- **Total Chains**: {}\n", total_chains));
+        section.push_str(&format!(
+            "- **Critical Issues and Errors**: {}\n",
             chains_with_critical
         ));
-        report.push_str(&format!("- **Warnings**: {}\n", chains_with_warnings));
-        report.push_str(&format!("- **Valid Chains**: {}\n\n", valid_chains));
-        report.push_str("---\n\n");
-
-        // Chain details
-        for (idx, chain) in chains.iter().enumerate() {
-            report.push_str(&format!("### Chain {}: {}\n\n", idx + 1, chain.name));
-            report.push_str(&format!("#### ID: {}\n\n", chain.id));
+        section.push_str(&format!("- **Warnings**: {}\n", chains_with_warnings));
+        section.push_str(&format!("- **Valid Chains**: {}\n\n", valid_chains));
+        section.push_str("---\n\n");
+        Self::write_section(writer, &mut section)?;
 
-            // Data path
-            report.push_str("#### Data Path:\n```\n");
-            for (idx, link) in chain.links.iter().enumerate() {
-                if idx > 0 {
-                    report.push_str(" → ");
+        match grouping {
+            ReportGrouping::Chain => {
+                for (idx, chain) in chains.clone().enumerate() {
+                    Self::write_chain_section(&mut section, idx, chain);
+                    Self::write_section(writer, &mut section)?;
                 }
-                report.push_str(&link.id);
             }
-            report.push_str("\n```\n\n");
+            ReportGrouping::Schema => Self::write_schema_pairs(&mut section, chains.clone()),
+        }
+        Self::write_deprecated_usages(&mut section, chains);
 
-            // Checked junctions
-            report.push_str("#### Checked Junctions:\n\n");
-            for (i, contract) in chain.contracts.iter().enumerate() {
-                if contract.mismatches.is_empty() {
-                    report.push_str(&format!(
-                        "{}. ✅ **{} → {}**\n",
-                        i + 1,
-                        contract.from_link_id,
-                        contract.to_link_id
-                    ));
-                    report.push_str("   - ✅ **Корректно**: все поля совпадают\n\n");
-                } else {
-                    report.push_str(&format!(
-                        "{}. ⚠️ **{} → {}**\n",
-                        i + 1,
-                        contract.from_link_id,
-                        contract.to_link_id
-                    ));
-                    for mismatch in &contract.mismatches {
-                        report.push_str(&format!(
-                            "   - ⚠️ **{:?}**: {}\n",
-                            mismatch.mismatch_type, mismatch.message
-                        ));
-                    }
-                    report.push_str("\n");
-                }
+        // Final conclusions
+        section.push_str("## Итоговые выводы\n\n");
+        if chains_with_critical == 0 && chains_with_warnings == 0 {
+            section.push_str("### ✅ Общая оценка: **КОРРЕКТНО**\n\n");
+        } else {
+            section.push_str("### ⚠️ Общая оценка: **ТРЕБУЕТ ВНИМАНИЯ**\n\n");
+        }
+        Self::write_section(writer, &mut section)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Writes a rendered section to `writer` and clears the buffer for the next one
+    fn write_section<W: Write>(writer: &mut W, section: &mut String) -> Result<()> {
+        writer.write_all(section.as_bytes())?;
+        section.clear();
+        Ok(())
+    }
+
+    /// Writes the informational list of chains that depend on deprecated fields or endpoints
+    fn write_deprecated_usages<'a>(
+        report: &mut String,
+        chains: impl IntoIterator<Item = &'a DataChain>,
+    ) {
+        let usages = find_deprecated_usages(chains);
+        if usages.is_empty() {
+            return;
+        }
+
+        report.push_str("## Deprecated Dependencies\n\n");
+        report
+            .push_str("Chains that still use deprecated fields or endpoints (informational):\n\n");
+        for usage in &usages {
+            match &usage.field {
+                Some(field) => report.push_str(&format!(
+                    "- **{}**: field `{}` of `{}`\n",
+                    usage.chain, field, usage.schema
+                )),
+                None => report.push_str(&format!(
+                    "- **{}**: schema `{}`\n",
+                    usage.chain, usage.schema
+                )),
             }
+        }
+        report.push_str("\n---\n\n");
+    }
 
-            // Result
-            let has_errors = chain.contracts.iter().any(|c| !c.mismatches.is_empty());
-            if has_errors {
-                report.push_str("#### Результат: ⚠️ **ТРЕБУЕТ ВНИМАНИЯ**\n\n");
-            } else {
-                report.push_str("#### Результат: ✅ **КОРРЕКТНА**\n\n");
+    /// Writes one section per schema pair with the chains that cross it
+    fn write_schema_pairs<'a>(
+        report: &mut String,
+        chains: impl IntoIterator<Item = &'a DataChain>,
+    ) {
+        for (idx, group) in group_by_schema_pair(chains).iter().enumerate() {
+            report.push_str(&format!(
+                "### Schema Pair {}: {} → {}\n\n",
+                idx + 1,
+                group.from_schema,
+                group.to_schema
+            ));
+            report.push_str(&format!(
+                "#### Affected Chains ({}):\n\n",
+                group.chains.len()
+            ));
+            for chain in &group.chains {
+                report.push_str(&format!("- {}\n", chain));
             }
+            report.push_str("\n#### Mismatches:\n\n");
+            for mismatch in &group.mismatches {
+                report.push_str(&format!(
+                    "- ⚠️ **{:?}**: {}\n",
+                    mismatch.mismatch_type, mismatch.message
+                ));
+            }
+            report.push_str("\n---\n\n");
+        }
+    }
 
-            report.push_str("---\n\n");
+    /// Writes the fields of both schemas of a contract as compact tables
+    ///
+    /// Schemas that cannot be parsed are skipped.
+    fn write_field_tables(report: &mut String, contract: &Contract) {
+        for schema_ref in [&contract.from_schema, &contract.to_schema] {
+            let Ok(schema) = SchemaParser::parse(schema_ref) else {
+                continue;
+            };
+            if schema.properties.is_empty() {
+                continue;
+            }
+            report.push_str(&format!("   `{}`:\n\n", schema_ref.name));
+            report.push_str("   | Field | Type | Required | Constraints |\n");
+            report.push_str("   |---|---|---|---|\n");
+            Self::write_field_rows(report, &schema, 0);
+            report.push('\n');
         }
+    }
 
-        // Final conclusions
-        report.push_str("## Итоговые выводы\n\n");
-        if chains_with_critical == 0 && chains_with_warnings == 0 {
-            report.push_str("### ✅ Общая оценка: **КОРРЕКТНО**\n\n");
+    /// Writes one table row per field; nested object fields follow their parent, indented
+    fn write_field_rows(report: &mut String, schema: &JsonSchema, depth: usize) {
+        let mut names: Vec<&String> = schema.properties.keys().collect();
+        names.sort();
+        for name in names {
+            let field = &schema.properties[name];
+            let required = schema.required.contains(name) || !field.optional;
+            let mut field_type = field.field_type.clone();
+            if field.recursive {
+                field_type.push_str(" (recursive)");
+            }
+            let constraints: Vec<String> = field.constraints.iter().map(constraint_label).collect();
+            report.push_str(&format!(
+                "   | {}{} | {} | {} | {} |\n",
+                "&nbsp;&nbsp;".repeat(depth),
+                escape_cell(name),
+                escape_cell(&field_type),
+                if required { "yes" } else { "no" },
+                escape_cell(&constraints.join(", "))
+            ));
+            if let Some(nested) = &field.nested_schema {
+                Self::write_field_rows(report, nested, depth + 1);
+            }
+        }
+    }
+
+    /// Writes the path and checked junctions of one chain
+    fn write_chain_section(report: &mut String, idx: usize, chain: &DataChain) {
+        report.push_str(&format!("### Chain {}: {}\n\n", idx + 1, chain.name));
+        report.push_str(&format!("#### ID: {}\n\n", chain.id));
+
+        // Data path
+        report.push_str("#### Data Path:\n```\n");
+        for (idx, link) in chain.links.iter().enumerate() {
+            if idx > 0 {
+                report.push_str(" → ");
+            }
+            report.push_str(&link.id);
+        }
+        report.push_str("\n```\n\n");
+        if chain.truncated {
+            report.push_str(
+                "> ✂️ Цепочка обрезана по `max_chain_length`: путь продолжается дальше последнего звена\n\n",
+            );
+        }
+
+        // Checked junctions
+        report.push_str("#### Checked Junctions:\n\n");
+        for (i, contract) in chain.contracts.iter().enumerate() {
+            let status = contract
+                .status_code
+                .map(|code| format!(" (HTTP {}: {})", code, contract.from_schema.name))
+                .unwrap_or_default();
+            if contract.mismatches.is_empty() {
+                report.push_str(&format!(
+                    "{}. ✅ **{} → {}**{}\n",
+                    i + 1,
+                    contract.from_link_id,
+                    contract.to_link_id,
+                    status
+                ));
+                report.push_str("   - ✅ **Корректно**: все поля совпадают\n\n");
+            } else {
+                report.push_str(&format!(
+                    "{}. ⚠️ **{} → {}**{}\n",
+                    i + 1,
+                    contract.from_link_id,
+                    contract.to_link_id,
+                    status
+                ));
+                for mismatch in &contract.mismatches {
+                    report.push_str(&format!(
+                        "   - ⚠️ **{:?}**: {}\n",
+                        mismatch.mismatch_type, mismatch.message
+                    ));
+                }
+                report.push('\n');
+            }
+            Self::write_field_tables(report, contract);
+        }
+
+        // Result
+        let has_errors = chain.contracts.iter().any(|c| !c.mismatches.is_empty());
+        if has_errors {
+            report.push_str("#### Результат: ⚠️ **ТРЕБУЕТ ВНИМАНИЯ**\n\n");
         } else {
-            report.push_str("### ⚠️ Общая оценка: **ТРЕБУЕТ ВНИМАНИЯ**\n\n");
+            report.push_str("#### Результат: ✅ **КОРРЕКТНА**\n\n");
         }
 
-        fs::write(Path::new(output_path), report)?;
-        Ok(())
+        report.push_str("---\n\n");
+    }
+}
+
+/// Short label of a field constraint for the field table
+fn constraint_label(constraint: &Constraint) -> String {
+    let value = |value: &ConstraintValue| match value {
+        ConstraintValue::Integer(value) => value.to_string(),
+        ConstraintValue::Float(value) => value.to_string(),
+    };
+    match constraint {
+        Constraint::Min(min) => format!("min {}", value(min)),
+        Constraint::Max(max) => format!("max {}", value(max)),
+        Constraint::Pattern(pattern) => format!("pattern `{}`", pattern),
+        Constraint::Email => "email".to_string(),
+        Constraint::Url => "url".to_string(),
+        Constraint::Enum(values) => format!("enum {}", values.join(" / ")),
+        Constraint::OneOf(types) => {
+            let types: Vec<String> = types.iter().map(|t| format!("{:?}", t)).collect();
+            format!("one of {}", types.join(" / "))
+        }
+        Constraint::UniqueItems => "unique items".to_string(),
+    }
+}
+
+/// Escapes `|` so a value does not split a table cell
+fn escape_cell(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dc_core::models::{BaseType, Location, Mismatch, MismatchType, Severity, TypeInfo};
+    use dc_core::test_support::{chain, contract, schema};
+    use serde_json::json;
+
+    fn user_chain() -> DataChain {
+        let from = schema(
+            "UserForm",
+            json!({
+                "type": "object",
+                "properties": {
+                    "name": {"type": "string", "minLength": 1},
+                    "address": {
+                        "type": "object",
+                        "properties": {"city": {"type": "string"}},
+                        "required": ["city"]
+                    }
+                },
+                "required": ["name"]
+            }),
+        );
+        let to = schema(
+            "UserCreate",
+            json!({
+                "type": "object",
+                "properties": {"name": {"type": "string"}, "age": {"type": "integer"}},
+                "required": ["name"]
+            }),
+        );
+        chain("POST /users", vec![contract(from, to)])
+    }
+
+    #[test]
+    fn test_contract_renders_field_tables() {
+        let chain = user_chain();
+
+        let mut report = String::new();
+        MarkdownReporter::write_chain_section(&mut report, 0, &chain);
+
+        assert!(report.contains("   `UserForm`:"));
+        assert!(report.contains("   | name | string | yes | min 1 |"));
+        assert!(report.contains("   | address | object | no |  |"));
+        assert!(report.contains("   | &nbsp;&nbsp;city | string | yes |  |"));
+        assert!(report.contains("   `UserCreate`:"));
+        assert!(report.contains("   | age | integer | no |  |"));
+    }
+
+    #[test]
+    fn test_report_matches_expected_markdown() {
+        // Schemas without properties render no field tables
+        let mut clean = user_chain();
+        clean.contracts[0].from_schema = schema("UserForm", json!({"type": "object"}));
+        clean.contracts[0].to_schema = schema("UserCreate", json!({"type": "object"}));
+        let mut failing = clean.clone();
+        failing.id = "chain-1".to_string();
+        failing.name = "PUT /users/{id}".to_string();
+        failing.contracts[0].severity = Severity::Error;
+        let type_info = TypeInfo {
+            base_type: BaseType::Integer,
+            schema_ref: None,
+            constraints: Vec::new(),
+            optional: true,
+        };
+        failing.contracts[0].mismatches = vec![Mismatch {
+            mismatch_type: MismatchType::MissingField,
+            rule: String::new(),
+            path: "age".to_string(),
+            expected: type_info.clone(),
+            actual: type_info,
+            location: Location {
+                file: "schema.json".to_string(),
+                line: 1,
+                column: None,
+            },
+            message: "Missing field 'age'".to_string(),
+        }];
+
+        let mut report = Vec::new();
+        MarkdownReporter::write_report_dated(
+            [clean, failing].iter(),
+            &mut report,
+            ReportGrouping::Chain,
+            chrono::NaiveDate::from_ymd_opt(2024, 5, 1).unwrap(),
+        )
+        .unwrap();
+
+        let expected = "# Data Chain Verification Report
+
+## Verification Date
+2024-05-01
+
+## Verification Statistics
+- **Total Chains**: 2
+- **Critical Issues and Errors**: 1
+- **Warnings**: 0
+- **Valid Chains**: 1
+
+---
+
+### Chain 1: POST /users
+
+#### ID: chain-0
+
+#### Data Path:
+```
+
+```
+
+#### Checked Junctions:
+
+1. ✅ **from → to**
+   - ✅ **Корректно**: все поля совпадают
+
+#### Результат: ✅ **КОРРЕКТНА**
+
+---
+
+### Chain 2: PUT /users/{id}
+
+#### ID: chain-1
+
+#### Data Path:
+```
+
+```
+
+#### Checked Junctions:
+
+1. ⚠️ **from → to**
+   - ⚠️ **MissingField**: Missing field 'age'
+
+#### Результат: ⚠️ **ТРЕБУЕТ ВНИМАНИЯ**
+
+---
+
+## Итоговые выводы
+
+### ⚠️ Общая оценка: **ТРЕБУЕТ ВНИМАНИЯ**
+
+";
+        assert_eq!(String::from_utf8(report).unwrap(), expected);
     }
 }
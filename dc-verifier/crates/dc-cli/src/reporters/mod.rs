@@ -1,5 +1,9 @@
 pub mod json;
+pub mod lsp;
 pub mod markdown;
+pub mod schema_pairs;
 
 pub use json::JsonReporter;
+pub use lsp::LspReporter;
 pub use markdown::*;
+pub use schema_pairs::*;
@@ -0,0 +1,104 @@
+//! Findings grouped by schema boundary
+//!
+//! The same schema pair (e.g. `UserCreate` → `UserModel`) often appears in many
+//! chains; grouping shows each boundary problem once with the affected chains.
+
+use dc_core::models::{DataChain, Mismatch};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Mismatches of one `(from_schema, to_schema)` pair across all chains
+#[derive(Debug, Clone, Serialize)]
+pub struct SchemaPairFindings {
+    /// Source schema name
+    pub from_schema: String,
+    /// Receiving schema name
+    pub to_schema: String,
+    /// Names of the chains crossing this boundary with mismatches
+    pub chains: Vec<String>,
+    /// Distinct mismatches (by field path and type)
+    pub mismatches: Vec<Mismatch>,
+}
+
+/// Aggregates contract mismatches by schema pair, sorted by schema names
+///
+/// Contracts without mismatches are left out.
+pub fn group_by_schema_pair<'a>(
+    chains: impl IntoIterator<Item = &'a DataChain>,
+) -> Vec<SchemaPairFindings> {
+    let mut groups: BTreeMap<(&str, &str), SchemaPairFindings> = BTreeMap::new();
+
+    for chain in chains {
+        for contract in chain.contracts.iter().filter(|c| !c.mismatches.is_empty()) {
+            let from = contract.from_schema.name.as_str();
+            let to = contract.to_schema.name.as_str();
+            let group = groups
+                .entry((from, to))
+                .or_insert_with(|| SchemaPairFindings {
+                    from_schema: from.to_string(),
+                    to_schema: to.to_string(),
+                    chains: Vec::new(),
+                    mismatches: Vec::new(),
+                });
+
+            if !group.chains.contains(&chain.name) {
+                group.chains.push(chain.name.clone());
+            }
+            for mismatch in &contract.mismatches {
+                let known = group.mismatches.iter().any(|existing| {
+                    existing.path == mismatch.path
+                        && existing.mismatch_type == mismatch.mismatch_type
+                });
+                if !known {
+                    group.mismatches.push(mismatch.clone());
+                }
+            }
+        }
+    }
+
+    groups.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dc_core::models::{BaseType, Location, MismatchType, Severity, TypeInfo};
+    use dc_core::test_support::{chain, contract, pydantic_schema};
+
+    fn user_chain(name: &str) -> DataChain {
+        let type_info = TypeInfo {
+            base_type: BaseType::String,
+            schema_ref: None,
+            constraints: Vec::new(),
+            optional: false,
+        };
+        let mut contract = contract(pydantic_schema("UserCreate"), pydantic_schema("UserModel"));
+        contract.mismatches = vec![Mismatch {
+            mismatch_type: MismatchType::MissingField,
+            rule: String::new(),
+            path: "email".to_string(),
+            expected: type_info.clone(),
+            actual: type_info,
+            location: Location {
+                file: "main.py".to_string(),
+                line: 10,
+                column: None,
+            },
+            message: "Missing field 'email'".to_string(),
+        }];
+        contract.severity = Severity::Warning;
+        chain(name, vec![contract])
+    }
+
+    #[test]
+    fn test_shared_schema_pair_is_reported_once() {
+        let groups =
+            group_by_schema_pair(&[user_chain("POST /users"), user_chain("PUT /users/{id}")]);
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].from_schema, "UserCreate");
+        assert_eq!(groups[0].to_schema, "UserModel");
+        assert_eq!(groups[0].chains, vec!["POST /users", "PUT /users/{id}"]);
+        assert_eq!(groups[0].mismatches.len(), 1);
+    }
+}
@@ -11,6 +11,7 @@ fn build_trivial_graph() -> CallGraph {
         line: 1,
         parameters: Vec::new(),
         return_type: None,
+        decorators: Vec::new(),
     }));
     // Затем создаем Route node с уже созданным handler
     let route = graph.add_node(CallNode::Route {
@@ -22,6 +23,10 @@ fn build_trivial_graph() -> CallGraph {
             line: 1,
             column: None,
         },
+        response_model: None,
+        operation_id: None,
+        status_code: None,
+        responses: Vec::new(),
     });
     // Обратное ребро не требуется, но мы возвращаем индекс,
     // чтобы бенчмарку было что измерять.
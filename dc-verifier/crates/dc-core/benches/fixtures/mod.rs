@@ -0,0 +1,67 @@
+//! Детерминированные синтетические входные данные для бенчмарков
+//!
+//! Один и тот же размер всегда дает один и тот же исходный код, поэтому
+//! результаты разных запусков и веток сравнимы.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Модуль с `models` Pydantic моделями и `functions` функциями, вызывающими друг друга
+///
+/// Модели называются `{Prefix}Model{i}`, функции - `{prefix}_step_{i}`.
+pub fn python_module(prefix: &str, models: usize, functions: usize) -> String {
+    let class_prefix = prefix.to_uppercase();
+    let mut source = String::from("from pydantic import BaseModel\n\n");
+    for idx in 0..models {
+        let _ = write!(
+            source,
+            "\nclass {class_prefix}Model{idx}(BaseModel):\n    name: str\n    count: int\n    tags: list[str] = []\n\n"
+        );
+    }
+    for idx in 0..functions {
+        let _ = writeln!(source, "\ndef {prefix}_step_{idx}(payload):");
+        if idx + 1 < functions {
+            let _ = writeln!(source, "    result = {prefix}_step_{}(payload)", idx + 1);
+            let _ = writeln!(source, "    return result");
+        } else {
+            let _ = writeln!(source, "    return payload");
+        }
+    }
+    source
+}
+
+/// FastAPI проект из `modules` модулей: у каждого модуля своя модель и route в `main.py`
+///
+/// `fastapi` и `pydantic` заменены заглушками в корне проекта: импорты
+/// разрешаются локально и не засоряют вывод бенчмарка предупреждениями.
+/// Возвращает путь к `main.py`.
+pub fn write_project(
+    dir: &Path,
+    modules: usize,
+    functions_per_module: usize,
+) -> io::Result<PathBuf> {
+    fs::write(dir.join("fastapi.py"), "class FastAPI:\n    pass\n")?;
+    fs::write(dir.join("pydantic.py"), "class BaseModel:\n    pass\n")?;
+
+    let mut main = String::from("from fastapi import FastAPI\n");
+    for idx in 0..modules {
+        let _ = writeln!(main, "from module_{idx} import M{idx}Model0, m{idx}_step_0");
+    }
+    main.push_str("\napp = FastAPI()\n");
+    for idx in 0..modules {
+        let _ = write!(
+            main,
+            "\n\n@app.post(\"/items_{idx}\")\ndef create_item_{idx}(payload: M{idx}Model0):\n    return m{idx}_step_0(payload)\n"
+        );
+        fs::write(
+            dir.join(format!("module_{idx}.py")),
+            python_module(&format!("m{idx}"), 1, functions_per_module),
+        )?;
+    }
+
+    let main_path = dir.join("main.py");
+    fs::write(&main_path, main)?;
+    Ok(main_path)
+}
@@ -0,0 +1,93 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use dc_core::analyzers::{ChainBuilder, ContractChecker};
+use dc_core::call_graph::CallGraphBuilder;
+use dc_core::data_flow::DataFlowTracker;
+use dc_core::parsers::{LocationConverter, PythonParser};
+use rustpython_parser::{parse, Mode};
+use tempfile::TempDir;
+
+mod fixtures;
+
+/// Разбор одного большого модуля: AST, вызовы и Pydantic модели
+fn bench_parse_large_module(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_python_module");
+    for functions in [100, 1_000] {
+        let source = fixtures::python_module("bench", functions / 10, functions);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(functions),
+            &source,
+            |b, source| {
+                b.iter(|| {
+                    let module = parse(source, Mode::Module, "bench.py").unwrap();
+                    let converter = LocationConverter::new(source.clone());
+                    let parser = PythonParser::new();
+                    black_box(parser.extract_calls(&module, "bench.py", &converter));
+                    black_box(parser.extract_pydantic_models(&module, "bench.py", &converter));
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Построение графа вызовов проекта из N модулей
+fn bench_build_graph(c: &mut Criterion) {
+    let mut group = c.benchmark_group("build_call_graph");
+    group.sample_size(20);
+    for modules in [10, 50] {
+        let temp_dir = TempDir::new().unwrap();
+        let main_path = fixtures::write_project(temp_dir.path(), modules, 10).unwrap();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(modules),
+            &main_path,
+            |b, main_path| {
+                b.iter(|| {
+                    let mut builder = CallGraphBuilder::new();
+                    builder.build_from_entry(main_path).unwrap();
+                    black_box(builder.into_graph());
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Полный конвейер: граф, цепочки и проверка контрактов
+fn bench_check_pipeline(c: &mut Criterion) {
+    let mut group = c.benchmark_group("check_pipeline");
+    group.sample_size(20);
+    for modules in [10, 50] {
+        let temp_dir = TempDir::new().unwrap();
+        let main_path = fixtures::write_project(temp_dir.path(), modules, 10).unwrap();
+        let checker = ContractChecker::new();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(modules),
+            &main_path,
+            |b, main_path| {
+                b.iter(|| {
+                    let mut builder = CallGraphBuilder::new();
+                    builder.build_from_entry(main_path).unwrap();
+                    let graph = builder.into_graph();
+                    let tracker = DataFlowTracker::new(&graph);
+                    let chains = ChainBuilder::new(&graph, &tracker)
+                        .find_all_chains()
+                        .unwrap();
+                    for chain in &chains {
+                        for contract in &chain.contracts {
+                            black_box(checker.check_chain_contract(&chain.name, contract));
+                        }
+                    }
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_parse_large_module,
+    bench_build_graph,
+    bench_check_pipeline
+);
+criterion_main!(benches);
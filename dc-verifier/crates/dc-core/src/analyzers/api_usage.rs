@@ -0,0 +1,310 @@
+use crate::analyzers::spec_drift::path_template;
+use crate::call_graph::{CallEdge, CallGraph, CallNode};
+use crate::models::{normalize_route_path, Location};
+use crate::parsers::ApiEndpoint;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+/// Методы HTTP клиентов (`axios.get`, `api.post`, `this.http.delete`)
+const CLIENT_METHODS: &[&str] = &["get", "post", "put", "patch", "delete", "head", "options"];
+
+/// HTTP вызов фронтенда (`fetch`, `axios.get(...)` и т.п.)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpCall {
+    /// HTTP метод ("GET")
+    pub method: String,
+    /// Путь запроса; подстановки шаблонной строки заменены на `{}`
+    pub path: String,
+    /// Расположение вызова в коде
+    pub location: Location,
+}
+
+/// Эндпоинты одной OpenAPI спецификации
+#[derive(Debug, Clone)]
+pub struct ApiSpec {
+    /// Источник спецификации (путь к файлу)
+    pub source: String,
+    pub endpoints: Vec<ApiEndpoint>,
+}
+
+/// Эндпоинт спецификации, который не вызывает фронтенд
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnusedEndpoint {
+    /// Источник спецификации
+    pub spec: String,
+    pub method: String,
+    pub path: String,
+}
+
+/// Сопоставление HTTP вызовов фронтенда с эндпоинтами спецификаций
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiUsage {
+    /// Вызовы, которым не соответствует ни один эндпоинт (опечатка или удаленный API)
+    pub unmatched_calls: Vec<HttpCall>,
+    /// Эндпоинты, которые фронтенд не вызывает
+    pub unused_endpoints: Vec<UnusedEndpoint>,
+}
+
+impl ApiUsage {
+    /// Все вызовы найдены в спецификациях и все эндпоинты используются
+    pub fn is_clean(&self) -> bool {
+        self.unmatched_calls.is_empty() && self.unused_endpoints.is_empty()
+    }
+}
+
+/// Находит HTTP вызовы в графе вызовов фронтенда
+///
+/// Учитываются вызовы внешних функций с литералом URL первым аргументом:
+/// `fetch(url, { method })` (по умолчанию GET) и методы клиентов
+/// (`axios.post(url, data)`). URL, который не начинается с `/` после
+/// отбрасывания origin, не считается запросом к API.
+pub fn find_http_calls(graph: &CallGraph) -> Vec<HttpCall> {
+    let mut calls: Vec<HttpCall> = graph
+        .edge_indices()
+        .filter_map(|idx| {
+            let CallEdge::Call {
+                callee,
+                argument_mapping,
+                location,
+                ..
+            } = &graph[idx]
+            else {
+                return None;
+            };
+            let Some(CallNode::ExternalCall { name }) = graph.node_weight(**callee) else {
+                return None;
+            };
+            let arguments: Vec<&str> = argument_mapping
+                .iter()
+                .map(|(_, value)| value.as_str())
+                .collect();
+            let (method, path) = http_request(name, &arguments)?;
+            Some(HttpCall {
+                method,
+                path,
+                location: location.clone(),
+            })
+        })
+        .collect();
+
+    calls.sort_by(|a, b| {
+        (&a.location.file, a.location.line).cmp(&(&b.location.file, b.location.line))
+    });
+    calls
+}
+
+/// Сопоставляет вызовы с эндпоинтами всех спецификаций по методу и шаблону пути
+pub fn match_api_usage(calls: &[HttpCall], specs: &[ApiSpec]) -> ApiUsage {
+    let mut endpoints = BTreeMap::new();
+    for spec in specs {
+        for endpoint in &spec.endpoints {
+            endpoints
+                .entry((
+                    endpoint.method.to_uppercase(),
+                    path_template(&endpoint.path),
+                ))
+                .or_insert_with(|| UnusedEndpoint {
+                    spec: spec.source.clone(),
+                    method: endpoint.method.to_uppercase(),
+                    path: normalize_route_path(&endpoint.path),
+                });
+        }
+    }
+
+    let mut used = HashSet::new();
+    let mut unmatched_calls = Vec::new();
+    for call in calls {
+        let key = (call.method.clone(), path_template(&call.path));
+        if endpoints.contains_key(&key) {
+            used.insert(key);
+        } else {
+            unmatched_calls.push(call.clone());
+        }
+    }
+
+    let unused_endpoints = endpoints
+        .into_iter()
+        .filter(|(key, _)| !used.contains(key))
+        .map(|(_, endpoint)| endpoint)
+        .collect();
+
+    ApiUsage {
+        unmatched_calls,
+        unused_endpoints,
+    }
+}
+
+/// Метод и путь запроса по имени вызываемой функции и аргументам
+fn http_request(name: &str, arguments: &[&str]) -> Option<(String, String)> {
+    let path = request_path(arguments.first()?)?;
+    let method = if name == "fetch" {
+        arguments
+            .get(1)
+            .and_then(|options| fetch_method(options))
+            .unwrap_or_else(|| "GET".to_string())
+    } else {
+        let (_, method) = name.rsplit_once('.')?;
+        if !CLIENT_METHODS.contains(&method) {
+            return None;
+        }
+        method.to_uppercase()
+    };
+    Some((method, path))
+}
+
+/// Путь из литерала URL (`"/users"`, `` `${API}/users/${id}?full=1` ``)
+fn request_path(url: &str) -> Option<String> {
+    let url = url
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .or_else(|| {
+            url.strip_prefix('`')
+                .and_then(|rest| rest.strip_suffix('`'))
+        })?;
+    // Подстановка в начале - базовый URL (`${API_URL}/users`)
+    let url = url.strip_prefix("${...}").unwrap_or(url);
+    let url = match url.split_once("://") {
+        Some((_, rest)) => &rest[rest.find('/')?..],
+        None => url,
+    };
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    if !path.starts_with('/') {
+        return None;
+    }
+
+    let segments: Vec<&str> = path
+        .split('/')
+        .map(|segment| {
+            if segment.contains("${...}") {
+                "{}"
+            } else {
+                segment
+            }
+        })
+        .collect();
+    Some(normalize_route_path(&segments.join("/")))
+}
+
+/// Метод из опций `fetch` (`{method: "POST", body: data}`)
+fn fetch_method(options: &str) -> Option<String> {
+    let (_, rest) = options.split_once("method: \"")?;
+    let (method, _) = rest.split_once('"')?;
+    Some(method.to_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::NodeId;
+    use std::path::PathBuf;
+
+    fn endpoint(method: &str, path: &str) -> ApiEndpoint {
+        ApiEndpoint {
+            path: path.to_string(),
+            method: method.to_string(),
+            operation_id: None,
+            request_schema: None,
+            response_schema: None,
+            status_code: "200".to_string(),
+            parameters: Vec::new(),
+            deprecated: false,
+        }
+    }
+
+    fn add_call(
+        graph: &mut CallGraph,
+        caller: NodeId,
+        name: &str,
+        arguments: &[&str],
+        line: usize,
+    ) {
+        let callee = NodeId::from(graph.add_node(CallNode::ExternalCall {
+            name: name.to_string(),
+        }));
+        graph.add_edge(
+            *caller,
+            *callee,
+            CallEdge::Call {
+                caller,
+                callee,
+                argument_mapping: arguments
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, value)| (format!("arg{}", idx), value.to_string()))
+                    .collect(),
+                location: Location {
+                    file: "api.ts".to_string(),
+                    line,
+                    column: None,
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn test_call_to_missing_endpoint_is_unmatched() {
+        let mut graph = CallGraph::new();
+        let caller = NodeId::from(graph.add_node(CallNode::Function {
+            name: "loadUser".to_string(),
+            file: PathBuf::from("api.ts"),
+            line: 1,
+            parameters: Vec::new(),
+            return_type: None,
+            decorators: Vec::new(),
+        }));
+        add_call(&mut graph, caller, "fetch", &["`${...}/users/${...}`"], 2);
+        add_call(
+            &mut graph,
+            caller,
+            "axios.post",
+            &["\"/order\"", "payload"],
+            3,
+        );
+        add_call(&mut graph, caller, "cache.get", &["\"user\""], 4);
+
+        let calls = find_http_calls(&graph);
+        let specs = vec![
+            ApiSpec {
+                source: "users.json".to_string(),
+                endpoints: vec![endpoint("GET", "/users/{user_id}")],
+            },
+            ApiSpec {
+                source: "orders.json".to_string(),
+                endpoints: vec![endpoint("POST", "/orders"), endpoint("GET", "/orders")],
+            },
+        ];
+        let usage = match_api_usage(&calls, &specs);
+
+        assert_eq!(calls.len(), 2);
+        assert_eq!(usage.unmatched_calls.len(), 1);
+        assert_eq!(
+            (
+                usage.unmatched_calls[0].method.as_str(),
+                usage.unmatched_calls[0].path.as_str()
+            ),
+            ("POST", "/order")
+        );
+        let unused: Vec<(&str, &str)> = usage
+            .unused_endpoints
+            .iter()
+            .map(|endpoint| (endpoint.method.as_str(), endpoint.path.as_str()))
+            .collect();
+        assert_eq!(unused, vec![("GET", "/orders"), ("POST", "/orders")]);
+        assert_eq!(usage.unused_endpoints[0].spec, "orders.json");
+    }
+
+    #[test]
+    fn test_fetch_method_comes_from_options() {
+        assert_eq!(
+            http_request(
+                "fetch",
+                &[
+                    "\"https://api.example.com/users?page=2\"",
+                    "{method: \"post\", body: data}"
+                ]
+            ),
+            Some(("POST".to_string(), "/users".to_string()))
+        );
+        assert_eq!(http_request("fetch", &["url"]), None);
+    }
+}
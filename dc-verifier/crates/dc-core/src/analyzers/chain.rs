@@ -1,11 +1,13 @@
+use crate::analyzers::SchemaParser;
 use crate::call_graph::{CallGraph, CallNode, Parameter};
 use crate::data_flow::DataFlowTracker;
 use crate::models::{
-    BaseType, ChainDirection, Contract, DataChain, Link, LinkType, Location, NodeId,
-    SchemaReference, SchemaType, Severity, TypeInfo,
+    normalize_route_path, BaseType, ChainDirection, ChainStatus, Contract, DataChain, Link,
+    LinkType, Location, NodeId, SchemaReference, SchemaType, Severity, TypeInfo,
+    CONTENT_TYPE_METADATA,
 };
 use anyhow::{anyhow, bail, Result};
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::path::Path;
 
 /// Построитель цепочек данных из графа вызовов
@@ -15,12 +17,55 @@ pub struct ChainBuilder<'a> {
     /// Отслеживатель потока данных
     #[allow(dead_code)]
     data_flow: &'a DataFlowTracker<'a>,
+    /// Путь к полезной нагрузке в конверте ответа (например, `data`)
+    response_envelope: Option<String>,
+    /// Максимальное число цепочек (путей) от одной точки входа в одном направлении
+    max_paths: usize,
+    /// Максимальное число звеньев в одной цепочке
+    max_chain_length: usize,
 }
 
+/// Число путей от одной точки входа по умолчанию
+pub const DEFAULT_MAX_PATHS: usize = 16;
+
+/// Максимальное число звеньев в одной цепочке по умолчанию
+pub const DEFAULT_MAX_CHAIN_LENGTH: usize = 64;
+
+/// Путь обхода графа и признак того, что он обрезан по длине
+type CollectedPath = (Vec<NodeId>, bool);
+
 impl<'a> ChainBuilder<'a> {
     /// Создает новый построитель цепочек
     pub fn new(graph: &'a CallGraph, data_flow: &'a DataFlowTracker<'a>) -> Self {
-        Self { graph, data_flow }
+        Self {
+            graph,
+            data_flow,
+            response_envelope: None,
+            max_paths: DEFAULT_MAX_PATHS,
+            max_chain_length: DEFAULT_MAX_CHAIN_LENGTH,
+        }
+    }
+
+    /// Задает конверт ответов API: схемы ответов сравниваются по вложенному полю
+    ///
+    /// Например, с `data` ответ `{"data": User, "meta": ...}` сравнивается как `User`.
+    pub fn with_response_envelope(mut self, envelope: Option<String>) -> Self {
+        self.response_envelope = envelope;
+        self
+    }
+
+    /// Задает максимальное число цепочек на одну точку входа и направление
+    pub fn with_max_paths(mut self, max_paths: usize) -> Self {
+        self.max_paths = max_paths.max(1);
+        self
+    }
+
+    /// Задает максимальное число звеньев в цепочке
+    ///
+    /// Более длинные пути обрезаются, а цепочка помечается `truncated`.
+    pub fn with_max_chain_length(mut self, max_chain_length: usize) -> Self {
+        self.max_chain_length = max_chain_length.max(1);
+        self
     }
 
     /// Строит цепочку от точки входа до конечной точки
@@ -33,71 +78,153 @@ impl<'a> ChainBuilder<'a> {
 
     /// Находит все цепочки в проекте
     pub fn find_all_chains(&self) -> Result<Vec<DataChain>> {
-        let mut chains = Vec::new();
+        Ok(self.iter_chains().filter_map(Result::ok).collect())
+    }
 
+    /// Лениво перебирает цепочки проекта
+    ///
+    /// Цепочки строятся по мере обхода routes (для каждого - прямые и обратные),
+    /// поэтому их можно проверять и отдавать в отчет, не держа все в памяти.
+    /// Ошибки построения отдельных цепочек возвращаются как `Err`.
+    pub fn iter_chains(&self) -> impl Iterator<Item = Result<DataChain>> + '_ {
         // Находим все routes (точки входа API)
         let routes =
-            crate::call_graph::find_nodes(&self.graph, |n| matches!(n, CallNode::Route { .. }));
-
-        for route in routes {
-            // Строим цепочку Frontend → Backend → Database
-            if let Ok(forward_chain) = self.build_forward_chain(route) {
-                chains.push(forward_chain);
-            }
-
-            // Строим цепочку Database → Backend → Frontend
-            if let Ok(reverse_chain) = self.build_reverse_chain(route) {
-                chains.push(reverse_chain);
-            }
-        }
-
-        Ok(chains)
+            crate::call_graph::find_nodes(self.graph, |n| matches!(n, CallNode::Route { .. }));
+
+        routes.into_iter().flat_map(move |route| {
+            [
+                // Frontend → Backend → Database
+                self.build_forward_chains(route),
+                // Database → Backend → Frontend
+                self.build_reverse_chains(route),
+            ]
+            .into_iter()
+            .flat_map(|chains| match chains {
+                Ok(chains) => chains.into_iter().map(Ok).collect::<Vec<_>>(),
+                Err(error) => vec![Err(error)],
+            })
+        })
     }
 
-    /// Строит цепочку Frontend → Backend → Database
+    /// Строит цепочку Frontend → Backend → Database (первый путь от точки входа)
     pub fn build_forward_chain(&self, start: NodeId) -> Result<DataChain> {
+        self.first_chain(self.build_forward_chains(start)?)
+    }
+
+    /// Строит цепочки Frontend → Backend → Database: по одной на каждый путь
+    ///
+    /// Ветвящийся обработчик (route вызывает два сервиса) дает несколько цепочек.
+    pub fn build_forward_chains(&self, start: NodeId) -> Result<Vec<DataChain>> {
         self.ensure_node_exists(start)?;
-        let path = self.collect_path(start, |node| {
-            crate::call_graph::outgoing_nodes(&self.graph, node)
+        let paths = self.collect_paths(start, |node| {
+            crate::call_graph::outgoing_nodes(self.graph, node)
         });
-
-        if path.is_empty() {
+        if paths.is_empty() {
             bail!("Не удалось построить прямую цепочку: пустой путь");
         }
 
-        let links = self.create_links_from_nodes(&path, ChainDirection::FrontendToBackend)?;
-        let contracts = self.build_contracts(&links);
-
-        Ok(DataChain {
-            id: format!("chain-{}", start.index()),
-            name: self.generate_chain_name(start)?,
-            links,
-            contracts,
-            direction: ChainDirection::FrontendToBackend,
-        })
+        let name = self.generate_chain_name(start)?;
+        paths
+            .iter()
+            .enumerate()
+            .map(|(index, (path, truncated))| {
+                let links =
+                    self.create_links_from_nodes(path, ChainDirection::FrontendToBackend)?;
+                let contracts = self.build_contracts(&links);
+
+                Ok(DataChain {
+                    id: Self::path_chain_id(format!("chain-{}", start.index()), index),
+                    name: Self::path_chain_id(name.clone(), index),
+                    links,
+                    contracts,
+                    direction: ChainDirection::FrontendToBackend,
+                    status: ChainStatus::default(),
+                    truncated: *truncated,
+                })
+            })
+            .collect()
     }
 
-    /// Строит цепочку Database → Backend → Frontend
+    /// Строит цепочку Database → Backend → Frontend (первый путь к точке входа)
     pub fn build_reverse_chain(&self, start: NodeId) -> Result<DataChain> {
+        self.first_chain(self.build_reverse_chains(start)?)
+    }
+
+    /// Строит цепочки Database → Backend → Frontend: по одной на каждый путь
+    ///
+    /// Путь идет по ребрам `Return` (куда возвращается значение узла), а для узлов
+    /// без них - по обращенным ребрам вызовов.
+    pub fn build_reverse_chains(&self, start: NodeId) -> Result<Vec<DataChain>> {
         self.ensure_node_exists(start)?;
-        let mut path = self.collect_path(start, |node| {
-            crate::call_graph::incoming_nodes(&self.graph, node)
+        let paths = self.collect_paths(start, |node| {
+            let receivers = crate::call_graph::return_receivers(self.graph, node);
+            if receivers.is_empty() {
+                crate::call_graph::incoming_nodes(self.graph, node)
+            } else {
+                receivers
+            }
         });
-        if path.is_empty() {
+        if paths.is_empty() {
             bail!("Не удалось построить обратную цепочку: пустой путь");
         }
-        path.reverse();
 
-        let links = self.create_links_from_nodes(&path, ChainDirection::BackendToFrontend)?;
-        let contracts = self.build_contracts(&links);
+        let name = format!("{} (reverse)", self.generate_chain_name(start)?);
+        paths
+            .into_iter()
+            .enumerate()
+            .map(|(index, (mut path, truncated))| {
+                path.reverse();
+                let links =
+                    self.create_links_from_nodes(&path, ChainDirection::BackendToFrontend)?;
+                // Стык с route дает по контракту на каждый HTTP код ответа:
+                // успешный с его кодом, затем объявленные ответы с ошибкой
+                let mut contracts = Vec::new();
+                for ((mut contract, nodes), pair) in self
+                    .build_contracts(&links)
+                    .into_iter()
+                    .zip(path.windows(2))
+                    .zip(links.windows(2))
+                {
+                    contract.status_code = self
+                        .route_status_code(nodes[0])
+                        .or_else(|| self.route_status_code(nodes[1]));
+                    contracts.push(contract);
+                    let (route, consumer) = if self.route_status_code(nodes[0]).is_some() {
+                        (&pair[0], &pair[1])
+                    } else {
+                        (&pair[1], &pair[0])
+                    };
+                    let errors = self.route_error_responses(route.node_id);
+                    contracts.extend(self.build_response_contracts(route, consumer, &errors));
+                }
 
-        Ok(DataChain {
-            id: format!("chain-reverse-{}", start.index()),
-            name: format!("{} (reverse)", self.generate_chain_name(start)?),
-            links,
-            contracts,
-            direction: ChainDirection::BackendToFrontend,
-        })
+                Ok(DataChain {
+                    id: Self::path_chain_id(format!("chain-reverse-{}", start.index()), index),
+                    name: Self::path_chain_id(name.clone(), index),
+                    links,
+                    contracts,
+                    direction: ChainDirection::BackendToFrontend,
+                    status: ChainStatus::default(),
+                    truncated,
+                })
+            })
+            .collect()
+    }
+
+    fn first_chain(&self, chains: Vec<DataChain>) -> Result<DataChain> {
+        chains
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("Не удалось построить цепочку: нет путей"))
+    }
+
+    /// Идентификатор цепочки пути: первый путь сохраняет базовый, остальные нумеруются
+    fn path_chain_id(base: String, index: usize) -> String {
+        if index == 0 {
+            base
+        } else {
+            format!("{} #{}", base, index + 1)
+        }
     }
 
     fn ensure_node_exists(&self, node_id: NodeId) -> Result<()> {
@@ -108,38 +235,71 @@ impl<'a> ChainBuilder<'a> {
         }
     }
 
-    fn collect_path<F>(&self, start: NodeId, get_neighbors: F) -> Vec<NodeId>
+    /// Перебирает различные пути от `start` до конечных узлов (обход в глубину)
+    ///
+    /// Путь заканчивается на узле без непосещенных соседей. Число путей ограничено
+    /// `max_paths`, длина пути - `max_chain_length` (такой путь помечается обрезанным).
+    /// Первый путь совпадает с жадным обходом по первому соседу.
+    fn collect_paths<F>(&self, start: NodeId, get_neighbors: F) -> Vec<CollectedPath>
     where
         F: Fn(NodeId) -> Vec<NodeId>,
     {
-        let mut order = Vec::new();
-        let mut current = start;
-        let mut visited = HashSet::new();
+        let mut paths = Vec::new();
+        let mut path = vec![start];
+        self.extend_paths(&mut path, &get_neighbors, &mut paths);
+        paths
+    }
 
-        loop {
-            if visited.contains(&current) {
-                break;
+    fn extend_paths<F>(
+        &self,
+        path: &mut Vec<NodeId>,
+        get_neighbors: &F,
+        paths: &mut Vec<CollectedPath>,
+    ) where
+        F: Fn(NodeId) -> Vec<NodeId>,
+    {
+        let current = path[path.len() - 1];
+        let mut next = Vec::new();
+        // Внешние вызовы не несут схем данных - цепочка через них не идет
+        for candidate in get_neighbors(current) {
+            if !path.contains(&candidate)
+                && !next.contains(&candidate)
+                && !self.is_external_call(candidate)
+            {
+                next.push(candidate);
             }
-            visited.insert(current);
-            order.push(current);
+        }
 
-            let next = get_neighbors(current)
-                .into_iter()
-                .find(|candidate| !visited.contains(candidate));
+        if next.is_empty() {
+            paths.push((path.clone(), false));
+            return;
+        }
+        if path.len() >= self.max_chain_length {
+            paths.push((path.clone(), true));
+            return;
+        }
 
-            match next {
-                Some(next_node) => current = next_node,
-                None => break,
+        for candidate in next {
+            if paths.len() >= self.max_paths {
+                break;
             }
+            path.push(candidate);
+            self.extend_paths(path, get_neighbors, paths);
+            path.pop();
         }
+    }
 
-        order
+    fn is_external_call(&self, node_id: NodeId) -> bool {
+        matches!(
+            self.graph.node_weight(*node_id),
+            Some(CallNode::ExternalCall { .. })
+        )
     }
 
     fn create_links_from_nodes(
         &self,
         nodes: &[NodeId],
-        _direction: ChainDirection,
+        direction: ChainDirection,
     ) -> Result<Vec<Link>> {
         let total = nodes.len();
         nodes
@@ -148,15 +308,13 @@ impl<'a> ChainBuilder<'a> {
             .map(|(idx, node_id)| {
                 let mut link_type = self.determine_link_type(*node_id);
                 // Упрощенная логика без дублирования по direction
-                if total == 1 {
-                    link_type = LinkType::Source;
-                } else if idx == 0 {
+                if total == 1 || idx == 0 {
                     link_type = LinkType::Source;
                 } else if idx == total - 1 {
                     link_type = LinkType::Sink;
                 }
                 // Иначе используем link_type из determine_link_type
-                self.create_link_from_node(*node_id, link_type)
+                self.create_link_from_node(*node_id, link_type, direction)
             })
             .collect()
     }
@@ -179,10 +337,76 @@ impl<'a> ChainBuilder<'a> {
             to_schema: to.schema_ref.clone(),
             mismatches: Vec::new(),
             severity: Severity::Info,
+            status_code: None,
+        }
+    }
+
+    /// Строит контракты ответа эндпоинта: по одному контракту на каждый HTTP код
+    ///
+    /// `responses` - пары (код ответа, схема тела ответа), например `200` и `422`.
+    /// Успешный ответ сравнивается со схемой потребителя, а тело ошибки потребитель
+    /// не типизирует: контракт ошибки фиксирует ее схему с обеих сторон.
+    pub fn build_response_contracts(
+        &self,
+        route: &Link,
+        consumer: &Link,
+        responses: &[(u16, SchemaReference)],
+    ) -> Vec<Contract> {
+        responses
+            .iter()
+            .map(|(status_code, schema)| {
+                let from_schema = self.unwrap_response(schema);
+                let to_schema = if (200..300).contains(status_code) {
+                    consumer.schema_ref.clone()
+                } else {
+                    from_schema.clone()
+                };
+                Contract {
+                    from_link_id: route.id.clone(),
+                    to_link_id: consumer.id.clone(),
+                    from_schema,
+                    to_schema,
+                    mismatches: Vec::new(),
+                    severity: Severity::Info,
+                    status_code: Some(*status_code),
+                }
+            })
+            .collect()
+    }
+
+    /// Ответы route с ошибкой (`responses=...` и неявный 422) со схемами тела
+    fn route_error_responses(&self, node_id: NodeId) -> Vec<(u16, SchemaReference)> {
+        let Some(CallNode::Route { responses, .. }) = self.graph.node_weight(*node_id) else {
+            return Vec::new();
+        };
+        responses
+            .iter()
+            .filter_map(|(code, type_info)| Some((*code, self.schema_from_type_info(type_info)?)))
+            .collect()
+    }
+
+    /// HTTP код ответа route: `status_code=...` декоратора, по умолчанию 200
+    fn route_status_code(&self, node_id: NodeId) -> Option<u16> {
+        match self.graph.node_weight(*node_id)? {
+            CallNode::Route { status_code, .. } => Some(status_code.unwrap_or(200)),
+            _ => None,
         }
     }
 
-    fn create_link_from_node(&self, node_id: NodeId, link_type: LinkType) -> Result<Link> {
+    /// Снимает конверт ответа, если он настроен и схема его содержит
+    fn unwrap_response(&self, schema: &SchemaReference) -> SchemaReference {
+        self.response_envelope
+            .as_deref()
+            .and_then(|envelope| SchemaParser::unwrap_envelope(schema, envelope))
+            .unwrap_or_else(|| schema.clone())
+    }
+
+    fn create_link_from_node(
+        &self,
+        node_id: NodeId,
+        link_type: LinkType,
+        direction: ChainDirection,
+    ) -> Result<Link> {
         let node = self
             .graph
             .node_weight(*node_id)
@@ -191,9 +415,23 @@ impl<'a> ChainBuilder<'a> {
 
         let (id, location, schema_ref) = match node {
             CallNode::Route { path, location, .. } => {
-                let schema = self.extract_route_schema(node_id)?;
+                // В обратной цепочке route отдает ответ клиенту (без конверта)
+                let response_schema = match direction {
+                    ChainDirection::BackendToFrontend => self
+                        .extract_route_response_schema(node_id)
+                        .map(|schema| self.unwrap_response(&schema)),
+                    ChainDirection::FrontendToBackend => None,
+                };
+                let schema = match response_schema {
+                    Some(schema) => schema,
+                    None => self.extract_route_schema(node_id)?,
+                };
                 (
-                    format!("route-{}-{}", path.replace('/', "-"), node_id.index()),
+                    format!(
+                        "route-{}-{}",
+                        normalize_route_path(&path).replace('/', "-"),
+                        node_id.index()
+                    ),
                     location,
                     schema,
                 )
@@ -243,6 +481,12 @@ impl<'a> ChainBuilder<'a> {
                     path.display()
                 );
             }
+            CallNode::ExternalCall { name } => {
+                bail!(
+                    "Невозможно создать звено цепочки из внешнего вызова: {}",
+                    name
+                );
+            }
         };
 
         Ok(Link {
@@ -276,18 +520,29 @@ impl<'a> ChainBuilder<'a> {
             .ok_or_else(|| anyhow!("Route узел не найден: {:?}", route_node_id))?;
 
         if let CallNode::Route { handler, .. } = route_node {
-            if let Some(handler_node) = self.graph.node_weight(handler.0).cloned() {
-                if let CallNode::Function {
-                    name,
-                    parameters,
-                    file,
-                    line,
-                    ..
-                } = handler_node
-                {
-                    let location = self.location_from_path(&file, line);
-                    return Ok(self.extract_function_schema(&parameters, &name, &location));
+            if let Some(CallNode::Function {
+                name,
+                parameters,
+                file,
+                line,
+                ..
+            }) = self.graph.node_weight(handler.0).cloned()
+            {
+                let location = self.location_from_path(&file, line);
+                let mut schema = self.extract_function_schema(&parameters, &name, &location);
+                // Параметры `Form(...)`/`File(...)` - тело запроса передается формой
+                let has_form_parts = parameters.iter().any(|param| {
+                    param.default_value.as_deref().is_some_and(|default| {
+                        default.starts_with("Form(") || default.starts_with("File(")
+                    })
+                });
+                if has_form_parts && !schema.is_form_body() {
+                    schema.metadata.insert(
+                        CONTENT_TYPE_METADATA.to_string(),
+                        "multipart/form-data".to_string(),
+                    );
                 }
+                return Ok(schema);
             }
         }
 
@@ -301,6 +556,162 @@ impl<'a> ChainBuilder<'a> {
         ))
     }
 
+    /// Извлекает схему ответа маршрута
+    ///
+    /// `response_model=...` декоратора - авторитетная схема ответа; тип возврата
+    /// handler используется, только если `response_model` не задан.
+    pub fn extract_route_response_schema(&self, route_node_id: NodeId) -> Option<SchemaReference> {
+        let CallNode::Route {
+            handler,
+            response_model,
+            ..
+        } = self.graph.node_weight(*route_node_id)?
+        else {
+            return None;
+        };
+        if let Some(response_model) = response_model {
+            return self.schema_from_type_info(response_model);
+        }
+
+        match self.graph.node_weight(handler.0)? {
+            CallNode::Function {
+                return_type: Some(return_type),
+                ..
+            } => self.schema_from_type_info(return_type),
+            _ => None,
+        }
+    }
+
+    /// Извлекает схему параметров запроса маршрута (query/path/header/cookie)
+    ///
+    /// В схему попадают параметры с маркерами `Query(...)`, `Path(...)`, `Header(...)`,
+    /// `Cookie(...)`, параметры из шаблона пути и простые типизированные параметры.
+    /// Тело запроса (модели, `Body`/`Form`/`File`) и зависимости (`Depends`) не входят.
+    pub fn extract_route_parameters_schema(
+        &self,
+        route_node_id: NodeId,
+    ) -> Option<SchemaReference> {
+        let CallNode::Route {
+            path,
+            handler,
+            location,
+            ..
+        } = self.graph.node_weight(*route_node_id)?
+        else {
+            return None;
+        };
+        let CallNode::Function {
+            name, parameters, ..
+        } = self.graph.node_weight(handler.0)?
+        else {
+            return None;
+        };
+
+        let path_params = Self::path_parameter_names(path);
+        let mut fields = Vec::new();
+        let mut required = Vec::new();
+        let mut locations = Vec::new();
+
+        for param in parameters {
+            let Some(kind) = Self::parameter_kind(param, &path_params) else {
+                continue;
+            };
+            fields.push(format!(
+                "{}:{}",
+                param.name,
+                Self::field_type_name(param.type_info.base_type)
+            ));
+            if kind == "path" || !param.optional {
+                required.push(param.name.clone());
+            }
+            locations.push(format!("{}:{}", param.name, kind));
+        }
+
+        if fields.is_empty() {
+            return None;
+        }
+
+        let mut metadata = HashMap::new();
+        metadata.insert("fields".to_string(), fields.join(","));
+        metadata.insert("required".to_string(), required.join(","));
+        metadata.insert("parameter_locations".to_string(), locations.join(","));
+
+        Some(SchemaReference {
+            name: format!("{}Parameters", name),
+            schema_type: SchemaType::Pydantic,
+            location: location.clone(),
+            metadata,
+        })
+    }
+
+    /// Определяет, откуда FastAPI берет параметр: query, path, header или cookie
+    pub(crate) fn parameter_kind(
+        param: &Parameter,
+        path_params: &[String],
+    ) -> Option<&'static str> {
+        if let Some(default) = &param.default_value {
+            let markers = [
+                ("Query(", "query"),
+                ("Path(", "path"),
+                ("Header(", "header"),
+                ("Cookie(", "cookie"),
+            ];
+            if let Some((_, kind)) = markers.iter().find(|(m, _)| default.starts_with(m)) {
+                return Some(kind);
+            }
+            // Тело запроса и зависимости
+            if ["Body(", "Form(", "File(", "Depends("]
+                .iter()
+                .any(|m| default.starts_with(m))
+            {
+                return None;
+            }
+        }
+
+        if path_params.contains(&param.name) {
+            return Some("path");
+        }
+
+        // Простые типы без модели FastAPI считает query-параметрами
+        let is_scalar = matches!(
+            param.type_info.base_type,
+            BaseType::String
+                | BaseType::Integer
+                | BaseType::Number
+                | BaseType::Boolean
+                | BaseType::Enum
+        );
+        if param.type_info.schema_ref.is_none() && is_scalar {
+            return Some("query");
+        }
+
+        None
+    }
+
+    /// Имена параметров из шаблона пути (`/items/{item_id}` → `item_id`)
+    pub(crate) fn path_parameter_names(path: &str) -> Vec<String> {
+        normalize_route_path(path)
+            .split('/')
+            .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Имя типа поля в формате метаданных схемы
+    fn field_type_name(base_type: BaseType) -> &'static str {
+        match base_type {
+            BaseType::String => "str",
+            BaseType::Integer => "int",
+            BaseType::Number => "float",
+            BaseType::Boolean => "bool",
+            BaseType::Object => "dict",
+            BaseType::Array => "list",
+            BaseType::Enum => "enum",
+            BaseType::Null => "none",
+            BaseType::Any | BaseType::Unknown => "any",
+        }
+    }
+
     fn extract_class_schema(&self, name: &str, location: &Location) -> SchemaReference {
         SchemaReference {
             name: name.to_string(),
@@ -387,11 +798,12 @@ impl<'a> ChainBuilder<'a> {
         Ok(match node {
             CallNode::Route { path, method, .. } => {
                 let method_str = format!("{:?}", method).to_uppercase();
-                format!("{} {}", method_str, path)
+                format!("{} {}", method_str, normalize_route_path(path))
             }
             CallNode::Function { name, .. } => format!("Function {}", name),
             CallNode::Class { name, .. } => format!("Class {}", name),
             CallNode::Method { name, .. } => format!("Method {}", name),
+            CallNode::ExternalCall { name } => format!("External {}", name),
             CallNode::Module { path } => {
                 format!(
                     "Module {}",
@@ -1,20 +1,44 @@
 use crate::analyzers::ContractRule;
-use crate::models::{Contract, Mismatch};
+use crate::models::{AllowRule, Contract, Mismatch, Severity};
+use std::collections::HashMap;
+
+/// Чувствительные поля по умолчанию (несоответствия на них всегда критичны)
+pub const DEFAULT_SENSITIVE_FIELDS: &[&str] = &[
+    "password",
+    "token",
+    "secret",
+    "api_key",
+    "access_token",
+    "refresh_token",
+    "ssn",
+];
 
 /// Проверятель контрактов - применяет правила к контрактам
 pub struct ContractChecker {
     rules: Vec<Box<dyn ContractRule>>,
+    allowlist: Vec<AllowRule>,
+    sensitive_fields: Vec<String>,
+    /// Серьезность правил из конфига (имя правила → серьезность)
+    severities: HashMap<String, Severity>,
 }
 
 impl ContractChecker {
     /// Создает новый проверятель с правилами по умолчанию
     pub fn new() -> Self {
-        let mut checker = Self { rules: Vec::new() };
+        let mut checker = Self {
+            rules: Vec::new(),
+            allowlist: Vec::new(),
+            sensitive_fields: Vec::new(),
+            severities: HashMap::new(),
+        };
 
         // Добавляем правила по умолчанию
         checker.add_rule(Box::new(crate::analyzers::TypeMismatchRule));
-        checker.add_rule(Box::new(crate::analyzers::MissingFieldRule));
+        checker.add_rule(Box::new(crate::analyzers::EnumMismatchRule));
+        checker.add_rule(Box::new(crate::analyzers::MissingFieldRule::default()));
+        checker.add_rule(Box::new(crate::analyzers::NamingMismatchRule));
         checker.add_rule(Box::new(crate::analyzers::UnnormalizedDataRule));
+        checker.add_rule(Box::new(crate::analyzers::ArraySizeRule));
 
         checker
     }
@@ -24,18 +48,154 @@ impl ContractChecker {
         self.rules.push(rule);
     }
 
+    /// Отключает правило по имени
+    ///
+    /// Без `naming_mismatch` поля, переданные под другим именем, сообщаются
+    /// правилом `missing_field`.
+    pub fn without_rule(mut self, name: &str) -> Self {
+        self.rules.retain(|rule| rule.name() != name);
+        if name == "naming_mismatch" {
+            for rule in &mut self.rules {
+                if rule.name() == "missing_field" {
+                    *rule = Box::new(crate::analyzers::MissingFieldRule {
+                        report_naming_variants: true,
+                    });
+                }
+            }
+        }
+        self
+    }
+
+    /// Включает необязательную проверку примеров схем (`invalid_example`)
+    pub fn with_example_validation(mut self) -> Self {
+        self.add_rule(Box::new(crate::analyzers::InvalidExampleRule));
+        self
+    }
+
+    /// Включает необязательный поиск нетипизированных полей (`flag_any`)
+    pub fn with_any_flagging(mut self) -> Self {
+        self.add_rule(Box::new(crate::analyzers::FlagAnyRule));
+        self
+    }
+
+    /// Задает список разрешенных (намеренных) несоответствий
+    pub fn with_allowlist(mut self, allowlist: Vec<AllowRule>) -> Self {
+        self.allowlist = allowlist;
+        self
+    }
+
+    /// Задает чувствительные поля: несоответствия на них повышаются до `Critical`
+    pub fn with_sensitive_fields(mut self, fields: Vec<String>) -> Self {
+        self.sensitive_fields = fields;
+        self
+    }
+
+    /// Переопределяет серьезность правил (имя правила → серьезность)
+    pub fn with_rule_severities(mut self, severities: HashMap<String, Severity>) -> Self {
+        self.severities = severities;
+        self
+    }
+
+    /// Серьезность находок правила: из конфига или объявленная правилом
+    pub fn rule_severity(&self, rule: &dyn ContractRule) -> Severity {
+        self.severities
+            .get(rule.name())
+            .copied()
+            .unwrap_or_else(|| rule.default_severity())
+    }
+
+    /// Возвращает все зарегистрированные правила
+    pub fn rules(&self) -> impl Iterator<Item = &dyn ContractRule> {
+        self.rules.iter().map(|rule| rule.as_ref())
+    }
+
+    /// Находит правило по имени
+    pub fn find_rule(&self, name: &str) -> Option<&dyn ContractRule> {
+        self.rules().find(|rule| rule.name() == name)
+    }
+
+    /// Возвращает документацию правила (имя и описание) или None, если правило неизвестно
+    pub fn explain(&self, name: &str) -> Option<String> {
+        self.find_rule(name)
+            .map(|rule| format!("{}\n\n{}", rule.name(), rule.description()))
+    }
+
     /// Проверяет контракт между двумя звеньями
+    ///
+    /// Формы (`multipart/form-data`, загрузка файлов) не являются JSON моделями:
+    /// правила полей к ним не применяются.
     pub fn check_contract(&self, contract: &Contract) -> Vec<Mismatch> {
         let mut all_mismatches = Vec::new();
+        if contract.from_schema.is_form_body() || contract.to_schema.is_form_body() {
+            return all_mismatches;
+        }
 
         for rule in &self.rules {
             let mismatches = rule.check(contract);
-            all_mismatches.extend(mismatches);
+            all_mismatches.extend(mismatches.into_iter().map(|mut mismatch| {
+                mismatch.rule = rule.name().to_string();
+                mismatch
+            }));
         }
 
         all_mismatches
     }
 
+    /// Проверяет контракт цепочки `chain_name`, отбрасывая разрешенные несоответствия
+    pub fn check_chain_contract(&self, chain_name: &str, contract: &Contract) -> Vec<Mismatch> {
+        self.check_contract(contract)
+            .into_iter()
+            .filter(|mismatch| !self.is_allowed(chain_name, mismatch))
+            .collect()
+    }
+
+    /// Проверяет, покрыто ли несоответствие списком разрешений
+    pub fn is_allowed(&self, chain_name: &str, mismatch: &Mismatch) -> bool {
+        self.allowlist
+            .iter()
+            .any(|rule| rule.matches(chain_name, mismatch))
+    }
+
+    /// Проверяет, относится ли несоответствие к чувствительному полю
+    ///
+    /// Сравнивается последний сегмент пути без учета регистра
+    /// (`user.password` совпадает с `password`).
+    pub fn is_sensitive(&self, mismatch: &Mismatch) -> bool {
+        let field = mismatch.path.rsplit('.').next().unwrap_or(&mismatch.path);
+        self.sensitive_fields
+            .iter()
+            .any(|sensitive| sensitive.eq_ignore_ascii_case(field))
+    }
+
+    /// Серьезность одного несоответствия
+    ///
+    /// Берется серьезность правила, обнаружившего несоответствие; если правило
+    /// неизвестно (находки вне `check_contract`), - наибольшая среди правил,
+    /// сообщающих о таком типе несоответствий. Несоответствия на
+    /// чувствительных полях всегда критичны.
+    pub fn mismatch_severity(&self, mismatch: &Mismatch) -> Severity {
+        if self.is_sensitive(mismatch) {
+            return Severity::Critical;
+        }
+        if let Some(rule) = self.find_rule(&mismatch.rule) {
+            return self.rule_severity(rule);
+        }
+        self.rules()
+            .filter(|rule| rule.mismatch_types().contains(&mismatch.mismatch_type))
+            .map(|rule| self.rule_severity(rule))
+            .max()
+            .unwrap_or(Severity::Warning)
+    }
+
+    /// Серьезность контракта - максимальная среди его несоответствий
+    pub fn contract_severity(&self, mismatches: &[Mismatch]) -> Severity {
+        mismatches
+            .iter()
+            .map(|mismatch| self.mismatch_severity(mismatch))
+            .max()
+            .unwrap_or(Severity::Info)
+    }
+
     /// Сравнивает две схемы и находит несоответствия
     pub fn compare_schemas(
         &self,
@@ -50,6 +210,7 @@ impl ContractChecker {
             to_schema: to.clone(),
             mismatches: Vec::new(),
             severity: crate::models::Severity::Info,
+            status_code: None,
         };
 
         // Используем все правила для проверки
@@ -62,3 +223,238 @@ impl Default for ContractChecker {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Location, MismatchType};
+    use crate::test_support::{contract, schema};
+    use serde_json::json;
+
+    #[test]
+    fn test_explain_type_mismatch() {
+        let checker = ContractChecker::new();
+        let doc = checker.explain("type_mismatch").unwrap();
+        assert!(doc.starts_with("type_mismatch"));
+        assert!(!checker
+            .find_rule("type_mismatch")
+            .unwrap()
+            .description()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_allowlisted_type_transformation_is_not_reported() {
+        let from = schema(
+            "UserRequest",
+            json!({
+                "type": "object",
+                "properties": {"user_id": {"type": "string"}, "age": {"type": "string"}},
+                "required": ["user_id", "age"]
+            }),
+        );
+        let to = schema(
+            "User",
+            json!({
+                "type": "object",
+                "properties": {"user_id": {"type": "integer"}, "age": {"type": "integer"}},
+                "required": ["user_id", "age"]
+            }),
+        );
+        let contract = contract(from, to);
+
+        let checker = ContractChecker::new().with_allowlist(vec![AllowRule {
+            route: Some("/users".to_string()),
+            path: Some("user_id".to_string()),
+            mismatch_type: Some(MismatchType::TypeMismatch),
+        }]);
+
+        let mismatches = checker.check_chain_contract("POST /users", &contract);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].path, "age");
+
+        // The allow rule is scoped to its route
+        let other = checker.check_chain_contract("POST /orders", &contract);
+        assert_eq!(other.len(), 2);
+    }
+
+    fn mismatch(mismatch_type: MismatchType, path: &str) -> Mismatch {
+        let type_info = crate::models::TypeInfo {
+            base_type: crate::models::BaseType::String,
+            schema_ref: None,
+            constraints: Vec::new(),
+            optional: false,
+        };
+        Mismatch {
+            mismatch_type,
+            rule: String::new(),
+            path: path.to_string(),
+            expected: type_info.clone(),
+            actual: type_info,
+            location: Location {
+                file: "schema.json".to_string(),
+                line: 1,
+                column: None,
+            },
+            message: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_sensitive_field_escalates_to_critical() {
+        let checker = ContractChecker::new().with_sensitive_fields(
+            DEFAULT_SENSITIVE_FIELDS
+                .iter()
+                .map(|f| f.to_string())
+                .collect(),
+        );
+
+        // Type mismatches: escalated on `password`, rule default (critical) on `nickname`
+        assert_eq!(
+            checker.contract_severity(&[mismatch(MismatchType::TypeMismatch, "password")]),
+            Severity::Critical
+        );
+        assert_eq!(
+            checker.contract_severity(&[mismatch(MismatchType::TypeMismatch, "nickname")]),
+            Severity::Critical
+        );
+
+        // Non-critical mismatches make the escalation visible
+        assert_eq!(
+            checker.contract_severity(&[mismatch(MismatchType::MissingField, "user.Password")]),
+            Severity::Critical
+        );
+        assert_eq!(
+            checker.contract_severity(&[mismatch(MismatchType::MissingField, "nickname")]),
+            Severity::Warning
+        );
+        assert_eq!(checker.contract_severity(&[]), Severity::Info);
+    }
+
+    #[test]
+    fn test_severity_follows_reporting_rule() {
+        let mut severities = HashMap::new();
+        severities.insert("enum_mismatch".to_string(), Severity::Info);
+        severities.insert("array_size".to_string(), Severity::Critical);
+        let checker = ContractChecker::new().with_rule_severities(severities);
+
+        let from = schema(
+            "OrderRequest",
+            json!({
+                "type": "object",
+                "properties": {"status": {"type": "string", "enum": ["new", "paid", "refunded"]}},
+                "required": ["status"]
+            }),
+        );
+        let to = schema(
+            "Order",
+            json!({
+                "type": "object",
+                "properties": {"status": {"type": "string", "enum": ["new", "paid"]}},
+                "required": ["status"]
+            }),
+        );
+        let mismatches = checker.compare_schemas(&from, &to);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].rule, "enum_mismatch");
+        assert_eq!(
+            mismatches[0].mismatch_type,
+            MismatchType::ValidationMismatch
+        );
+
+        // array_size shares ValidationMismatch, but its severity does not leak
+        assert_eq!(checker.contract_severity(&mismatches), Severity::Info);
+        // Without a known rule the highest severity of the type applies
+        assert_eq!(
+            checker.contract_severity(&[mismatch(MismatchType::ValidationMismatch, "status")]),
+            Severity::Critical
+        );
+    }
+
+    #[test]
+    fn test_flag_any_findings_are_warnings() {
+        let checker = ContractChecker::new().with_any_flagging();
+        let from = schema(
+            "Event",
+            json!({"type": "object", "properties": {"payload": {}}, "required": ["payload"]}),
+        );
+        let to = schema(
+            "EventIn",
+            json!({
+                "type": "object",
+                "properties": {"payload": {"type": "string"}},
+                "required": ["payload"]
+            }),
+        );
+
+        let mismatches = checker.compare_schemas(&from, &to);
+        assert!(mismatches
+            .iter()
+            .any(|mismatch| mismatch.rule == "flag_any"));
+        // TypeMismatch findings of flag_any do not take type_mismatch's critical severity
+        assert_eq!(checker.contract_severity(&mismatches), Severity::Warning);
+    }
+
+    #[test]
+    fn test_disabled_naming_mismatch_reports_missing_field() {
+        let from = schema(
+            "UserForm",
+            json!({
+                "type": "object",
+                "properties": {"userName": {"type": "string"}},
+                "required": ["userName"]
+            }),
+        );
+        let to = schema(
+            "UserCreate",
+            json!({
+                "type": "object",
+                "properties": {"user_name": {"type": "string"}},
+                "required": ["user_name"]
+            }),
+        );
+
+        let mismatches = ContractChecker::new().compare_schemas(&from, &to);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].mismatch_type, MismatchType::NamingMismatch);
+
+        let checker = ContractChecker::new().without_rule("naming_mismatch");
+        assert!(checker.find_rule("naming_mismatch").is_none());
+        let mismatches = checker.compare_schemas(&from, &to);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].mismatch_type, MismatchType::MissingField);
+        assert_eq!(mismatches[0].path, "user_name");
+    }
+
+    #[test]
+    fn test_disabled_naming_mismatch_skips_renamed_field_not_in_required() {
+        let from = schema(
+            "UserForm",
+            json!({
+                "type": "object",
+                "properties": {"userName": {"type": "string"}}
+            }),
+        );
+        // `user_name` is not in `required`: nothing to report once it is renamed
+        let to = schema(
+            "UserCreate",
+            json!({
+                "type": "object",
+                "properties": {"user_name": {"type": "string"}, "email": {"type": "string"}},
+                "required": ["email"]
+            }),
+        );
+
+        let checker = ContractChecker::new().without_rule("naming_mismatch");
+        let mismatches = checker.compare_schemas(&from, &to);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].mismatch_type, MismatchType::MissingField);
+        assert_eq!(mismatches[0].path, "email");
+    }
+
+    #[test]
+    fn test_explain_unknown_rule() {
+        let checker = ContractChecker::new();
+        assert!(checker.explain("no_such_rule").is_none());
+    }
+}
@@ -0,0 +1,196 @@
+use crate::analyzers::ChainBuilder;
+use crate::call_graph::{CallGraph, CallNode, Parameter};
+use crate::models::{normalize_route_path, BaseType, Location, TypeInfo};
+use serde::{Deserialize, Serialize};
+
+/// Насколько типизирован route: параметры, тело запроса и ответ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteCoverage {
+    /// HTTP метод ("POST")
+    pub method: String,
+    /// Путь route
+    pub path: String,
+    /// Имя handler функции
+    pub handler: String,
+    /// Расположение route в коде
+    pub location: Location,
+    /// У всех параметров path/query/header/cookie известен тип
+    pub params_typed: bool,
+    /// Тело запроса разрешено в схему или тип (`None` - у handler нет параметров тела)
+    pub body_typed: Option<bool>,
+    /// Тип ответа известен (`response_model` или тип возврата handler)
+    pub response_typed: bool,
+    /// Параметры без аннотации или с неразрешенным типом
+    pub unresolved_parameters: Vec<String>,
+}
+
+impl RouteCoverage {
+    /// Параметры, тело и ответ route полностью типизированы
+    pub fn is_fully_typed(&self) -> bool {
+        self.params_typed && self.body_typed != Some(false) && self.response_typed
+    }
+}
+
+/// Карта типизации всех routes графа (по пути и методу)
+///
+/// Зависимости (`Depends(...)`) не учитываются. Параметр, который FastAPI не
+/// берет из path/query/header/cookie, считается частью тела запроса.
+pub fn route_coverage(graph: &CallGraph) -> Vec<RouteCoverage> {
+    let mut routes: Vec<RouteCoverage> = graph
+        .node_weights()
+        .filter_map(|node| {
+            let CallNode::Route {
+                path,
+                method,
+                handler,
+                location,
+                response_model,
+                ..
+            } = node
+            else {
+                return None;
+            };
+            let Some(CallNode::Function {
+                name,
+                parameters,
+                return_type,
+                ..
+            }) = graph.node_weight(**handler)
+            else {
+                return None;
+            };
+
+            let path_params = ChainBuilder::path_parameter_names(path);
+            let mut params_typed = true;
+            let mut body_typed = None;
+            let mut unresolved_parameters = Vec::new();
+            for param in parameters.iter().filter(|param| !is_dependency(param)) {
+                let resolved = is_resolved(&param.type_info);
+                if !resolved {
+                    unresolved_parameters.push(param.name.clone());
+                }
+                if ChainBuilder::parameter_kind(param, &path_params).is_some() {
+                    params_typed &= resolved;
+                } else {
+                    body_typed = Some(body_typed.unwrap_or(true) && resolved);
+                }
+            }
+
+            Some(RouteCoverage {
+                method: format!("{:?}", method).to_uppercase(),
+                path: normalize_route_path(path),
+                handler: name.clone(),
+                location: location.clone(),
+                params_typed,
+                body_typed,
+                response_typed: response_model
+                    .as_ref()
+                    .or(return_type.as_ref())
+                    .is_some_and(is_resolved),
+                unresolved_parameters,
+            })
+        })
+        .collect();
+
+    routes.sort_by(|a, b| (&a.path, &a.method).cmp(&(&b.path, &b.method)));
+    routes
+}
+
+/// Параметр-зависимость (`db = Depends(get_db)`)
+fn is_dependency(param: &Parameter) -> bool {
+    param
+        .default_value
+        .as_deref()
+        .is_some_and(|default| default.starts_with("Depends("))
+}
+
+/// Тип разрешен: есть схема или известный базовый тип
+fn is_resolved(type_info: &TypeInfo) -> bool {
+    type_info.schema_ref.is_some()
+        || !matches!(type_info.base_type, BaseType::Unknown | BaseType::Any)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::call_graph::CallGraphBuilder;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_untyped_body_parameter_is_listed() {
+        let temp_dir = TempDir::new().unwrap();
+        let main_path = temp_dir.path().join("main.py");
+        std::fs::write(
+            &main_path,
+            r#"
+from fastapi import FastAPI
+
+app = FastAPI()
+
+
+@app.post("/items/{item_id}")
+def update_item(item_id: int, payload, verbose: bool = False):
+    return payload
+
+
+@app.get("/items")
+def list_items(limit: int = 10):
+    return []
+"#,
+        )
+        .unwrap();
+
+        let mut builder = CallGraphBuilder::new();
+        builder.build_from_entry(&main_path).unwrap();
+        let coverage = route_coverage(&builder.into_graph());
+
+        assert_eq!(coverage.len(), 2);
+        let list = &coverage[0];
+        assert_eq!(list.path, "/items");
+        assert!(list.params_typed);
+        assert_eq!(list.body_typed, None);
+        assert!(list.unresolved_parameters.is_empty());
+        let update = &coverage[1];
+        assert_eq!(
+            (update.method.as_str(), update.handler.as_str()),
+            ("POST", "update_item")
+        );
+        assert!(update.params_typed);
+        assert_eq!(update.body_typed, Some(false));
+        assert!(!update.response_typed);
+        assert_eq!(update.unresolved_parameters, vec!["payload".to_string()]);
+    }
+
+    #[test]
+    fn test_response_model_types_response() {
+        let temp_dir = TempDir::new().unwrap();
+        let main_path = temp_dir.path().join("main.py");
+        std::fs::write(
+            &main_path,
+            r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+
+class Item(BaseModel):
+    name: str
+
+
+@app.get("/items/{item_id}", response_model=Item)
+def get_item(item_id: int):
+    return {"name": "item"}
+"#,
+        )
+        .unwrap();
+
+        let mut builder = CallGraphBuilder::new();
+        builder.build_from_entry(&main_path).unwrap();
+        let coverage = route_coverage(&builder.into_graph());
+
+        assert_eq!(coverage.len(), 1);
+        assert!(coverage[0].response_typed);
+        assert!(coverage[0].is_fully_typed());
+    }
+}
@@ -0,0 +1,117 @@
+use crate::analyzers::SchemaParser;
+use crate::models::DataChain;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Зависимость цепочки от устаревшей схемы, эндпоинта или поля
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct DeprecatedUsage {
+    /// Имя цепочки (например, "POST /users")
+    pub chain: String,
+    /// Имя схемы
+    pub schema: String,
+    /// Устаревшее поле (`None` - устарела вся схема или ее эндпоинт)
+    pub field: Option<String>,
+}
+
+impl std::fmt::Display for DeprecatedUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "{}: {}.{}", self.chain, self.schema, field),
+            None => write!(f, "{}: {}", self.chain, self.schema),
+        }
+    }
+}
+
+/// Находит цепочки, которые все еще зависят от устаревших схем и полей
+///
+/// Учитываются схемы контрактов цепочки: схема с metadata `deprecated`
+/// (OpenAPI `deprecated: true` у операции, JSDoc `@deprecated` у интерфейса)
+/// и поля, помеченные устаревшими. Результат информационный и не влияет
+/// на статус цепочки; схемы, которые не удалось разобрать, пропускаются.
+pub fn find_deprecated_usages<'a>(
+    chains: impl IntoIterator<Item = &'a DataChain>,
+) -> Vec<DeprecatedUsage> {
+    let mut usages = BTreeSet::new();
+
+    for chain in chains {
+        let schemas = chain
+            .contracts
+            .iter()
+            .flat_map(|contract| [&contract.from_schema, &contract.to_schema]);
+        for schema_ref in schemas {
+            if schema_ref.is_deprecated() {
+                usages.insert(DeprecatedUsage {
+                    chain: chain.name.clone(),
+                    schema: schema_ref.name.clone(),
+                    field: None,
+                });
+            }
+
+            let Ok(schema) = SchemaParser::parse(schema_ref) else {
+                continue;
+            };
+            for (field_name, field) in &schema.properties {
+                if field.deprecated {
+                    usages.insert(DeprecatedUsage {
+                        chain: chain.name.clone(),
+                        schema: schema_ref.name.clone(),
+                        field: Some(field_name.clone()),
+                    });
+                }
+            }
+        }
+    }
+
+    usages.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SchemaReference, SchemaType};
+    use crate::test_support::{chain, contract, schema};
+    use serde_json::json;
+
+    fn openapi_schema(name: &str, json_schema: serde_json::Value) -> SchemaReference {
+        SchemaReference {
+            schema_type: SchemaType::OpenAPI,
+            ..schema(name, json_schema)
+        }
+    }
+
+    #[test]
+    fn test_deprecated_openapi_field_used_in_chain_is_listed() {
+        let from_schema = openapi_schema(
+            "UserForm",
+            json!({
+                "type": "object",
+                "properties": {
+                    "name": {"type": "string"},
+                    "nickname": {"type": "string", "deprecated": true}
+                },
+                "required": ["name"]
+            }),
+        );
+        let to_schema = openapi_schema(
+            "UserCreate",
+            json!({
+                "type": "object",
+                "properties": {"name": {"type": "string"}},
+                "required": ["name"]
+            }),
+        );
+        let chain = chain("POST /users", vec![contract(from_schema, to_schema)]);
+
+        let usages = find_deprecated_usages(&[chain]);
+
+        assert_eq!(
+            usages,
+            vec![DeprecatedUsage {
+                chain: "POST /users".to_string(),
+                schema: "UserForm".to_string(),
+                field: Some("nickname".to_string()),
+            }]
+        );
+    }
+}
@@ -1,9 +1,19 @@
+pub mod api_usage;
 pub mod chain;
 pub mod contract;
+pub mod coverage;
+pub mod deprecation;
 pub mod rules;
+pub mod schema_compat;
 pub mod schema_parser;
+pub mod spec_drift;
 
+pub use api_usage::*;
 pub use chain::*;
 pub use contract::*;
+pub use coverage::*;
+pub use deprecation::*;
 pub use rules::*;
+pub use schema_compat::*;
 pub use schema_parser::*;
+pub use spec_drift::*;
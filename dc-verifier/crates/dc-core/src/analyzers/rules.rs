@@ -1,5 +1,8 @@
-use crate::analyzers::schema_parser::SchemaParser;
-use crate::models::{BaseType, Contract, Mismatch, MismatchType, TypeInfo};
+use crate::analyzers::schema_parser::{FieldInfo, JsonSchema, SchemaParser};
+use crate::models::{
+    BaseType, Constraint, ConstraintValue, Contract, Mismatch, MismatchType, Severity, TypeInfo,
+};
+use serde_json::Value;
 
 /// Трейт для правил проверки контрактов
 pub trait ContractRule: Send + Sync {
@@ -8,6 +11,17 @@ pub trait ContractRule: Send + Sync {
 
     /// Имя правила
     fn name(&self) -> &str;
+
+    /// Описание правила: что проверяет, о чем сообщает и как исправить находку
+    fn description(&self) -> &str;
+
+    /// Типы несоответствий, о которых сообщает правило
+    fn mismatch_types(&self) -> &[MismatchType];
+
+    /// Серьезность находок правила, если она не задана в секции `[rules]` конфига
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
 }
 
 /// Правило проверки несоответствия типов
@@ -29,9 +43,10 @@ impl ContractRule for TypeMismatchRule {
         for (field_name, from_field) in &from_schema.properties {
             if let Some(to_field) = to_schema.properties.get(field_name) {
                 // Проверяем несоответствие типов
-                if from_field.base_type != to_field.base_type {
+                if !Self::is_compatible(from_field, to_field) {
                     mismatches.push(Mismatch {
                         mismatch_type: MismatchType::TypeMismatch,
+                        rule: String::new(),
                         path: field_name.clone(),
                         expected: TypeInfo {
                             base_type: from_field.base_type,
@@ -61,10 +76,188 @@ impl ContractRule for TypeMismatchRule {
     fn name(&self) -> &str {
         "type_mismatch"
     }
+
+    fn mismatch_types(&self) -> &[MismatchType] {
+        &[MismatchType::TypeMismatch]
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Critical
+    }
+
+    fn description(&self) -> &str {
+        "Checks that fields present on both sides of a contract have the same base type.\n\
+         \n\
+         Flags: a field whose type in the source schema (e.g. `age: string` in a TypeScript\n\
+         interface) differs from the type in the receiving schema (e.g. `age: int` in a Pydantic\n\
+         model). Type mismatches are reported as critical.\n\
+         \n\
+         Fix: align the field types on both sides, or convert the value explicitly before it\n\
+         crosses the boundary (e.g. `Number(value)` on the frontend).\n\
+         \n\
+         Configure: set `type_mismatch` in the `[rules]` section of dc-verifier.toml."
+    }
+}
+
+impl TypeMismatchRule {
+    /// Проверяет, можно ли передать значение поля источника в поле приемника
+    ///
+    /// Enum → String допустимо (расширение). String → Enum допустимо, только если
+    /// значения источника - подмножество значений приемника. Enum → Enum с известными
+    /// значениями проверяет [`EnumMismatchRule`].
+    /// Для объединений (`string | number`) каждый вариант источника должен
+    /// подходить под один из вариантов приемника.
+    fn is_compatible(from: &FieldInfo, to: &FieldInfo) -> bool {
+        let from_members = union_members(from);
+        let to_members = union_members(to);
+        if from_members.is_some() || to_members.is_some() {
+            let from_members = from_members.unwrap_or_else(|| vec![from.base_type]);
+            let to_members = to_members.unwrap_or_else(|| vec![to.base_type]);
+            return to_members.contains(&BaseType::Any)
+                || from_members
+                    .iter()
+                    .all(|member| to_members.contains(member));
+        }
+
+        match (from.base_type, to.base_type) {
+            // Нетипизированное поле (`Any`, `unknown`) совместимо с любым; о нем сообщает `flag_any`
+            (BaseType::Any | BaseType::Unknown, _) | (_, BaseType::Any | BaseType::Unknown) => true,
+            (BaseType::Enum, BaseType::String) => true,
+            (BaseType::Enum | BaseType::String, BaseType::Enum) => {
+                match (enum_values(&from.constraints), enum_values(&to.constraints)) {
+                    // Наборы значений enum → enum сравнивает EnumMismatchRule
+                    (Some(_), Some(_)) if from.base_type == BaseType::Enum => true,
+                    (Some(from_values), Some(to_values)) => {
+                        from_values.iter().all(|value| to_values.contains(value))
+                    }
+                    // Значения неизвестны: enum → enum считаем совместимым, string → enum - нет
+                    _ => from.base_type == BaseType::Enum,
+                }
+            }
+            (from_type, to_type) => from_type == to_type,
+        }
+    }
+}
+
+/// Возвращает варианты объединения типов поля (`OneOf`)
+fn union_members(field: &FieldInfo) -> Option<Vec<BaseType>> {
+    field
+        .constraints
+        .iter()
+        .find_map(|constraint| match constraint {
+            Constraint::OneOf(members) => Some(members.clone()),
+            _ => None,
+        })
+}
+
+/// Возвращает значения перечисления из ограничений поля
+fn enum_values(constraints: &[Constraint]) -> Option<&[String]> {
+    constraints.iter().find_map(|constraint| match constraint {
+        Constraint::Enum(values) => Some(values.as_slice()),
+        _ => None,
+    })
+}
+
+/// Правило проверки наборов значений перечислений
+///
+/// Значение источника, которого нет среди значений приемника, будет отклонено
+/// приемником. Приемник, принимающий больше значений, чем отправляет источник, безопасен.
+pub struct EnumMismatchRule;
+
+impl ContractRule for EnumMismatchRule {
+    fn check(&self, contract: &Contract) -> Vec<Mismatch> {
+        let mut mismatches = Vec::new();
+
+        let Ok(from_schema) = SchemaParser::parse(&contract.from_schema) else {
+            return mismatches;
+        };
+        let Ok(to_schema) = SchemaParser::parse(&contract.to_schema) else {
+            return mismatches;
+        };
+
+        for (field_name, from_field) in &from_schema.properties {
+            let Some(to_field) = to_schema.properties.get(field_name) else {
+                continue;
+            };
+            if from_field.base_type != BaseType::Enum || to_field.base_type != BaseType::Enum {
+                continue;
+            }
+            let (Some(from_values), Some(to_values)) = (
+                enum_values(&from_field.constraints),
+                enum_values(&to_field.constraints),
+            ) else {
+                continue;
+            };
+
+            let rejected: Vec<&str> = from_values
+                .iter()
+                .filter(|value| !to_values.contains(value))
+                .map(String::as_str)
+                .collect();
+            if rejected.is_empty() {
+                continue;
+            }
+
+            mismatches.push(Mismatch {
+                mismatch_type: MismatchType::ValidationMismatch,
+                rule: String::new(),
+                path: field_name.clone(),
+                expected: TypeInfo {
+                    base_type: from_field.base_type,
+                    schema_ref: None,
+                    constraints: from_field.constraints.clone(),
+                    optional: from_field.optional,
+                },
+                actual: TypeInfo {
+                    base_type: to_field.base_type,
+                    schema_ref: None,
+                    constraints: to_field.constraints.clone(),
+                    optional: to_field.optional,
+                },
+                location: contract.to_schema.location.clone(),
+                message: format!(
+                    "Enum values of field '{}' not accepted by the receiver: [{}]",
+                    field_name,
+                    rejected.join(", ")
+                ),
+            });
+        }
+
+        mismatches
+    }
+
+    fn name(&self) -> &str {
+        "enum_mismatch"
+    }
+
+    fn mismatch_types(&self) -> &[MismatchType] {
+        &[MismatchType::ValidationMismatch]
+    }
+
+    fn description(&self) -> &str {
+        "Checks that every enum value the source can send is accepted by the receiver.\n\
+         \n\
+         Flags: an enum field whose source values are not all members of the receiving enum\n\
+         (e.g. the frontend sends `\"guest\"` but the backend enum is `admin | user`). The\n\
+         message lists the rejected values. A receiver accepting more values than the source\n\
+         sends is safe and not reported. Reported as a warning.\n\
+         \n\
+         Fix: add the missing variants to the receiving enum, or stop sending them.\n\
+         \n\
+         Configure: set `enum_mismatch` in the `[rules]` section of dc-verifier.toml."
+    }
 }
 
 /// Правило проверки отсутствующих полей
-pub struct MissingFieldRule;
+///
+/// Поле, которое источник передает под другим именем (`user_name` vs `userName`),
+/// по умолчанию не сообщается: о нем сообщает `NamingMismatchRule`.
+#[derive(Default)]
+pub struct MissingFieldRule {
+    /// Сообщать и о полях, найденных у источника под другим именем
+    /// (когда `naming_mismatch` выключено)
+    pub report_naming_variants: bool,
+}
 
 impl ContractRule for MissingFieldRule {
     fn check(&self, contract: &Contract) -> Vec<Mismatch> {
@@ -78,13 +271,29 @@ impl ContractRule for MissingFieldRule {
             return mismatches;
         };
 
+        // Тело запроса необязательно (OpenAPI `requestBody.required: false`),
+        // и источник его не передает - отсутствующих полей нет
+        let body_optional = contract
+            .to_schema
+            .metadata
+            .get("body_required")
+            .is_some_and(|required| required == "false");
+        if body_optional && from_schema.properties.is_empty() {
+            return mismatches;
+        }
+
         // Проверяем обязательные поля в схеме приемника
+        // Поле под другим именем (`user_name` vs `userName`) сообщает `NamingMismatchRule`
         for required_field in &to_schema.required {
-            if !from_schema.properties.contains_key(required_field) {
+            if !from_schema.properties.contains_key(required_field)
+                && (self.report_naming_variants
+                    || naming_counterpart(required_field, &from_schema).is_none())
+            {
                 // Поле отсутствует в схеме источника
                 let to_field = to_schema.properties.get(required_field);
                 mismatches.push(Mismatch {
                     mismatch_type: MismatchType::MissingField,
+                    rule: String::new(),
                     path: required_field.clone(),
                     expected: TypeInfo {
                         base_type: to_field.map(|f| f.base_type).unwrap_or(BaseType::Unknown),
@@ -110,31 +319,32 @@ impl ContractRule for MissingFieldRule {
         // Также проверяем поля, которые есть в to_schema, но отсутствуют в from_schema
         // (если они не опциональные)
         for (field_name, to_field) in &to_schema.properties {
-            if !to_field.optional && !from_schema.properties.contains_key(field_name) {
-                if !to_schema.required.contains(field_name) {
-                    // Добавляем в required, если еще не там
-                    mismatches.push(Mismatch {
-                        mismatch_type: MismatchType::MissingField,
-                        path: field_name.clone(),
-                        expected: TypeInfo {
-                            base_type: to_field.base_type,
-                            schema_ref: None,
-                            constraints: to_field.constraints.clone(),
-                            optional: false,
-                        },
-                        actual: TypeInfo {
-                            base_type: BaseType::Unknown,
-                            schema_ref: None,
-                            constraints: Vec::new(),
-                            optional: true,
-                        },
-                        location: contract.from_schema.location.clone(),
-                        message: format!(
-                            "Missing required field '{}' in source schema",
-                            field_name
-                        ),
-                    });
-                }
+            if !to_field.optional
+                && !from_schema.properties.contains_key(field_name)
+                && !to_schema.required.contains(field_name)
+                && (self.report_naming_variants
+                    || naming_counterpart(field_name, &from_schema).is_none())
+            {
+                // Добавляем в required, если еще не там
+                mismatches.push(Mismatch {
+                    mismatch_type: MismatchType::MissingField,
+                    rule: String::new(),
+                    path: field_name.clone(),
+                    expected: TypeInfo {
+                        base_type: to_field.base_type,
+                        schema_ref: None,
+                        constraints: to_field.constraints.clone(),
+                        optional: false,
+                    },
+                    actual: TypeInfo {
+                        base_type: BaseType::Unknown,
+                        schema_ref: None,
+                        constraints: Vec::new(),
+                        optional: true,
+                    },
+                    location: contract.from_schema.location.clone(),
+                    message: format!("Missing required field '{}' in source schema", field_name),
+                });
             }
         }
 
@@ -144,6 +354,145 @@ impl ContractRule for MissingFieldRule {
     fn name(&self) -> &str {
         "missing_field"
     }
+
+    fn mismatch_types(&self) -> &[MismatchType] {
+        &[MismatchType::MissingField]
+    }
+
+    fn description(&self) -> &str {
+        "Checks that every required field of the receiving schema is provided by the source.\n\
+         \n\
+         Flags: a field that is required (not optional and without a default) in the receiving\n\
+         schema but absent from the source schema. Reported as a warning.\n\
+         \n\
+         Fix: add the field to the source schema, or make it optional / give it a default value\n\
+         in the receiving schema.\n\
+         \n\
+         Configure: set `missing_field` in the `[rules]` section of dc-verifier.toml."
+    }
+}
+
+/// Правило проверки одного поля под разными именами
+///
+/// Поле приемника, которого нет в источнике, но которое есть там после
+/// перевода snake_case ↔ camelCase (`user_name` и `userName`), - одно
+/// переименование, а не отсутствующее поле.
+pub struct NamingMismatchRule;
+
+impl ContractRule for NamingMismatchRule {
+    fn check(&self, contract: &Contract) -> Vec<Mismatch> {
+        let Ok(from_schema) = SchemaParser::parse(&contract.from_schema) else {
+            return Vec::new();
+        };
+        let Ok(to_schema) = SchemaParser::parse(&contract.to_schema) else {
+            return Vec::new();
+        };
+
+        let mut field_names: Vec<&String> = to_schema
+            .properties
+            .keys()
+            .filter(|name| !from_schema.properties.contains_key(*name))
+            .collect();
+        field_names.sort();
+
+        field_names
+            .into_iter()
+            .filter_map(|field_name| {
+                let source_name = naming_counterpart(field_name, &from_schema)?;
+                let to_field = &to_schema.properties[field_name];
+                let from_field = &from_schema.properties[source_name];
+                Some(Mismatch {
+                    mismatch_type: MismatchType::NamingMismatch,
+                    rule: String::new(),
+                    path: field_name.clone(),
+                    expected: TypeInfo {
+                        base_type: to_field.base_type,
+                        schema_ref: None,
+                        constraints: to_field.constraints.clone(),
+                        optional: to_field.optional,
+                    },
+                    actual: TypeInfo {
+                        base_type: from_field.base_type,
+                        schema_ref: None,
+                        constraints: from_field.constraints.clone(),
+                        optional: from_field.optional,
+                    },
+                    location: contract.from_schema.location.clone(),
+                    message: format!(
+                        "Field '{}' is named '{}' in source schema: convert {} names or add an alias",
+                        field_name,
+                        source_name,
+                        if field_name.contains('_') {
+                            "camelCase to snake_case"
+                        } else {
+                            "snake_case to camelCase"
+                        }
+                    ),
+                })
+            })
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        "naming_mismatch"
+    }
+
+    fn mismatch_types(&self) -> &[MismatchType] {
+        &[MismatchType::NamingMismatch]
+    }
+
+    fn description(&self) -> &str {
+        "Checks that a field is not sent under a snake_case/camelCase variant of its name.\n\
+         \n\
+         Flags: a receiving field that is absent from the source while the source has the same\n\
+         name in the other case style - e.g. `user_name` in Python and `userName` in TypeScript.\n\
+         Reported once as a warning instead of a missing field.\n\
+         \n\
+         Fix: convert names at the boundary (Pydantic `alias_generator=to_camel`, a serializer on\n\
+         the frontend), or rename one side.\n\
+         \n\
+         Configure: set `naming_mismatch` in the `[rules]` section of dc-verifier.toml; teams that\n\
+         transform names on purpose can lower it to `info` or list the fields under `[[allow]]`.\n\
+         `disable = [\"naming_mismatch\"]` turns the rule off: such fields are then reported by\n\
+         `missing_field`."
+    }
+}
+
+/// Имя поля источника, совпадающее с `field_name` после snake_case ↔ camelCase
+fn naming_counterpart<'a>(field_name: &str, source: &'a JsonSchema) -> Option<&'a String> {
+    [snake_to_camel(field_name), camel_to_snake(field_name)]
+        .into_iter()
+        .filter(|name| name != field_name)
+        .find_map(|name| source.properties.get_key_value(&name).map(|(key, _)| key))
+}
+
+/// `user_name` → `userName`
+fn snake_to_camel(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut upper = false;
+    for ch in name.chars() {
+        if ch == '_' && !result.is_empty() {
+            upper = true;
+        } else if upper {
+            result.extend(ch.to_uppercase());
+            upper = false;
+        } else {
+            result.push(ch);
+        }
+    }
+    result
+}
+
+/// `userName` → `user_name`
+fn camel_to_snake(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 4);
+    for ch in name.chars() {
+        if ch.is_uppercase() && !result.is_empty() {
+            result.push('_');
+        }
+        result.extend(ch.to_lowercase());
+    }
+    result
 }
 
 /// Правило проверки ненормализованных данных
@@ -179,6 +528,7 @@ impl ContractRule for UnnormalizedDataRule {
                 if to_has_email && !from_has_email && from_field.base_type == BaseType::String {
                     mismatches.push(Mismatch {
                         mismatch_type: MismatchType::UnnormalizedData,
+                        rule: String::new(),
                         path: field_name.clone(),
                         expected: TypeInfo {
                             base_type: to_field.base_type,
@@ -214,6 +564,7 @@ impl ContractRule for UnnormalizedDataRule {
                     // Если в приемнике есть паттерн, но в источнике нет - возможна проблема
                     mismatches.push(Mismatch {
                         mismatch_type: MismatchType::UnnormalizedData,
+                        rule: String::new(),
                         path: field_name.clone(),
                         expected: TypeInfo {
                             base_type: to_field.base_type,
@@ -243,4 +594,675 @@ impl ContractRule for UnnormalizedDataRule {
     fn name(&self) -> &str {
         "unnormalized_data"
     }
+
+    fn mismatch_types(&self) -> &[MismatchType] {
+        &[MismatchType::UnnormalizedData]
+    }
+
+    fn description(&self) -> &str {
+        "Checks that string fields validated by the receiver are validated by the source too.\n\
+         \n\
+         Flags: a string field that has an `email` or `pattern` constraint in the receiving schema\n\
+         but no such constraint in the source schema, so unnormalized values may reach the\n\
+         receiver. Reported as a warning.\n\
+         \n\
+         Fix: add the same validation on the source side (e.g. `z.string().email()`), or\n\
+         normalize the value before sending it.\n\
+         \n\
+         Configure: set `unnormalized_data` in the `[rules]` section of dc-verifier.toml."
+    }
+}
+
+/// Правило проверки числа и уникальности элементов массивов
+///
+/// Источник должен гарантировать ограничения `minItems`/`maxItems`/`uniqueItems`
+/// приемника: массив без `maxItems` (или с большим) может превысить лимит приемника.
+pub struct ArraySizeRule;
+
+impl ContractRule for ArraySizeRule {
+    fn check(&self, contract: &Contract) -> Vec<Mismatch> {
+        let Ok(from_schema) = SchemaParser::parse(&contract.from_schema) else {
+            return Vec::new();
+        };
+        let Ok(to_schema) = SchemaParser::parse(&contract.to_schema) else {
+            return Vec::new();
+        };
+
+        let mut mismatches = Vec::new();
+        for (field_name, to_field) in &to_schema.properties {
+            let Some(from_field) = from_schema.properties.get(field_name) else {
+                continue;
+            };
+            if to_field.base_type != BaseType::Array || from_field.base_type != BaseType::Array {
+                continue;
+            }
+
+            for problem in Self::violations(from_field, to_field) {
+                mismatches.push(Mismatch {
+                    mismatch_type: MismatchType::ValidationMismatch,
+                    rule: String::new(),
+                    path: field_name.clone(),
+                    expected: TypeInfo {
+                        base_type: to_field.base_type,
+                        schema_ref: None,
+                        constraints: to_field.constraints.clone(),
+                        optional: to_field.optional,
+                    },
+                    actual: TypeInfo {
+                        base_type: from_field.base_type,
+                        schema_ref: None,
+                        constraints: from_field.constraints.clone(),
+                        optional: from_field.optional,
+                    },
+                    location: contract.from_schema.location.clone(),
+                    message: format!("Array field '{}' {}", field_name, problem),
+                });
+            }
+        }
+
+        mismatches
+    }
+
+    fn name(&self) -> &str {
+        "array_size"
+    }
+
+    fn mismatch_types(&self) -> &[MismatchType] {
+        &[MismatchType::ValidationMismatch]
+    }
+
+    fn description(&self) -> &str {
+        "Checks that array size and uniqueness limits of the receiver are enforced by the source.\n\
+         \n\
+         Flags: an array field whose receiving schema sets `maxItems`, `minItems` or `uniqueItems`\n\
+         while the source allows more items, fewer items or duplicates - e.g. a source `tags`\n\
+         list without `maxItems` sent to a receiver with `maxItems: 3`. Reported as a warning.\n\
+         \n\
+         Fix: add the same limit on the source side (e.g. `z.array(...).max(3)`), or relax the\n\
+         receiving schema.\n\
+         \n\
+         Configure: set `array_size` in the `[rules]` section of dc-verifier.toml."
+    }
+}
+
+impl ArraySizeRule {
+    /// Нарушения ограничений приемника массивом источника
+    fn violations(from_field: &FieldInfo, to_field: &FieldInfo) -> Vec<String> {
+        let mut problems = Vec::new();
+        if let Some(to_max) = Self::items_bound(to_field, false) {
+            match Self::items_bound(from_field, false) {
+                Some(from_max) if from_max <= to_max => {}
+                Some(from_max) => problems.push(format!(
+                    "allows up to {} items, receiver accepts at most {}",
+                    from_max, to_max
+                )),
+                None => problems.push(format!(
+                    "has no item limit, receiver accepts at most {}",
+                    to_max
+                )),
+            }
+        }
+        if let Some(to_min) = Self::items_bound(to_field, true) {
+            let from_min = Self::items_bound(from_field, true).unwrap_or(0);
+            if from_min < to_min {
+                problems.push(format!(
+                    "may have {} items, receiver requires at least {}",
+                    from_min, to_min
+                ));
+            }
+        }
+        let unique = |field: &FieldInfo| field.constraints.contains(&Constraint::UniqueItems);
+        if unique(to_field) && !unique(from_field) {
+            problems.push("may contain duplicates, receiver requires unique items".to_string());
+        }
+        problems
+    }
+
+    /// Граница числа элементов (`minItems` при `is_min`, иначе `maxItems`)
+    fn items_bound(field: &FieldInfo, is_min: bool) -> Option<i64> {
+        field
+            .constraints
+            .iter()
+            .find_map(|constraint| match (constraint, is_min) {
+                (Constraint::Min(ConstraintValue::Integer(bound)), true)
+                | (Constraint::Max(ConstraintValue::Integer(bound)), false) => Some(*bound),
+                _ => None,
+            })
+    }
+}
+
+/// Правило поиска нетипизированных полей (`Any`, `unknown`, `dict`) напротив типизированных
+///
+/// Необязательное: включается через `enable = ["flag_any"]` в секции `[rules]`. По умолчанию
+/// такие поля совместимы с любым типом и не проверяются, что может скрывать ошибки.
+pub struct FlagAnyRule;
+
+impl ContractRule for FlagAnyRule {
+    fn check(&self, contract: &Contract) -> Vec<Mismatch> {
+        let Ok(from_schema) = SchemaParser::parse(&contract.from_schema) else {
+            return Vec::new();
+        };
+        let Ok(to_schema) = SchemaParser::parse(&contract.to_schema) else {
+            return Vec::new();
+        };
+
+        let mut field_names: Vec<&String> = from_schema
+            .properties
+            .keys()
+            .filter(|name| to_schema.properties.contains_key(*name))
+            .collect();
+        field_names.sort();
+
+        let mut mismatches = Vec::new();
+        for field_name in field_names {
+            let from_field = &from_schema.properties[field_name];
+            let to_field = &to_schema.properties[field_name];
+            let (side, untyped, typed, location) = if Self::hides_type(from_field, to_field) {
+                (
+                    "source",
+                    from_field,
+                    to_field,
+                    &contract.from_schema.location,
+                )
+            } else if Self::hides_type(to_field, from_field) {
+                (
+                    "receiving",
+                    to_field,
+                    from_field,
+                    &contract.to_schema.location,
+                )
+            } else {
+                continue;
+            };
+
+            mismatches.push(Mismatch {
+                mismatch_type: MismatchType::TypeMismatch,
+                rule: String::new(),
+                path: field_name.clone(),
+                expected: TypeInfo {
+                    base_type: typed.base_type,
+                    schema_ref: None,
+                    constraints: typed.constraints.clone(),
+                    optional: typed.optional,
+                },
+                actual: TypeInfo {
+                    base_type: untyped.base_type,
+                    schema_ref: None,
+                    constraints: untyped.constraints.clone(),
+                    optional: untyped.optional,
+                },
+                location: location.clone(),
+                message: format!(
+                    "Field '{}' is untyped ({}) in {} schema while the other side expects {:?}",
+                    field_name, untyped.field_type, side, typed.base_type
+                ),
+            });
+        }
+
+        mismatches
+    }
+
+    fn name(&self) -> &str {
+        "flag_any"
+    }
+
+    fn mismatch_types(&self) -> &[MismatchType] {
+        &[MismatchType::TypeMismatch]
+    }
+
+    fn description(&self) -> &str {
+        "Flags fields typed `Any`, `unknown` or a bare `dict` where the other side has a concrete type.\n\
+         \n\
+         Flags: a field such as `payload: Any` in a Pydantic model or `data: any` in a TypeScript\n\
+         interface opposite a typed field (`string`, a nested model). Such fields are otherwise\n\
+         compatible with anything and hide real gaps. Reported as a warning.\n\
+         \n\
+         Fix: give the field the concrete type or model of the other side.\n\
+         \n\
+         Configure: optional; enable with `enable = [\"flag_any\"]` in the `[rules]` section\n\
+         of dc-verifier.toml."
+    }
+}
+
+impl FlagAnyRule {
+    /// `field` не типизирован, а поле другой стороны `other` - типизировано
+    ///
+    /// Голый `dict` отмечается только напротив объекта с известными полями:
+    /// остальные случаи - обычное несоответствие типов.
+    fn hides_type(field: &FieldInfo, other: &FieldInfo) -> bool {
+        let untyped_any = |field: &FieldInfo| {
+            matches!(field.base_type, BaseType::Any | BaseType::Unknown)
+                && union_members(field).is_none()
+        };
+        let bare_object = |field: &FieldInfo| {
+            field.base_type == BaseType::Object && field.nested_schema.is_none()
+        };
+
+        if untyped_any(field) {
+            !untyped_any(other) && !bare_object(other)
+        } else {
+            bare_object(field)
+                && other.base_type == BaseType::Object
+                && other.nested_schema.is_some()
+        }
+    }
+}
+
+/// Правило проверки примеров (`example`/`examples`) против собственной схемы
+///
+/// Необязательное: включается через `enable = ["invalid_example"]` в секции `[rules]`.
+/// Проверяются примеры схемы приемника - документированного контракта.
+pub struct InvalidExampleRule;
+
+impl ContractRule for InvalidExampleRule {
+    fn check(&self, contract: &Contract) -> Vec<Mismatch> {
+        let mut mismatches = Vec::new();
+
+        let Some(raw) = contract
+            .to_schema
+            .metadata
+            .get("json_schema")
+            .and_then(|json| serde_json::from_str::<Value>(json).ok())
+        else {
+            return mismatches;
+        };
+        let Ok(schema) = SchemaParser::parse(&contract.to_schema) else {
+            return mismatches;
+        };
+
+        // Пример уровня схемы: объект со значениями полей
+        let schema_examples = raw
+            .get("example")
+            .into_iter()
+            .chain(Self::examples(&raw))
+            .filter_map(|example| example.as_object());
+
+        let mut samples: Vec<(&String, &Value)> =
+            schema_examples.flat_map(|example| example.iter()).collect();
+
+        // Примеры уровня поля
+        if let Some(properties) = raw.get("properties").and_then(|p| p.as_object()) {
+            for (field_name, property) in properties {
+                for example in property
+                    .get("example")
+                    .into_iter()
+                    .chain(Self::examples(property))
+                {
+                    samples.push((field_name, example));
+                }
+            }
+        }
+
+        for (field_name, example) in samples {
+            let Some(field) = schema.properties.get(field_name) else {
+                continue;
+            };
+            let Some(problem) = Self::violation(field, example) else {
+                continue;
+            };
+
+            mismatches.push(Mismatch {
+                mismatch_type: MismatchType::ValidationMismatch,
+                rule: String::new(),
+                path: field_name.clone(),
+                expected: TypeInfo {
+                    base_type: field.base_type,
+                    schema_ref: None,
+                    constraints: field.constraints.clone(),
+                    optional: field.optional,
+                },
+                actual: TypeInfo {
+                    base_type: Self::value_type(example),
+                    schema_ref: None,
+                    constraints: Vec::new(),
+                    optional: field.optional,
+                },
+                location: contract.to_schema.location.clone(),
+                message: format!(
+                    "Example {} for field '{}' violates its own schema: {}",
+                    example, field_name, problem
+                ),
+            });
+        }
+
+        mismatches
+    }
+
+    fn name(&self) -> &str {
+        "invalid_example"
+    }
+
+    fn mismatch_types(&self) -> &[MismatchType] {
+        &[MismatchType::ValidationMismatch]
+    }
+
+    fn description(&self) -> &str {
+        "Checks that documented examples satisfy the schema they belong to.\n\
+         \n\
+         Flags: an `example`/`examples` value (on a field or on the whole schema) whose type,\n\
+         enum membership or min/max bounds contradict the field's own schema - e.g. an example\n\
+         role `\"superuser\"` for a field restricted to `admin | user`. Reported as a warning.\n\
+         \n\
+         Fix: update the example (or the schema, if the example reflects real data).\n\
+         \n\
+         Configure: optional; enable with `enable = [\"invalid_example\"]` in the `[rules]`\n\
+         section of dc-verifier.toml."
+    }
+}
+
+impl InvalidExampleRule {
+    /// Значения `examples`: массив (JSON Schema) или объект `{name: {value}}` (OpenAPI)
+    fn examples(value: &Value) -> Vec<&Value> {
+        match value.get("examples") {
+            Some(Value::Array(items)) => items.iter().collect(),
+            Some(Value::Object(named)) => named
+                .values()
+                .map(|example| example.get("value").unwrap_or(example))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Базовый тип JSON-значения примера
+    fn value_type(value: &Value) -> BaseType {
+        match value {
+            Value::String(_) => BaseType::String,
+            Value::Number(number) if number.is_i64() || number.is_u64() => BaseType::Integer,
+            Value::Number(_) => BaseType::Number,
+            Value::Bool(_) => BaseType::Boolean,
+            Value::Array(_) => BaseType::Array,
+            Value::Object(_) => BaseType::Object,
+            Value::Null => BaseType::Null,
+        }
+    }
+
+    /// Описание нарушения схемы примером (None - пример корректен)
+    fn violation(field: &FieldInfo, example: &Value) -> Option<String> {
+        if example.is_null() && field.optional {
+            return None;
+        }
+
+        let actual = Self::value_type(example);
+        let type_ok = match field.base_type {
+            BaseType::Any | BaseType::Unknown => true,
+            BaseType::Number => matches!(actual, BaseType::Number | BaseType::Integer),
+            BaseType::Enum => true,
+            expected => expected == actual,
+        };
+        if !type_ok {
+            return Some(format!("expected {:?}, got {:?}", field.base_type, actual));
+        }
+
+        if let Some(values) = enum_values(&field.constraints) {
+            let rendered = example
+                .as_str()
+                .map(str::to_string)
+                .unwrap_or_else(|| example.to_string());
+            if !values.contains(&rendered) {
+                return Some(format!("not one of [{}]", values.join(", ")));
+            }
+        }
+
+        // Для строк ограничения - длина, для чисел - значение
+        let measured = match example {
+            Value::String(text) => Some((text.chars().count() as f64, true)),
+            Value::Number(number) => number.as_f64().map(|n| (n, false)),
+            _ => None,
+        };
+        let (measured, is_length) = measured?;
+        for constraint in &field.constraints {
+            let (bound, is_min) = match constraint {
+                Constraint::Min(bound) => (bound, true),
+                Constraint::Max(bound) => (bound, false),
+                _ => continue,
+            };
+            // Длины всегда целые; границы чисел могут быть любыми числами
+            let limit = match (bound, is_length) {
+                (ConstraintValue::Integer(limit), _) => *limit as f64,
+                (ConstraintValue::Float(limit), false) => *limit,
+                _ => continue,
+            };
+            if is_min && measured < limit {
+                return Some(format!("below minimum {}", limit));
+            }
+            if !is_min && measured > limit {
+                return Some(format!("above maximum {}", limit));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SchemaReference;
+    use crate::test_support::{contract, schema};
+    use serde_json::json;
+
+    fn role_schema(name: &str, role: serde_json::Value) -> SchemaReference {
+        schema(
+            name,
+            json!({"type": "object", "properties": {"role": role}, "required": ["role"]}),
+        )
+    }
+
+    #[test]
+    fn test_enum_to_string_is_widening() {
+        let from = role_schema(
+            "Source",
+            json!({"type": "string", "enum": ["admin", "user"]}),
+        );
+        let to = role_schema("Target", json!({"type": "string"}));
+
+        assert!(TypeMismatchRule.check(&contract(from, to)).is_empty());
+    }
+
+    #[test]
+    fn test_string_to_enum_is_flagged() {
+        let from = role_schema("Source", json!({"type": "string"}));
+        let to = role_schema(
+            "Target",
+            json!({"type": "string", "enum": ["admin", "user"]}),
+        );
+
+        let mismatches = TypeMismatchRule.check(&contract(from, to));
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].expected.base_type, BaseType::String);
+        assert_eq!(mismatches[0].actual.base_type, BaseType::Enum);
+    }
+
+    #[test]
+    fn test_enum_subset_is_compatible() {
+        let subset = role_schema("Source", json!({"type": "string", "enum": ["admin"]}));
+        let superset = role_schema(
+            "Target",
+            json!({"type": "string", "enum": ["admin", "user"]}),
+        );
+
+        assert!(TypeMismatchRule
+            .check(&contract(subset.clone(), superset.clone()))
+            .is_empty());
+        assert!(EnumMismatchRule
+            .check(&contract(subset.clone(), superset.clone()))
+            .is_empty());
+        assert_eq!(EnumMismatchRule.check(&contract(superset, subset)).len(), 1);
+    }
+
+    #[test]
+    fn test_enum_value_rejected_by_receiver_is_listed() {
+        let from = role_schema(
+            "Source",
+            json!({"type": "string", "enum": ["admin", "user", "guest"]}),
+        );
+        let to = role_schema(
+            "Target",
+            json!({"type": "string", "enum": ["admin", "user"]}),
+        );
+
+        let mismatches = EnumMismatchRule.check(&contract(from.clone(), to.clone()));
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].path, "role");
+        assert!(mismatches[0].message.contains("[guest]"));
+
+        // Приемник принимает больше значений, чем отправляет источник
+        assert!(EnumMismatchRule.check(&contract(to, from)).is_empty());
+    }
+
+    #[test]
+    fn test_example_outside_number_bounds_is_flagged() {
+        let from = schema("Source", json!({"type": "object", "properties": {}}));
+        let to = schema(
+            "Target",
+            json!({
+                "type": "object",
+                "properties": {"price": {"type": "number", "minimum": 0.5, "example": 0.25}}
+            }),
+        );
+        let mismatches = InvalidExampleRule.check(&contract(from, to));
+        assert_eq!(mismatches.len(), 1);
+        assert!(mismatches[0].message.contains("below minimum 0.5"));
+
+        // Integer bounds (`confloat(le=10)`) apply to float examples as well
+        let price = FieldInfo {
+            field_type: "float".to_string(),
+            base_type: BaseType::Number,
+            optional: false,
+            constraints: vec![Constraint::Max(ConstraintValue::Integer(10))],
+            nested_schema: None,
+            deprecated: false,
+            recursive: false,
+        };
+        assert!(InvalidExampleRule::violation(&price, &json!(10.5)).is_some());
+        assert!(InvalidExampleRule::violation(&price, &json!(9.5)).is_none());
+    }
+
+    #[test]
+    fn test_example_outside_enum_is_flagged() {
+        let from = role_schema("Source", json!({"type": "string"}));
+        let to = role_schema(
+            "Target",
+            json!({"type": "string", "enum": ["admin", "user"], "example": "superuser"}),
+        );
+
+        let mismatches = InvalidExampleRule.check(&contract(from.clone(), to));
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(
+            mismatches[0].mismatch_type,
+            MismatchType::ValidationMismatch
+        );
+        assert_eq!(mismatches[0].path, "role");
+
+        let valid = role_schema(
+            "Target",
+            json!({"type": "string", "enum": ["admin", "user"], "examples": ["admin"]}),
+        );
+        assert!(InvalidExampleRule.check(&contract(from, valid)).is_empty());
+    }
+
+    #[test]
+    fn test_union_accepts_any_member() {
+        let to = schema(
+            "Target",
+            json!({"type": "object", "properties": {"value": {"type": ["string", "number"]}}}),
+        );
+        let string_source = schema(
+            "Source",
+            json!({"type": "object", "properties": {"value": {"type": "string"}}}),
+        );
+        let bool_source = schema(
+            "Source",
+            json!({"type": "object", "properties": {"value": {"type": "boolean"}}}),
+        );
+
+        assert!(TypeMismatchRule
+            .check(&contract(string_source, to.clone()))
+            .is_empty());
+        assert_eq!(TypeMismatchRule.check(&contract(bool_source, to)).len(), 1);
+    }
+
+    #[test]
+    fn test_source_without_max_items_is_flagged() {
+        let to = schema(
+            "Target",
+            json!({
+                "type": "object",
+                "properties": {"tags": {"type": "array", "items": {"type": "string"}, "maxItems": 3}}
+            }),
+        );
+        let unbounded = schema(
+            "Source",
+            json!({"type": "object", "properties": {"tags": {"type": "array", "items": {"type": "string"}}}}),
+        );
+        let bounded = schema(
+            "Source",
+            json!({"type": "object", "properties": {"tags": {"type": "array", "maxItems": 2}}}),
+        );
+
+        let mismatches = ArraySizeRule.check(&contract(unbounded, to.clone()));
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].path, "tags");
+        assert_eq!(
+            mismatches[0].mismatch_type,
+            MismatchType::ValidationMismatch
+        );
+        assert!(mismatches[0].message.contains("at most 3"));
+        assert!(ArraySizeRule.check(&contract(bounded, to)).is_empty());
+    }
+
+    #[test]
+    fn test_any_source_field_is_flagged_only_by_flag_any() {
+        let to = schema(
+            "UserCreate",
+            json!({
+                "type": "object",
+                "properties": {"age": {"type": "integer"}, "meta": {}},
+                "required": ["age"]
+            }),
+        );
+        let from = schema(
+            "UserForm",
+            json!({
+                "type": "object",
+                "properties": {"age": {}, "meta": {}},
+                "required": ["age"]
+            }),
+        );
+        let contract = contract(from, to);
+
+        assert!(TypeMismatchRule.check(&contract).is_empty());
+        let mismatches = FlagAnyRule.check(&contract);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].path, "age");
+        assert!(mismatches[0].message.contains("in source schema"));
+    }
+
+    #[test]
+    fn test_snake_and_camel_names_are_one_naming_mismatch() {
+        let to = schema(
+            "UserCreate",
+            json!({
+                "type": "object",
+                "properties": {"user_name": {"type": "string"}, "email": {"type": "string"}},
+                "required": ["user_name", "email"]
+            }),
+        );
+        let from = schema(
+            "UserForm",
+            json!({
+                "type": "object",
+                "properties": {"userName": {"type": "string"}, "email": {"type": "string"}},
+                "required": ["userName", "email"]
+            }),
+        );
+        let contract = contract(from, to);
+
+        assert!(MissingFieldRule::default().check(&contract).is_empty());
+        let mismatches = NamingMismatchRule.check(&contract);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].mismatch_type, MismatchType::NamingMismatch);
+        assert_eq!(mismatches[0].path, "user_name");
+        assert!(mismatches[0].message.contains("'userName'"));
+    }
 }
@@ -0,0 +1,246 @@
+use crate::analyzers::{ContractChecker, SchemaParser};
+use crate::models::{Mismatch, SchemaReference};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Совместимость новой версии схемы с предыдущей
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Compatibility {
+    /// Схема не изменилась
+    Unchanged,
+    /// Обратно совместимое изменение (добавлено необязательное поле и т.п.)
+    Compatible,
+    /// Ломающее изменение (удалено поле, сужен тип, поле стало обязательным)
+    Breaking,
+}
+
+/// Одно изменение схемы между версиями
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaChange {
+    /// Путь к полю (пустой для изменений всей схемы)
+    pub path: String,
+    /// Совместимость изменения
+    pub compatibility: Compatibility,
+    /// Описание изменения
+    pub description: String,
+}
+
+/// Итог сравнения двух версий одной схемы
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaCompatibility {
+    /// Имя схемы
+    pub name: String,
+    /// Совместимость версии - самое серьезное из изменений
+    pub compatibility: Compatibility,
+    /// Изменения схемы
+    pub changes: Vec<SchemaChange>,
+}
+
+impl SchemaCompatibility {
+    fn new(name: &str, changes: Vec<SchemaChange>) -> Self {
+        let compatibility = changes
+            .iter()
+            .map(|change| change.compatibility)
+            .max()
+            .unwrap_or(Compatibility::Unchanged);
+        Self {
+            name: name.to_string(),
+            compatibility,
+            changes,
+        }
+    }
+}
+
+/// Применяет правила контрактов к двум версиям одноименной схемы без графа вызовов
+///
+/// Правила по умолчанию `ContractChecker` запускаются на синтетическом контракте
+/// `old → new`: несоответствие означает, что данные старой версии не подходят новой.
+pub fn compare_models(old: &SchemaReference, new: &SchemaReference) -> Vec<Mismatch> {
+    ContractChecker::new().compare_schemas(old, new)
+}
+
+/// Сравнивает предыдущую (`old`) и новую (`new`) версии одной схемы
+///
+/// Новая версия совместима, если данные, валидные для старой версии, принимаются
+/// новой: поэтому правила `checker` применяются к контракту `old → new`
+/// (сужение типа, новое обязательное поле). Удаленные поля и поля, ставшие
+/// обязательными, также считаются ломающими; новые необязательные поля - совместимыми.
+pub fn compare_schema_versions(
+    old: &SchemaReference,
+    new: &SchemaReference,
+    checker: &ContractChecker,
+) -> Result<SchemaCompatibility> {
+    let old_schema = SchemaParser::parse(old)?;
+    let new_schema = SchemaParser::parse(new)?;
+    let mut changes = Vec::new();
+
+    let mut old_fields: Vec<_> = old_schema.properties.iter().collect();
+    old_fields.sort_by(|a, b| a.0.cmp(b.0));
+    for (field_name, old_field) in old_fields {
+        let Some(new_field) = new_schema.properties.get(field_name) else {
+            changes.push(SchemaChange {
+                path: field_name.clone(),
+                compatibility: Compatibility::Breaking,
+                description: format!("Field '{}' was removed", field_name),
+            });
+            continue;
+        };
+
+        let was_required = old_schema.required.contains(field_name) || !old_field.optional;
+        let is_required = new_schema.required.contains(field_name) || !new_field.optional;
+        if !was_required && is_required {
+            changes.push(SchemaChange {
+                path: field_name.clone(),
+                compatibility: Compatibility::Breaking,
+                description: format!("Field '{}' became required", field_name),
+            });
+        }
+    }
+
+    for mismatch in checker.compare_schemas(old, new) {
+        changes.push(SchemaChange {
+            path: mismatch.path,
+            compatibility: Compatibility::Breaking,
+            description: mismatch.message,
+        });
+    }
+
+    let mut added_fields: Vec<_> = new_schema
+        .properties
+        .iter()
+        .filter(|(field_name, _)| !old_schema.properties.contains_key(*field_name))
+        .collect();
+    added_fields.sort_by(|a, b| a.0.cmp(b.0));
+    for (field_name, new_field) in added_fields {
+        // Новое обязательное поле уже отмечено правилом missing_field
+        let is_required = new_schema.required.contains(field_name) || !new_field.optional;
+        if !is_required {
+            changes.push(SchemaChange {
+                path: field_name.clone(),
+                compatibility: Compatibility::Compatible,
+                description: format!("Optional field '{}' was added", field_name),
+            });
+        }
+    }
+
+    Ok(SchemaCompatibility::new(&new.name, changes))
+}
+
+/// Сравнивает схемы двух запусков анализа по именам
+///
+/// Схемы, удаленные в новом запуске, считаются ломающим изменением,
+/// добавленные - совместимым. Схемы, которые не удалось разобрать, пропускаются.
+pub fn schema_compatibility_report(
+    old: &[SchemaReference],
+    new: &[SchemaReference],
+    checker: &ContractChecker,
+) -> Vec<SchemaCompatibility> {
+    let old_by_name: BTreeMap<&str, &SchemaReference> = old
+        .iter()
+        .map(|schema| (schema.name.as_str(), schema))
+        .collect();
+    let new_by_name: BTreeMap<&str, &SchemaReference> = new
+        .iter()
+        .map(|schema| (schema.name.as_str(), schema))
+        .collect();
+
+    let mut report = Vec::new();
+    for (name, old_schema) in &old_by_name {
+        match new_by_name.get(name) {
+            Some(new_schema) => {
+                if let Ok(result) = compare_schema_versions(old_schema, new_schema, checker) {
+                    report.push(result);
+                }
+            }
+            None => report.push(SchemaCompatibility::new(
+                name,
+                vec![SchemaChange {
+                    path: String::new(),
+                    compatibility: Compatibility::Breaking,
+                    description: format!("Schema '{}' was removed", name),
+                }],
+            )),
+        }
+    }
+    for name in new_by_name.keys() {
+        if !old_by_name.contains_key(name) {
+            report.push(SchemaCompatibility::new(
+                name,
+                vec![SchemaChange {
+                    path: String::new(),
+                    compatibility: Compatibility::Compatible,
+                    description: format!("Schema '{}' was added", name),
+                }],
+            ));
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::MismatchType;
+    use crate::test_support::schema;
+    use serde_json::json;
+
+    #[test]
+    fn test_removed_required_field_is_breaking() {
+        let old = schema(
+            "User",
+            json!({
+                "type": "object",
+                "properties": {"name": {"type": "string"}, "email": {"type": "string"}},
+                "required": ["name", "email"]
+            }),
+        );
+        let new = schema(
+            "User",
+            json!({
+                "type": "object",
+                "properties": {"name": {"type": "string"}, "nickname": {"type": "string"}},
+                "required": ["name"]
+            }),
+        );
+
+        let result = compare_schema_versions(&old, &new, &ContractChecker::new()).unwrap();
+
+        assert_eq!(result.compatibility, Compatibility::Breaking);
+        assert!(result.changes.iter().any(
+            |change| change.path == "email" && change.compatibility == Compatibility::Breaking
+        ));
+        assert!(result
+            .changes
+            .iter()
+            .any(|change| change.path == "nickname"
+                && change.compatibility == Compatibility::Compatible));
+    }
+
+    #[test]
+    fn test_compare_models_reports_changed_field_type() {
+        let old = schema(
+            "User",
+            json!({
+                "type": "object",
+                "properties": {"name": {"type": "string"}, "age": {"type": "integer"}},
+                "required": ["name", "age"]
+            }),
+        );
+        let new = schema(
+            "User",
+            json!({
+                "type": "object",
+                "properties": {"name": {"type": "string"}, "age": {"type": "string"}},
+                "required": ["name", "age"]
+            }),
+        );
+
+        let mismatches = compare_models(&old, &new);
+
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].mismatch_type, MismatchType::TypeMismatch);
+        assert_eq!(mismatches[0].path, "age");
+    }
+}
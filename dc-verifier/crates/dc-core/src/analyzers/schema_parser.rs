@@ -4,7 +4,7 @@ use serde_json::Value;
 use std::collections::HashMap;
 
 /// Представление JSON Schema для сравнения
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct JsonSchema {
     /// Тип схемы (object, array, string, number, etc.)
     pub schema_type: String,
@@ -14,6 +14,8 @@ pub struct JsonSchema {
     pub required: Vec<String>,
     /// Элементы массива (для type="array")
     pub items: Option<Box<JsonSchema>>,
+    /// Позиционные типы элементов кортежа (`[string, number]`, `prefixItems`)
+    pub tuple_items: Vec<JsonSchema>,
     /// Дополнительные ограничения
     pub constraints: Vec<Constraint>,
 }
@@ -31,6 +33,148 @@ pub struct FieldInfo {
     pub constraints: Vec<Constraint>,
     /// Вложенная схема (для объектов)
     pub nested_schema: Option<Box<JsonSchema>>,
+    /// Поле помечено устаревшим (`deprecated: true`, JSDoc `@deprecated`)
+    pub deprecated: bool,
+    /// Поле ссылается на схему, которая уже разворачивается выше (`children: List[Node]`);
+    /// `nested_schema` для него не строится, чтобы разрешение не зацикливалось
+    pub recursive: bool,
+}
+
+impl JsonSchema {
+    /// Стандартный документ JSON Schema (`type`, `properties`, `required`, ограничения)
+    pub fn to_json_value(&self) -> Value {
+        let mut object = serde_json::Map::new();
+        if let Some(type_name) = json_type_name(&self.schema_type) {
+            object.insert("type".to_string(), Value::from(type_name));
+        }
+
+        if !self.properties.is_empty() {
+            let mut names: Vec<&String> = self.properties.keys().collect();
+            names.sort();
+            let properties = names
+                .iter()
+                .map(|name| (name.to_string(), self.properties[*name].to_json_value()))
+                .collect();
+            object.insert("properties".to_string(), Value::Object(properties));
+
+            // Поле обязательно, если оно указано в `required` или не помечено как optional
+            let required: Vec<Value> = names
+                .into_iter()
+                .filter(|name| self.required.contains(name) || !self.properties[*name].optional)
+                .map(|name| Value::from(name.as_str()))
+                .collect();
+            if !required.is_empty() {
+                object.insert("required".to_string(), Value::Array(required));
+            }
+        }
+
+        if let Some(items) = &self.items {
+            object.insert("items".to_string(), items.to_json_value());
+        }
+        if !self.tuple_items.is_empty() {
+            let items = self.tuple_items.iter().map(JsonSchema::to_json_value);
+            object.insert("prefixItems".to_string(), Value::Array(items.collect()));
+        }
+        insert_constraints(&mut object, &self.schema_type, &self.constraints);
+
+        Value::Object(object)
+    }
+}
+
+impl FieldInfo {
+    /// JSON Schema поля
+    pub fn to_json_value(&self) -> Value {
+        let type_name = SchemaParser::schema_type_name(self.base_type);
+        let mut object = match &self.nested_schema {
+            // Вложенный объект или кортеж описывает само поле
+            Some(nested) if nested.schema_type == type_name => match nested.to_json_value() {
+                Value::Object(object) => object,
+                _ => serde_json::Map::new(),
+            },
+            Some(nested) if self.base_type == BaseType::Array => {
+                let mut object = serde_json::Map::new();
+                object.insert("items".to_string(), nested.to_json_value());
+                object
+            }
+            _ => serde_json::Map::new(),
+        };
+
+        if let Some(type_name) = json_type_name(type_name) {
+            object.insert("type".to_string(), Value::from(type_name));
+        }
+        insert_constraints(&mut object, type_name, &self.constraints);
+        if self.deprecated {
+            object.insert("deprecated".to_string(), Value::from(true));
+        }
+        if self.recursive {
+            object.insert("x-recursive".to_string(), Value::from(true));
+        }
+
+        Value::Object(object)
+    }
+}
+
+/// Имя типа JSON Schema (`enum`, `any` и `unknown` не имеют ключа `type`)
+fn json_type_name(schema_type: &str) -> Option<&str> {
+    match schema_type {
+        "string" | "number" | "integer" | "boolean" | "object" | "array" | "null" => {
+            Some(schema_type)
+        }
+        _ => None,
+    }
+}
+
+/// Переводит ограничения в ключевые слова JSON Schema с учетом типа
+fn insert_constraints(
+    object: &mut serde_json::Map<String, Value>,
+    schema_type: &str,
+    constraints: &[Constraint],
+) {
+    let (min_key, max_key) = match schema_type {
+        "string" | "enum" => ("minLength", "maxLength"),
+        "array" => ("minItems", "maxItems"),
+        _ => ("minimum", "maximum"),
+    };
+    let number = |value: &ConstraintValue| match value {
+        ConstraintValue::Integer(value) => Value::from(*value),
+        ConstraintValue::Float(value) => Value::from(*value),
+    };
+
+    for constraint in constraints {
+        match constraint {
+            Constraint::Min(value) => {
+                object.insert(min_key.to_string(), number(value));
+            }
+            Constraint::Max(value) => {
+                object.insert(max_key.to_string(), number(value));
+            }
+            Constraint::Pattern(pattern) => {
+                object.insert("pattern".to_string(), Value::from(pattern.as_str()));
+            }
+            Constraint::Email => {
+                object.insert("format".to_string(), Value::from("email"));
+            }
+            Constraint::Url => {
+                object.insert("format".to_string(), Value::from("uri"));
+            }
+            Constraint::Enum(values) => {
+                object.insert("enum".to_string(), Value::from(values.clone()));
+            }
+            Constraint::UniqueItems => {
+                object.insert("uniqueItems".to_string(), Value::from(true));
+            }
+            Constraint::OneOf(types) => {
+                let variants = types
+                    .iter()
+                    .map(|base_type| SchemaParser::schema_type_name(*base_type))
+                    .filter_map(json_type_name)
+                    .map(|type_name| serde_json::json!({ "type": type_name }))
+                    .collect();
+                object.remove("type");
+                object.insert("anyOf".to_string(), Value::Array(variants));
+            }
+        }
+    }
 }
 
 /// Парсер схем из SchemaReference
@@ -45,9 +189,176 @@ impl SchemaParser {
             SchemaType::TypeScript => Self::parse_typescript(schema_ref),
             SchemaType::OpenAPI => Self::parse_openapi(schema_ref),
             SchemaType::JsonSchema => Self::parse_json_schema(schema_ref),
+            SchemaType::Protobuf => Self::parse_protobuf(schema_ref),
         }
     }
 
+    /// Парсит SchemaReference и разрешает ссылки полей на другие модели
+    ///
+    /// Поле с типом `Address` (а также `Optional[Address]`, `Address | None`
+    /// и `List[Address]`) получает разобранную схему модели `Address` из `models`
+    /// в `nested_schema`. Ссылка на модель, которая уже разворачивается выше
+    /// (`class Node: children: List[Node]`), не разворачивается повторно:
+    /// поле помечается `recursive`.
+    pub fn parse_with_models(
+        schema_ref: &SchemaReference,
+        models: &[SchemaReference],
+    ) -> Result<JsonSchema> {
+        let mut visited = vec![schema_ref.name.clone()];
+        Self::parse_resolving(schema_ref, models, &mut visited)
+    }
+
+    fn parse_resolving(
+        schema_ref: &SchemaReference,
+        models: &[SchemaReference],
+        visited: &mut Vec<String>,
+    ) -> Result<JsonSchema> {
+        let mut schema = Self::parse(schema_ref)?;
+
+        for field in schema.properties.values_mut() {
+            if field.nested_schema.is_some() {
+                continue;
+            }
+            let (type_name, is_list) = Self::referenced_model(&field.field_type);
+            if visited.iter().any(|name| name == type_name) {
+                field.recursive = true;
+                field.base_type = Self::model_field_base_type(is_list);
+                continue;
+            }
+            let Some(model) = models.iter().find(|model| model.name == type_name) else {
+                continue;
+            };
+
+            visited.push(model.name.clone());
+            let nested = Self::parse_resolving(model, models, visited);
+            visited.pop();
+
+            if let Ok(nested) = nested {
+                // Для списка моделей вложенная схема описывает элементы массива
+                field.base_type = Self::model_field_base_type(is_list);
+                field.nested_schema = Some(Box::new(nested));
+            }
+        }
+
+        Ok(schema)
+    }
+
+    /// Снимает конверт ответа (`{"data": ..., "meta": ...}`) по пути `envelope`
+    ///
+    /// Путь задается через точку (`data`, `result.payload`). Поддерживаются схемы
+    /// с полной JSON схемой в metadata; ссылки `$ref` на `#/$defs/...` и
+    /// `#/definitions/...` разрешаются внутри того же документа.
+    /// Возвращает `None`, если схема не содержит поля по указанному пути.
+    pub fn unwrap_envelope(
+        schema_ref: &SchemaReference,
+        envelope: &str,
+    ) -> Option<SchemaReference> {
+        let root: Value = serde_json::from_str(schema_ref.metadata.get("json_schema")?).ok()?;
+
+        let mut name = schema_ref.name.clone();
+        let mut current = &root;
+        for segment in envelope.split('.').filter(|segment| !segment.is_empty()) {
+            let field = current.get("properties")?.get(segment)?;
+            current = match Self::local_ref(field) {
+                Some(reference) => {
+                    // Для ссылки на модель используем ее имя
+                    name = reference
+                        .rsplit('/')
+                        .next()
+                        .unwrap_or(reference)
+                        .to_string();
+                    root.pointer(reference.strip_prefix('#')?)?
+                }
+                None => {
+                    name = format!("{}.{}", name, segment);
+                    field
+                }
+            };
+        }
+        if std::ptr::eq(current, &root) {
+            return None;
+        }
+
+        // Сохраняем определения, чтобы вложенные ссылки оставались разрешимыми
+        let mut inner = current.clone();
+        if let (Some(inner), Some(root)) = (inner.as_object_mut(), root.as_object()) {
+            for key in ["$defs", "definitions"] {
+                if let Some(defs) = root.get(key) {
+                    inner.entry(key).or_insert_with(|| defs.clone());
+                }
+            }
+        }
+
+        let mut metadata = schema_ref.metadata.clone();
+        metadata.insert("json_schema".to_string(), inner.to_string());
+        Some(SchemaReference {
+            name,
+            schema_type: schema_ref.schema_type,
+            location: schema_ref.location.clone(),
+            metadata,
+        })
+    }
+
+    /// Локальная ссылка `$ref` поля (в том числе `allOf` из одной ссылки)
+    fn local_ref(value: &Value) -> Option<&str> {
+        value.get("$ref").and_then(Value::as_str).or_else(|| {
+            match value
+                .get("allOf")
+                .and_then(Value::as_array)
+                .map(Vec::as_slice)
+            {
+                Some([single]) => single.get("$ref").and_then(Value::as_str),
+                _ => None,
+            }
+        })
+    }
+
+    /// Базовый тип поля, ссылающегося на модель или список моделей
+    fn model_field_base_type(is_list: bool) -> BaseType {
+        if is_list {
+            BaseType::Array
+        } else {
+            BaseType::Object
+        }
+    }
+
+    /// Имя модели из аннотации и признак списка (`List[Node]`, `list[Node]`)
+    fn referenced_model(field_type: &str) -> (&str, bool) {
+        let name = Self::referenced_model_name(field_type);
+        let element = ["List[", "list["].iter().find_map(|prefix| {
+            name.strip_prefix(prefix)
+                .and_then(|rest| rest.strip_suffix(']'))
+        });
+        match element {
+            Some(element) => (Self::referenced_model_name(element), true),
+            None => (name, false),
+        }
+    }
+
+    /// Возвращает имя модели из аннотации, снимая `Optional[...]`, `| None`
+    /// и кавычки forward-ссылок (`"Node"`)
+    fn referenced_model_name(field_type: &str) -> &str {
+        let field_type = field_type.trim().trim_matches(|c| c == '"' || c == '\'');
+        if let Some(inner) = field_type
+            .strip_prefix("Optional[")
+            .and_then(|rest| rest.strip_suffix(']'))
+        {
+            return Self::referenced_model_name(inner);
+        }
+
+        let parts: Vec<&str> = field_type.split('|').map(str::trim).collect();
+        let non_null: Vec<&str> = parts
+            .iter()
+            .copied()
+            .filter(|part| *part != "None")
+            .collect();
+        if parts.len() > 1 && non_null.len() == 1 {
+            return non_null[0];
+        }
+
+        field_type
+    }
+
     /// Парсит Pydantic схему
     fn parse_pydantic(schema_ref: &SchemaReference) -> Result<JsonSchema> {
         // Проверяем, есть ли полная JSON схема в metadata
@@ -97,20 +408,26 @@ impl SchemaParser {
                         continue;
                     }
 
+                    let (base_type, constraints) = Self::field_type_info(&field_type);
                     properties.insert(
                         name.clone(),
                         FieldInfo {
                             field_type: field_type.clone(),
-                            base_type: Self::base_type_from_string(&field_type),
+                            base_type,
                             optional: true, // По умолчанию поля опциональны
-                            constraints: Vec::new(),
+                            constraints,
                             nested_schema: None,
+                            deprecated: false,
+                            recursive: false,
                         },
                     );
                 }
             }
         }
 
+        // Ограничения из аннотаций вроде `conint(ge=0)`
+        Self::apply_field_constraints(schema_ref, &mut properties)?;
+
         // Синхронизируем optional и required: если required пустой, все поля optional=true
         // Иначе устанавливаем optional=false для полей в required
         if required.is_empty() {
@@ -138,14 +455,40 @@ impl SchemaParser {
             properties,
             required,
             items: None,
+            tuple_items: Vec::new(),
             constraints: Vec::new(),
         })
     }
 
     /// Парсит Zod схему
+    ///
+    /// Поля Zod схемы записываются в формате TypeScript (`name:type[:optional]`):
+    /// поле обязательно, если не помечено `optional`.
+    /// Ограничения цепочек валидаторов (`.min(3)`, `.email()`) добавляются к полям.
     fn parse_zod(schema_ref: &SchemaReference) -> Result<JsonSchema> {
-        // Аналогично Pydantic
-        Self::parse_pydantic(schema_ref)
+        let mut schema = Self::parse_typescript(schema_ref)?;
+        Self::apply_field_constraints(schema_ref, &mut schema.properties)?;
+        Ok(schema)
+    }
+
+    /// Добавляет к полям ограничения из метаданных `FIELD_CONSTRAINTS_METADATA`
+    fn apply_field_constraints(
+        schema_ref: &SchemaReference,
+        properties: &mut HashMap<String, FieldInfo>,
+    ) -> Result<()> {
+        if let Some(constraints_str) = schema_ref
+            .metadata
+            .get(crate::models::FIELD_CONSTRAINTS_METADATA)
+        {
+            let field_constraints: HashMap<String, Vec<Constraint>> =
+                serde_json::from_str(constraints_str)?;
+            for (field_name, constraints) in field_constraints {
+                if let Some(field_info) = properties.get_mut(&field_name) {
+                    field_info.constraints.extend(constraints);
+                }
+            }
+        }
+        Ok(())
     }
 
     /// Парсит TypeScript схему
@@ -166,10 +509,16 @@ impl SchemaParser {
         // Извлекаем поля из metadata (формат: "name:type:optional" или "name:type")
         let mut properties = HashMap::new();
         let mut required = Vec::new();
+        // Поля с JSDoc `@deprecated` (имена через запятую)
+        let deprecated_fields: Vec<&str> = schema_ref
+            .metadata
+            .get(crate::models::DEPRECATED_FIELDS_METADATA)
+            .map(|names| names.split(',').map(str::trim).collect())
+            .unwrap_or_default();
 
         if let Some(fields_str) = schema_ref.metadata.get("fields") {
-            for field in fields_str.split(',') {
-                let field = field.trim();
+            // Запятые внутри типов (`[number, number]`) не разделяют поля
+            for field in Self::split_top_level(fields_str) {
                 if field.is_empty() {
                     continue;
                 }
@@ -185,13 +534,20 @@ impl SchemaParser {
                         .unwrap_or(false);
 
                     if !name.is_empty() && !field_type.is_empty() {
-                        let base_type = Self::base_type_from_string(&field_type);
+                        let tuple = Self::parse_tuple(&field_type);
+                        let (base_type, constraints) = if tuple.is_some() {
+                            (BaseType::Array, Vec::new())
+                        } else {
+                            Self::field_type_info(&field_type)
+                        };
                         let field_info = FieldInfo {
                             field_type,
                             base_type,
                             optional,
-                            constraints: Vec::new(),
-                            nested_schema: None,
+                            constraints,
+                            nested_schema: tuple.map(Box::new),
+                            deprecated: deprecated_fields.contains(&name.as_str()),
+                            recursive: false,
                         };
                         properties.insert(name.clone(), field_info);
 
@@ -203,27 +559,25 @@ impl SchemaParser {
             }
         }
 
-        // Если есть тип в metadata (для type aliases)
+        // Если есть тип в metadata (для type aliases и enum)
         if let Some(type_str) = schema_ref.metadata.get("type") {
             let base_type = Self::base_type_from_string(type_str);
-            let schema_type = match base_type {
-                BaseType::String => "string",
-                BaseType::Number => "number",
-                BaseType::Integer => "integer",
-                BaseType::Boolean => "boolean",
-                BaseType::Object => "object",
-                BaseType::Array => "array",
-                BaseType::Null => "null",
-                BaseType::Any => "any",
-                BaseType::Unknown => "unknown",
+            // Значения enum записаны через запятую в `values`
+            let constraints = match schema_ref.metadata.get("values") {
+                Some(values) if base_type == BaseType::Enum => vec![Constraint::Enum(
+                    values
+                        .split(',')
+                        .filter(|value| !value.is_empty())
+                        .map(str::to_string)
+                        .collect(),
+                )],
+                _ => Vec::new(),
             };
-            return Ok(JsonSchema {
-                schema_type: schema_type.to_string(),
-                properties: HashMap::new(),
-                required: Vec::new(),
-                items: None,
-                constraints: Vec::new(),
-            });
+            return Ok(Self::parse_tuple(type_str).unwrap_or_else(|| JsonSchema {
+                schema_type: Self::schema_type_name(base_type).to_string(),
+                constraints,
+                ..JsonSchema::default()
+            }));
         }
 
         Ok(JsonSchema {
@@ -231,15 +585,88 @@ impl SchemaParser {
             properties,
             required,
             items: None,
+            tuple_items: Vec::new(),
             constraints: Vec::new(),
         })
     }
 
+    /// Разбирает тип кортежа TypeScript (`[string, number]`) в массив с позиционными типами
+    ///
+    /// Необязательные элементы (`number?`) и вложенные кортежи поддерживаются.
+    fn parse_tuple(type_str: &str) -> Option<JsonSchema> {
+        let inner = type_str.trim().strip_prefix('[')?.strip_suffix(']')?;
+        let tuple_items = Self::split_top_level(inner)
+            .into_iter()
+            .filter(|item| !item.is_empty())
+            .map(|item| {
+                let item = item.trim_end_matches('?');
+                Self::parse_tuple(item).unwrap_or_else(|| JsonSchema {
+                    schema_type: Self::schema_type_name(Self::base_type_from_string(item))
+                        .to_string(),
+                    ..JsonSchema::default()
+                })
+            })
+            .collect();
+
+        Some(JsonSchema {
+            schema_type: "array".to_string(),
+            tuple_items,
+            ..JsonSchema::default()
+        })
+    }
+
+    /// Делит строку по запятым верхнего уровня (вне `[]`, `<>`, `()` и `{}`)
+    ///
+    /// Стрелка функционального типа (`(value: number) => string`) не закрывает `<>`.
+    fn split_top_level(value: &str) -> Vec<&str> {
+        let mut parts = Vec::new();
+        let mut depth = 0i32;
+        let mut start = 0;
+        let mut previous = None;
+        for (idx, ch) in value.char_indices() {
+            let arrow = ch == '>' && previous == Some('=');
+            previous = Some(ch);
+            match ch {
+                '[' | '<' | '(' | '{' => depth += 1,
+                '>' if arrow => {}
+                ']' | '>' | ')' | '}' => depth -= 1,
+                ',' if depth == 0 => {
+                    parts.push(value[start..idx].trim());
+                    start = idx + 1;
+                }
+                _ => {}
+            }
+        }
+        parts.push(value[start..].trim());
+        parts
+    }
+
+    /// Имя типа JSON Schema для базового типа
+    fn schema_type_name(base_type: BaseType) -> &'static str {
+        match base_type {
+            BaseType::String => "string",
+            BaseType::Number => "number",
+            BaseType::Integer => "integer",
+            BaseType::Boolean => "boolean",
+            BaseType::Object => "object",
+            BaseType::Array => "array",
+            BaseType::Enum => "enum",
+            BaseType::Null => "null",
+            BaseType::Any => "any",
+            BaseType::Unknown => "unknown",
+        }
+    }
+
     /// Парсит OpenAPI схему
     fn parse_openapi(schema_ref: &SchemaReference) -> Result<JsonSchema> {
         Self::parse_json_schema(schema_ref)
     }
 
+    /// Парсит сообщение protobuf (JSON Schema строится при разборе `.proto`)
+    fn parse_protobuf(schema_ref: &SchemaReference) -> Result<JsonSchema> {
+        Self::parse_json_schema(schema_ref)
+    }
+
     /// Парсит JSON Schema
     fn parse_json_schema(schema_ref: &SchemaReference) -> Result<JsonSchema> {
         // Извлекаем JSON Schema из metadata
@@ -305,6 +732,7 @@ impl SchemaParser {
         if json_value.get("format").and_then(|v| v.as_str()) == Some("uri") {
             constraints.push(Constraint::Url);
         }
+        constraints.extend(Self::array_constraints(json_value));
         if let Some(enum_values) = json_value.get("enum").and_then(|v| v.as_array()) {
             let enum_strings: Vec<String> = enum_values
                 .iter()
@@ -322,26 +750,39 @@ impl SchemaParser {
             None
         };
 
+        // Позиционные элементы кортежа (JSON Schema 2020-12 `prefixItems`)
+        let tuple_items = match json_value.get("prefixItems").and_then(|v| v.as_array()) {
+            Some(prefix_items) => prefix_items
+                .iter()
+                .map(Self::parse_json_value)
+                .collect::<Result<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+
         Ok(JsonSchema {
             schema_type,
             properties,
             required,
             items,
+            tuple_items,
             constraints,
         })
     }
 
     /// Парсит свойство из JSON Schema
-    fn parse_property(prop_value: &Value) -> Result<FieldInfo> {
-        let field_type = prop_value
-            .get("type")
-            .and_then(|v| v.as_str())
-            .unwrap_or("any")
-            .to_string();
-
-        let base_type = Self::base_type_from_string(&field_type);
+    pub(crate) fn parse_property(prop_value: &Value) -> Result<FieldInfo> {
+        // `"type": ["string", "number"]` - объединение типов
+        let field_type = match prop_value.get("type") {
+            Some(Value::Array(types)) => types
+                .iter()
+                .filter_map(|t| t.as_str())
+                .collect::<Vec<_>>()
+                .join(" | "),
+            Some(t) => t.as_str().unwrap_or("any").to_string(),
+            None => "any".to_string(),
+        };
 
-        let mut constraints = Vec::new();
+        let (mut base_type, mut constraints) = Self::field_type_info(&field_type);
 
         // Извлекаем constraints для поля
         if let Some(min) = prop_value.get("minimum").and_then(|v| v.as_f64()) {
@@ -359,6 +800,20 @@ impl SchemaParser {
         if let Some(pattern) = prop_value.get("pattern").and_then(|v| v.as_str()) {
             constraints.push(Constraint::Pattern(pattern.to_string()));
         }
+        constraints.extend(Self::array_constraints(prop_value));
+        if let Some(enum_values) = prop_value.get("enum").and_then(|v| v.as_array()) {
+            let enum_strings: Vec<String> = enum_values
+                .iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect();
+            if !enum_strings.is_empty() {
+                constraints.push(Constraint::Enum(enum_strings));
+                // Строка с закрытым набором значений - это перечисление
+                if matches!(base_type, BaseType::String | BaseType::Unknown) {
+                    base_type = BaseType::Enum;
+                }
+            }
+        }
 
         // Проверяем вложенную схему (для объектов)
         let nested_schema = if field_type == "object" {
@@ -373,9 +828,63 @@ impl SchemaParser {
             optional: true, // Будет установлено позже на основе required
             constraints,
             nested_schema,
+            deprecated: prop_value
+                .get("deprecated")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            recursive: prop_value
+                .get("x-recursive")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
         })
     }
 
+    /// Ограничения числа и уникальности элементов массива (`minItems`, `maxItems`, `uniqueItems`)
+    fn array_constraints(value: &Value) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        if let Some(min_items) = value.get("minItems").and_then(|v| v.as_u64()) {
+            constraints.push(Constraint::Min(ConstraintValue::Integer(min_items as i64)));
+        }
+        if let Some(max_items) = value.get("maxItems").and_then(|v| v.as_u64()) {
+            constraints.push(Constraint::Max(ConstraintValue::Integer(max_items as i64)));
+        }
+        if value.get("uniqueItems").and_then(|v| v.as_bool()) == Some(true) {
+            constraints.push(Constraint::UniqueItems);
+        }
+        constraints
+    }
+
+    /// Базовый тип и ограничения поля по строке типа, с учетом объединений (`str | int`)
+    fn field_type_info(field_type: &str) -> (BaseType, Vec<Constraint>) {
+        let members: Vec<BaseType> = field_type
+            .split('|')
+            .map(|member| Self::base_type_from_string(member.trim()))
+            .collect();
+        if members.len() < 2 {
+            return (Self::base_type_from_string(field_type), Vec::new());
+        }
+        Self::union_type_info(members)
+    }
+
+    /// Сводит члены объединения к базовому типу и ограничению `OneOf`
+    ///
+    /// `null` не считается отдельным вариантом; если остается один тип - это он,
+    /// иначе базовый тип неизвестен, а варианты перечислены в `OneOf`.
+    pub fn union_type_info(members: Vec<BaseType>) -> (BaseType, Vec<Constraint>) {
+        let mut distinct: Vec<BaseType> = Vec::new();
+        for member in members {
+            if member != BaseType::Null && !distinct.contains(&member) {
+                distinct.push(member);
+            }
+        }
+
+        match distinct.as_slice() {
+            [] => (BaseType::Null, Vec::new()),
+            [single] => (*single, Vec::new()),
+            _ => (BaseType::Unknown, vec![Constraint::OneOf(distinct)]),
+        }
+    }
+
     /// Преобразует строковый тип в BaseType
     fn base_type_from_string(type_str: &str) -> BaseType {
         match type_str.to_lowercase().as_str() {
@@ -385,8 +894,26 @@ impl SchemaParser {
             "bool" | "boolean" => BaseType::Boolean,
             "list" | "array" => BaseType::Array,
             "dict" | "object" => BaseType::Object,
+            "enum" | "literal" => BaseType::Enum,
             "null" | "none" => BaseType::Null,
             _ => BaseType::Unknown,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_top_level_skips_arrows() {
+        assert_eq!(
+            SchemaParser::split_top_level("format:(value: number) => string,tags:Array<string>"),
+            vec!["format:(value: number) => string", "tags:Array<string>"]
+        );
+        assert_eq!(
+            SchemaParser::split_top_level("[number, number], Map<string, number>"),
+            vec!["[number, number]", "Map<string, number>"]
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use crate::analyzers::ContractChecker;
+use crate::call_graph::{CallGraph, CallNode, HttpMethod};
+use crate::models::{normalize_route_path, Mismatch, SchemaReference, INLINE_SCHEMA_METADATA};
+use crate::parsers::ApiEndpoint;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Расхождение реализации с эталонной OpenAPI спецификацией (contract-first)
+#[derive(Debug, Clone)]
+pub enum SpecDrift {
+    /// Route реализован, но не описан в спецификации
+    MissingInSpec { method: String, path: String },
+    /// Эндпоинт описан в спецификации, но не реализован
+    MissingInImplementation { method: String, path: String },
+    /// Схема тела запроса или ответа отличается от спецификации
+    SchemaDrift {
+        method: String,
+        path: String,
+        /// "request" или "response"
+        part: &'static str,
+        /// Схема из спецификации
+        spec_schema: String,
+        /// Схема реализации
+        implementation_schema: String,
+        /// Несоответствия полей (спецификация → реализация)
+        mismatches: Vec<Mismatch>,
+    },
+}
+
+impl std::fmt::Display for SpecDrift {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpecDrift::MissingInSpec { method, path } => {
+                write!(f, "{} {}: implemented but not in the spec", method, path)
+            }
+            SpecDrift::MissingInImplementation { method, path } => {
+                write!(f, "{} {}: in the spec but not implemented", method, path)
+            }
+            SpecDrift::SchemaDrift {
+                method,
+                path,
+                part,
+                spec_schema,
+                implementation_schema,
+                mismatches,
+            } => write!(
+                f,
+                "{} {}: {} schema drift (spec: {}, implementation: {}, {} field mismatch(es))",
+                method,
+                path,
+                part,
+                spec_schema,
+                implementation_schema,
+                mismatches.len()
+            ),
+        }
+    }
+}
+
+/// Схемы реализованного route
+struct ImplementedRoute {
+    path: String,
+    operation_id: Option<String>,
+    request_schema: Option<SchemaReference>,
+    response_schema: Option<SchemaReference>,
+}
+
+/// Сравнивает routes графа вызовов с эндпоинтами эталонной спецификации
+///
+/// Эндпоинты сопоставляются по `operationId`, а без совпадения - по HTTP методу
+/// и шаблону пути: стиль записи и имена параметров не учитываются
+/// (`/users/{user_id}` == `/users/:id`). Для общих эндпоинтов
+/// схемы тела запроса и ответа сравниваются правилами `checker`.
+pub fn compare_with_spec(
+    graph: &CallGraph,
+    endpoints: &[ApiEndpoint],
+    checker: &ContractChecker,
+) -> Vec<SpecDrift> {
+    let implemented = implemented_routes(graph);
+    let specified: BTreeMap<(String, String), &ApiEndpoint> = endpoints
+        .iter()
+        .map(|endpoint| {
+            (
+                (
+                    endpoint.method.to_uppercase(),
+                    path_template(&endpoint.path),
+                ),
+                endpoint,
+            )
+        })
+        .collect();
+    let by_operation_id: HashMap<&str, &(String, String)> = specified
+        .iter()
+        .filter_map(|(key, endpoint)| Some((endpoint.operation_id.as_deref()?, key)))
+        .collect();
+
+    let mut drifts = Vec::new();
+    let mut matched = HashSet::new();
+
+    for ((method, template), route) in &implemented {
+        let path = &route.path;
+        let key = route
+            .operation_id
+            .as_deref()
+            .and_then(|operation_id| by_operation_id.get(operation_id).copied())
+            .cloned()
+            .unwrap_or_else(|| (method.clone(), template.clone()));
+        let Some(endpoint) = specified.get(&key) else {
+            drifts.push(SpecDrift::MissingInSpec {
+                method: method.clone(),
+                path: path.clone(),
+            });
+            continue;
+        };
+        matched.insert(key);
+
+        let parts = [
+            ("request", &endpoint.request_schema, &route.request_schema),
+            (
+                "response",
+                &endpoint.response_schema,
+                &route.response_schema,
+            ),
+        ];
+        for (part, spec_schema, implementation_schema) in parts {
+            let (Some(spec_schema), Some(implementation_schema)) =
+                (spec_schema, implementation_schema)
+            else {
+                continue;
+            };
+
+            let mismatches = checker.compare_schemas(spec_schema, implementation_schema);
+            let spec_name = schema_short_name(&spec_schema.name);
+            // Встроенная схема спецификации безымянна: сравниваются только поля
+            let names_match = spec_schema.metadata.contains_key(INLINE_SCHEMA_METADATA)
+                || spec_name == implementation_schema.name;
+            if mismatches.is_empty() && names_match {
+                continue;
+            }
+            drifts.push(SpecDrift::SchemaDrift {
+                method: method.clone(),
+                path: path.clone(),
+                part,
+                spec_schema: spec_name.to_string(),
+                implementation_schema: implementation_schema.name.clone(),
+                mismatches,
+            });
+        }
+    }
+
+    for ((method, template), endpoint) in &specified {
+        if !matched.contains(&(method.clone(), template.clone())) {
+            drifts.push(SpecDrift::MissingInImplementation {
+                method: method.clone(),
+                path: normalize_route_path(&endpoint.path),
+            });
+        }
+    }
+
+    drifts
+}
+
+/// Routes графа по (метод, шаблон пути)
+fn implemented_routes(graph: &CallGraph) -> BTreeMap<(String, String), ImplementedRoute> {
+    graph
+        .node_weights()
+        .filter_map(|node| {
+            let CallNode::Route {
+                path,
+                method,
+                handler,
+                operation_id,
+                ..
+            } = node
+            else {
+                return None;
+            };
+
+            let (request_schema, response_schema, operation_id) = match graph.node_weight(**handler)
+            {
+                Some(CallNode::Function {
+                    name,
+                    parameters,
+                    return_type,
+                    ..
+                }) => (
+                    parameters
+                        .iter()
+                        .find_map(|param| param.type_info.schema_ref.clone()),
+                    return_type
+                        .as_ref()
+                        .and_then(|return_type| return_type.schema_ref.clone()),
+                    operation_id
+                        .clone()
+                        .or_else(|| Some(fastapi_operation_id(name, path, *method))),
+                ),
+                _ => (None, None, operation_id.clone()),
+            };
+
+            Some((
+                (format!("{:?}", method).to_uppercase(), path_template(path)),
+                ImplementedRoute {
+                    path: normalize_route_path(path),
+                    operation_id,
+                    request_schema,
+                    response_schema,
+                },
+            ))
+        })
+        .collect()
+}
+
+/// `operationId`, который FastAPI генерирует для route без `operation_id=`
+///
+/// Повторяет `fastapi.routing.generate_unique_id`: имя handler и путь, в которых
+/// все символы, кроме букв, цифр и `_`, заменены на `_`, плюс метод в нижнем регистре
+/// (`get_user` + `/users/{user_id}` + GET → `get_user_users__user_id__get`).
+pub fn fastapi_operation_id(handler: &str, path: &str, method: HttpMethod) -> String {
+    let operation_id: String = format!("{}{}", handler, path)
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!(
+        "{}_{}",
+        operation_id,
+        format!("{:?}", method).to_lowercase()
+    )
+}
+
+/// Имя схемы без префикса `$ref` (`#/components/schemas/User` → `User`)
+fn schema_short_name(name: &str) -> &str {
+    name.rsplit('/').next().unwrap_or(name)
+}
+
+/// Шаблон пути для сопоставления: параметры без имен (`/users/{id}` → `/users/{}`)
+pub(crate) fn path_template(path: &str) -> String {
+    normalize_route_path(path)
+        .split('/')
+        .map(|segment| {
+            if segment.starts_with('{') && segment.ends_with('}') {
+                "{}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
@@ -1,19 +1,90 @@
 use crate::call_graph::{CallEdge, CallGraph, CallNode};
+use crate::models::NodeId;
 use anyhow::Result;
 use bincode;
 use blake3;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use sled::Db;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 /// Хранилище кэша для графов вызовов
+///
+/// Клоны разделяют одну базу и общую статистику попаданий.
+#[derive(Clone)]
 pub struct CacheStore {
     db: Db,
+    hits: Arc<AtomicUsize>,
+    misses: Arc<AtomicUsize>,
+}
+
+/// Статистика попаданий в кэш результатов по файлам
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Файлы, взятые из кэша без повторного разбора
+    pub hits: usize,
+    /// Новые или измененные файлы, которые пришлось разобрать
+    pub misses: usize,
 }
 
 impl CacheStore {
     /// Создает новое хранилище кэша
     pub fn new(path: &str) -> Result<Self> {
         let db = sled::open(path)?;
-        Ok(Self { db })
+        Ok(Self {
+            db,
+            hits: Arc::new(AtomicUsize::new(0)),
+            misses: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    /// Возвращает статистику попаданий в кэш результатов по файлам
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Загружает результат обработки файла, если его содержимое не изменилось
+    ///
+    /// Ключом служит blake3-хеш содержимого: при любом изменении файла
+    /// возвращается `None`, и файл нужно разобрать заново.
+    pub fn load_file_entry<T: DeserializeOwned>(
+        &self,
+        file_path: &str,
+        content: &[u8],
+    ) -> Result<Option<T>> {
+        if self.is_changed(file_path, content)? {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return Ok(None);
+        }
+
+        match self.db.get(format!("entry:{}", file_path))? {
+            Some(data) => {
+                let entry = bincode::deserialize(data.as_ref())?;
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Ok(Some(entry))
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Сохраняет результат обработки файла вместе с хешем его содержимого
+    pub fn save_file_entry<T: Serialize>(
+        &self,
+        file_path: &str,
+        content: &[u8],
+        entry: &T,
+    ) -> Result<()> {
+        let serialized = bincode::serialize(entry)?;
+        self.db.insert(format!("entry:{}", file_path), serialized)?;
+        self.save_file_hash(file_path, content)
     }
 
     /// Проверяет, изменился ли граф для файла
@@ -35,6 +106,48 @@ impl CacheStore {
         Ok(())
     }
 
+    /// Проверяет, изменился ли набор файлов (состав или содержимое) под ключом `key`
+    ///
+    /// Используется для результатов, зависящих от многих файлов (например, DOT
+    /// граф адаптера): неизменный набор можно не обрабатывать повторно.
+    pub fn is_changed_files(&self, key: &str, files: &[PathBuf]) -> Result<bool> {
+        self.is_changed(key, Self::files_hash(files)?.as_bytes())
+    }
+
+    /// Сохраняет хеш набора файлов под ключом `key` вместе с самим набором
+    pub fn save_files_hash(&self, key: &str, files: &[PathBuf]) -> Result<()> {
+        self.db
+            .insert(format!("files:{}", key), bincode::serialize(files)?)?;
+        self.save_file_hash(key, Self::files_hash(files)?.as_bytes())
+    }
+
+    /// Набор файлов, сохраненный `save_files_hash` под ключом `key` (пустой, если его нет)
+    ///
+    /// Позволяет проверить и файлы, найденные только при обработке (например,
+    /// импортированные модули вне исходных директорий).
+    pub fn saved_files(&self, key: &str) -> Result<Vec<PathBuf>> {
+        match self.db.get(format!("files:{}", key))? {
+            Some(data) => Ok(bincode::deserialize(data.as_ref())?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Хеш путей и содержимого файлов (порядок файлов не важен)
+    ///
+    /// Удаленный файл учитывается только путем, поэтому его удаление меняет хеш.
+    fn files_hash(files: &[PathBuf]) -> Result<blake3::Hash> {
+        let mut sorted: Vec<&PathBuf> = files.iter().collect();
+        sorted.sort();
+        let mut hasher = blake3::Hasher::new();
+        for file in sorted {
+            hasher.update(file.to_string_lossy().as_bytes());
+            if let Ok(content) = std::fs::read(file) {
+                hasher.update(blake3::hash(&content).as_bytes());
+            }
+        }
+        Ok(hasher.finalize())
+    }
+
     /// Сохраняет граф вызовов
     pub fn save_graph(&self, graph_id: &str, graph: &CallGraph) -> Result<()> {
         // Сериализуем граф вручную, так как petgraph::Graph не сериализуем напрямую
@@ -97,13 +210,24 @@ impl CacheStore {
                 std::collections::HashMap::new();
 
             // Добавляем узлы
+            let mut nodes = Vec::with_capacity(graph_data.nodes.len());
             for (old_idx, node) in graph_data.nodes {
-                let new_idx = graph.add_node(node);
+                let new_idx = graph.add_node(node.clone());
                 index_map.insert(old_idx, new_idx);
+                nodes.push((new_idx, node));
+            }
+
+            // Ссылки внутри узлов (Route.handler, Method.class, Class.methods)
+            // указывают на старые индексы - перенумеровываем их
+            for (new_idx, mut node) in nodes {
+                for node_id in node.node_ids_mut() {
+                    *node_id = remap_node_id(&index_map, *node_id)?;
+                }
+                graph[new_idx] = node;
             }
 
             // Добавляем рёбра
-            for (source_old, target_old, edge) in graph_data.edges {
+            for (source_old, target_old, mut edge) in graph_data.edges {
                 let source_new = index_map.get(&source_old).copied().ok_or_else(|| {
                     anyhow::anyhow!(
                         "Corrupted cache: missing node {} while restoring edge ({} -> {})",
@@ -121,6 +245,9 @@ impl CacheStore {
                     )
                 })?;
 
+                for node_id in edge.node_ids_mut() {
+                    *node_id = remap_node_id(&index_map, *node_id)?;
+                }
                 graph.add_edge(source_new, target_new, edge);
             }
 
@@ -131,13 +258,64 @@ impl CacheStore {
     }
 }
 
+/// Переводит сохраненный индекс узла в индекс восстановленного графа
+fn remap_node_id(
+    index_map: &std::collections::HashMap<u32, petgraph::graph::NodeIndex>,
+    node_id: NodeId,
+) -> Result<NodeId> {
+    index_map
+        .get(&(node_id.index() as u32))
+        .copied()
+        .map(NodeId::from)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Corrupted cache: reference to missing node {}",
+                node_id.index()
+            )
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Location, NodeId};
+    use crate::call_graph::HttpMethod;
+    use crate::models::Location;
     use petgraph::graph::NodeIndex;
     use tempfile::TempDir;
 
+    #[test]
+    fn file_entry_invalidated_by_content_change() {
+        let dir = TempDir::new().unwrap();
+        let store = CacheStore::new(dir.path().to_str().unwrap()).unwrap();
+
+        store
+            .save_file_entry("a.ts", b"v1", &vec!["foo".to_string()])
+            .unwrap();
+
+        let cached: Option<Vec<String>> = store.load_file_entry("a.ts", b"v1").unwrap();
+        assert_eq!(cached, Some(vec!["foo".to_string()]));
+        let changed: Option<Vec<String>> = store.load_file_entry("a.ts", b"v2").unwrap();
+        assert_eq!(changed, None);
+        assert_eq!(store.stats(), CacheStats { hits: 1, misses: 1 });
+    }
+
+    #[test]
+    fn file_set_is_saved_and_detects_deleted_files() {
+        let dir = TempDir::new().unwrap();
+        let store = CacheStore::new(dir.path().join("db").to_str().unwrap()).unwrap();
+        let file = dir.path().join("a.py");
+        std::fs::write(&file, "x = 1\n").unwrap();
+        let files = vec![file.clone()];
+
+        store.save_files_hash("dot:a", &files).unwrap();
+        assert_eq!(store.saved_files("dot:a").unwrap(), files);
+        assert!(!store.is_changed_files("dot:a", &files).unwrap());
+
+        std::fs::remove_file(&file).unwrap();
+        assert!(store.is_changed_files("dot:a", &files).unwrap());
+        assert!(store.saved_files("dot:b").unwrap().is_empty());
+    }
+
     #[test]
     fn fails_when_edge_references_missing_node() {
         let dir = TempDir::new().unwrap();
@@ -175,4 +353,80 @@ mod tests {
             "unexpected error: {err}"
         );
     }
+
+    #[test]
+    fn route_handler_remapped_after_reload() {
+        let dir = TempDir::new().unwrap();
+        let store = CacheStore::new(dir.path().to_str().unwrap()).unwrap();
+
+        #[derive(serde::Serialize)]
+        struct GraphData {
+            nodes: Vec<(u32, CallNode)>,
+            edges: Vec<(u32, u32, CallEdge)>,
+        }
+
+        // Сохраненные индексы не совпадают с порядком восстановления узлов
+        let location = Location {
+            file: "main.py".into(),
+            line: 4,
+            column: None,
+        };
+        let route = CallNode::Route {
+            path: "/users".to_string(),
+            method: HttpMethod::Get,
+            handler: NodeId(NodeIndex::new(20)),
+            location: location.clone(),
+            response_model: None,
+            operation_id: None,
+            status_code: None,
+            responses: Vec::new(),
+        };
+        let module = CallNode::Module {
+            path: "main.py".into(),
+        };
+        let handler = CallNode::Function {
+            name: "get_users".to_string(),
+            file: "main.py".into(),
+            line: 5,
+            parameters: Vec::new(),
+            return_type: None,
+            decorators: Vec::new(),
+        };
+        let edge = CallEdge::Call {
+            caller: NodeId(NodeIndex::new(10)),
+            callee: NodeId(NodeIndex::new(20)),
+            argument_mapping: Vec::new(),
+            location,
+        };
+        let data = GraphData {
+            nodes: vec![(30, route), (10, module), (20, handler)],
+            edges: vec![(10, 20, edge)],
+        };
+        store
+            .db
+            .insert("graph:test", bincode::serialize(&data).unwrap())
+            .unwrap();
+
+        let loaded = store.load_graph("test").unwrap().unwrap();
+
+        let Some(CallNode::Route { handler, .. }) = loaded
+            .node_weights()
+            .find(|node| matches!(node, CallNode::Route { .. }))
+        else {
+            panic!("route not restored");
+        };
+        assert!(matches!(
+            loaded.node_weight(handler.0),
+            Some(CallNode::Function { name, .. }) if name == "get_users"
+        ));
+
+        let Some(CallEdge::Call { caller, callee, .. }) = loaded.edge_weights().next() else {
+            panic!("call edge not restored");
+        };
+        assert!(matches!(
+            loaded.node_weight(caller.0),
+            Some(CallNode::Module { .. })
+        ));
+        assert_eq!(callee, handler);
+    }
 }
@@ -1,14 +1,35 @@
 use anyhow::{Context, Result};
+use petgraph::visit::EdgeRef;
 use rustpython_parser::ast::Ranged;
 use rustpython_parser::{ast, parse, Mode};
+use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::analyzers::SchemaParser;
 use crate::call_graph::decorator::Decorator;
-use crate::call_graph::{CallEdge, CallGraph, CallNode, HttpMethod, Parameter};
-use crate::models::{BaseType, NodeId, TypeInfo};
-use crate::parsers::{Call, Import, LocationConverter, PythonParser};
+use crate::call_graph::{CallEdge, CallGraph, CallNode, HttpMethod, Parameter, PathCache};
+use crate::models::{
+    dedupe_schema_refs, BaseType, Location, NodeId, SchemaReference, SchemaType, TypeInfo,
+    CONTENT_TYPE_METADATA,
+};
+use crate::parsers::{
+    constrained_type, is_test_file, Call, Import, LocationConverter, PythonParser,
+};
+
+/// What an import alias stands for (`import numpy as np`, `from db import crud as c`)
+struct ImportAlias {
+    /// Dotted name behind the alias (`numpy`, `db.crud`)
+    target: String,
+    /// Analyzed module file the alias points into
+    module: Option<PathBuf>,
+    /// Definition the alias names inside `module` (`from m import f as g` → `f`)
+    name: Option<String>,
+}
+
+/// Router variable (`router` of `@router.get`) with its defining file
+type RouterKey = (PathBuf, String);
 
 /// Call graph builder - main class for creating call graphs from code
 pub struct CallGraphBuilder {
@@ -24,12 +45,35 @@ pub struct CallGraphBuilder {
     module_nodes: HashMap<PathBuf, NodeId>,
     /// Cache of functions/methods (key: file + name)
     function_nodes: HashMap<String, NodeId>,
+    /// Cache of external (unresolved) calls by name
+    external_nodes: HashMap<String, NodeId>,
+    /// Base class names of each analyzed class (from `ClassDef.bases`), by class key (file + name)
+    class_bases: HashMap<String, Vec<String>>,
+    /// Returned expression of each function/method that returns a value
+    return_values: HashMap<NodeId, String>,
+    /// Pydantic models of the analyzed files by class name (for `response_model=`)
+    models: HashMap<String, SchemaReference>,
+    /// Import aliases of each file (`np` for `import numpy as np`)
+    import_aliases: HashMap<PathBuf, HashMap<String, ImportAlias>>,
+    /// Names each analyzed module exports to `from module import *`
+    module_exports: HashMap<PathBuf, Vec<String>>,
+    /// Names bound by `from module import name` (without `as`) in each file
+    imported_names: HashMap<PathBuf, HashMap<String, ImportAlias>>,
+    /// Routes declared on each router variable (`router` of `@router.get`) by defining file,
+    /// with their path relative to that router
+    router_routes: HashMap<RouterKey, Vec<(NodeId, String)>>,
+    /// Routes already mounted by an `include_router` of the router that lists them
+    mounted_routes: HashSet<(RouterKey, NodeId)>,
     /// Project root
     project_root: Option<PathBuf>,
+    /// Memoized `canonicalize` results
+    canonical_paths: PathCache,
     /// Maximum recursion depth (None = unlimited)
     max_depth: Option<usize>,
     /// Current recursion depth
     current_depth: usize,
+    /// Follow imports of test modules (`test_*.py`, `conftest.py`)
+    include_tests: bool,
 }
 
 impl CallGraphBuilder {
@@ -53,9 +97,20 @@ impl CallGraphBuilder {
             parser,
             module_nodes: HashMap::new(),
             function_nodes: HashMap::new(),
+            external_nodes: HashMap::new(),
+            class_bases: HashMap::new(),
+            return_values: HashMap::new(),
+            models: HashMap::new(),
+            import_aliases: HashMap::new(),
+            module_exports: HashMap::new(),
+            imported_names: HashMap::new(),
+            router_routes: HashMap::new(),
+            mounted_routes: HashSet::new(),
             project_root: None,
+            canonical_paths: PathCache::new(),
             max_depth: None,
             current_depth: 0,
+            include_tests: false,
         }
     }
 
@@ -65,6 +120,18 @@ impl CallGraphBuilder {
         self
     }
 
+    /// Follows imports of test modules, which are skipped by default
+    pub fn with_include_tests(mut self, include_tests: bool) -> Self {
+        self.include_tests = include_tests;
+        self
+    }
+
+    /// Sets the root for absolute imports (by default the entry point's directory)
+    pub fn with_project_root(mut self, project_root: PathBuf) -> Self {
+        self.project_root = Some(self.normalize_path(&project_root));
+        self
+    }
+
     /// Finds the entry point (main.py, app.py) in the project
     pub fn find_entry_point(&self, project_root: &Path) -> Result<PathBuf> {
         let candidates = ["main.py", "app.py", "__main__.py"];
@@ -81,7 +148,7 @@ impl CallGraphBuilder {
 
     /// Builds the graph from an entry point
     pub fn build_from_entry(&mut self, entry: &Path) -> Result<()> {
-        let normalized_entry = Self::normalize_path(entry);
+        let normalized_entry = self.normalize_path(entry);
 
         if self.processed_files.contains(&normalized_entry) {
             return Ok(()); // Already processed
@@ -120,8 +187,12 @@ impl CallGraphBuilder {
 
         self.processed_files.insert(normalized_entry.clone());
         self.entry_points.push(normalized_entry.clone());
+        // Known before imports are followed, so circular star imports see them
+        self.module_exports
+            .insert(normalized_entry.clone(), self.parser.module_exports(&ast));
 
         self.process_imports(&ast, module_node, &normalized_entry, &converter)?;
+        self.collect_models(&ast, &normalized_entry, &converter);
         self.extract_functions_and_classes(&ast, &normalized_entry, &converter)?;
         self.process_calls(&ast, module_node, &normalized_entry, &converter)?;
         self.process_decorators(&ast, &normalized_entry, &converter)?;
@@ -137,6 +208,18 @@ impl CallGraphBuilder {
         import: &Import,
         current_file: &Path,
     ) -> Result<NodeId> {
+        if let Some(alias) = &import.alias {
+            self.record_import_alias(alias, import, current_file);
+        } else if let [name] = import.names.as_slice() {
+            if name != "*" {
+                let binding = self.import_binding(import, current_file);
+                self.imported_names
+                    .entry(self.normalize_path(current_file))
+                    .or_default()
+                    .insert(name.clone(), binding);
+            }
+        }
+
         let import_path = match self.resolve_import_path(&import.path, current_file) {
             Ok(path) => path,
             Err(err) => {
@@ -147,6 +230,9 @@ impl CallGraphBuilder {
                 return Ok(from);
             }
         };
+        if !self.include_tests && is_test_file(&import_path) {
+            return Ok(from);
+        }
         let module_node = self.get_or_create_module_node(&import_path)?;
 
         self.graph.add_edge(
@@ -163,6 +249,10 @@ impl CallGraphBuilder {
         // Recursively build graph for the imported module
         let _ = self.build_from_entry(&import_path);
 
+        if import.names.iter().any(|name| name == "*") {
+            self.record_star_import(import, &import_path, current_file);
+        }
+
         Ok(module_node)
     }
 
@@ -173,10 +263,16 @@ impl CallGraphBuilder {
         call: &Call,
         current_file: &Path,
     ) -> Result<NodeId> {
-        let Some(callee_node) = self.find_function_node(&call.name, current_file) else {
-            // Function not found, return caller without creating edge
-            return Ok(caller);
-        };
+        // `np.array` of `import numpy as np` is a call of `numpy.array`
+        let (call_name, aliased_definition) =
+            match self.resolve_aliased_call(&call.name, current_file) {
+                Some((expanded, definition)) => (expanded, definition),
+                None => (call.name.clone(), None),
+            };
+        // No definition in the sources: third-party or builtin call
+        let callee_node = aliased_definition
+            .or_else(|| self.find_function_node(&call.name, current_file))
+            .unwrap_or_else(|| self.get_or_create_external_node(&call_name));
 
         let argument_mapping = call
             .arguments
@@ -201,9 +297,14 @@ impl CallGraphBuilder {
                 location: call.location.clone(),
             },
         );
+        self.add_return_edge(callee_node, caller);
+
+        if call.name.rsplit('.').next() == Some("include_router") {
+            self.include_router(call, current_file);
+        }
 
         if let Some(file) = self.node_file_path(callee_node) {
-            let normalized = Self::normalize_path(&file);
+            let normalized = self.normalize_path(&file);
             if !self.processed_files.contains(&normalized) {
                 let _ = self.build_from_entry(&normalized);
             }
@@ -227,12 +328,11 @@ impl CallGraphBuilder {
             return Ok(());
         };
 
-        let http_method = self
-            .extract_http_method(&decorator.name)
-            .unwrap_or(HttpMethod::Get);
+        // `@app.get("/users")` or `@app.get(path="/users")`
         let route_path = decorator
             .arguments
             .first()
+            .or_else(|| decorator.keyword_arguments.get("path"))
             .cloned()
             .unwrap_or_else(|| "/".to_string());
 
@@ -241,32 +341,551 @@ impl CallGraphBuilder {
             location.file = current_file.to_string_lossy().to_string();
         }
 
-        let route_node = NodeId::from(self.graph.add_node(CallNode::Route {
-            path: route_path,
-            method: http_method,
-            handler: handler_node,
-            location: location.clone(),
+        // `response_model=UserOut` (or `schemas.UserOut`, `List[UserOut]`) names a model of the analyzed files
+        let response_model = decorator
+            .keyword_arguments
+            .get("response_model")
+            .and_then(|model| self.response_model_type(model));
+        // Without `operation_id=` FastAPI generates the id from the final path,
+        // which is known only after `include_router` (see `fastapi_operation_id`)
+        let operation_id = decorator.keyword_arguments.get("operation_id").cloned();
+        // `status_code=201` or `status_code=status.HTTP_201_CREATED`
+        let status_code = decorator
+            .keyword_arguments
+            .get("status_code")
+            .and_then(|code| parse_status_code(code));
+        // `responses={404: {"model": NotFound}}` declares the error responses
+        let mut responses: Vec<(u16, TypeInfo)> = decorator
+            .keyword_arguments
+            .get("responses")
+            .map(|value| parse_responses(value))
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(code, model)| Some((code, self.response_model_type(model)?)))
+            .collect();
+        // FastAPI answers 422 when request parameters fail validation (Flask does not validate)
+        if !decorator.name.ends_with(".route")
+            && !responses.iter().any(|(code, _)| *code == 422)
+            && self.has_request_parameters(handler_node)
+        {
+            responses.push((422, validation_error_type(&location)));
+        }
+
+        // Flask `@app.route(path, methods=["GET", "POST"])` serves every listed method
+        for http_method in self.extract_http_methods(decorator) {
+            let route_node = NodeId::from(self.graph.add_node(CallNode::Route {
+                path: route_path.clone(),
+                method: http_method,
+                handler: handler_node,
+                location: location.clone(),
+                response_model: response_model.clone(),
+                operation_id: operation_id.clone(),
+                status_code,
+                responses: responses.clone(),
+            }));
+
+            self.graph.add_edge(
+                route_node.0,
+                handler_node.0,
+                CallEdge::Call {
+                    caller: route_node,
+                    callee: handler_node,
+                    argument_mapping: Vec::new(),
+                    location: location.clone(),
+                },
+            );
+            // `outgoing_nodes` lists the handler first, then the `Depends()` dependencies
+            for dependency in self.find_dependency_nodes(handler_node, current_file) {
+                self.graph.add_edge(
+                    route_node.0,
+                    dependency.0,
+                    CallEdge::Call {
+                        caller: route_node,
+                        callee: dependency,
+                        argument_mapping: Vec::new(),
+                        location: location.clone(),
+                    },
+                );
+            }
+
+            // The route responds with what the handler returns
+            self.add_return_edge(handler_node, route_node);
+
+            if let Some((router, _)) = decorator.name.rsplit_once('.') {
+                self.router_routes
+                    .entry((self.normalize_path(current_file), router.to_string()))
+                    .or_default()
+                    .push((route_node, route_path.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether a handler takes parameters FastAPI validates (everything but `Depends()`)
+    fn has_request_parameters(&self, handler: NodeId) -> bool {
+        let Some(CallNode::Function { parameters, .. }) = self.graph.node_weight(*handler) else {
+            return false;
+        };
+        parameters.iter().any(|parameter| {
+            !parameter
+                .default_value
+                .as_deref()
+                .is_some_and(|value| value.starts_with("Depends("))
+        })
+    }
+
+    /// Response type of a `response_model=` value naming a model of the analyzed files
+    ///
+    /// Containers keep their shape: `List[UserOut]` is an `Array` of `UserOut`,
+    /// `Dict[str, UserOut]` an `Object` of them; `Optional[...]` marks it optional.
+    fn response_model_type(&self, model: &str) -> Option<TypeInfo> {
+        let schema = self.models.get(Self::response_model_name(model))?.clone();
+        let mut optional = false;
+        let mut model = model.trim();
+        let base_type = loop {
+            let Some((outer, inner)) = model.split_once('[') else {
+                break BaseType::Object;
+            };
+            match outer.rsplit('.').next().unwrap_or(outer) {
+                "Optional" => {
+                    optional = true;
+                    model = inner.strip_suffix(']').unwrap_or(inner).trim();
+                }
+                "List" | "list" | "Sequence" | "Set" | "set" | "FrozenSet" | "frozenset"
+                | "Tuple" | "tuple" => break BaseType::Array,
+                _ => break BaseType::Object,
+            }
+        };
+        Some(TypeInfo {
+            base_type,
+            schema_ref: Some(schema),
+            constraints: Vec::new(),
+            optional,
+        })
+    }
+
+    /// Model class name of a `response_model=` value
+    ///
+    /// Containers (`List[UserOut]`, `Optional[UserOut]`, `list[schemas.UserOut]`)
+    /// are unwrapped to the item model; a module prefix is dropped.
+    fn response_model_name(model: &str) -> &str {
+        let mut model = model.trim();
+        while let Some((_, inner)) = model.split_once('[') {
+            let inner = inner.strip_suffix(']').unwrap_or(inner);
+            // `Dict[str, UserOut]`: the value type is the model
+            model = inner.rsplit(',').next().unwrap_or(inner).trim();
+        }
+        model.rsplit('.').next().unwrap_or(model)
+    }
+
+    /// Applies `app.include_router(router, prefix="/api")` to the routes of `router`
+    ///
+    /// The prefix is prepended to the router-local route paths. The first include
+    /// of a router mounts its route nodes; every further include (the same router
+    /// under `/v1` and `/v2`) gets its own copies. The routes also become routes of
+    /// the including router, so prefixes of nested routers add up.
+    fn include_router(&mut self, call: &Call, current_file: &Path) {
+        let Some(router) = call.arguments.iter().find(|arg| {
+            arg.parameter_name.is_none() || arg.parameter_name.as_deref() == Some("router")
+        }) else {
+            return;
+        };
+        let prefix = call
+            .arguments
+            .iter()
+            .find(|arg| arg.parameter_name.as_deref() == Some("prefix"))
+            .map(|arg| arg.value.clone())
+            .unwrap_or_default();
+
+        let Some((router_key, routes)) = self.find_router_routes(&router.value, current_file)
+        else {
+            return;
+        };
+        let mut included = Vec::new();
+        for (route, local_path) in routes {
+            let path = format!("{}{}", prefix, local_path);
+            let route = if self.mounted_routes.insert((router_key.clone(), route)) {
+                if let Some(CallNode::Route { path: mounted, .. }) =
+                    self.graph.node_weight_mut(*route)
+                {
+                    *mounted = path.clone();
+                }
+                route
+            } else {
+                self.copy_route(route, path.clone())
+            };
+            included.push((route, path));
+        }
+
+        if let Some((parent, _)) = call.name.rsplit_once('.') {
+            self.router_routes
+                .entry((self.normalize_path(current_file), parent.to_string()))
+                .or_default()
+                .extend(included);
+        }
+    }
+
+    /// Copy of a route node under another path, with the same dependency and handler edges
+    fn copy_route(&mut self, route: NodeId, path: String) -> NodeId {
+        let Some(CallNode::Route {
+            method,
+            handler,
+            location,
+            response_model,
+            operation_id,
+            status_code,
+            responses,
+            ..
+        }) = self.graph.node_weight(*route).cloned()
+        else {
+            return route;
+        };
+        let copy = NodeId::from(self.graph.add_node(CallNode::Route {
+            path,
+            method,
+            handler,
+            location,
+            response_model,
+            operation_id,
+            status_code,
+            responses,
         }));
 
-        self.graph.add_edge(
-            route_node.0,
-            handler_node.0,
-            CallEdge::Call {
-                caller: route_node,
-                callee: handler_node,
-                argument_mapping: Vec::new(),
-                location,
+        // petgraph lists the latest edge first: re-adding in reverse keeps the order
+        let mut edges: Vec<(NodeId, CallEdge)> = self
+            .graph
+            .edges(*route)
+            .map(|edge| (NodeId::from(edge.target()), edge.weight().clone()))
+            .collect();
+        edges.reverse();
+        for (target, edge) in edges {
+            let edge = match edge {
+                CallEdge::Call {
+                    callee,
+                    argument_mapping,
+                    location,
+                    ..
+                } => CallEdge::Call {
+                    caller: copy,
+                    callee,
+                    argument_mapping,
+                    location,
+                },
+                other => other,
+            };
+            self.graph.add_edge(copy.0, target.0, edge);
+        }
+        self.add_return_edge(handler, copy);
+        copy
+    }
+
+    /// Routes of a router expression (`router`, `users_router`, `users.router`)
+    ///
+    /// Imported names are followed to the module that defines the router,
+    /// including re-exports through a package `__init__.py`.
+    fn find_router_routes(
+        &self,
+        router: &str,
+        current_file: &Path,
+    ) -> Option<(RouterKey, Vec<(NodeId, String)>)> {
+        let binding = |file: &Path, name: &str| {
+            let file = self.normalize_path(file);
+            self.import_aliases
+                .get(&file)
+                .and_then(|names| names.get(name))
+                .or_else(|| self.imported_names.get(&file)?.get(name))
+        };
+
+        let (mut file, mut name) = match router.rsplit_once('.') {
+            // `users.router`: attribute of an imported module
+            Some((module, name)) => match binding(current_file, module) {
+                Some(ImportAlias {
+                    module: Some(module),
+                    name: None,
+                    ..
+                }) => (module.clone(), name.to_string()),
+                _ => return None,
             },
-        );
+            None => (current_file.to_path_buf(), router.to_string()),
+        };
 
-        Ok(())
+        // Bounded, as import cycles would otherwise loop forever
+        for _ in 0..8 {
+            let key = (self.normalize_path(&file), name.clone());
+            if let Some(routes) = self.router_routes.get(&key) {
+                return Some((key, routes.clone()));
+            }
+            match binding(&file, &name) {
+                Some(ImportAlias {
+                    module: Some(module),
+                    name: Some(imported),
+                    ..
+                }) => {
+                    file = module.clone();
+                    name = imported.clone();
+                }
+                _ => break,
+            }
+        }
+        None
+    }
+
+    /// Remembers what an `as` alias of `current_file` stands for
+    fn record_import_alias(&mut self, alias: &str, import: &Import, current_file: &Path) {
+        let import_alias = self.import_binding(import, current_file);
+        self.import_aliases
+            .entry(self.normalize_path(current_file))
+            .or_default()
+            .insert(alias.to_string(), import_alias);
+    }
+
+    /// What the name bound by an import stands for
+    ///
+    /// `from pkg import mod` may name a submodule: it is analyzed like an
+    /// imported module, otherwise the name is a definition of `pkg`.
+    fn import_binding(&mut self, import: &Import, current_file: &Path) -> ImportAlias {
+        match import.names.first() {
+            None => ImportAlias {
+                target: import.path.clone(),
+                module: self.resolve_import_path(&import.path, current_file).ok(),
+                name: None,
+            },
+            Some(name) => {
+                let target = format!("{}.{}", import.path, name);
+                match self.resolve_import_path(&target, current_file) {
+                    Ok(submodule) => {
+                        let _ = self.build_from_entry(&submodule);
+                        ImportAlias {
+                            target,
+                            module: Some(submodule),
+                            name: None,
+                        }
+                    }
+                    Err(_) => ImportAlias {
+                        target,
+                        module: self.resolve_import_path(&import.path, current_file).ok(),
+                        name: Some(name.clone()),
+                    },
+                }
+            }
+        }
+    }
+
+    /// Makes the exports of `module` (`from module import *`) importable names of `current_file`
+    ///
+    /// They are recorded like `from module import name` aliases, so calls
+    /// resolve to the definitions of `module` rather than same-named ones
+    /// elsewhere in the project.
+    fn record_star_import(&mut self, import: &Import, module: &Path, current_file: &Path) {
+        let Some(exports) = self.module_exports.get(&self.normalize_path(module)) else {
+            return;
+        };
+        let star_aliases: Vec<(String, ImportAlias)> = exports
+            .iter()
+            .map(|name| {
+                (
+                    name.clone(),
+                    ImportAlias {
+                        target: format!("{}.{}", import.path, name),
+                        module: Some(module.to_path_buf()),
+                        name: Some(name.clone()),
+                    },
+                )
+            })
+            .collect();
+
+        self.import_aliases
+            .entry(self.normalize_path(current_file))
+            .or_default()
+            .extend(star_aliases);
+    }
+
+    /// Expands an import alias at the start of a call name
+    ///
+    /// Returns the call name with the alias replaced by its target
+    /// (`np.array` → `numpy.array`) and the definition it names when the
+    /// target module is analyzed. `None` if the call does not use an alias.
+    fn resolve_aliased_call(
+        &self,
+        name: &str,
+        current_file: &Path,
+    ) -> Option<(String, Option<NodeId>)> {
+        let aliases = self
+            .import_aliases
+            .get(&self.normalize_path(current_file))?;
+        let (head, rest) = match name.split_once('.') {
+            Some((head, rest)) => (head, Some(rest)),
+            None => (name, None),
+        };
+        let alias = aliases.get(head)?;
+
+        let expanded = match rest {
+            Some(rest) => format!("{}.{}", alias.target, rest),
+            None => alias.target.clone(),
+        };
+        let definition_name = [alias.name.as_deref(), rest]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(".");
+        let definition = alias
+            .module
+            .as_deref()
+            .filter(|_| !definition_name.is_empty())
+            .and_then(|module| self.find_function_in_file(&definition_name, module));
+
+        Some((expanded, definition))
+    }
+
+    /// Finds a definition by name in one file (the latest definition wins)
+    fn find_function_in_file(&self, name: &str, file: &Path) -> Option<NodeId> {
+        let file_prefix = format!("{}::", self.normalize_path(file).to_string_lossy());
+        self.function_nodes
+            .iter()
+            .filter(|(key, _)| key.starts_with(&file_prefix))
+            .filter_map(|(key, node)| {
+                let (key_name, line) = Self::split_function_key(key)?;
+                (key_name == name).then_some((line, *node))
+            })
+            .max_by_key(|(line, _)| *line)
+            .map(|(_, node)| node)
+    }
+
+    /// Registers the Pydantic models of a module (the first definition of a name wins)
+    ///
+    /// A model met again at the same location keeps its most complete reference
+    /// (see [`dedupe_schema_refs`]).
+    fn collect_models(&mut self, ast: &ast::Mod, file_path: &Path, converter: &LocationConverter) {
+        let file_path = file_path.to_string_lossy();
+        for model in self
+            .parser
+            .extract_pydantic_models(ast, &file_path, converter)
+        {
+            match self.models.entry(model.name.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(model);
+                }
+                Entry::Occupied(mut entry) => {
+                    if let [richest] = dedupe_schema_refs([entry.get().clone(), model]).as_slice() {
+                        *entry.get_mut() = richest.clone();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Remembers the returned expression of a function/method body
+    fn record_return_value(&mut self, node: NodeId, body: &[ast::Stmt]) {
+        if let Some(value) = self.parser.return_value(body) {
+            self.return_values.insert(node, value);
+        }
+    }
+
+    /// Adds a `Return` edge when `callee` returns a value to `caller`
+    ///
+    /// One edge per pair: repeated calls of the same function do not duplicate it.
+    fn add_return_edge(&mut self, callee: NodeId, caller: NodeId) {
+        let Some(return_value) = self.return_values.get(&callee).cloned() else {
+            return;
+        };
+        let exists = self
+            .graph
+            .edges_connecting(*callee, *caller)
+            .any(|edge| matches!(edge.weight(), CallEdge::Return { .. }));
+        if !exists {
+            self.graph.add_edge(
+                *callee,
+                *caller,
+                CallEdge::Return {
+                    from: callee,
+                    to: caller,
+                    return_value,
+                },
+            );
+        }
     }
 
     /// Gets the built graph
-    pub fn into_graph(self) -> CallGraph {
+    ///
+    /// Model schemas of the graph get their fields referencing other models of
+    /// the analyzed files resolved (see [`Self::resolve_model_references`]).
+    pub fn into_graph(mut self) -> CallGraph {
+        self.resolve_model_references();
         self.graph
     }
 
+    /// Resolves fields referencing other models (`address: Address`, `items: List[Item]`)
+    ///
+    /// Schemas of parameters, return types, `response_model=` and `responses=` are parsed with
+    /// [`SchemaParser::parse_with_models`]; when a field resolves to a nested model,
+    /// the resolved schema is stored in `json_schema` metadata, so contract checks
+    /// see the nested fields and self-references stay marked `x-recursive`.
+    fn resolve_model_references(&mut self) {
+        if self.models.is_empty() {
+            return;
+        }
+        let models: Vec<SchemaReference> = self.models.values().cloned().collect();
+        let resolve = |type_info: &mut TypeInfo| {
+            let Some(schema) = type_info.schema_ref.as_mut() else {
+                return;
+            };
+            if schema.schema_type != SchemaType::Pydantic
+                || schema.metadata.contains_key("json_schema")
+            {
+                return;
+            }
+            let Ok(resolved) = SchemaParser::parse_with_models(schema, &models) else {
+                return;
+            };
+            let has_references = resolved
+                .properties
+                .values()
+                .any(|field| field.nested_schema.is_some() || field.recursive);
+            if has_references {
+                schema.metadata.insert(
+                    "json_schema".to_string(),
+                    resolved.to_json_value().to_string(),
+                );
+            }
+        };
+
+        for node in self.graph.node_weights_mut() {
+            match node {
+                CallNode::Function {
+                    parameters,
+                    return_type,
+                    ..
+                }
+                | CallNode::Method {
+                    parameters,
+                    return_type,
+                    ..
+                } => {
+                    for parameter in parameters {
+                        resolve(&mut parameter.type_info);
+                    }
+                    if let Some(return_type) = return_type {
+                        resolve(return_type);
+                    }
+                }
+                CallNode::Route {
+                    response_model,
+                    responses,
+                    ..
+                } => {
+                    if let Some(response_model) = response_model {
+                        resolve(response_model);
+                    }
+                    for (_, response) in responses {
+                        resolve(response);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
     /// Gets a reference to the graph
     pub fn graph(&self) -> &CallGraph {
         &self.graph
@@ -403,11 +1022,13 @@ impl CallGraphBuilder {
             file: file_path.to_path_buf(),
             line,
             parameters,
-            return_type: None,
+            return_type: self.return_type(func_def.returns.as_deref()),
+            decorators: self.non_route_decorators(&func_def.decorator_list),
         }));
 
-        let key = Self::function_key(file_path, &func_def.name);
+        let key = self.function_key(file_path, &func_def.name, line);
         self.function_nodes.insert(key, node_id);
+        self.record_return_value(node_id, &func_def.body);
 
         Ok(node_id)
     }
@@ -429,11 +1050,13 @@ impl CallGraphBuilder {
             file: file_path.to_path_buf(),
             line,
             parameters,
-            return_type: None,
+            return_type: self.return_type(func_def.returns.as_deref()),
+            decorators: self.non_route_decorators(&func_def.decorator_list),
         }));
 
-        let key = Self::function_key(file_path, &func_def.name);
+        let key = self.function_key(file_path, &func_def.name, line);
         self.function_nodes.insert(key, node_id);
+        self.record_return_value(node_id, &func_def.body);
 
         Ok(node_id)
     }
@@ -444,9 +1067,10 @@ impl CallGraphBuilder {
         class_node: NodeId,
         func_def: &ast::StmtFunctionDef,
         file_path: &Path,
-        _converter: &LocationConverter,
+        converter: &LocationConverter,
     ) -> Result<NodeId> {
         let mut parameters = self.convert_parameters(&func_def.args);
+        let (line, _column) = converter.byte_offset_to_location(func_def.range().start().into());
         // Check decorators before removing the first parameter
         let has_staticmethod = self.has_decorator(&func_def.decorator_list, "staticmethod");
         if !has_staticmethod && !parameters.is_empty() {
@@ -459,11 +1083,16 @@ impl CallGraphBuilder {
             name: func_def.name.to_string(),
             class: class_node,
             parameters,
-            return_type: None,
+            return_type: self.return_type(func_def.returns.as_deref()),
         }));
 
-        let key = Self::function_key(file_path, &format!("{}.{}", class_name, func_def.name));
+        let key = self.function_key(
+            file_path,
+            &format!("{}.{}", class_name, func_def.name),
+            line,
+        );
         self.function_nodes.insert(key, node_id);
+        self.record_return_value(node_id, &func_def.body);
 
         Ok(node_id)
     }
@@ -474,9 +1103,10 @@ impl CallGraphBuilder {
         class_node: NodeId,
         func_def: &ast::StmtAsyncFunctionDef,
         file_path: &Path,
-        _converter: &LocationConverter,
+        converter: &LocationConverter,
     ) -> Result<NodeId> {
         let mut parameters = self.convert_parameters(&func_def.args);
+        let (line, _column) = converter.byte_offset_to_location(func_def.range().start().into());
         // Проверяем декораторы перед удалением первого параметра
         let has_staticmethod = self.has_decorator(&func_def.decorator_list, "staticmethod");
         if !has_staticmethod && !parameters.is_empty() {
@@ -488,11 +1118,16 @@ impl CallGraphBuilder {
             name: func_def.name.to_string(),
             class: class_node,
             parameters,
-            return_type: None,
+            return_type: self.return_type(func_def.returns.as_deref()),
         }));
 
-        let key = Self::function_key(file_path, &format!("{}.{}", class_name, func_def.name));
+        let key = self.function_key(
+            file_path,
+            &format!("{}.{}", class_name, func_def.name),
+            line,
+        );
         self.function_nodes.insert(key, node_id);
+        self.record_return_value(node_id, &func_def.body);
 
         Ok(node_id)
     }
@@ -501,17 +1136,31 @@ impl CallGraphBuilder {
         &mut self,
         class_def: &ast::StmtClassDef,
         file_path: &Path,
-        _converter: &LocationConverter,
+        converter: &LocationConverter,
     ) -> Result<NodeId> {
+        let (line, _column) = converter.byte_offset_to_location(class_def.range().start().into());
         let node_id = NodeId::from(self.graph.add_node(CallNode::Class {
             name: class_def.name.to_string(),
             file: file_path.to_path_buf(),
             methods: Vec::new(),
         }));
 
-        let key = Self::function_key(file_path, &class_def.name);
+        let key = self.function_key(file_path, &class_def.name, line);
         self.function_nodes.insert(key, node_id);
 
+        // `module.Base` is tracked by its last segment, as class keys are
+        let bases = class_def
+            .bases
+            .iter()
+            .filter_map(|base| match base {
+                ast::Expr::Name(name) => Some(name.id.to_string()),
+                ast::Expr::Attribute(attr) => Some(attr.attr.to_string()),
+                _ => None,
+            })
+            .collect();
+        self.class_bases
+            .insert(self.class_key(file_path, &class_def.name), bases);
+
         Ok(node_id)
     }
 
@@ -599,73 +1248,299 @@ impl CallGraphBuilder {
 
     /// Creates a parameter from ArgWithDefault (with default)
     fn create_parameter_from_arg_with_default(&self, arg: &ast::ArgWithDefault) -> Parameter {
-        let optional = arg.default.is_some();
-        let default_value = arg.default.as_deref().map(|expr| {
-            // Extract text representation of the default expression
-            match expr {
-                ast::Expr::Constant(constant) => match &constant.value {
-                    ast::Constant::Str(s) => format!("\"{}\"", s),
-                    ast::Constant::Int(i) => i.to_string(),
-                    ast::Constant::Float(f) => f.to_string(),
-                    ast::Constant::Bool(b) => b.to_string(),
-                    ast::Constant::None => "None".to_string(),
-                    _ => format!("{:?}", constant.value),
-                },
-                _ => format!("{:?}", expr),
-            }
-        });
+        self.build_parameter(&arg.def, arg.default.as_deref())
+    }
 
-        Parameter {
-            name: arg.def.arg.to_string(),
-            type_info: TypeInfo {
+    /// Creates a parameter from Arg (without default)
+    fn create_parameter_from_arg(&self, arg: &ast::Arg, default: Option<&ast::Expr>) -> Parameter {
+        self.build_parameter(arg, default)
+    }
+
+    fn build_parameter(&self, arg: &ast::Arg, default: Option<&ast::Expr>) -> Parameter {
+        // `db: Annotated[Session, Depends(get_db)]` carries the marker in the annotation
+        let (annotation, marker) = match arg.annotation.as_deref() {
+            Some(annotation) => {
+                let (annotation, marker) = Self::split_annotated(annotation);
+                (Some(annotation), marker)
+            }
+            None => (None, None),
+        };
+        let default = default.or(marker);
+        // FastAPI markers without a real default (`Query(...)`, `Path()`) keep the parameter required
+        let optional = match default {
+            Some(expr) => !Self::is_required_marker(expr),
+            None => false,
+        };
+        let default_value = default.map(Self::default_value_to_string);
+        let type_info = match annotation {
+            // Whether a parameter may be omitted depends on its default, not on `Optional`
+            Some(annotation) => TypeInfo {
+                optional,
+                ..self.annotation_type_info(annotation)
+            },
+            None => TypeInfo {
                 base_type: BaseType::Unknown,
                 schema_ref: None,
                 constraints: Vec::new(),
                 optional,
             },
+        };
+
+        Parameter {
+            name: arg.arg.to_string(),
+            type_info,
             optional,
             default_value,
         }
     }
 
-    /// Creates a parameter from Arg (without default)
-    /// Takes &Box<Arg>
-    fn create_parameter_from_arg(
-        &self,
-        arg: &Box<ast::Arg>,
-        default: Option<&ast::Expr>,
-    ) -> Parameter {
-        let optional = default.is_some();
-        let default_value = default.map(|expr| {
-            // Extract text representation of the default expression
-            match expr {
-                ast::Expr::Constant(constant) => match &constant.value {
-                    ast::Constant::Str(s) => format!("\"{}\"", s),
-                    ast::Constant::Int(i) => i.to_string(),
-                    ast::Constant::Float(f) => f.to_string(),
-                    ast::Constant::Bool(b) => b.to_string(),
-                    ast::Constant::None => "None".to_string(),
-                    _ => format!("{:?}", constant.value),
-                },
-                _ => format!("{:?}", expr),
+    /// Return type of a function from its `-> ...` annotation
+    ///
+    /// `-> None` declares that nothing is returned and gives no type.
+    fn return_type(&self, returns: Option<&ast::Expr>) -> Option<TypeInfo> {
+        match returns? {
+            ast::Expr::Constant(constant) if matches!(constant.value, ast::Constant::None) => None,
+            annotation => Some(self.annotation_type_info(annotation)),
+        }
+    }
+
+    /// Converts a type annotation into `TypeInfo`
+    ///
+    /// `Optional[X]` and `X | None` are unwrapped to `X` and marked optional.
+    fn annotation_type_info(&self, annotation: &ast::Expr) -> TypeInfo {
+        // `conint(ge=0)`, `constr(max_length=10)`: ограничения заданы в самой аннотации
+        let constrained = constrained_type(annotation);
+        let base_type = match &constrained {
+            Some(constrained) => constrained.base_type,
+            None => Self::annotation_base_type(annotation),
+        };
+
+        // Uploaded files are multipart form parts, not JSON models
+        let schema_ref = if Self::is_upload_annotation(annotation) {
+            Some(Self::upload_file_schema())
+        } else {
+            self.annotation_model(annotation)
+        };
+
+        TypeInfo {
+            base_type,
+            schema_ref,
+            constraints: constrained
+                .map(|constrained| constrained.constraints)
+                .unwrap_or_default(),
+            optional: Self::is_optional_annotation(annotation),
+        }
+    }
+
+    /// Whether an annotation admits `None` (`Optional[X]`, `X | None`)
+    fn is_optional_annotation(annotation: &ast::Expr) -> bool {
+        match annotation {
+            ast::Expr::Subscript(sub) => {
+                matches!(sub.value.as_ref(), ast::Expr::Name(name) if name.id.as_str() == "Optional")
             }
-        });
+            ast::Expr::BinOp(bin_op) if matches!(bin_op.op, ast::Operator::BitOr) => {
+                [bin_op.left.as_ref(), bin_op.right.as_ref()]
+                    .into_iter()
+                    .any(|side| {
+                        matches!(side, ast::Expr::Constant(constant) if matches!(constant.value, ast::Constant::None))
+                    })
+            }
+            _ => false,
+        }
+    }
 
-        Parameter {
-            name: arg.arg.to_string(),
-            type_info: TypeInfo {
-                base_type: BaseType::Unknown,
-                schema_ref: None,
-                constraints: Vec::new(),
-                optional,
+    /// Extracts text representation of a default expression
+    ///
+    /// FastAPI parameter markers are kept in a compact form, e.g. `Query(...)`,
+    /// `Header(None)`, `Depends(get_db)`.
+    fn default_value_to_string(expr: &ast::Expr) -> String {
+        match expr {
+            ast::Expr::Constant(constant) => match &constant.value {
+                ast::Constant::Str(s) => format!("\"{}\"", s),
+                ast::Constant::Int(i) => i.to_string(),
+                ast::Constant::Float(f) => f.to_string(),
+                ast::Constant::Bool(b) => b.to_string(),
+                ast::Constant::None => "None".to_string(),
+                ast::Constant::Ellipsis => "...".to_string(),
+                _ => format!("{:?}", constant.value),
             },
-            optional,
-            default_value,
+            ast::Expr::Name(name) => name.id.to_string(),
+            ast::Expr::Attribute(attr) => {
+                format!(
+                    "{}.{}",
+                    Self::default_value_to_string(&attr.value),
+                    attr.attr
+                )
+            }
+            ast::Expr::Call(call) => match Self::fastapi_marker(call) {
+                Some(marker) => {
+                    let default = Self::marker_default(call)
+                        .map(Self::default_value_to_string)
+                        .unwrap_or_default();
+                    format!("{}({})", marker, default)
+                }
+                None => format!("{:?}", expr),
+            },
+            _ => format!("{:?}", expr),
+        }
+    }
+
+    /// Returns the FastAPI parameter marker name (`Query`, `Path`, `Header`, ...) of a call
+    fn fastapi_marker(call: &ast::ExprCall) -> Option<&str> {
+        let name = match call.func.as_ref() {
+            ast::Expr::Name(name) => name.id.as_str(),
+            ast::Expr::Attribute(attr) => attr.attr.as_str(),
+            _ => return None,
+        };
+        match name {
+            "Query" | "Path" | "Header" | "Cookie" | "Body" | "Form" | "File" | "Depends" => {
+                Some(name)
+            }
+            _ => None,
+        }
+    }
+
+    /// Default of a FastAPI marker: first positional argument, or `dependency=` for
+    /// `Depends` and `default=` for the other markers
+    fn marker_default(call: &ast::ExprCall) -> Option<&ast::Expr> {
+        let keyword = match Self::fastapi_marker(call) {
+            Some("Depends") => "dependency",
+            _ => "default",
+        };
+        call.args.first().or_else(|| {
+            call.keywords
+                .iter()
+                .find(|kw| kw.arg.as_ref().is_some_and(|arg| arg.as_str() == keyword))
+                .map(|kw| &kw.value)
+        })
+    }
+
+    /// Splits `Annotated[T, Depends(x)]` into `T` and its FastAPI marker (if any)
+    ///
+    /// Other annotations are returned as is, without a marker.
+    fn split_annotated(annotation: &ast::Expr) -> (&ast::Expr, Option<&ast::Expr>) {
+        let ast::Expr::Subscript(sub) = annotation else {
+            return (annotation, None);
+        };
+        let is_annotated = match sub.value.as_ref() {
+            ast::Expr::Name(name) => name.id.as_str() == "Annotated",
+            ast::Expr::Attribute(attr) => attr.attr.as_str() == "Annotated",
+            _ => false,
+        };
+        match sub.slice.as_ref() {
+            ast::Expr::Tuple(tuple) if is_annotated && !tuple.elts.is_empty() => {
+                let marker = tuple.elts[1..].iter().find(|metadata| {
+                    matches!(metadata, ast::Expr::Call(call) if Self::fastapi_marker(call).is_some())
+                });
+                (&tuple.elts[0], marker)
+            }
+            _ => (annotation, None),
+        }
+    }
+
+    /// Whether a default is a FastAPI marker that does not provide a value (`Query(...)`, `Path()`)
+    fn is_required_marker(expr: &ast::Expr) -> bool {
+        let ast::Expr::Call(call) = expr else {
+            return false;
+        };
+        match Self::fastapi_marker(call) {
+            Some("Path") => true,
+            Some("Depends") | None => false,
+            Some(_) => match Self::marker_default(call) {
+                None => true,
+                Some(ast::Expr::Constant(constant)) => {
+                    matches!(constant.value, ast::Constant::Ellipsis)
+                }
+                Some(_) => false,
+            },
+        }
+    }
+
+    /// Whether an annotation is `UploadFile` (possibly `fastapi.UploadFile` or `List[UploadFile]`)
+    fn is_upload_annotation(annotation: &ast::Expr) -> bool {
+        match annotation {
+            ast::Expr::Name(name) => name.id.as_str() == "UploadFile",
+            ast::Expr::Attribute(attr) => attr.attr.as_str() == "UploadFile",
+            ast::Expr::Subscript(sub) => Self::is_upload_annotation(&sub.slice),
+            _ => false,
+        }
+    }
+
+    /// Request body schema of an uploaded file
+    fn upload_file_schema() -> SchemaReference {
+        SchemaReference {
+            name: "UploadFile".to_string(),
+            schema_type: SchemaType::JsonSchema,
+            location: Location {
+                file: String::new(),
+                line: 0,
+                column: None,
+            },
+            metadata: HashMap::from([(
+                CONTENT_TYPE_METADATA.to_string(),
+                "multipart/form-data".to_string(),
+            )]),
+        }
+    }
+
+    /// Pydantic model an annotation refers to (`UserModel`, `schemas.UserModel`, `Optional[UserModel]`)
+    ///
+    /// Only models of the already analyzed files are known: imported modules
+    /// are analyzed before the definitions of the importing file.
+    fn annotation_model(&self, annotation: &ast::Expr) -> Option<SchemaReference> {
+        match annotation {
+            ast::Expr::Name(name) => self.models.get(name.id.as_str()).cloned(),
+            ast::Expr::Attribute(attr) => self.models.get(attr.attr.as_str()).cloned(),
+            ast::Expr::Subscript(sub) => match sub.value.as_ref() {
+                ast::Expr::Name(name) if name.id.as_str() == "Optional" => {
+                    self.annotation_model(&sub.slice)
+                }
+                _ => None,
+            },
+            ast::Expr::BinOp(bin_op) if matches!(bin_op.op, ast::Operator::BitOr) => self
+                .annotation_model(&bin_op.left)
+                .or_else(|| self.annotation_model(&bin_op.right)),
+            _ => None,
+        }
+    }
+
+    /// Maps a simple type annotation (`str`, `int`, `Optional[int]`) to a base type
+    fn annotation_base_type(annotation: &ast::Expr) -> BaseType {
+        match annotation {
+            ast::Expr::Name(name) => match name.id.as_str() {
+                "str" => BaseType::String,
+                "int" => BaseType::Integer,
+                "float" => BaseType::Number,
+                "bool" => BaseType::Boolean,
+                "dict" | "Dict" => BaseType::Object,
+                "list" | "List" | "set" | "Set" | "tuple" | "Tuple" => BaseType::Array,
+                "Any" => BaseType::Any,
+                _ => BaseType::Unknown,
+            },
+            ast::Expr::Subscript(sub) => match sub.value.as_ref() {
+                ast::Expr::Name(name) if name.id.as_str() == "Optional" => {
+                    Self::annotation_base_type(&sub.slice)
+                }
+                ast::Expr::Name(name) if name.id.as_str() == "Literal" => BaseType::Enum,
+                other => Self::annotation_base_type(other),
+            },
+            // `X | None`
+            ast::Expr::BinOp(bin_op) if matches!(bin_op.op, ast::Operator::BitOr) => {
+                match bin_op.left.as_ref() {
+                    ast::Expr::Constant(constant)
+                        if matches!(constant.value, ast::Constant::None) =>
+                    {
+                        Self::annotation_base_type(&bin_op.right)
+                    }
+                    left => Self::annotation_base_type(left),
+                }
+            }
+            _ => BaseType::Unknown,
         }
     }
 
     fn get_or_create_module_node(&mut self, path: &Path) -> Result<NodeId> {
-        let normalized = Self::normalize_path(path);
+        let normalized = self.normalize_path(path);
         if let Some(node) = self.module_nodes.get(&normalized) {
             return Ok(*node);
         }
@@ -677,26 +1552,108 @@ impl CallGraphBuilder {
         Ok(node)
     }
 
-    fn function_key(path: &Path, name: &str) -> String {
-        format!("{}::{}", Self::normalize_path(path).to_string_lossy(), name)
+    fn get_or_create_external_node(&mut self, name: &str) -> NodeId {
+        if let Some(node) = self.external_nodes.get(name) {
+            return *node;
+        }
+
+        let node = NodeId::from(self.graph.add_node(CallNode::ExternalCall {
+            name: name.to_string(),
+        }));
+        self.external_nodes.insert(name.to_string(), node);
+        node
     }
 
-    fn find_function_node(&self, name: &str, current_file: &Path) -> Option<NodeId> {
-        let normalized = Self::normalize_path(current_file);
-        let direct_key = Self::function_key(&normalized, name);
-        if let Some(node) = self.function_nodes.get(&direct_key) {
-            return Some(*node);
+    /// Creates key for a definition: `path::name@line`
+    ///
+    /// The definition line keeps same-named definitions in one file
+    /// (conditional definitions, redefinitions) as distinct nodes.
+    fn function_key(&self, path: &Path, name: &str, line: usize) -> String {
+        format!(
+            "{}::{}@{}",
+            self.normalize_path(path).to_string_lossy(),
+            name,
+            line
+        )
+    }
+
+    /// Key of a class in `class_bases`: `path::Class`
+    fn class_key(&self, path: &Path, name: &str) -> String {
+        format!("{}::{}", self.normalize_path(path).to_string_lossy(), name)
+    }
+
+    /// Finds the key of class `name` as seen from `file`
+    ///
+    /// A class defined in `file` wins, then the class its import names;
+    /// otherwise the first definition by key.
+    fn find_class_key(&self, name: &str, file: &Path) -> Option<&str> {
+        let (mut current_file, mut current_name) = (self.normalize_path(file), name.to_string());
+        // Bounded, as import cycles would otherwise loop forever
+        for _ in 0..8 {
+            let key = self.class_key(&current_file, &current_name);
+            if let Some((key, _)) = self.class_bases.get_key_value(&key) {
+                return Some(key);
+            }
+            let binding = self
+                .import_aliases
+                .get(&current_file)
+                .and_then(|names| names.get(&current_name))
+                .or_else(|| self.imported_names.get(&current_file)?.get(&current_name));
+            match binding {
+                Some(ImportAlias {
+                    module: Some(module),
+                    name: Some(imported),
+                    ..
+                }) => {
+                    current_file = self.normalize_path(module);
+                    current_name = imported.clone();
+                }
+                _ => break,
+            }
         }
+        self.class_bases
+            .keys()
+            .filter(|key| {
+                key.rsplit_once("::")
+                    .is_some_and(|(_, class)| class == name)
+            })
+            .min()
+            .map(String::as_str)
+    }
 
-        // Find all matches by ends_with("::name")
+    /// Splits a function key into the definition name and line
+    fn split_function_key(key: &str) -> Option<(&str, usize)> {
+        let (_, definition) = key.rsplit_once("::")?;
+        let (name, line) = definition.rsplit_once('@')?;
+        Some((name, line.parse().ok()?))
+    }
+
+    fn find_function_node(&self, name: &str, current_file: &Path) -> Option<NodeId> {
+        let normalized = self.normalize_path(current_file);
+        let file_prefix = format!("{}::", normalized.to_string_lossy());
+
+        // Find all definitions with this name
         let matches: Vec<_> = self
             .function_nodes
             .iter()
-            .filter(|(key, _)| key.ends_with(&format!("::{}", name)))
+            .filter(|(key, _)| {
+                Self::split_function_key(key).is_some_and(|(key_name, _)| key_name == name)
+            })
             .collect();
 
+        // Definition in the current file: the latest one wins, as at runtime
+        if let Some((_, node)) = matches
+            .iter()
+            .filter(|(key, _)| key.starts_with(&file_prefix))
+            .max_by_key(|(key, _)| Self::split_function_key(key).map(|(_, line)| line))
+        {
+            return Some(**node);
+        }
+
         if matches.is_empty() {
-            return crate::call_graph::find_node_by_name(&self.graph, name);
+            return self
+                .find_inherited_method(name, current_file)
+                .or_else(|| crate::call_graph::find_node_by_name(&self.graph, name));
         }
 
         if matches.len() == 1 {
@@ -708,7 +1665,7 @@ impl CallGraphBuilder {
         let current_dir = normalized.parent().map(|p| p.to_path_buf());
         if let Some(dir) = current_dir {
             if let Some((_, node)) = matches.iter().find(|(key, _)| {
-                if let Some(key_path) = Self::extract_path_from_key(key) {
+                if let Some(key_path) = self.extract_path_from_key(key) {
                     key_path.parent() == Some(&dir)
                 } else {
                     false
@@ -720,7 +1677,7 @@ impl CallGraphBuilder {
 
         // 2. Prefer matches with the longest common prefix
         let best_match = matches.iter().max_by_key(|(key, _)| {
-            if let Some(key_path) = Self::extract_path_from_key(key) {
+            if let Some(key_path) = self.extract_path_from_key(key) {
                 Self::common_prefix_length(&normalized, &key_path)
             } else {
                 0
@@ -739,19 +1696,89 @@ impl CallGraphBuilder {
 
         // 3. Fallback: select first deterministically (sorted by key)
         let mut sorted_matches = matches.clone();
-        sorted_matches.sort_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b));
+        sorted_matches.sort_by_key(|(key_a, _)| *key_a);
         sorted_matches.first().map(|(_, node)| **node)
     }
 
-    /// Extracts path from function key (format "path::name")
-    fn extract_path_from_key(key: &str) -> Option<PathBuf> {
-        if let Some(pos) = key.rfind("::") {
-            let path = PathBuf::from(&key[..pos]);
-            // Try to canonicalize, but return non-canonicalized path on error
-            path.canonicalize().ok().or(Some(path))
-        } else {
-            None
+    /// Resolves `Class.method` defined on a base class of `Class`
+    ///
+    /// Bases are searched depth-first in declaration order, which matches the
+    /// Python MRO for single inheritance and simple mixin hierarchies.
+    fn find_inherited_method(&self, name: &str, current_file: &Path) -> Option<NodeId> {
+        let (class_name, method) = name.rsplit_once('.')?;
+        let class_key = self.find_class_key(class_name, current_file)?;
+        let mut visited = HashSet::new();
+        let mut stack: Vec<(&str, &str)> = self.base_classes(class_key);
+
+        while let Some((base, base_file)) = stack.pop() {
+            let Some(base_key) = self.find_class_key(base, Path::new(base_file)) else {
+                continue;
+            };
+            if !visited.insert(base_key) {
+                continue;
+            }
+            let (defining_file, _) = base_key.rsplit_once("::")?;
+            let qualified = format!("{}.{}", base, method);
+            if let Some(node) = self.find_function_in_file(&qualified, Path::new(defining_file)) {
+                return Some(node);
+            }
+            stack.extend(self.base_classes(base_key));
+        }
+        None
+    }
+
+    /// Bases of a class with the file they are looked up from, last base first
+    fn base_classes<'a>(&'a self, class_key: &'a str) -> Vec<(&'a str, &'a str)> {
+        let file = class_key.rsplit_once("::").map_or("", |(file, _)| file);
+        self.class_bases
+            .get(class_key)
+            .into_iter()
+            .flatten()
+            .rev()
+            .map(|base| (base.as_str(), file))
+            .collect()
+    }
+
+    /// Resolves `Depends(...)` parameters of a handler to dependency function nodes
+    ///
+    /// `Depends(deps.get_db)` is looked up by its full name first, then by the last
+    /// segment. Unresolved dependencies are skipped.
+    fn find_dependency_nodes(&self, handler: NodeId, current_file: &Path) -> Vec<NodeId> {
+        let parameters = match self.graph.node_weight(*handler) {
+            Some(CallNode::Function { parameters, .. })
+            | Some(CallNode::Method { parameters, .. }) => parameters,
+            _ => return Vec::new(),
+        };
+
+        let mut dependencies = Vec::new();
+        for parameter in parameters {
+            let Some(name) = parameter
+                .default_value
+                .as_deref()
+                .and_then(|value| value.strip_prefix("Depends("))
+                .and_then(|value| value.strip_suffix(')'))
+                .filter(|name| !name.is_empty())
+            else {
+                continue;
+            };
+            let short_name = name.rsplit('.').next().unwrap_or(name);
+            let node = self
+                .find_function_node(name, current_file)
+                .or_else(|| self.find_function_node(short_name, current_file));
+            if let Some(node) = node {
+                if !dependencies.contains(&node) {
+                    dependencies.push(node);
+                }
+            }
         }
+        dependencies
+    }
+
+    /// Extracts path from function key (format "path::name@line")
+    fn extract_path_from_key(&self, key: &str) -> Option<PathBuf> {
+        // Canonicalize, falling back to the non-canonicalized path on error
+        key.rfind("::")
+            .map(|pos| self.normalize_path(Path::new(&key[..pos])))
     }
 
     /// Calculates the length of the common prefix of two paths
@@ -791,11 +1818,12 @@ impl CallGraphBuilder {
                     Some(PathBuf::from(location.file))
                 }
             }
+            CallNode::ExternalCall { .. } => None,
         }
     }
 
     fn resolve_import_path(&self, import_path: &str, current_file: &Path) -> Result<PathBuf> {
-        let normalized_current = Self::normalize_path(current_file);
+        let normalized_current = self.normalize_path(current_file);
         let base_dir = normalized_current
             .parent()
             .map(|p| p.to_path_buf())
@@ -847,7 +1875,7 @@ impl CallGraphBuilder {
 
         let remaining = import_path.trim_start_matches('.');
         if !remaining.is_empty() {
-            let replaced = remaining.replace('.', &std::path::MAIN_SEPARATOR.to_string());
+            let replaced = remaining.replace('.', std::path::MAIN_SEPARATOR_STR);
             path = path.join(replaced);
         }
 
@@ -867,7 +1895,7 @@ impl CallGraphBuilder {
             .project_root
             .clone()
             .unwrap_or_else(|| PathBuf::from("."));
-        let replaced = import_path.replace('.', &std::path::MAIN_SEPARATOR.to_string());
+        let replaced = import_path.replace('.', std::path::MAIN_SEPARATOR_STR);
         let mut path = root.join(replaced);
 
         if path.is_dir() {
@@ -878,8 +1906,9 @@ impl CallGraphBuilder {
         path
     }
 
-    fn normalize_path(path: &Path) -> PathBuf {
-        path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+    /// Normalizes path (canonicalization is memoized per builder)
+    fn normalize_path(&self, path: &Path) -> PathBuf {
+        self.canonical_paths.normalize(path)
     }
 
     fn is_route_decorator(&self, name: &str) -> bool {
@@ -891,6 +1920,38 @@ impl CallGraphBuilder {
         method_part.parse().ok()
     }
 
+    /// HTTP methods of a route decorator
+    ///
+    /// FastAPI names the method in the attribute (`@app.post`); Flask
+    /// `@app.route` lists them in `methods=[...]` and defaults to GET.
+    fn extract_http_methods(&self, decorator: &Decorator) -> Vec<HttpMethod> {
+        if decorator.name.rsplit('.').next() != Some("route") {
+            return vec![self
+                .extract_http_method(&decorator.name)
+                .unwrap_or(HttpMethod::Get)];
+        }
+
+        // Повторы (`["GET", "POST", "GET"]`) убираются с сохранением порядка
+        let mut methods: Vec<HttpMethod> = Vec::new();
+        let declared = decorator
+            .keyword_arguments
+            .get("methods")
+            .map(|methods| methods.trim_matches(|c| matches!(c, '[' | ']' | '(' | ')')))
+            .unwrap_or_default();
+        for method in declared
+            .split(',')
+            .filter_map(|method| method.trim().parse().ok())
+        {
+            if !methods.contains(&method) {
+                methods.push(method);
+            }
+        }
+        if methods.is_empty() {
+            methods.push(HttpMethod::Get);
+        }
+        methods
+    }
+
     /// Проверяет, есть ли указанный декоратор в списке декораторов
     fn has_decorator(&self, decorator_list: &[ast::Expr], decorator_name: &str) -> bool {
         for decorator in decorator_list {
@@ -904,6 +1965,15 @@ impl CallGraphBuilder {
         false
     }
 
+    /// Имена декораторов, которые не регистрируют маршрут (`require_auth`, `cache`)
+    fn non_route_decorators(&self, decorator_list: &[ast::Expr]) -> Vec<String> {
+        decorator_list
+            .iter()
+            .filter_map(|decorator| self.get_decorator_name(decorator))
+            .filter(|name| !self.is_route_decorator(name))
+            .collect()
+    }
+
     /// Извлекает имя декоратора из AST выражения
     fn get_decorator_name(&self, decorator: &ast::Expr) -> Option<String> {
         match decorator {
@@ -920,3 +1990,95 @@ impl CallGraphBuilder {
         }
     }
 }
+
+/// HTTP status of a `status_code=` argument: `201` or `status.HTTP_201_CREATED`
+fn parse_status_code(value: &str) -> Option<u16> {
+    let value = value.trim();
+    if let Ok(code) = value.parse() {
+        return Some(code);
+    }
+    let name = value.rsplit('.').next()?.strip_prefix("HTTP_")?;
+    name.get(..3)?.parse().ok()
+}
+
+/// Body of FastAPI's 422 response: `{"detail": [{"loc": [...], "msg": ..., "type": ...}]}`
+fn validation_error_type(location: &Location) -> TypeInfo {
+    let json_schema = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "detail": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "loc": {"type": "array"},
+                        "msg": {"type": "string"},
+                        "type": {"type": "string"}
+                    },
+                    "required": ["loc", "msg", "type"]
+                }
+            }
+        }
+    });
+    TypeInfo {
+        base_type: BaseType::Object,
+        schema_ref: Some(SchemaReference {
+            name: "HTTPValidationError".to_string(),
+            schema_type: SchemaType::JsonSchema,
+            location: location.clone(),
+            metadata: HashMap::from([("json_schema".to_string(), json_schema.to_string())]),
+        }),
+        constraints: Vec::new(),
+        optional: false,
+    }
+}
+
+/// Models of a `responses=` argument rendered as `{404: {model: NotFound}, 409: {...}}`
+///
+/// Entries without `model` (only `description`) have no body to compare and are skipped.
+fn parse_responses(value: &str) -> Vec<(u16, &str)> {
+    let Some(entries) = strip_braces(value) else {
+        return Vec::new();
+    };
+    split_dict_entries(entries)
+        .into_iter()
+        .filter_map(|(code, response)| {
+            let code = parse_status_code(code)?;
+            let model = split_dict_entries(strip_braces(response)?)
+                .into_iter()
+                .find(|(key, _)| *key == "model")?
+                .1;
+            Some((code, model))
+        })
+        .collect()
+}
+
+fn strip_braces(value: &str) -> Option<&str> {
+    value.trim().strip_prefix('{')?.strip_suffix('}')
+}
+
+/// `key: value` pairs of a rendered dict, split on top-level commas only
+fn split_dict_entries(entries: &str) -> Vec<(&str, &str)> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (idx, ch) in entries.char_indices() {
+        match ch {
+            '[' | '(' | '{' => depth += 1,
+            ']' | ')' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&entries[start..idx]);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&entries[start..]);
+    parts
+        .into_iter()
+        .filter_map(|entry| {
+            let (key, value) = entry.split_once(':')?;
+            Some((key.trim(), value.trim()))
+        })
+        .collect()
+}
@@ -1,12 +1,17 @@
 use crate::models::Location;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Декоратор функции (для Python)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Decorator {
     /// Имя декоратора (например, "app.post")
     pub name: String,
-    /// Аргументы декоратора
+    /// Позиционные аргументы декоратора
     pub arguments: Vec<String>,
+    /// Именованные аргументы декоратора (`response_model=UserOut`)
+    #[serde(default)]
+    pub keyword_arguments: HashMap<String, String>,
     /// Расположение в коде
     pub location: Location,
     /// Имя функции, к которой применяется декоратор
@@ -37,3 +37,15 @@ pub enum CallEdge {
         return_value: String,
     },
 }
+
+impl CallEdge {
+    /// Ссылки на узлы графа, хранящиеся внутри ребра
+    ///
+    /// Нужны для перенумерации при восстановлении графа (например, из кэша).
+    pub fn node_ids_mut(&mut self) -> [&mut NodeId; 2] {
+        match self {
+            CallEdge::Import { from, to, .. } | CallEdge::Return { from, to, .. } => [from, to],
+            CallEdge::Call { caller, callee, .. } => [caller, callee],
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::call_graph::{CallEdge, CallNode};
 use crate::models::NodeId;
+use petgraph::visit::EdgeRef;
 use petgraph::{Directed, Graph};
 
 /// Граф вызовов - основная структура для представления связей между узлами кода
@@ -12,12 +13,7 @@ where
 {
     graph
         .node_indices()
-        .filter(|&idx| {
-            graph
-                .node_weight(idx)
-                .map(|node| predicate(node))
-                .unwrap_or(false)
-        })
+        .filter(|&idx| graph.node_weight(idx).map(&predicate).unwrap_or(false))
         .map(NodeId::from)
         .collect()
 }
@@ -33,23 +29,103 @@ pub fn find_node_by_name(graph: &CallGraph, name: &str) -> Option<NodeId> {
                 CallNode::Method { name: n, .. } => Some(n == name),
                 CallNode::Route { .. } => None,
                 CallNode::Module { .. } => None,
+                CallNode::ExternalCall { .. } => None,
             }) == Some(true)
         })
         .map(NodeId::from)
 }
 
 /// Получает все входящие узлы (кто вызывает этот узел)
+///
+/// Ребра `Return` не учитываются: они идут от вызываемого к вызывающему.
 pub fn incoming_nodes(graph: &CallGraph, node: NodeId) -> Vec<NodeId> {
-    graph
-        .neighbors_directed(*node, petgraph::Direction::Incoming)
-        .map(NodeId::from)
-        .collect()
+    neighbors_without_returns(graph, node, petgraph::Direction::Incoming)
 }
 
 /// Получает все исходящие узлы (кого вызывает этот узел)
+///
+/// Ребра `Return` не учитываются: они идут от вызываемого к вызывающему.
+/// У route первым идет handler, за ним зависимости `Depends()` в порядке объявления.
 pub fn outgoing_nodes(graph: &CallGraph, node: NodeId) -> Vec<NodeId> {
+    let Some(CallNode::Route { handler, .. }) = graph.node_weight(*node) else {
+        return neighbors_without_returns(graph, node, petgraph::Direction::Outgoing);
+    };
+    let mut edges: Vec<_> = graph
+        .edges_directed(*node, petgraph::Direction::Outgoing)
+        .filter(|edge| !matches!(edge.weight(), CallEdge::Return { .. }))
+        .collect();
+    edges.sort_by_key(|edge| (edge.target() != **handler, edge.id()));
+    edges
+        .into_iter()
+        .map(|edge| NodeId::from(edge.target()))
+        .collect()
+}
+
+/// Получает узлы, которые получают возвращаемое значение узла (ребра `Return`)
+pub fn return_receivers(graph: &CallGraph, node: NodeId) -> Vec<NodeId> {
     graph
-        .neighbors_directed(*node, petgraph::Direction::Outgoing)
-        .map(NodeId::from)
+        .edges_directed(*node, petgraph::Direction::Outgoing)
+        .filter(|edge| matches!(edge.weight(), CallEdge::Return { .. }))
+        .map(|edge| NodeId::from(edge.target()))
+        .collect()
+}
+
+/// Соседи узла в направлении `direction` по ребрам импорта и вызова
+fn neighbors_without_returns(
+    graph: &CallGraph,
+    node: NodeId,
+    direction: petgraph::Direction,
+) -> Vec<NodeId> {
+    graph
+        .edges_directed(*node, direction)
+        .filter(|edge| !matches!(edge.weight(), CallEdge::Return { .. }))
+        .map(|edge| match direction {
+            petgraph::Direction::Incoming => NodeId::from(edge.source()),
+            petgraph::Direction::Outgoing => NodeId::from(edge.target()),
+        })
         .collect()
 }
+
+/// Строит граф только из модулей и связей импорта между ними
+///
+/// Используется для обзора архитектуры: функции, классы, маршруты и вызовы
+/// отбрасываются. Идентификаторы узлов в ребрах пересчитываются под новый граф.
+pub fn imports_only(graph: &CallGraph) -> CallGraph {
+    let mut filtered = CallGraph::new();
+    let mut node_map = std::collections::HashMap::new();
+
+    for idx in graph.node_indices() {
+        if let Some(node @ CallNode::Module { .. }) = graph.node_weight(idx) {
+            node_map.insert(idx, filtered.add_node(node.clone()));
+        }
+    }
+
+    for edge in graph.edge_indices() {
+        let (Some((source, target)), Some(weight)) =
+            (graph.edge_endpoints(edge), graph.edge_weight(edge))
+        else {
+            continue;
+        };
+        let (Some(&new_source), Some(&new_target)) = (node_map.get(&source), node_map.get(&target))
+        else {
+            continue;
+        };
+        if let CallEdge::Import {
+            import_path, file, ..
+        } = weight
+        {
+            filtered.add_edge(
+                new_source,
+                new_target,
+                CallEdge::Import {
+                    from: NodeId::from(new_source),
+                    to: NodeId::from(new_target),
+                    import_path: import_path.clone(),
+                    file: file.clone(),
+                },
+            );
+        }
+    }
+
+    filtered
+}
@@ -0,0 +1,197 @@
+use crate::call_graph::{CallEdge, CallGraph, CallNode};
+use crate::models::NodeId;
+use anyhow::{bail, Context, Result};
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Версия JSON формата графа
+pub const GRAPH_JSON_VERSION: u32 = 1;
+
+/// Документ графа вызовов в JSON
+///
+/// Узлы и ребра ссылаются друг на друга по `id` узла; id не обязаны идти
+/// подряд, поэтому узлы и ребра можно добавлять и удалять вручную
+/// (например, ребро вызова для динамической диспетчеризации).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GraphDocument {
+    /// Версия формата (`GRAPH_JSON_VERSION`)
+    pub version: u32,
+    pub nodes: Vec<GraphDocumentNode>,
+    pub edges: Vec<GraphDocumentEdge>,
+}
+
+/// Узел документа графа
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GraphDocumentNode {
+    /// Идентификатор узла, на который ссылаются ребра и другие узлы
+    pub id: u32,
+    pub node: CallNode,
+}
+
+/// Ребро документа графа
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GraphDocumentEdge {
+    /// id узла-источника
+    pub source: u32,
+    /// id узла-приемника
+    pub target: u32,
+    pub edge: CallEdge,
+}
+
+/// Экспорт и импорт графа вызовов в стабильном JSON формате
+///
+/// В отличие от кэша (bincode), формат документирован и редактируется вручную:
+/// граф можно выгрузить, дополнить внешним инструментом и передать в `ChainBuilder`.
+pub trait CallGraphJson {
+    /// Сериализует граф в JSON; id узла совпадает с его индексом в графе
+    fn to_json(&self) -> Result<String>;
+
+    /// Восстанавливает граф из JSON
+    ///
+    /// Узлы добавляются в порядке документа, ссылки на узлы внутри узлов и ребер
+    /// переводятся из id документа в индексы нового графа.
+    fn from_json(json: &str) -> Result<Self>
+    where
+        Self: Sized;
+}
+
+impl CallGraphJson for CallGraph {
+    fn to_json(&self) -> Result<String> {
+        let nodes = self
+            .node_indices()
+            .map(|idx| GraphDocumentNode {
+                id: idx.index() as u32,
+                node: self[idx].clone(),
+            })
+            .collect();
+        let edges = self
+            .edge_indices()
+            .filter_map(|idx| {
+                let (source, target) = self.edge_endpoints(idx)?;
+                Some(GraphDocumentEdge {
+                    source: source.index() as u32,
+                    target: target.index() as u32,
+                    edge: self[idx].clone(),
+                })
+            })
+            .collect();
+        let document = GraphDocument {
+            version: GRAPH_JSON_VERSION,
+            nodes,
+            edges,
+        };
+        Ok(serde_json::to_string_pretty(&document)?)
+    }
+
+    fn from_json(json: &str) -> Result<Self> {
+        let document: GraphDocument =
+            serde_json::from_str(json).context("Invalid call graph JSON")?;
+        if document.version != GRAPH_JSON_VERSION {
+            bail!(
+                "Unsupported call graph JSON version {} (expected {})",
+                document.version,
+                GRAPH_JSON_VERSION
+            );
+        }
+
+        let mut graph = CallGraph::new();
+        let mut index_map: HashMap<u32, NodeIndex<u32>> = HashMap::new();
+        for entry in &document.nodes {
+            let idx = graph.add_node(entry.node.clone());
+            if index_map.insert(entry.id, idx).is_some() {
+                bail!("Duplicate node id {} in call graph JSON", entry.id);
+            }
+        }
+
+        let resolve = |id: u32| {
+            index_map
+                .get(&id)
+                .copied()
+                .with_context(|| format!("Call graph JSON references missing node {}", id))
+        };
+
+        for idx in graph.node_indices().collect::<Vec<_>>() {
+            for node_id in graph[idx].node_ids_mut() {
+                *node_id = NodeId::from(resolve(node_id.index() as u32)?);
+            }
+        }
+
+        for entry in document.edges {
+            let source = resolve(entry.source)?;
+            let target = resolve(entry.target)?;
+            let mut edge = entry.edge;
+            for node_id in edge.node_ids_mut() {
+                *node_id = NodeId::from(resolve(node_id.index() as u32)?);
+            }
+            graph.add_edge(source, target, edge);
+        }
+
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::ChainBuilder;
+    use crate::call_graph::CallGraphBuilder;
+    use crate::data_flow::DataFlowTracker;
+    use tempfile::TempDir;
+
+    fn chains_json(graph: &CallGraph) -> serde_json::Value {
+        let tracker = DataFlowTracker::new(graph);
+        let chains = ChainBuilder::new(graph, &tracker)
+            .find_all_chains()
+            .unwrap();
+        serde_json::to_value(chains).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip_keeps_chains() {
+        let temp_dir = TempDir::new().unwrap();
+        let main_path = temp_dir.path().join("main.py");
+        std::fs::write(
+            &main_path,
+            r#"
+from fastapi import FastAPI
+
+app = FastAPI()
+
+
+def save(user):
+    return user
+
+
+@app.post("/users")
+def create_user(name: str):
+    return save(name)
+"#,
+        )
+        .unwrap();
+        let mut builder = CallGraphBuilder::new();
+        builder.build_from_entry(&main_path).unwrap();
+        let graph = builder.into_graph();
+
+        let json = graph.to_json().unwrap();
+        let restored = CallGraph::from_json(&json).unwrap();
+
+        assert_eq!(restored.node_count(), graph.node_count());
+        assert_eq!(restored.edge_count(), graph.edge_count());
+        let chains = chains_json(&graph);
+        assert!(!chains.as_array().unwrap().is_empty());
+        assert_eq!(chains_json(&restored), chains);
+    }
+
+    #[test]
+    fn test_missing_node_reference_is_rejected() {
+        let json = r#"{
+            "version": 1,
+            "nodes": [{"id": 7, "node": {"ExternalCall": {"name": "print"}}}],
+            "edges": [{"source": 7, "target": 8, "edge": {"Return": {"from": 7, "to": 8, "return_value": "x"}}}]
+        }"#;
+
+        let error = CallGraph::from_json(json).unwrap_err();
+        assert!(error.to_string().contains("missing node 8"));
+    }
+}
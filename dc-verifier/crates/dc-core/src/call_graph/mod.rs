@@ -2,10 +2,14 @@ pub mod builder;
 pub mod decorator;
 pub mod edge;
 pub mod graph;
+pub mod json;
 pub mod node;
+pub mod path_cache;
 
 pub use builder::*;
 pub use decorator::*;
 pub use edge::*;
 pub use graph::*;
+pub use json::*;
 pub use node::*;
+pub use path_cache::*;
@@ -22,6 +22,9 @@ pub enum CallNode {
         parameters: Vec<Parameter>,
         /// Тип возвращаемого значения (если известен)
         return_type: Option<TypeInfo>,
+        /// Декораторы функции, кроме маршрутных (`require_auth`, `cache`)
+        #[serde(default)]
+        decorators: Vec<String>,
     },
     /// Класс
     Class {
@@ -53,7 +56,44 @@ pub enum CallNode {
         handler: NodeId,
         /// Расположение в коде
         location: Location,
+        /// Тип ответа из `response_model=...` декоратора (приоритетнее типа возврата handler):
+        /// модель в `schema_ref`, контейнер (`List[UserOut]` - `Array`) в `base_type`
+        #[serde(default)]
+        response_model: Option<TypeInfo>,
+        /// `operationId` из `operation_id=...` декоратора (`None` - FastAPI генерирует его,
+        /// см. `fastapi_operation_id`)
+        #[serde(default)]
+        operation_id: Option<String>,
+        /// HTTP код успешного ответа из `status_code=...` декоратора
+        #[serde(default)]
+        status_code: Option<u16>,
+        /// Ответы с ошибкой: из `responses={404: {"model": NotFound}}` декоратора
+        /// и 422 `HTTPValidationError`, если FastAPI валидирует параметры handler
+        #[serde(default)]
+        responses: Vec<(u16, TypeInfo)>,
     },
+    /// Внешний вызов, для которого нет определения в исходниках
+    /// (стандартная библиотека, сторонние пакеты, `console.log` и т.п.)
+    ExternalCall {
+        /// Имя вызываемой функции, как оно записано в коде
+        name: String,
+    },
+}
+
+impl CallNode {
+    /// Ссылки на другие узлы графа, хранящиеся внутри узла
+    ///
+    /// Нужны для перенумерации при восстановлении графа (например, из кэша).
+    pub fn node_ids_mut(&mut self) -> Vec<&mut NodeId> {
+        match self {
+            CallNode::Class { methods, .. } => methods.iter_mut().collect(),
+            CallNode::Method { class, .. } => vec![class],
+            CallNode::Route { handler, .. } => vec![handler],
+            CallNode::Module { .. } | CallNode::Function { .. } | CallNode::ExternalCall { .. } => {
+                Vec::new()
+            }
+        }
+    }
 }
 
 /// Параметр функции/метода
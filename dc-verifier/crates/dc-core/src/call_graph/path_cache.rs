@@ -0,0 +1,41 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Кэш канонических путей построителя графа
+///
+/// Построители нормализуют одни и те же файлы много раз (ключи функций,
+/// разрешение импортов, пути узлов); кэш оставляет один вызов `canonicalize`
+/// на уникальный путь. Путь, который не удалось канонизировать, сохраняется как есть.
+#[derive(Debug, Default)]
+pub struct PathCache {
+    paths: RefCell<HashMap<PathBuf, PathBuf>>,
+    canonicalize_calls: Cell<usize>,
+}
+
+impl PathCache {
+    /// Создает пустой кэш
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Канонический путь (или исходный, если файла нет)
+    pub fn normalize(&self, path: &Path) -> PathBuf {
+        if let Some(normalized) = self.paths.borrow().get(path) {
+            return normalized.clone();
+        }
+
+        self.canonicalize_calls
+            .set(self.canonicalize_calls.get() + 1);
+        let normalized = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        self.paths
+            .borrow_mut()
+            .insert(path.to_path_buf(), normalized.clone());
+        normalized
+    }
+
+    /// Сколько раз вызывался `canonicalize` (число уникальных путей)
+    pub fn canonicalize_calls(&self) -> usize {
+        self.canonicalize_calls.get()
+    }
+}
@@ -22,10 +22,7 @@ impl<'a> DataFlowTracker<'a> {
 
     /// Добавляет переменную к узлу
     pub fn add_variable(&mut self, node: NodeId, variable: Variable) {
-        self.variables
-            .entry(node)
-            .or_insert_with(Vec::new)
-            .push(variable);
+        self.variables.entry(node).or_default().push(variable);
     }
 
     /// Отслеживает переменную через граф
@@ -6,5 +6,7 @@ pub mod entry_point;
 pub mod error;
 pub mod models;
 pub mod parsers;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
 
 pub use error::{ConfigError, DcError, GraphError, ParseError, ValidationError};
@@ -1,4 +1,4 @@
-use crate::models::{Contract, Location, SchemaReference};
+use crate::models::{Contract, Location, SchemaReference, Severity};
 use petgraph::graph::NodeIndex;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::ops::Deref;
@@ -25,6 +25,49 @@ pub struct DataChain {
     pub contracts: Vec<Contract>,
     /// Направление потока данных
     pub direction: ChainDirection,
+    /// Итог проверки цепочки (заполняется после проверки контрактов)
+    #[serde(default)]
+    pub status: ChainStatus,
+    /// Цепочка обрезана по `max_chain_length`: путь продолжается дальше последнего звена
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+/// Итог проверки цепочки по максимальной серьезности ее контрактов
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChainStatus {
+    /// Проблем нет (или только информационные)
+    #[default]
+    Pass,
+    /// Есть предупреждения
+    Warn,
+    /// Есть критические проблемы
+    Fail,
+}
+
+impl ChainStatus {
+    /// Статус для максимальной серьезности контрактов
+    pub fn from_severity(severity: Severity) -> Self {
+        match severity {
+            Severity::Info => ChainStatus::Pass,
+            Severity::Warning => ChainStatus::Warn,
+            Severity::Error | Severity::Critical => ChainStatus::Fail,
+        }
+    }
+}
+
+impl DataChain {
+    /// Пересчитывает статус цепочки по серьезности ее контрактов
+    pub fn update_status(&mut self) {
+        let severity = self
+            .contracts
+            .iter()
+            .map(|contract| contract.severity)
+            .max()
+            .unwrap_or(Severity::Info);
+        self.status = ChainStatus::from_severity(severity);
+    }
 }
 
 /// Звено цепочки - один узел в графе вызовов
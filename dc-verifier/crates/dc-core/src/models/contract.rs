@@ -1,4 +1,4 @@
-use crate::models::{Location, SchemaReference, TypeInfo};
+use crate::models::{normalize_route_path, Location, SchemaReference, TypeInfo};
 use serde::{Deserialize, Serialize};
 
 /// Контракт между двумя звеньями цепочки
@@ -16,6 +16,9 @@ pub struct Contract {
     pub mismatches: Vec<Mismatch>,
     /// Серьезность проблем в контракте
     pub severity: Severity,
+    /// HTTP код ответа (только для контрактов ответа)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
 }
 
 /// Обнаруженное несоответствие на стыке
@@ -23,6 +26,9 @@ pub struct Contract {
 pub struct Mismatch {
     /// Тип несоответствия
     pub mismatch_type: MismatchType,
+    /// Имя правила, обнаружившего несоответствие (пусто, если неизвестно)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub rule: String,
     /// Путь к полю (например, "discount" или "client_data.full_name")
     pub path: String,
     /// Ожидаемый тип/значение
@@ -35,8 +41,53 @@ pub struct Mismatch {
     pub message: String,
 }
 
+/// Разрешенное (намеренное) несоответствие
+///
+/// Описывает преобразование, которое происходит на стыке осознанно
+/// (например, `str` id → `int` pk). Незаданные поля совпадают с любым значением.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllowRule {
+    /// Маршрут или имя цепочки (например, "POST /users" или "/users")
+    pub route: Option<String>,
+    /// Путь к полю (например, "user_id"); покрывает и вложенные поля
+    pub path: Option<String>,
+    /// Тип несоответствия
+    pub mismatch_type: Option<MismatchType>,
+}
+
+impl AllowRule {
+    /// Проверяет, покрывает ли правило несоответствие в цепочке `chain_name`
+    pub fn matches(&self, chain_name: &str, mismatch: &Mismatch) -> bool {
+        let route_matches = self.route.as_deref().is_none_or(|route| {
+            let name = chain_name.trim_end_matches(" (reverse)");
+            let (method, path) = name.split_once(' ').unwrap_or(("", name));
+            let path = normalize_route_path(path);
+            match route.split_once(' ') {
+                Some((route_method, route_path)) => {
+                    route_method.eq_ignore_ascii_case(method)
+                        && normalize_route_path(route_path) == path
+                }
+                None => normalize_route_path(route) == path,
+            }
+        });
+        let path_matches = self.path.as_deref().is_none_or(|path| {
+            mismatch.path == path
+                || mismatch
+                    .path
+                    .strip_prefix(path)
+                    .is_some_and(|rest| rest.starts_with('.'))
+        });
+        let type_matches = self
+            .mismatch_type
+            .is_none_or(|mismatch_type| mismatch_type == mismatch.mismatch_type);
+
+        route_matches && path_matches && type_matches
+    }
+}
+
 /// Тип несоответствия
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum MismatchType {
     /// Несоответствие типов (например, number vs string)
     TypeMismatch,
@@ -48,6 +99,8 @@ pub enum MismatchType {
     ValidationMismatch,
     /// Ненормализованные данные
     UnnormalizedData,
+    /// Одно поле под разными именами (`user_name` и `userName`)
+    NamingMismatch,
 }
 
 /// Серьезность проблемы
@@ -57,6 +110,37 @@ pub enum Severity {
     Info,
     /// Предупреждение (может вызвать проблемы)
     Warning,
+    /// Ошибка (нарушает контракт, но не угрожает данным)
+    Error,
     /// Критическая проблема (вызовет ошибку)
     Critical,
 }
+
+impl Severity {
+    /// Имя уровня в конфиге (`info`, `warning`, `error`, `critical`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+            Severity::Critical => "critical",
+        }
+    }
+}
+
+impl std::str::FromStr for Severity {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "info" => Ok(Severity::Info),
+            "warning" => Ok(Severity::Warning),
+            "error" => Ok(Severity::Error),
+            "critical" => Ok(Severity::Critical),
+            _ => anyhow::bail!(
+                "Unknown severity: {}. Supported severities: info, warning, error, critical",
+                value
+            ),
+        }
+    }
+}
@@ -1,9 +1,11 @@
 pub mod chain;
 pub mod contract;
 pub mod location;
+pub mod route;
 pub mod schema;
 
 pub use chain::*;
 pub use contract::*;
 pub use location::*;
+pub use route::*;
 pub use schema::*;
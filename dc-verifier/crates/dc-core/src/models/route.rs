@@ -0,0 +1,65 @@
+/// Приводит шаблон пути route к канонической форме `/users/{id}`
+///
+/// Разные фреймворки записывают параметры пути по-разному: `{id}` и `{id:int}`
+/// (FastAPI/Starlette), `:id` (Express), `<int:id>` (Flask), `[id]` (Next.js).
+/// Канонический вид используется при именовании цепочек, сопоставлении routes
+/// и в отчетах, чтобы один и тот же endpoint назывался одинаково.
+/// Повторные `/` схлопываются, завершающий `/` отбрасывается (кроме корня).
+pub fn normalize_route_path(path: &str) -> String {
+    let segments: Vec<String> = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(normalize_segment)
+        .collect();
+
+    format!("/{}", segments.join("/"))
+}
+
+/// Канонизирует один сегмент пути
+fn normalize_segment(segment: &str) -> String {
+    let param = if let Some(name) = segment.strip_prefix(':') {
+        Some(name)
+    } else if let Some(inner) = segment
+        .strip_prefix('{')
+        .and_then(|rest| rest.strip_suffix('}'))
+    {
+        // {id:int} → id
+        Some(inner.split(':').next().unwrap_or(inner))
+    } else if let Some(inner) = segment
+        .strip_prefix('<')
+        .and_then(|rest| rest.strip_suffix('>'))
+    {
+        // <int:id> → id
+        Some(inner.rsplit(':').next().unwrap_or(inner))
+    } else {
+        segment
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+            .map(|inner| inner.trim_start_matches("..."))
+    };
+
+    match param.map(str::trim) {
+        Some(name) if !name.is_empty() => format!("{{{}}}", name),
+        _ => segment.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_styles_normalize_to_same_path() {
+        let expected = "/users/{id}/orders";
+        for path in [
+            "/users/{id}/orders",
+            "/users/:id/orders",
+            "/users/{id:int}/orders/",
+            "/users/<int:id>/orders",
+            "users/[id]//orders",
+        ] {
+            assert_eq!(normalize_route_path(path), expected, "{}", path);
+        }
+        assert_eq!(normalize_route_path("/"), "/");
+    }
+}
@@ -15,6 +15,137 @@ pub struct SchemaReference {
     pub metadata: HashMap<String, String>,
 }
 
+/// Ключ метаданных с content type тела запроса (`multipart/form-data`)
+pub const CONTENT_TYPE_METADATA: &str = "content_type";
+
+/// Ключ метаданных с ограничениями полей (JSON: имя поля → список `Constraint`)
+///
+/// Заполняется для Pydantic полей с типами вроде `constr(max_length=10)`, `conint(ge=0)`
+/// и для полей Zod схем с цепочками валидаторов (`z.string().min(3).email()`).
+pub const FIELD_CONSTRAINTS_METADATA: &str = "field_constraints";
+
+/// Ключ метаданных устаревшей схемы или эндпоинта (`"true"`)
+pub const DEPRECATED_METADATA: &str = "deprecated";
+
+/// Ключ метаданных с устаревшими полями схемы (имена через запятую)
+pub const DEPRECATED_FIELDS_METADATA: &str = "deprecated_fields";
+
+/// Ключ метаданных схемы, встроенной в спецификацию без `$ref` (`"true"`)
+///
+/// Имя такой схемы синтезировано (`createUserRequest`) и не сравнивается с именами моделей кода.
+pub const INLINE_SCHEMA_METADATA: &str = "inline_schema";
+
+/// Ключ метаданных с сигнатурами методов интерфейса (JSON: список `InterfaceMethod`)
+pub const METHODS_METADATA: &str = "methods";
+
+/// Сигнатура метода интерфейса (`save(user: User): void`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InterfaceMethod {
+    /// Имя метода
+    pub name: String,
+    /// Параметры в порядке объявления
+    pub parameters: Vec<MethodParameter>,
+    /// Возвращаемый тип (`None`, если не указан)
+    pub return_type: Option<String>,
+    /// Метод необязателен (`save?(...)`)
+    pub optional: bool,
+}
+
+/// Параметр метода интерфейса
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MethodParameter {
+    /// Имя параметра
+    pub name: String,
+    /// Тип параметра (`None`, если не указан)
+    pub type_name: Option<String>,
+    /// Параметр необязателен (`user?: User`)
+    pub optional: bool,
+}
+
+impl SchemaReference {
+    /// Нормализованный документ JSON Schema схемы
+    ///
+    /// Схема разбирается [`SchemaParser::parse`](crate::analyzers::SchemaParser::parse),
+    /// поэтому результат одинаков для Pydantic, Zod, TypeScript и OpenAPI схем.
+    pub fn to_json_schema(&self) -> anyhow::Result<serde_json::Value> {
+        Ok(crate::analyzers::SchemaParser::parse(self)?.to_json_value())
+    }
+
+    /// Тело передается формой (`multipart/form-data`, `application/x-www-form-urlencoded`),
+    /// а не JSON моделью
+    pub fn is_form_body(&self) -> bool {
+        self.metadata
+            .get(CONTENT_TYPE_METADATA)
+            .is_some_and(|content_type| is_form_content_type(content_type))
+    }
+
+    /// Сигнатуры методов интерфейса (пусто для схем данных)
+    pub fn methods(&self) -> Vec<InterfaceMethod> {
+        self.metadata
+            .get(METHODS_METADATA)
+            .and_then(|methods| serde_json::from_str(methods).ok())
+            .unwrap_or_default()
+    }
+
+    /// Схема (или ее эндпоинт) помечена устаревшей
+    pub fn is_deprecated(&self) -> bool {
+        self.metadata
+            .get(DEPRECATED_METADATA)
+            .is_some_and(|value| value == "true")
+    }
+}
+
+/// Убирает дубликаты ссылок на одну модель, оставляя самую полную
+///
+/// Одна модель может быть извлечена несколько раз (из каждого импортирующего файла,
+/// из AST и через PyO3) с разной полнотой метаданных. Дубликатами считаются ссылки
+/// с одинаковыми именем и расположением; неизвестная строка (`0`, как у схем из PyO3)
+/// совпадает с любой строкой того же файла. Из дубликатов остается ссылка с
+/// `json_schema`, а при равенстве - с большим числом метаданных; известное
+/// расположение сохраняется. Порядок первых вхождений не меняется.
+pub fn dedupe_schema_refs(
+    schemas: impl IntoIterator<Item = SchemaReference>,
+) -> Vec<SchemaReference> {
+    let mut result: Vec<SchemaReference> = Vec::new();
+    for schema in schemas {
+        let duplicate = result.iter_mut().find(|existing| {
+            existing.name == schema.name
+                && existing.location.file == schema.location.file
+                && (existing.location.line == schema.location.line
+                    || existing.location.line == 0
+                    || schema.location.line == 0)
+        });
+        let Some(existing) = duplicate else {
+            result.push(schema);
+            continue;
+        };
+
+        let known_location = if existing.location.line != 0 {
+            existing.location.clone()
+        } else {
+            schema.location.clone()
+        };
+        if metadata_richness(&schema) > metadata_richness(existing) {
+            *existing = schema;
+        }
+        existing.location = known_location;
+    }
+    result
+}
+
+/// Полнота метаданных: наличие `json_schema`, затем число ключей
+fn metadata_richness(schema: &SchemaReference) -> (bool, usize) {
+    (
+        schema.metadata.contains_key("json_schema"),
+        schema.metadata.len(),
+    )
+}
+
+/// Content type формы: поля такого тела не сравниваются как поля JSON модели
+pub fn is_form_content_type(content_type: &str) -> bool {
+    content_type.starts_with("multipart/") || content_type == "application/x-www-form-urlencoded"
+}
+
 /// Тип схемы
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SchemaType {
@@ -28,6 +159,8 @@ pub enum SchemaType {
     OpenAPI,
     /// JSON Schema
     JsonSchema,
+    /// Сообщение protobuf (gRPC)
+    Protobuf,
 }
 
 /// Информация о типе данных
@@ -52,6 +185,8 @@ pub enum BaseType {
     Boolean,
     Object,
     Array,
+    /// Перечисление: закрытое множество значений (сами значения - в `Constraint::Enum`)
+    Enum,
     Null,
     Any,
     Unknown,
@@ -60,9 +195,9 @@ pub enum BaseType {
 /// Ограничение/валидация для типа
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum Constraint {
-    /// Минимальная длина (для строк) или значение (для чисел)
+    /// Минимальная длина (для строк), значение (для чисел) или число элементов (для массивов)
     Min(ConstraintValue),
-    /// Максимальная длина (для строк) или значение (для чисел)
+    /// Максимальная длина (для строк), значение (для чисел) или число элементов (для массивов)
     Max(ConstraintValue),
     /// Регулярное выражение (для строк)
     Pattern(String),
@@ -72,6 +207,10 @@ pub enum Constraint {
     Url,
     /// Enum значения
     Enum(Vec<String>),
+    /// Допустимые типы объединения (`string | number`): подходит значение любого из них
+    OneOf(Vec<BaseType>),
+    /// Элементы массива не повторяются (`uniqueItems: true`)
+    UniqueItems,
 }
 
 /// Значение ограничения
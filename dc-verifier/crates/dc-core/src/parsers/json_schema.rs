@@ -0,0 +1,168 @@
+use crate::analyzers::SchemaParser;
+use crate::models::{Location, SchemaReference, SchemaType};
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Суффикс файлов с JSON Schema контрактами
+pub const JSON_SCHEMA_FILE_SUFFIX: &str = ".schema.json";
+
+/// Ключевые слова, хотя бы одно из которых есть у корня JSON Schema
+const SCHEMA_KEYWORDS: &[&str] = &[
+    "type",
+    "properties",
+    "$ref",
+    "allOf",
+    "anyOf",
+    "oneOf",
+    "enum",
+    "items",
+];
+
+/// Загрузчик самостоятельных JSON Schema контрактов (`*.schema.json`)
+pub struct JsonSchemaParser;
+
+impl JsonSchemaParser {
+    /// Создает загрузчик
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Загружает все `*.schema.json` из каталога (рекурсивно, в порядке путей)
+    pub fn load_dir(&self, dir: &Path) -> Result<Vec<SchemaReference>> {
+        let mut files = Vec::new();
+        collect_schema_files(dir, &mut files)
+            .with_context(|| format!("Failed to read schema directory {}", dir.display()))?;
+        files.sort();
+
+        files.iter().map(|file| self.load_file(file)).collect()
+    }
+
+    /// Загружает один файл JSON Schema
+    ///
+    /// Имя схемы - `title`, а без него - имя файла без `.schema.json`.
+    /// Файл должен быть JSON объектом с ключевыми словами JSON Schema,
+    /// который разбирает `SchemaParser`.
+    pub fn load_file(&self, path: &Path) -> Result<SchemaReference> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        self.parse(&content, &path.to_string_lossy())
+    }
+
+    /// Разбирает содержимое файла `file_path` в ссылку на схему
+    pub fn parse(&self, content: &str, file_path: &str) -> Result<SchemaReference> {
+        let schema: Value = serde_json::from_str(content)
+            .with_context(|| format!("{} is not valid JSON", file_path))?;
+        let Some(object) = schema.as_object() else {
+            bail!("{} is not a JSON Schema: expected an object", file_path);
+        };
+        if !SCHEMA_KEYWORDS
+            .iter()
+            .any(|keyword| object.contains_key(*keyword))
+        {
+            bail!(
+                "{} is not a JSON Schema: none of {} is set",
+                file_path,
+                SCHEMA_KEYWORDS.join(", ")
+            );
+        }
+
+        let name = object
+            .get("title")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| schema_name_from_path(file_path));
+        let mut metadata = std::collections::HashMap::new();
+        metadata.insert("json_schema".to_string(), schema.to_string());
+
+        let schema_ref = SchemaReference {
+            name,
+            schema_type: SchemaType::JsonSchema,
+            location: Location {
+                file: file_path.to_string(),
+                line: 1,
+                column: None,
+            },
+            metadata,
+        };
+        SchemaParser::parse(&schema_ref)
+            .with_context(|| format!("{} is not a supported JSON Schema", file_path))?;
+
+        Ok(schema_ref)
+    }
+}
+
+impl Default for JsonSchemaParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Имя схемы по имени файла: `schemas/user.schema.json` → `user`
+fn schema_name_from_path(file_path: &str) -> String {
+    let file_name = Path::new(file_path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    file_name
+        .strip_suffix(JSON_SCHEMA_FILE_SUFFIX)
+        .unwrap_or(&file_name)
+        .to_string()
+}
+
+fn collect_schema_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_schema_files(&path, files)?;
+        } else if path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().ends_with(JSON_SCHEMA_FILE_SUFFIX))
+        {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_dir_yields_schema_per_file() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("user.schema.json"),
+            r#"{"title": "User", "type": "object", "properties": {"name": {"type": "string"}}, "required": ["name"]}"#,
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("order.schema.json"),
+            r#"{"type": "object", "properties": {"total": {"type": "number"}}}"#,
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("notes.json"), "[]").unwrap();
+
+        let schemas = JsonSchemaParser::new().load_dir(temp_dir.path()).unwrap();
+
+        let names: Vec<&str> = schemas.iter().map(|schema| schema.name.as_str()).collect();
+        assert_eq!(names, vec!["order", "User"]);
+        for schema in &schemas {
+            assert_eq!(schema.schema_type, SchemaType::JsonSchema);
+            assert!(schema.metadata["json_schema"].contains("properties"));
+        }
+        let user = SchemaParser::parse(&schemas[1]).unwrap();
+        assert!(user.properties.contains_key("name"));
+    }
+
+    #[test]
+    fn test_non_schema_file_is_rejected() {
+        let error = JsonSchemaParser::new()
+            .parse(r#"{"name": "not a schema"}"#, "broken.schema.json")
+            .unwrap_err();
+        assert!(error.to_string().contains("not a JSON Schema"));
+    }
+}
@@ -1,26 +1,61 @@
+pub mod json_schema;
 pub mod location;
 pub mod openapi;
+pub mod proto;
 pub mod python;
 pub mod typescript;
 
+pub use json_schema::*;
 pub use location::*;
 pub use openapi::*;
+pub use proto::*;
 pub use python::*;
 pub use typescript::*;
 
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Проверяет, является ли файл тестом (`test_*.py`, `*_test.py`, `conftest.py`,
+/// `*.test.ts`, `*.spec.ts`, файлы в `__tests__/`)
+///
+/// Фикстуры и моки тестов засоряют граф, поэтому адаптеры по умолчанию их пропускают.
+pub fn is_test_file(path: &Path) -> bool {
+    if path
+        .components()
+        .any(|component| component.as_os_str() == "__tests__")
+    {
+        return true;
+    }
+
+    let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    if let Some(stem) = file_name.strip_suffix(".py") {
+        return stem.starts_with("test_") || stem.ends_with("_test") || stem == "conftest";
+    }
+
+    // `user.test.ts`, `user.spec.tsx`, `user.test.js`
+    let mut parts = file_name.rsplitn(3, '.');
+    let _extension = parts.next();
+    matches!(parts.next(), Some("test" | "spec")) && parts.next().is_some()
+}
+
 /// Импорт модуля/функции
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Import {
     /// Путь импорта (например, "fastapi" или "db.crud")
     pub path: String,
     /// Импортируемые имена (если есть)
     pub names: Vec<String>,
+    /// Псевдоним из `as` (`import numpy as np` → `np`)
+    #[serde(default)]
+    pub alias: Option<String>,
     /// Расположение в коде
     pub location: crate::models::Location,
 }
 
 /// Вызов функции
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Call {
     /// Имя вызываемой функции
     pub name: String,
@@ -33,7 +68,7 @@ pub struct Call {
 }
 
 /// Аргумент вызова функции
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CallArgument {
     /// Имя параметра (если именованный)
     pub parameter_name: Option<String>,
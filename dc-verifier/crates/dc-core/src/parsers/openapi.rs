@@ -1,8 +1,11 @@
-use crate::models::SchemaReference;
-use anyhow::Result;
+use crate::models::{BaseType, SchemaReference, TypeInfo};
+use anyhow::{Context, Result};
 use serde_json::Value;
+use std::fs;
+use std::path::Path;
 
 /// Парсер OpenAPI спецификаций для связывания frontend/backend
+#[derive(Debug)]
 pub struct OpenApiParser {
     spec: Value,
 }
@@ -10,13 +13,58 @@ pub struct OpenApiParser {
 impl OpenApiParser {
     /// Создает парсер из JSON строки
     pub fn from_json(spec_json: &str) -> Result<Self> {
-        let spec: Value = serde_json::from_str(spec_json)?;
+        let spec: Value = serde_json::from_str(spec_json).context("Invalid OpenAPI JSON")?;
+        Self::from_value(spec)
+    }
+
+    /// Создает парсер из YAML строки
+    ///
+    /// Документ приводится к тому же `serde_json::Value`, что и JSON, поэтому
+    /// извлечение эндпоинтов одинаково для обоих форматов.
+    pub fn from_yaml(spec_yaml: &str) -> Result<Self> {
+        let spec: serde_yaml::Value =
+            serde_yaml::from_str(spec_yaml).context("Invalid OpenAPI YAML")?;
+        Self::from_value(yaml_to_json(spec))
+    }
+
+    /// Читает спецификацию из файла: `.yaml`/`.yml` - YAML, остальное - JSON
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read OpenAPI spec: {}", path.display()))?;
+        let is_yaml = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml"));
+        let parser = if is_yaml {
+            Self::from_yaml(&content)
+        } else {
+            Self::from_json(&content)
+        };
+        parser.with_context(|| format!("Failed to parse OpenAPI spec: {}", path.display()))
+    }
+
+    fn from_value(spec: Value) -> Result<Self> {
+        if !spec.get("paths").is_some_and(Value::is_object) {
+            anyhow::bail!("OpenAPI spec has no `paths` object");
+        }
         Ok(Self { spec })
     }
 
+    /// Версия формата по ключу верхнего уровня: `swagger: "2.0"` или `openapi: 3.x`
+    pub fn spec_version(&self) -> SpecVersion {
+        match self.spec.get("swagger").and_then(|v| v.as_str()) {
+            Some(version) if version.starts_with('2') => SpecVersion::Swagger2,
+            _ => SpecVersion::OpenApi3,
+        }
+    }
+
     /// Извлекает все эндпоинты из спецификации
+    ///
+    /// Swagger 2.0 описывает тело запроса параметром `in: body`, а схему ответа -
+    /// прямо в `responses.<код>.schema`; OpenAPI 3.x - через `content`.
     pub fn extract_endpoints(&self) -> Vec<ApiEndpoint> {
         let mut endpoints = Vec::new();
+        let version = self.spec_version();
 
         // Валидные HTTP методы (case-insensitive)
         let valid_methods = [
@@ -34,15 +82,42 @@ impl OpenApiParser {
                         }
 
                         if let Some(operation_obj) = operation.as_object() {
+                            let deprecated = operation_obj
+                                .get("deprecated")
+                                .and_then(|d| d.as_bool())
+                                .unwrap_or(false);
+                            let mark = |schema: Option<SchemaReference>| {
+                                schema.map(|schema| mark_deprecated(schema, deprecated))
+                            };
+                            let operation_id = operation_obj
+                                .get("operationId")
+                                .and_then(|id| id.as_str())
+                                .map(|s| s.to_string());
+                            // Имя встроенных схем: `createUserRequest`, `post_users_idResponse`
+                            let operation_name = operation_id
+                                .clone()
+                                .unwrap_or_else(|| operation_name(&method_lower, path));
+                            let (status_code, response_schema) =
+                                self.extract_response_schema(operation_obj, &operation_name);
+                            let request_schema = match version {
+                                SpecVersion::Swagger2 => self.extract_swagger_request_schema(
+                                    path_item_obj,
+                                    operation_obj,
+                                    &operation_name,
+                                ),
+                                SpecVersion::OpenApi3 => {
+                                    self.extract_request_schema(operation_obj, &operation_name)
+                                }
+                            };
                             endpoints.push(ApiEndpoint {
                                 path: path.clone(),
                                 method: method_key.to_uppercase(),
-                                operation_id: operation_obj
-                                    .get("operationId")
-                                    .and_then(|id| id.as_str())
-                                    .map(|s| s.to_string()),
-                                request_schema: self.extract_request_schema(operation_obj),
-                                response_schema: self.extract_response_schema(operation_obj),
+                                operation_id,
+                                request_schema: mark(request_schema),
+                                response_schema: mark(response_schema),
+                                status_code,
+                                parameters: self.extract_parameters(path_item_obj, operation_obj),
+                                deprecated,
                             });
                         }
                     }
@@ -56,51 +131,413 @@ impl OpenApiParser {
     fn extract_request_schema(
         &self,
         operation: &serde_json::Map<String, Value>,
+        operation_name: &str,
     ) -> Option<SchemaReference> {
-        operation
-            .get("requestBody")?
-            .get("content")?
-            .get("application/json")?
-            .get("schema")?
-            .get("$ref")
+        let request_body = operation.get("requestBody")?;
+        // Как и в спецификации, без `required: true` тело запроса необязательно
+        let body_required = request_body
+            .get("required")
+            .and_then(|r| r.as_bool())
+            .unwrap_or(false);
+
+        let content = request_body.get("content")?.as_object()?;
+
+        let mut metadata = std::collections::HashMap::new();
+        metadata.insert("body_required".to_string(), body_required.to_string());
+
+        if let Some(json) = content.get("application/json") {
+            let schema = json.get("schema")?;
+            return self.body_schema(schema, format!("{}Request", operation_name), metadata);
+        }
+
+        // Формы (загрузка файлов): поля не сравниваются как JSON модель
+        let (content_type, media) = content
+            .iter()
+            .find(|(content_type, _)| crate::models::is_form_content_type(content_type))?;
+        metadata.insert(
+            crate::models::CONTENT_TYPE_METADATA.to_string(),
+            content_type.clone(),
+        );
+        let name = media
+            .get("schema")
+            .and_then(|schema| schema.get("$ref"))
             .and_then(|r| r.as_str())
-            .map(|s| SchemaReference {
-                name: s.to_string(),
-                schema_type: crate::models::SchemaType::OpenAPI,
-                location: crate::models::Location {
-                    file: String::new(),
-                    line: 0,
-                    column: None,
+            .unwrap_or("FormData");
+        Some(self.schema_reference(name, metadata))
+    }
+
+    /// Тело запроса Swagger 2.0: параметр `in: body` или параметры формы `in: formData`
+    fn extract_swagger_request_schema(
+        &self,
+        path_item: &serde_json::Map<String, Value>,
+        operation: &serde_json::Map<String, Value>,
+        operation_name: &str,
+    ) -> Option<SchemaReference> {
+        let parameters: Vec<Value> = [path_item, operation]
+            .into_iter()
+            .filter_map(|owner| owner.get("parameters").and_then(|p| p.as_array()))
+            .flatten()
+            .filter_map(
+                |parameter| match parameter.get("$ref").and_then(|r| r.as_str()) {
+                    Some(reference) => self.resolve_ref(reference),
+                    None => Some(parameter.clone()),
                 },
-                metadata: std::collections::HashMap::new(),
-            })
+            )
+            .collect();
+
+        fn location(parameter: &Value) -> Option<&str> {
+            parameter.get("in").and_then(|l| l.as_str())
+        }
+
+        if let Some(body) = parameters.iter().find(|p| location(p) == Some("body")) {
+            let mut metadata = std::collections::HashMap::new();
+            // В Swagger 2.0 параметр тела по умолчанию необязателен
+            let required = body
+                .get("required")
+                .and_then(|r| r.as_bool())
+                .unwrap_or(false);
+            metadata.insert("body_required".to_string(), required.to_string());
+            let schema = body.get("schema")?;
+            return self.body_schema(schema, format!("{}Request", operation_name), metadata);
+        }
+
+        // Формы: поля не сравниваются как JSON модель
+        if parameters.iter().any(|p| location(p) == Some("formData")) {
+            let consumes = operation
+                .get("consumes")
+                .or_else(|| self.spec.get("consumes"))
+                .and_then(|c| c.as_array());
+            let content_type = consumes
+                .into_iter()
+                .flatten()
+                .filter_map(|c| c.as_str())
+                .find(|c| crate::models::is_form_content_type(c))
+                .unwrap_or("application/x-www-form-urlencoded");
+            let metadata = std::collections::HashMap::from([(
+                crate::models::CONTENT_TYPE_METADATA.to_string(),
+                content_type.to_string(),
+            )]);
+            return Some(self.schema_reference("FormData", metadata));
+        }
+
+        None
+    }
+
+    /// Параметры операции (path/query/header/cookie)
+    ///
+    /// Параметры уровня пути действуют для всех операций; параметр операции с тем
+    /// же `name` и `in` их переопределяет. Ссылки `#/components/parameters/...`
+    /// разрешаются.
+    fn extract_parameters(
+        &self,
+        path_item: &serde_json::Map<String, Value>,
+        operation: &serde_json::Map<String, Value>,
+    ) -> Vec<ApiParameter> {
+        let mut parameters: Vec<ApiParameter> = Vec::new();
+        let declared = [path_item, operation]
+            .into_iter()
+            .filter_map(|owner| owner.get("parameters").and_then(|p| p.as_array()))
+            .flatten();
+        for parameter in declared {
+            let Some(parameter) = self.extract_parameter(parameter) else {
+                continue;
+            };
+            parameters.retain(|existing| {
+                existing.name != parameter.name || existing.location != parameter.location
+            });
+            parameters.push(parameter);
+        }
+        parameters
     }
 
+    fn extract_parameter(&self, parameter: &Value) -> Option<ApiParameter> {
+        let parameter = match parameter.get("$ref").and_then(|r| r.as_str()) {
+            Some(reference) => self.resolve_ref(reference)?,
+            None => parameter.clone(),
+        };
+        let name = parameter.get("name")?.as_str()?.to_string();
+        let location = parameter.get("in")?.as_str()?.to_string();
+        // Тело и поля формы Swagger 2.0 - это тело запроса, а не параметры
+        if location == "body" || location == "formData" {
+            return None;
+        }
+        // Параметр пути всегда обязателен
+        let required = location == "path"
+            || parameter
+                .get("required")
+                .and_then(|r| r.as_bool())
+                .unwrap_or(false);
+
+        // В Swagger 2.0 тип описан прямо в параметре (`type: integer`)
+        let schema = parameter
+            .get("schema")
+            .or_else(|| parameter.get("type").is_some().then_some(&parameter));
+        let schema_ref = schema
+            .and_then(|schema| schema.get("$ref"))
+            .and_then(|r| r.as_str())
+            .map(|reference| self.schema_reference(reference, std::collections::HashMap::new()));
+        let field = schema
+            .map(|schema| self.inline_refs(schema, &mut Vec::new()))
+            .and_then(|schema| crate::analyzers::SchemaParser::parse_property(&schema).ok());
+
+        Some(ApiParameter {
+            name,
+            location,
+            required,
+            type_info: TypeInfo {
+                base_type: field
+                    .as_ref()
+                    .map_or(BaseType::Unknown, |field| field.base_type),
+                schema_ref,
+                constraints: field.map(|field| field.constraints).unwrap_or_default(),
+                optional: !required,
+            },
+        })
+    }
+
+    /// Код ответа операции и схема его тела
+    ///
+    /// Берется наименьший 2xx код (`201` для создания ресурса), иначе `default`.
+    /// Код пустой, если у операции нет ни успешного ответа, ни `default`.
     fn extract_response_schema(
         &self,
         operation: &serde_json::Map<String, Value>,
+        operation_name: &str,
+    ) -> (String, Option<SchemaReference>) {
+        let Some(responses) = operation.get("responses").and_then(|r| r.as_object()) else {
+            return (String::new(), None);
+        };
+        let Some(status_code) = responses
+            .keys()
+            .filter(|code| code.len() == 3 && code.starts_with('2'))
+            .filter(|code| code.chars().all(|c| c.is_ascii_digit()))
+            .min()
+            .or_else(|| responses.keys().find(|code| code.as_str() == "default"))
+        else {
+            return (String::new(), None);
+        };
+
+        let response = &responses[status_code];
+        let schema = match self.spec_version() {
+            SpecVersion::Swagger2 => response.get("schema"),
+            SpecVersion::OpenApi3 => response
+                .get("content")
+                .and_then(|content| content.get("application/json"))
+                .and_then(|json| json.get("schema")),
+        };
+        let schema = schema.and_then(|schema| {
+            self.body_schema(
+                schema,
+                format!("{}Response", operation_name),
+                std::collections::HashMap::new(),
+            )
+        });
+        (status_code.clone(), schema)
+    }
+
+    /// Схема тела запроса или ответа: `$ref` или встроенная схема
+    ///
+    /// Обертки вокруг ссылки разворачиваются до нее: `anyOf`/`oneOf`, добавляющие
+    /// только `null` (`Optional[User]`), и элементы массива (`List[User]`).
+    /// Встроенная схема объекта (`{type: object, properties: {...}}`) сохраняется в
+    /// metadata `json_schema` под синтезированным именем `inline_name`; остальные
+    /// схемы (`string`, массив примитивов) не являются моделями - `None`.
+    fn body_schema(
+        &self,
+        schema: &Value,
+        inline_name: String,
+        mut metadata: std::collections::HashMap<String, String>,
     ) -> Option<SchemaReference> {
-        operation
-            .get("responses")?
-            .get("200")?
-            .get("content")?
-            .get("application/json")?
-            .get("schema")?
-            .get("$ref")
-            .and_then(|r| r.as_str())
-            .map(|s| SchemaReference {
-                name: s.to_string(),
-                schema_type: crate::models::SchemaType::OpenAPI,
-                location: crate::models::Location {
-                    file: String::new(),
-                    line: 0,
-                    column: None,
-                },
-                metadata: std::collections::HashMap::new(),
-            })
+        if let Some(reference) = schema.get("$ref").and_then(|r| r.as_str()) {
+            return Some(self.schema_reference(reference, metadata));
+        }
+
+        let non_null_member = ["anyOf", "oneOf"].iter().find_map(|key| {
+            let members = schema.get(*key)?.as_array()?;
+            let mut non_null = members
+                .iter()
+                .filter(|member| member.get("type").and_then(|t| t.as_str()) != Some("null"));
+            match (non_null.next(), non_null.next()) {
+                (Some(member), None) => Some(member),
+                _ => None,
+            }
+        });
+        if let Some(member) = non_null_member {
+            return self.body_schema(member, inline_name, metadata);
+        }
+        if schema.get("type").and_then(|t| t.as_str()) == Some("array") {
+            return self.body_schema(schema.get("items")?, inline_name, metadata);
+        }
+
+        let is_object = schema.get("type").and_then(|t| t.as_str()) == Some("object")
+            && schema.get("properties").is_some_and(|p| p.is_object());
+        if !is_object {
+            return None;
+        }
+
+        metadata.insert(
+            "json_schema".to_string(),
+            self.inline_refs(schema, &mut Vec::new()).to_string(),
+        );
+        metadata.insert(
+            crate::models::INLINE_SCHEMA_METADATA.to_string(),
+            "true".to_string(),
+        );
+        Some(SchemaReference {
+            name: inline_name,
+            schema_type: crate::models::SchemaType::OpenAPI,
+            location: crate::models::Location {
+                file: String::new(),
+                line: 0,
+                column: None,
+            },
+            metadata,
+        })
+    }
+
+    /// Ссылка на схему спецификации с разрешенной JSON схемой в metadata `json_schema`
+    ///
+    /// Имя схемы - исходная строка `$ref`. Если ссылку разрешить не удалось,
+    /// metadata остается без `json_schema`.
+    fn schema_reference(
+        &self,
+        reference: &str,
+        mut metadata: std::collections::HashMap<String, String>,
+    ) -> SchemaReference {
+        if let Some(schema) = self.resolve_ref(reference) {
+            metadata.insert("json_schema".to_string(), schema.to_string());
+        }
+        SchemaReference {
+            name: reference.to_string(),
+            schema_type: crate::models::SchemaType::OpenAPI,
+            location: crate::models::Location {
+                file: String::new(),
+                line: 0,
+                column: None,
+            },
+            metadata,
+        }
+    }
+
+    /// Разрешает локальную ссылку (`#/components/schemas/User`) в объект схемы
+    ///
+    /// Вложенные `$ref` (в `properties`, `items`, `allOf` и т.д.) подставляются
+    /// рекурсивно. Ссылка на схему, которая уже разрешается выше по цепочке
+    /// (цикл `User.friends: [User]`), остается как есть.
+    /// `None`, если ссылка не локальная или указывает в никуда.
+    pub fn resolve_ref(&self, reference: &str) -> Option<Value> {
+        self.resolve_ref_guarded(reference, &mut Vec::new())
+    }
+
+    fn resolve_ref_guarded(&self, reference: &str, resolving: &mut Vec<String>) -> Option<Value> {
+        let target = self.spec.pointer(reference.strip_prefix('#')?)?;
+        resolving.push(reference.to_string());
+        let resolved = self.inline_refs(target, resolving);
+        resolving.pop();
+        Some(resolved)
+    }
+
+    /// Копия значения с подставленными вложенными `$ref`
+    fn inline_refs(&self, value: &Value, resolving: &mut Vec<String>) -> Value {
+        match value {
+            Value::Object(object) => {
+                if let Some(reference) = object.get("$ref").and_then(|r| r.as_str()) {
+                    if resolving.iter().any(|outer| outer == reference) {
+                        return value.clone();
+                    }
+                    return self
+                        .resolve_ref_guarded(reference, resolving)
+                        .unwrap_or_else(|| value.clone());
+                }
+                Value::Object(
+                    object
+                        .iter()
+                        .map(|(key, item)| (key.clone(), self.inline_refs(item, resolving)))
+                        .collect(),
+                )
+            }
+            Value::Array(items) => Value::Array(
+                items
+                    .iter()
+                    .map(|item| self.inline_refs(item, resolving))
+                    .collect(),
+            ),
+            _ => value.clone(),
+        }
     }
 }
 
+/// Имя операции без `operationId`: метод и сегменты пути (`post /users/{id}` → `post_users_id`)
+fn operation_name(method: &str, path: &str) -> String {
+    std::iter::once(method)
+        .chain(
+            path.split('/')
+                .map(|segment| segment.trim_matches(|c| c == '{' || c == '}'))
+                .filter(|segment| !segment.is_empty()),
+        )
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Переводит YAML в JSON: ключи-числа (коды ответов `200:`) и ключи-булевы становятся строками
+fn yaml_to_json(value: serde_yaml::Value) -> Value {
+    match value {
+        serde_yaml::Value::Null => Value::Null,
+        serde_yaml::Value::Bool(b) => Value::Bool(b),
+        serde_yaml::Value::Number(number) => {
+            if let Some(i) = number.as_i64() {
+                Value::from(i)
+            } else if let Some(u) = number.as_u64() {
+                Value::from(u)
+            } else {
+                number.as_f64().map(Value::from).unwrap_or(Value::Null)
+            }
+        }
+        serde_yaml::Value::String(s) => Value::String(s),
+        serde_yaml::Value::Sequence(items) => {
+            Value::Array(items.into_iter().map(yaml_to_json).collect())
+        }
+        serde_yaml::Value::Mapping(mapping) => Value::Object(
+            mapping
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = match key {
+                        serde_yaml::Value::String(s) => s,
+                        serde_yaml::Value::Number(number) => number.to_string(),
+                        serde_yaml::Value::Bool(b) => b.to_string(),
+                        other => serde_yaml::to_string(&other)
+                            .unwrap_or_default()
+                            .trim()
+                            .to_string(),
+                    };
+                    (key, yaml_to_json(value))
+                })
+                .collect(),
+        ),
+        serde_yaml::Value::Tagged(tagged) => yaml_to_json(tagged.value),
+    }
+}
+
+/// Отмечает схему устаревшего эндпоинта ключом metadata `deprecated`
+fn mark_deprecated(mut schema: SchemaReference, deprecated: bool) -> SchemaReference {
+    if deprecated {
+        schema.metadata.insert(
+            crate::models::DEPRECATED_METADATA.to_string(),
+            "true".to_string(),
+        );
+    }
+    schema
+}
+
+/// Версия формата спецификации
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecVersion {
+    /// Swagger 2.0 (`swagger: "2.0"`)
+    Swagger2,
+    /// OpenAPI 3.x (`openapi: 3.0.0`, `3.1.0`)
+    OpenApi3,
+}
+
 /// API эндпоинт из OpenAPI спецификации
 #[derive(Debug, Clone)]
 pub struct ApiEndpoint {
@@ -109,6 +546,23 @@ pub struct ApiEndpoint {
     pub operation_id: Option<String>,
     pub request_schema: Option<SchemaReference>,
     pub response_schema: Option<SchemaReference>,
+    /// Код ответа, к которому относится `response_schema` (`"200"`, `"201"`, `"default"`)
+    pub status_code: String,
+    /// Параметры операции вместе с параметрами уровня пути
+    pub parameters: Vec<ApiParameter>,
+    /// Эндпоинт помечен `deprecated: true`
+    pub deprecated: bool,
+}
+
+/// Параметр операции OpenAPI
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiParameter {
+    pub name: String,
+    /// Где передается параметр: `path`, `query`, `header` или `cookie`
+    pub location: String,
+    pub required: bool,
+    /// Тип из `schema` параметра
+    pub type_info: TypeInfo,
 }
 
 #[cfg(test)]
@@ -197,6 +651,350 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_yaml_matches_json() {
+        let spec_yaml = r##"
+openapi: 3.0.0
+paths:
+  /users:
+    post:
+      operationId: createUser
+      responses:
+        201:
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/User"
+components:
+  schemas:
+    User:
+      type: object
+      properties:
+        id:
+          type: integer
+"##;
+
+        let parser = OpenApiParser::from_yaml(spec_yaml).unwrap();
+        let endpoints = parser.extract_endpoints();
+        assert_eq!(endpoints.len(), 1);
+        assert_eq!(endpoints[0].operation_id, Some("createUser".to_string()));
+        assert_eq!(endpoints[0].status_code, "201");
+        assert!(
+            endpoints[0].response_schema.as_ref().unwrap().metadata["json_schema"]
+                .contains("integer")
+        );
+    }
+
+    #[test]
+    fn test_parse_error_differs_from_missing_paths() {
+        let invalid = OpenApiParser::from_yaml("paths: [unclosed").unwrap_err();
+        assert!(invalid.to_string().contains("Invalid OpenAPI YAML"));
+
+        let no_paths =
+            OpenApiParser::from_yaml("openapi: 3.0.0\ninfo:\n  title: API\n").unwrap_err();
+        assert!(no_paths.to_string().contains("no `paths`"));
+    }
+
+    #[test]
+    fn test_from_path_picks_format_by_extension() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let yaml_path = dir.path().join("openapi.yml");
+        fs::write(
+            &yaml_path,
+            "openapi: 3.0.0\npaths:\n  /health:\n    get: {}\n",
+        )
+        .unwrap();
+        let json_path = dir.path().join("openapi.json");
+        fs::write(
+            &json_path,
+            r#"{"openapi": "3.0.0", "paths": {"/health": {"get": {}}}}"#,
+        )
+        .unwrap();
+
+        for path in [yaml_path, json_path] {
+            let endpoints = OpenApiParser::from_path(&path).unwrap().extract_endpoints();
+            assert_eq!(endpoints.len(), 1, "{}", path.display());
+        }
+        let error = OpenApiParser::from_path(&dir.path().join("missing.yaml")).unwrap_err();
+        assert!(error.to_string().contains("Failed to read"));
+    }
+
+    #[test]
+    fn test_path_item_parameters_apply_to_every_operation() {
+        let spec_json = json!({
+            "openapi": "3.0.0",
+            "paths": {
+                "/users/{user_id}": {
+                    "parameters": [
+                        {"name": "user_id", "in": "path", "schema": {"type": "integer"}},
+                        {"$ref": "#/components/parameters/Verbose"}
+                    ],
+                    "get": {
+                        "parameters": [
+                            {
+                                "name": "fields",
+                                "in": "query",
+                                "required": true,
+                                "schema": {"type": "string", "enum": ["short", "full"]}
+                            },
+                            {"name": "verbose", "in": "query", "required": true, "schema": {"type": "boolean"}}
+                        ],
+                        "responses": {"200": {"description": "Success"}}
+                    },
+                    "delete": {
+                        "responses": {"204": {"description": "Deleted"}}
+                    }
+                }
+            },
+            "components": {
+                "parameters": {
+                    "Verbose": {"name": "verbose", "in": "query", "schema": {"type": "boolean"}}
+                }
+            }
+        });
+
+        let parser = OpenApiParser::from_json(&spec_json.to_string()).unwrap();
+        let endpoints = parser.extract_endpoints();
+        let get = endpoints.iter().find(|e| e.method == "GET").unwrap();
+        let delete = endpoints.iter().find(|e| e.method == "DELETE").unwrap();
+
+        let summary = |endpoint: &ApiEndpoint| {
+            endpoint
+                .parameters
+                .iter()
+                .map(|p| (p.name.clone(), p.location.clone(), p.required))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            summary(delete),
+            vec![
+                ("user_id".to_string(), "path".to_string(), true),
+                ("verbose".to_string(), "query".to_string(), false),
+            ]
+        );
+        // `verbose` операции переопределяет параметр уровня пути
+        assert_eq!(
+            summary(get),
+            vec![
+                ("user_id".to_string(), "path".to_string(), true),
+                ("fields".to_string(), "query".to_string(), true),
+                ("verbose".to_string(), "query".to_string(), true),
+            ]
+        );
+        assert_eq!(get.parameters[0].type_info.base_type, BaseType::Integer);
+        assert_eq!(get.parameters[1].type_info.base_type, BaseType::Enum);
+        assert!(!get.parameters[1].type_info.optional);
+    }
+
+    #[test]
+    fn test_inline_request_body_gets_synthesized_name() {
+        let spec_json = json!({
+            "openapi": "3.0.0",
+            "paths": {
+                "/users": {
+                    "post": {
+                        "operationId": "createUser",
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "type": "object",
+                                        "properties": {
+                                            "name": {"type": "string"},
+                                            "age": {"type": "integer"}
+                                        },
+                                        "required": ["name"]
+                                    }
+                                }
+                            }
+                        },
+                        "responses": {"201": {"description": "Created"}}
+                    }
+                },
+                "/users/{user_id}": {
+                    "put": {
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "schema": {"type": "object", "properties": {}}
+                                }
+                            }
+                        },
+                        "responses": {"200": {"description": "Success"}}
+                    }
+                }
+            }
+        });
+
+        let parser = OpenApiParser::from_json(&spec_json.to_string()).unwrap();
+        let endpoints = parser.extract_endpoints();
+        let create = endpoints.iter().find(|e| e.method == "POST").unwrap();
+        let request = create.request_schema.as_ref().unwrap();
+        assert_eq!(request.name, "createUserRequest");
+        assert_eq!(
+            request.metadata[crate::models::INLINE_SCHEMA_METADATA],
+            "true"
+        );
+
+        let parsed = crate::analyzers::SchemaParser::parse(request).unwrap();
+        assert_eq!(parsed.properties.len(), 2);
+        assert_eq!(parsed.required, vec!["name".to_string()]);
+
+        let update = endpoints.iter().find(|e| e.method == "PUT").unwrap();
+        assert_eq!(
+            update.request_schema.as_ref().unwrap().name,
+            "put_users_user_idRequest"
+        );
+    }
+
+    #[test]
+    fn test_nullable_and_array_bodies_unwrap_to_reference() {
+        let spec_json = json!({
+            "openapi": "3.0.0",
+            "paths": {
+                "/users": {
+                    "post": {
+                        "operationId": "createUser",
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "anyOf": [
+                                            {"$ref": "#/components/schemas/User"},
+                                            {"type": "null"}
+                                        ]
+                                    }
+                                }
+                            }
+                        },
+                        "responses": {
+                            "200": {
+                                "description": "Success",
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "type": "array",
+                                            "items": {"$ref": "#/components/schemas/User"}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    "get": {
+                        "responses": {
+                            "200": {
+                                "description": "Success",
+                                "content": {
+                                    "application/json": {
+                                        "schema": {"type": "array", "items": {"type": "string"}}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "User": {
+                        "type": "object",
+                        "properties": {"name": {"type": "string"}},
+                        "required": ["name"]
+                    }
+                }
+            }
+        });
+
+        let parser = OpenApiParser::from_json(&spec_json.to_string()).unwrap();
+        let endpoints = parser.extract_endpoints();
+        let create = endpoints.iter().find(|e| e.method == "POST").unwrap();
+
+        let request = create.request_schema.as_ref().unwrap();
+        assert_eq!(request.name, "#/components/schemas/User");
+        assert!(!request
+            .metadata
+            .contains_key(crate::models::INLINE_SCHEMA_METADATA));
+        let response = create.response_schema.as_ref().unwrap();
+        assert_eq!(response.name, "#/components/schemas/User");
+        let parsed = crate::analyzers::SchemaParser::parse(response).unwrap();
+        assert_eq!(parsed.required, vec!["name".to_string()]);
+
+        // A list of strings is not a model
+        let list = endpoints.iter().find(|e| e.method == "GET").unwrap();
+        assert!(list.response_schema.is_none());
+    }
+
+    #[test]
+    fn test_spec_version_detection() {
+        let version = |spec: Value| {
+            OpenApiParser::from_json(&spec.to_string())
+                .unwrap()
+                .spec_version()
+        };
+        assert_eq!(
+            version(json!({"swagger": "2.0", "paths": {}})),
+            SpecVersion::Swagger2
+        );
+        assert_eq!(
+            version(json!({"openapi": "3.1.0", "paths": {}})),
+            SpecVersion::OpenApi3
+        );
+        assert_eq!(version(json!({"paths": {}})), SpecVersion::OpenApi3);
+    }
+
+    #[test]
+    fn test_swagger2_body_parameter_and_response_schema() {
+        let spec_json = json!({
+            "swagger": "2.0",
+            "paths": {
+                "/users": {
+                    "post": {
+                        "operationId": "createUser",
+                        "parameters": [
+                            {
+                                "name": "user",
+                                "in": "body",
+                                "required": true,
+                                "schema": {"$ref": "#/definitions/User"}
+                            },
+                            {"name": "notify", "in": "query", "type": "boolean"}
+                        ],
+                        "responses": {
+                            "201": {"schema": {"$ref": "#/definitions/User"}}
+                        }
+                    }
+                }
+            },
+            "definitions": {
+                "User": {
+                    "type": "object",
+                    "properties": {"name": {"type": "string"}},
+                    "required": ["name"]
+                }
+            }
+        });
+
+        let parser = OpenApiParser::from_json(&spec_json.to_string()).unwrap();
+        let endpoint = &parser.extract_endpoints()[0];
+
+        let request = endpoint.request_schema.as_ref().unwrap();
+        assert_eq!(request.name, "#/definitions/User");
+        assert_eq!(request.metadata["body_required"], "true");
+        assert!(request.metadata["json_schema"].contains("\"name\""));
+        assert_eq!(endpoint.status_code, "201");
+        assert_eq!(
+            endpoint.response_schema.as_ref().unwrap().name,
+            "#/definitions/User"
+        );
+        assert_eq!(endpoint.parameters.len(), 1);
+        assert_eq!(endpoint.parameters[0].name, "notify");
+        assert_eq!(
+            endpoint.parameters[0].type_info.base_type,
+            BaseType::Boolean
+        );
+    }
+
     #[test]
     fn test_empty_paths() {
         let spec_json = json!({
@@ -248,4 +1046,207 @@ mod tests {
         assert_eq!(endpoints.len(), 1);
         assert_eq!(endpoints[0].method, "GET");
     }
+
+    fn request_contract(spec_json: Value) -> crate::models::Contract {
+        let parser = OpenApiParser::from_json(&spec_json.to_string()).unwrap();
+        let endpoints = parser.extract_endpoints();
+        let mut to_schema = endpoints[0].request_schema.clone().unwrap();
+        // Схема тела запроса (в реальном анализе берется из components)
+        to_schema.metadata.insert(
+            "json_schema".to_string(),
+            json!({
+                "type": "object",
+                "properties": {"name": {"type": "string"}},
+                "required": ["name"]
+            })
+            .to_string(),
+        );
+
+        let mut from_schema = to_schema.clone();
+        from_schema.name = "EmptyBody".to_string();
+        from_schema.metadata.clear();
+        from_schema.metadata.insert(
+            "json_schema".to_string(),
+            json!({"type": "object", "properties": {}}).to_string(),
+        );
+
+        crate::models::Contract {
+            from_link_id: "frontend".to_string(),
+            to_link_id: "backend".to_string(),
+            from_schema,
+            to_schema,
+            mismatches: Vec::new(),
+            severity: crate::models::Severity::Info,
+            status_code: None,
+        }
+    }
+
+    fn spec_with_request_body(required: bool) -> Value {
+        json!({
+            "openapi": "3.0.0",
+            "paths": {
+                "/users/search": {
+                    "post": {
+                        "requestBody": {
+                            "required": required,
+                            "content": {
+                                "application/json": {
+                                    "schema": {"$ref": "#/components/schemas/UserFilter"}
+                                }
+                            }
+                        },
+                        "responses": {"200": {"description": "Success"}}
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_ref_is_resolved_into_json_schema() {
+        let spec_json = json!({
+            "openapi": "3.0.0",
+            "paths": {
+                "/users": {
+                    "get": {
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {"$ref": "#/components/schemas/User"}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "User": {
+                        "type": "object",
+                        "properties": {
+                            "name": {"type": "string"},
+                            "address": {"$ref": "#/components/schemas/Address"},
+                            "friends": {
+                                "type": "array",
+                                "items": {"$ref": "#/components/schemas/User"}
+                            }
+                        },
+                        "required": ["name"]
+                    },
+                    "Address": {
+                        "type": "object",
+                        "properties": {"city": {"type": "string"}}
+                    }
+                }
+            }
+        });
+
+        let parser = OpenApiParser::from_json(&spec_json.to_string()).unwrap();
+        let response = parser.extract_endpoints()[0]
+            .response_schema
+            .clone()
+            .unwrap();
+        assert_eq!(response.name, "#/components/schemas/User");
+
+        let schema: Value = serde_json::from_str(&response.metadata["json_schema"]).unwrap();
+        assert_eq!(
+            schema["properties"]["address"]["properties"]["city"]["type"],
+            "string"
+        );
+        // Цикл: ссылка на саму схему не разворачивается
+        assert_eq!(
+            schema["properties"]["friends"]["items"]["$ref"],
+            "#/components/schemas/User"
+        );
+
+        let parsed = crate::analyzers::SchemaParser::parse(&response).unwrap();
+        assert!(parsed.properties.contains_key("name"));
+        assert_eq!(parsed.required, vec!["name".to_string()]);
+        assert!(parser.resolve_ref("#/components/schemas/Missing").is_none());
+    }
+
+    fn spec_with_responses(responses: Value) -> Value {
+        json!({
+            "openapi": "3.0.0",
+            "paths": {"/users": {"post": {"responses": responses}}},
+            "components": {
+                "schemas": {
+                    "User": {"type": "object", "properties": {"id": {"type": "integer"}}}
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_created_response_is_used_without_200() {
+        let user = json!({
+            "content": {
+                "application/json": {"schema": {"$ref": "#/components/schemas/User"}}
+            }
+        });
+        let spec_json = spec_with_responses(json!({
+            "422": {"description": "Validation Error"},
+            "201": user,
+        }));
+
+        let parser = OpenApiParser::from_json(&spec_json.to_string()).unwrap();
+        let endpoint = &parser.extract_endpoints()[0];
+        assert_eq!(endpoint.status_code, "201");
+        assert_eq!(
+            endpoint.response_schema.as_ref().unwrap().name,
+            "#/components/schemas/User"
+        );
+    }
+
+    #[test]
+    fn test_default_response_is_the_fallback() {
+        let spec_json = spec_with_responses(json!({
+            "default": {
+                "content": {
+                    "application/json": {"schema": {"$ref": "#/components/schemas/User"}}
+                }
+            },
+            "404": {"description": "Not Found"},
+        }));
+
+        let parser = OpenApiParser::from_json(&spec_json.to_string()).unwrap();
+        let endpoint = &parser.extract_endpoints()[0];
+        assert_eq!(endpoint.status_code, "default");
+        assert!(endpoint.response_schema.is_some());
+
+        let no_content = spec_with_responses(json!({"204": {"description": "Deleted"}}));
+        let parser = OpenApiParser::from_json(&no_content.to_string()).unwrap();
+        let endpoint = &parser.extract_endpoints()[0];
+        assert_eq!(endpoint.status_code, "204");
+        assert!(endpoint.response_schema.is_none());
+    }
+
+    #[test]
+    fn test_optional_request_body_skips_missing_fields() {
+        use crate::analyzers::{ContractRule, MissingFieldRule};
+
+        let contract = request_contract(spec_with_request_body(false));
+        assert_eq!(
+            contract.to_schema.metadata.get("body_required"),
+            Some(&"false".to_string())
+        );
+        assert!(MissingFieldRule::default().check(&contract).is_empty());
+
+        let contract = request_contract(spec_with_request_body(true));
+        assert_eq!(MissingFieldRule::default().check(&contract).len(), 1);
+
+        // Без `required` тело запроса необязательно
+        let mut spec = spec_with_request_body(true);
+        spec["paths"]["/users/search"]["post"]["requestBody"]
+            .as_object_mut()
+            .unwrap()
+            .remove("required");
+        let contract = request_contract(spec);
+        assert_eq!(
+            contract.to_schema.metadata.get("body_required"),
+            Some(&"false".to_string())
+        );
+    }
 }
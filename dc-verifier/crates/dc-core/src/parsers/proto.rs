@@ -0,0 +1,752 @@
+use crate::models::{Location, SchemaReference, SchemaType};
+use crate::parsers::ApiEndpoint;
+use anyhow::{bail, Context, Result};
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Парсер `.proto` файлов (gRPC сервисы и сообщения protobuf)
+pub struct ProtoParser;
+
+/// Разобранный `.proto` файл
+#[derive(Debug, Clone)]
+pub struct ProtoFile {
+    /// Пакет (`package users.v1;`)
+    pub package: Option<String>,
+    /// gRPC сервисы
+    pub services: Vec<ProtoService>,
+    /// Сообщения в виде схем (`SchemaType::Protobuf`, JSON Schema в metadata)
+    pub messages: Vec<SchemaReference>,
+}
+
+/// gRPC сервис
+#[derive(Debug, Clone)]
+pub struct ProtoService {
+    /// Имя сервиса
+    pub name: String,
+    /// Методы сервиса
+    pub rpcs: Vec<ProtoRpc>,
+    /// Расположение в файле
+    pub location: Location,
+}
+
+/// Метод gRPC сервиса (`rpc GetUser(GetUserRequest) returns (User);`)
+#[derive(Debug, Clone)]
+pub struct ProtoRpc {
+    /// Имя метода
+    pub name: String,
+    /// Тип сообщения запроса
+    pub request_type: String,
+    /// Тип сообщения ответа
+    pub response_type: String,
+    /// Клиентский поток (`stream` у запроса)
+    pub client_streaming: bool,
+    /// Серверный поток (`stream` у ответа)
+    pub server_streaming: bool,
+    /// Расположение в файле
+    pub location: Location,
+}
+
+/// Токен `.proto` файла с номером строки
+#[derive(Debug, Clone)]
+struct Token {
+    text: String,
+    line: usize,
+}
+
+/// Сообщение до преобразования в JSON Schema
+struct RawMessage {
+    /// Имя сообщения
+    name: String,
+    /// Полное имя внутри файла (`Outer.Inner` для вложенного сообщения)
+    scope: String,
+    line: usize,
+    fields: Vec<ProtoField>,
+}
+
+/// Поле сообщения до преобразования в JSON Schema
+struct ProtoField {
+    name: String,
+    label: Option<String>,
+    field_type: FieldType,
+}
+
+enum FieldType {
+    /// Скалярный тип, сообщение или перечисление
+    Scalar(String),
+    /// `map<K, V>` - в JSON это объект, ключи всегда строки; хранится тип значения
+    Map(String),
+}
+
+impl ProtoParser {
+    /// Создает новый парсер
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Читает и парсит `.proto` файл
+    pub fn from_path(&self, path: &Path) -> Result<ProtoFile> {
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read proto file: {}", path.display()))?;
+        self.parse(&source, &path.to_string_lossy())
+            .with_context(|| format!("Failed to parse proto file: {}", path.display()))
+    }
+
+    /// Парсит содержимое `.proto` файла
+    pub fn parse(&self, source: &str, file: &str) -> Result<ProtoFile> {
+        let tokens = tokenize(source);
+        let mut cursor = Cursor {
+            tokens: &tokens,
+            pos: 0,
+        };
+
+        let mut package = None;
+        let mut services = Vec::new();
+        let mut raw_messages = Vec::new();
+        let mut enums = HashMap::new();
+
+        while let Some(token) = cursor.next() {
+            match token.text.as_str() {
+                "package" => {
+                    package = Some(cursor.expect_ident()?);
+                    cursor.skip_statement();
+                }
+                "service" => services.push(parse_service(&mut cursor, file, token.line)?),
+                "message" => {
+                    parse_message(&mut cursor, token.line, "", &mut raw_messages, &mut enums)?
+                }
+                "enum" => {
+                    let (name, values) = parse_enum(&mut cursor)?;
+                    enums.insert(name, values);
+                }
+                "{" => cursor.skip_block()?,
+                ";" => {}
+                // syntax, import, option и прочие инструкции верхнего уровня
+                _ => cursor.skip_statement(),
+            }
+        }
+
+        let message_names: HashSet<&str> = raw_messages
+            .iter()
+            .map(|message| message.name.as_str())
+            .collect();
+        let types = TypeScope {
+            package: package.as_deref(),
+            messages: &message_names,
+            enums: &enums,
+        };
+        let messages = raw_messages
+            .iter()
+            .map(|message| {
+                let schema = message_json_schema(message, &types);
+                let mut metadata = HashMap::new();
+                metadata.insert("json_schema".to_string(), schema.to_string());
+                if let Some(package) = &package {
+                    metadata.insert("package".to_string(), package.clone());
+                }
+                SchemaReference {
+                    name: message.name.clone(),
+                    schema_type: SchemaType::Protobuf,
+                    location: Location {
+                        file: file.to_string(),
+                        line: message.line,
+                        column: None,
+                    },
+                    metadata,
+                }
+            })
+            .collect();
+
+        Ok(ProtoFile {
+            package,
+            services,
+            messages,
+        })
+    }
+}
+
+impl Default for ProtoParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProtoFile {
+    /// Представляет методы сервисов как эндпоинты
+    ///
+    /// Путь соответствует HTTP/2 пути gRPC: `/package.Service/Method`, метод - `POST`.
+    /// Схемы запроса и ответа - сообщения этого же файла (если найдены).
+    pub fn endpoints(&self) -> Vec<ApiEndpoint> {
+        let find_message = |type_name: &str| {
+            let short_name = type_name.rsplit('.').next().unwrap_or(type_name);
+            self.messages
+                .iter()
+                .find(|message| message.name == short_name)
+                .cloned()
+        };
+
+        self.services
+            .iter()
+            .flat_map(|service| {
+                let service_path = match &self.package {
+                    Some(package) => format!("{}.{}", package, service.name),
+                    None => service.name.clone(),
+                };
+                service.rpcs.iter().map(move |rpc| ApiEndpoint {
+                    path: format!("/{}/{}", service_path, rpc.name),
+                    method: "POST".to_string(),
+                    operation_id: Some(rpc.name.clone()),
+                    request_schema: find_message(&rpc.request_type),
+                    response_schema: find_message(&rpc.response_type),
+                    status_code: "200".to_string(),
+                    parameters: Vec::new(),
+                    deprecated: false,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Разбивает исходник на токены, отбрасывая комментарии
+fn tokenize(source: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = source.chars().peekable();
+    let mut line = 1;
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\n' => line += 1,
+            c if c.is_whitespace() => {}
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        line += 1;
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            '"' | '\'' => {
+                let mut text = String::from(ch);
+                for c in chars.by_ref() {
+                    text.push(c);
+                    if c == ch {
+                        break;
+                    }
+                }
+                tokens.push(Token { text, line });
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' => {
+                let mut text = String::from(c);
+                while let Some(&next) = chars.peek() {
+                    if next.is_alphanumeric() || next == '_' || next == '.' {
+                        text.push(next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(Token { text, line });
+            }
+            c => tokens.push(Token {
+                text: c.to_string(),
+                line,
+            }),
+        }
+    }
+
+    tokens
+}
+
+/// Курсор по токенам
+struct Cursor<'t> {
+    tokens: &'t [Token],
+    pos: usize,
+}
+
+impl<'t> Cursor<'t> {
+    fn next(&mut self) -> Option<&'t Token> {
+        let token = self.tokens.get(self.pos)?;
+        self.pos += 1;
+        Some(token)
+    }
+
+    fn peek(&self) -> Option<&'t str> {
+        self.tokens.get(self.pos).map(|token| token.text.as_str())
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<()> {
+        match self.next() {
+            Some(token) if token.text == expected => Ok(()),
+            Some(token) => bail!(
+                "line {}: expected '{}', found '{}'",
+                token.line,
+                expected,
+                token.text
+            ),
+            None => bail!("unexpected end of file, expected '{}'", expected),
+        }
+    }
+
+    fn expect_ident(&mut self) -> Result<String> {
+        match self.next() {
+            Some(token)
+                if token
+                    .text
+                    .starts_with(|c: char| c.is_alphabetic() || c == '_' || c == '.') =>
+            {
+                Ok(token.text.clone())
+            }
+            Some(token) => bail!(
+                "line {}: expected identifier, found '{}'",
+                token.line,
+                token.text
+            ),
+            None => bail!("unexpected end of file, expected identifier"),
+        }
+    }
+
+    /// Пропускает инструкцию до `;` (или блок `{ ... }`, если он встретился раньше)
+    fn skip_statement(&mut self) {
+        while let Some(token) = self.next() {
+            match token.text.as_str() {
+                ";" => return,
+                "{" => {
+                    let _ = self.skip_block();
+                    return;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Пропускает тело блока после уже прочитанной `{`
+    fn skip_block(&mut self) -> Result<()> {
+        let mut depth = 1;
+        while let Some(token) = self.next() {
+            match token.text.as_str() {
+                "{" => depth += 1,
+                "}" => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                _ => {}
+            }
+        }
+        bail!("unexpected end of file: unclosed block")
+    }
+}
+
+/// `service Name { rpc ... }`
+fn parse_service(cursor: &mut Cursor, file: &str, line: usize) -> Result<ProtoService> {
+    let name = cursor.expect_ident()?;
+    cursor.expect("{")?;
+
+    let mut rpcs = Vec::new();
+    while let Some(token) = cursor.next() {
+        match token.text.as_str() {
+            "}" => {
+                return Ok(ProtoService {
+                    name,
+                    rpcs,
+                    location: Location {
+                        file: file.to_string(),
+                        line,
+                        column: None,
+                    },
+                })
+            }
+            "rpc" => {
+                let rpc_name = cursor.expect_ident()?;
+                let (request_type, client_streaming) = parse_rpc_type(cursor)?;
+                cursor.expect("returns")?;
+                let (response_type, server_streaming) = parse_rpc_type(cursor)?;
+                // `;` или блок опций `{ option ...; }`
+                cursor.skip_statement();
+                rpcs.push(ProtoRpc {
+                    name: rpc_name,
+                    request_type,
+                    response_type,
+                    client_streaming,
+                    server_streaming,
+                    location: Location {
+                        file: file.to_string(),
+                        line: token.line,
+                        column: None,
+                    },
+                });
+            }
+            ";" => {}
+            _ => cursor.skip_statement(),
+        }
+    }
+    bail!("unexpected end of file in service {}", name)
+}
+
+/// `(stream Type)` → (Type, stream)
+fn parse_rpc_type(cursor: &mut Cursor) -> Result<(String, bool)> {
+    cursor.expect("(")?;
+    let mut streaming = false;
+    let mut type_name = cursor.expect_ident()?;
+    if type_name == "stream" && cursor.peek() != Some(")") {
+        streaming = true;
+        type_name = cursor.expect_ident()?;
+    }
+    cursor.expect(")")?;
+    Ok((type_name, streaming))
+}
+
+/// `message Name { ... }`, вложенные сообщения добавляются отдельно
+///
+/// `parent` - полное имя внешнего сообщения (пусто на верхнем уровне)
+fn parse_message(
+    cursor: &mut Cursor,
+    line: usize,
+    parent: &str,
+    messages: &mut Vec<RawMessage>,
+    enums: &mut HashMap<String, Vec<String>>,
+) -> Result<()> {
+    let name = cursor.expect_ident()?;
+    cursor.expect("{")?;
+
+    let scope = qualify(parent, &name);
+    let mut fields = Vec::new();
+    parse_message_body(cursor, &scope, &mut fields, messages, enums)?;
+    messages.push(RawMessage {
+        name,
+        scope,
+        line,
+        fields,
+    });
+    Ok(())
+}
+
+fn parse_message_body(
+    cursor: &mut Cursor,
+    scope: &str,
+    fields: &mut Vec<ProtoField>,
+    messages: &mut Vec<RawMessage>,
+    enums: &mut HashMap<String, Vec<String>>,
+) -> Result<()> {
+    while let Some(token) = cursor.next() {
+        match token.text.as_str() {
+            "}" => return Ok(()),
+            ";" => {}
+            "message" => parse_message(cursor, token.line, scope, messages, enums)?,
+            // Вложенное перечисление доступно как `Outer.Inner`
+            "enum" => {
+                let (name, values) = parse_enum(cursor)?;
+                enums.insert(qualify(scope, &name), values);
+            }
+            // Поля oneof - обычные необязательные поля сообщения
+            "oneof" => {
+                cursor.expect_ident()?;
+                cursor.expect("{")?;
+                parse_message_body(cursor, scope, fields, messages, enums)?;
+            }
+            "option" | "reserved" | "extensions" | "extend" => cursor.skip_statement(),
+            "map" => {
+                cursor.expect("<")?;
+                // Тип ключа
+                cursor.expect_ident()?;
+                cursor.expect(",")?;
+                let value = cursor.expect_ident()?;
+                cursor.expect(">")?;
+                let name = cursor.expect_ident()?;
+                cursor.skip_statement();
+                fields.push(ProtoField {
+                    name,
+                    label: None,
+                    field_type: FieldType::Map(value),
+                });
+            }
+            label @ ("repeated" | "optional" | "required") => {
+                let field_type = cursor.expect_ident()?;
+                let name = cursor.expect_ident()?;
+                cursor.skip_statement();
+                fields.push(ProtoField {
+                    name,
+                    label: Some(label.to_string()),
+                    field_type: FieldType::Scalar(field_type),
+                });
+            }
+            _ => {
+                let name = cursor.expect_ident()?;
+                cursor.skip_statement();
+                fields.push(ProtoField {
+                    name,
+                    label: None,
+                    field_type: FieldType::Scalar(token.text.clone()),
+                });
+            }
+        }
+    }
+    bail!("unexpected end of file in message body")
+}
+
+/// `enum Name { A = 0; B = 1; }` → (Name, [A, B])
+fn parse_enum(cursor: &mut Cursor) -> Result<(String, Vec<String>)> {
+    let name = cursor.expect_ident()?;
+    cursor.expect("{")?;
+
+    let mut values = Vec::new();
+    while let Some(token) = cursor.next() {
+        match token.text.as_str() {
+            "}" => return Ok((name, values)),
+            ";" => {}
+            "option" | "reserved" => cursor.skip_statement(),
+            value => {
+                values.push(value.to_string());
+                cursor.skip_statement();
+            }
+        }
+    }
+    bail!("unexpected end of file in enum {}", name)
+}
+
+/// `Outer.Inner` из имени внешнего сообщения и вложенного имени
+fn qualify(parent: &str, name: &str) -> String {
+    if parent.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", parent, name)
+    }
+}
+
+/// Типы, объявленные в файле: для разрешения типов полей
+struct TypeScope<'a> {
+    package: Option<&'a str>,
+    messages: &'a HashSet<&'a str>,
+    /// Перечисления по полному имени внутри файла (`User.Status`)
+    enums: &'a HashMap<String, Vec<String>>,
+}
+
+impl TypeScope<'_> {
+    /// Значения перечисления, на которое ссылается поле сообщения `scope`
+    ///
+    /// Как в protoc, имя ищется от самой вложенной области к верхнему уровню.
+    fn find_enum(&self, type_name: &str, scope: &str) -> Option<&Vec<String>> {
+        // Имя с пакетом (`users.v1.Status`) указывает на тип верхнего уровня файла
+        let type_name = type_name.trim_start_matches('.');
+        if let Some(name) = self
+            .package
+            .and_then(|package| type_name.strip_prefix(package))
+            .and_then(|name| name.strip_prefix('.'))
+        {
+            return self.enums.get(name);
+        }
+
+        let mut scope = Some(scope);
+        while let Some(current) = scope.filter(|current| !current.is_empty()) {
+            if let Some(values) = self.enums.get(&qualify(current, type_name)) {
+                return Some(values);
+            }
+            scope = current.rsplit_once('.').map(|(parent, _)| parent);
+        }
+        self.enums.get(type_name)
+    }
+}
+
+/// JSON Schema сообщения
+///
+/// Поля proto3 необязательны (имеют значения по умолчанию), обязательны только
+/// поля с меткой `required` (proto2).
+fn message_json_schema(message: &RawMessage, types: &TypeScope) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+
+    for field in &message.fields {
+        let schema = match &field.field_type {
+            FieldType::Map(value) => json!({
+                "type": "object",
+                "additionalProperties": scalar_json_schema(value, &message.scope, types),
+            }),
+            FieldType::Scalar(type_name) if field.label.as_deref() == Some("repeated") => json!({
+                "type": "array",
+                "items": scalar_json_schema(type_name, &message.scope, types),
+            }),
+            FieldType::Scalar(type_name) => scalar_json_schema(type_name, &message.scope, types),
+        };
+        properties.insert(field.name.clone(), schema);
+        if field.label.as_deref() == Some("required") {
+            required.push(field.name.clone());
+        }
+    }
+
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+}
+
+/// JSON Schema типа поля (по JSON-отображению protobuf)
+///
+/// 64-битные целые в JSON передаются строками (`"id": "42"`).
+fn scalar_json_schema(type_name: &str, scope: &str, types: &TypeScope) -> Value {
+    let short_name = type_name.rsplit('.').next().unwrap_or(type_name);
+    match type_name {
+        "double" | "float" => json!({"type": "number"}),
+        "int32" | "uint32" | "sint32" | "fixed32" | "sfixed32" => json!({"type": "integer"}),
+        "int64" | "uint64" | "sint64" | "fixed64" | "sfixed64" => {
+            json!({"type": "string", "format": type_name})
+        }
+        "bool" => json!({"type": "boolean"}),
+        "string" | "bytes" => json!({"type": "string"}),
+        "google.protobuf.Timestamp" => json!({"type": "string", "format": "date-time"}),
+        _ => match types.find_enum(type_name, scope) {
+            Some(values) => json!({"type": "string", "enum": values}),
+            None if types.messages.contains(short_name) => {
+                json!({"type": "object", "title": short_name})
+            }
+            None => json!({"type": "object"}),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::SchemaParser;
+    use crate::models::BaseType;
+
+    #[test]
+    fn test_parse_service_and_message() {
+        let source = r#"
+syntax = "proto3";
+
+package users.v1;
+
+// Сервис пользователей
+service UserService {
+  rpc GetUser(GetUserRequest) returns (User);
+}
+
+message GetUserRequest {
+  int64 id = 1;
+}
+
+message User {
+  int64 id = 1;
+  string name = 2;
+  repeated string tags = 3;
+  bool active = 4 [deprecated = true];
+  Status status = 5;
+
+  enum Status {
+    UNKNOWN = 0;
+    ACTIVE = 1;
+  }
+}
+"#;
+        let proto = ProtoParser::new().parse(source, "users.proto").unwrap();
+
+        assert_eq!(proto.package.as_deref(), Some("users.v1"));
+        assert_eq!(proto.services.len(), 1);
+        assert_eq!(proto.services[0].name, "UserService");
+        assert_eq!(proto.services[0].rpcs.len(), 1);
+        let rpc = &proto.services[0].rpcs[0];
+        assert_eq!(rpc.name, "GetUser");
+        assert_eq!(rpc.request_type, "GetUserRequest");
+        assert_eq!(rpc.response_type, "User");
+
+        let endpoints = proto.endpoints();
+        assert_eq!(endpoints.len(), 1);
+        assert_eq!(endpoints[0].path, "/users.v1.UserService/GetUser");
+        assert_eq!(endpoints[0].method, "POST");
+        assert_eq!(
+            endpoints[0]
+                .response_schema
+                .as_ref()
+                .map(|s| s.name.as_str()),
+            Some("User")
+        );
+
+        let user = proto
+            .messages
+            .iter()
+            .find(|message| message.name == "User")
+            .unwrap();
+        assert_eq!(user.schema_type, SchemaType::Protobuf);
+        let schema = SchemaParser::parse(user).unwrap();
+        // int64 is a string in the protobuf JSON mapping
+        assert_eq!(schema.properties["id"].base_type, BaseType::String);
+        assert_eq!(schema.properties["name"].base_type, BaseType::String);
+        assert_eq!(schema.properties["tags"].base_type, BaseType::Array);
+        assert_eq!(schema.properties["active"].base_type, BaseType::Boolean);
+        assert_eq!(schema.properties["status"].base_type, BaseType::Enum);
+    }
+
+    #[test]
+    fn test_nested_enums_are_qualified_by_message() {
+        let source = r#"
+syntax = "proto3";
+
+package orders.v1;
+
+enum Status {
+  STATUS_UNKNOWN = 0;
+}
+
+message Order {
+  enum Status {
+    PENDING = 0;
+    SHIPPED = 1;
+  }
+  Status status = 1;
+  uint64 total = 2;
+  fixed32 count = 3;
+}
+
+message Refund {
+  enum Status {
+    REQUESTED = 0;
+  }
+  Status status = 1;
+  Order.Status order_status = 2;
+  orders.v1.Status global = 3;
+}
+"#;
+        let proto = ProtoParser::new().parse(source, "orders.proto").unwrap();
+        let message_schema = |name: &str| {
+            let message = proto.messages.iter().find(|m| m.name == name).unwrap();
+            serde_json::from_str::<Value>(&message.metadata["json_schema"]).unwrap()
+        };
+
+        let order = message_schema("Order");
+        assert_eq!(
+            order["properties"]["status"]["enum"],
+            json!(["PENDING", "SHIPPED"])
+        );
+        assert_eq!(
+            order["properties"]["total"],
+            json!({"type": "string", "format": "uint64"})
+        );
+        assert_eq!(order["properties"]["count"]["type"], "integer");
+
+        let refund = message_schema("Refund");
+        assert_eq!(refund["properties"]["status"]["enum"], json!(["REQUESTED"]));
+        assert_eq!(
+            refund["properties"]["order_status"]["enum"],
+            json!(["PENDING", "SHIPPED"])
+        );
+        assert_eq!(
+            refund["properties"]["global"]["enum"],
+            json!(["STATUS_UNKNOWN"])
+        );
+    }
+}
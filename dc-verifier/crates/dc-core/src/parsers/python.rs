@@ -4,12 +4,150 @@ use rustpython_parser::ast::Ranged;
 use std::path::Path;
 
 use crate::call_graph::CallNode;
-use crate::models::Location;
+use crate::models::{BaseType, Constraint, ConstraintValue, Location};
 use crate::parsers::{Call, CallArgument, Import, LocationConverter};
 
 /// Парсер Python кода с анализом вызовов
 pub struct PythonParser;
 
+/// Область видимости при обходе вызовов: функция или класс
+struct Scope {
+    name: String,
+    is_class: bool,
+}
+
+/// Тип Pydantic с ограничениями в самой аннотации (`constr(max_length=10)`, `conint(ge=0)`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstrainedType {
+    /// Имя базового Python типа (`str`, `int`, `float`, `list`)
+    pub type_name: &'static str,
+    /// Базовый тип
+    pub base_type: BaseType,
+    /// Ограничения из именованных аргументов конструктора
+    pub constraints: Vec<Constraint>,
+}
+
+/// Распознает конструктор типа с ограничениями Pydantic в аннотации
+///
+/// Поддерживаются `constr`, `conbytes`, `conint`, `confloat`, `condecimal`, `conlist`,
+/// `conset` и `confrozenset` (в том числе внутри `Optional[...]` и `... | None`).
+/// `ge`/`gt` дают `Min`, `le`/`lt` - `Max` (строгие границы целых сдвигаются на 1),
+/// `min_length`/`max_length` (и `min_items`/`max_items`) - `Min`/`Max` длины,
+/// `regex`/`pattern` - `Pattern`; `conset`/`confrozenset` получают `UniqueItems`.
+pub fn constrained_type(annotation: &ast::Expr) -> Option<ConstrainedType> {
+    let call = match annotation {
+        ast::Expr::Call(call) => call,
+        ast::Expr::Subscript(sub) if matches!(sub.value.as_ref(), ast::Expr::Name(name) if name.id.as_str() == "Optional") =>
+        {
+            return constrained_type(&sub.slice);
+        }
+        ast::Expr::BinOp(bin_op) if matches!(bin_op.op, ast::Operator::BitOr) => {
+            return constrained_type(&bin_op.left).or_else(|| constrained_type(&bin_op.right));
+        }
+        _ => return None,
+    };
+
+    let name = match call.func.as_ref() {
+        ast::Expr::Name(name) => name.id.as_str(),
+        ast::Expr::Attribute(attr) => attr.attr.as_str(),
+        _ => return None,
+    };
+    let (type_name, base_type) = match name {
+        "constr" => ("str", BaseType::String),
+        "conbytes" => ("bytes", BaseType::String),
+        "conint" => ("int", BaseType::Integer),
+        "confloat" | "condecimal" => ("float", BaseType::Number),
+        "conlist" | "conset" | "confrozenset" => ("list", BaseType::Array),
+        _ => return None,
+    };
+
+    // Элементы множества уникальны
+    let mut constraints = Vec::new();
+    if matches!(name, "conset" | "confrozenset") {
+        constraints.push(Constraint::UniqueItems);
+    }
+    for keyword in &call.keywords {
+        let Some(arg) = keyword.arg.as_deref() else {
+            continue;
+        };
+        let constraint = match arg {
+            "ge" | "gt" | "le" | "lt" => {
+                let Some(value) = constraint_number(&keyword.value) else {
+                    continue;
+                };
+                // Строгая граница целого числа: `gt=0` - это `>= 1`;
+                // за пределами i64 (`gt=<i64::MAX>`) граница не задается
+                let value = match (value, base_type, arg) {
+                    (ConstraintValue::Integer(value), BaseType::Integer, "gt") => {
+                        let Some(value) = value.checked_add(1) else {
+                            continue;
+                        };
+                        ConstraintValue::Integer(value)
+                    }
+                    (ConstraintValue::Integer(value), BaseType::Integer, "lt") => {
+                        let Some(value) = value.checked_sub(1) else {
+                            continue;
+                        };
+                        ConstraintValue::Integer(value)
+                    }
+                    (value, _, _) => value,
+                };
+                if matches!(arg, "ge" | "gt") {
+                    Constraint::Min(value)
+                } else {
+                    Constraint::Max(value)
+                }
+            }
+            "min_length" | "min_items" | "max_length" | "max_items" => {
+                let Some(ConstraintValue::Integer(length)) = constraint_number(&keyword.value)
+                else {
+                    continue;
+                };
+                if arg.starts_with("min") {
+                    Constraint::Min(ConstraintValue::Integer(length))
+                } else {
+                    Constraint::Max(ConstraintValue::Integer(length))
+                }
+            }
+            "regex" | "pattern" => match &keyword.value {
+                ast::Expr::Constant(constant) => match &constant.value {
+                    ast::Constant::Str(pattern) => Constraint::Pattern(pattern.clone()),
+                    _ => continue,
+                },
+                _ => continue,
+            },
+            _ => continue,
+        };
+        constraints.push(constraint);
+    }
+
+    Some(ConstrainedType {
+        type_name,
+        base_type,
+        constraints,
+    })
+}
+
+/// Числовое значение аргумента ограничения (`0`, `1.5`, `-10`)
+fn constraint_number(expr: &ast::Expr) -> Option<ConstraintValue> {
+    match expr {
+        ast::Expr::Constant(constant) => match &constant.value {
+            ast::Constant::Int(value) => {
+                value.to_string().parse().ok().map(ConstraintValue::Integer)
+            }
+            ast::Constant::Float(value) => Some(ConstraintValue::Float(*value)),
+            _ => None,
+        },
+        ast::Expr::UnaryOp(unary) if matches!(unary.op, ast::UnaryOp::USub) => {
+            match constraint_number(&unary.operand)? {
+                ConstraintValue::Integer(value) => Some(ConstraintValue::Integer(-value)),
+                ConstraintValue::Float(value) => Some(ConstraintValue::Float(-value)),
+            }
+        }
+        _ => None,
+    }
+}
+
 impl PythonParser {
     /// Создает новый парсер
     pub fn new() -> Self {
@@ -31,13 +169,10 @@ impl PythonParser {
     ) -> Vec<Import> {
         let mut imports = Vec::new();
 
-        match ast {
-            ast::Mod::Module(module) => {
-                for stmt in &module.body {
-                    self.extract_imports_from_stmt(stmt, &mut imports, file_path, converter);
-                }
+        if let ast::Mod::Module(module) = ast {
+            for stmt in &module.body {
+                self.extract_imports_from_stmt(stmt, &mut imports, file_path, converter);
             }
-            _ => {}
         }
 
         imports
@@ -58,6 +193,7 @@ impl PythonParser {
                     imports.push(Import {
                         path: alias.name.to_string(),
                         names: vec![],
+                        alias: alias.asname.as_ref().map(|asname| asname.to_string()),
                         location: crate::models::Location {
                             file: file_path.to_string(),
                             line,
@@ -74,6 +210,7 @@ impl PythonParser {
                         imports.push(Import {
                             path: module.to_string(),
                             names: vec![alias.name.to_string()],
+                            alias: alias.asname.as_ref().map(|asname| asname.to_string()),
                             location: crate::models::Location {
                                 file: file_path.to_string(),
                                 line,
@@ -87,6 +224,59 @@ impl PythonParser {
         }
     }
 
+    /// Имена, которые модуль отдает при `from module import *`
+    ///
+    /// Если в модуле задан `__all__` (список или кортеж строк), возвращается он,
+    /// иначе - публичные (без `_` в начале) функции и классы верхнего уровня.
+    pub fn module_exports(&self, ast: &ast::Mod) -> Vec<String> {
+        let ast::Mod::Module(module) = ast else {
+            return Vec::new();
+        };
+
+        let dunder_all = module.body.iter().rev().find_map(|stmt| {
+            let ast::Stmt::Assign(assign) = stmt else {
+                return None;
+            };
+            let assigns_all = assign.targets.iter().any(
+                |target| matches!(target, ast::Expr::Name(name) if name.id.as_str() == "__all__"),
+            );
+            if !assigns_all {
+                return None;
+            }
+            let elts = match assign.value.as_ref() {
+                ast::Expr::List(list) => &list.elts,
+                ast::Expr::Tuple(tuple) => &tuple.elts,
+                _ => return None,
+            };
+            Some(
+                elts.iter()
+                    .filter_map(|elt| match elt {
+                        ast::Expr::Constant(constant) => match &constant.value {
+                            ast::Constant::Str(name) => Some(name.to_string()),
+                            _ => None,
+                        },
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        });
+        if let Some(names) = dunder_all {
+            return names;
+        }
+
+        module
+            .body
+            .iter()
+            .filter_map(|stmt| match stmt {
+                ast::Stmt::FunctionDef(func_def) => Some(func_def.name.to_string()),
+                ast::Stmt::AsyncFunctionDef(func_def) => Some(func_def.name.to_string()),
+                ast::Stmt::ClassDef(class_def) => Some(class_def.name.to_string()),
+                _ => None,
+            })
+            .filter(|name| !name.starts_with('_'))
+            .collect()
+    }
+
     /// Извлекает вызовы функций из AST
     pub fn extract_calls(
         &self,
@@ -104,6 +294,44 @@ impl PythonParser {
         calls
     }
 
+    /// Возвращаемое выражение тела функции (первый `return <значение>`)
+    ///
+    /// Учитываются вложенные блоки (`if`, `for`, `with`, `try`), но не вложенные
+    /// функции и классы. `None`, если функция ничего не возвращает.
+    pub fn return_value(&self, body: &[ast::Stmt]) -> Option<String> {
+        body.iter().find_map(|stmt| match stmt {
+            ast::Stmt::Return(ret_stmt) => ret_stmt
+                .value
+                .as_ref()
+                .map(|value| self.expr_to_string(value)),
+            ast::Stmt::If(if_stmt) => self
+                .return_value(&if_stmt.body)
+                .or_else(|| self.return_value(&if_stmt.orelse)),
+            ast::Stmt::For(for_stmt) => self
+                .return_value(&for_stmt.body)
+                .or_else(|| self.return_value(&for_stmt.orelse)),
+            ast::Stmt::AsyncFor(for_stmt) => self
+                .return_value(&for_stmt.body)
+                .or_else(|| self.return_value(&for_stmt.orelse)),
+            ast::Stmt::While(while_stmt) => self
+                .return_value(&while_stmt.body)
+                .or_else(|| self.return_value(&while_stmt.orelse)),
+            ast::Stmt::With(with_stmt) => self.return_value(&with_stmt.body),
+            ast::Stmt::AsyncWith(with_stmt) => self.return_value(&with_stmt.body),
+            ast::Stmt::Try(try_stmt) => self
+                .return_value(&try_stmt.body)
+                .or_else(|| {
+                    try_stmt.handlers.iter().find_map(|handler| {
+                        let ast::ExceptHandler::ExceptHandler(handler) = handler;
+                        self.return_value(&handler.body)
+                    })
+                })
+                .or_else(|| self.return_value(&try_stmt.orelse))
+                .or_else(|| self.return_value(&try_stmt.finalbody)),
+            _ => None,
+        })
+    }
+
     /// Извлекает декораторы FastAPI
     pub fn extract_decorators(
         &self,
@@ -140,12 +368,33 @@ impl PythonParser {
 
                         // Извлекаем информацию о полях
                         let mut fields = Vec::new();
+                        let mut required = Vec::new();
+                        let mut field_constraints = std::collections::BTreeMap::new();
                         for body_stmt in &class_def.body {
                             if let ast::Stmt::AnnAssign(ann_assign) = body_stmt {
                                 if let ast::Expr::Name(name) = ann_assign.target.as_ref() {
                                     let field_name = name.id.to_string();
-                                    let field_type =
-                                        self.expr_to_string(ann_assign.annotation.as_ref());
+                                    // `conint(ge=0)`: базовый тип, ограничения - отдельно
+                                    let field_type = match constrained_type(&ann_assign.annotation)
+                                    {
+                                        Some(constrained) => {
+                                            if !constrained.constraints.is_empty() {
+                                                field_constraints.insert(
+                                                    field_name.clone(),
+                                                    constrained.constraints,
+                                                );
+                                            }
+                                            constrained.type_name.to_string()
+                                        }
+                                        None => self.expr_to_string(ann_assign.annotation.as_ref()),
+                                    };
+                                    // Поле обязательно, если у него нет значения по умолчанию
+                                    // и аннотация не допускает None
+                                    if !self.has_field_default(ann_assign.value.as_deref())
+                                        && !self.is_optional_annotation(&ann_assign.annotation)
+                                    {
+                                        required.push(field_name.clone());
+                                    }
                                     fields.push(format!("{}:{}", field_name, field_type));
                                 }
                             }
@@ -153,6 +402,15 @@ impl PythonParser {
 
                         if !fields.is_empty() {
                             metadata.insert("fields".to_string(), fields.join(","));
+                            metadata.insert("required".to_string(), required.join(","));
+                        }
+                        if !field_constraints.is_empty() {
+                            if let Ok(constraints) = serde_json::to_string(&field_constraints) {
+                                metadata.insert(
+                                    crate::models::FIELD_CONSTRAINTS_METADATA.to_string(),
+                                    constraints,
+                                );
+                            }
                         }
 
                         let range = class_def.range();
@@ -176,6 +434,69 @@ impl PythonParser {
         models
     }
 
+    /// Проверяет, допускает ли аннотация None (`Optional[T]`, `Union[T, None]`, `T | None`)
+    fn is_optional_annotation(&self, annotation: &ast::Expr) -> bool {
+        match annotation {
+            ast::Expr::Constant(constant) => matches!(constant.value, ast::Constant::None),
+            ast::Expr::Name(name) => name.id.as_str() == "None",
+            ast::Expr::BinOp(bin_op) if matches!(bin_op.op, ast::Operator::BitOr) => {
+                self.is_optional_annotation(&bin_op.left)
+                    || self.is_optional_annotation(&bin_op.right)
+            }
+            ast::Expr::Subscript(sub) => {
+                let base = self.expr_to_string(&sub.value);
+                match base.rsplit('.').next().unwrap_or(&base) {
+                    "Optional" => true,
+                    "Union" => match sub.slice.as_ref() {
+                        ast::Expr::Tuple(tuple) => tuple
+                            .elts
+                            .iter()
+                            .any(|elt| self.is_optional_annotation(elt)),
+                        other => self.is_optional_annotation(other),
+                    },
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Проверяет, задает ли значение поля модели default
+    ///
+    /// `= ...` и `Field(...)` / `Field(description=...)` означают обязательное поле;
+    /// любое другое значение, `Field(<default>)`, `Field(default=...)`
+    /// или `Field(default_factory=...)` - поле со значением по умолчанию.
+    fn has_field_default(&self, value: Option<&ast::Expr>) -> bool {
+        let Some(value) = value else {
+            return false;
+        };
+
+        let is_ellipsis = |expr: &ast::Expr| {
+            matches!(
+                expr,
+                ast::Expr::Constant(constant) if matches!(constant.value, ast::Constant::Ellipsis)
+            )
+        };
+
+        match value {
+            ast::Expr::Call(call_expr)
+                if self
+                    .call_name(&call_expr.func)
+                    .is_some_and(|name| name.rsplit('.').next() == Some("Field")) =>
+            {
+                if let Some(first) = call_expr.args.first() {
+                    return !is_ellipsis(first);
+                }
+                call_expr.keywords.iter().any(|kw| match kw.arg.as_deref() {
+                    Some("default") => !is_ellipsis(&kw.value),
+                    Some("default_factory") => true,
+                    _ => false,
+                })
+            }
+            other => !is_ellipsis(other),
+        }
+    }
+
     /// Проверяет, является ли базовый класс Pydantic BaseModel
     fn is_pydantic_base_model(&self, bases: &[ast::Expr]) -> bool {
         for base in bases {
@@ -183,7 +504,7 @@ impl PythonParser {
             // Извлекаем последний сегмент пути (split по '.' или '::')
             let last_segment = base_name
                 .split('.')
-                .last()
+                .next_back()
                 .or_else(|| base_name.split("::").last())
                 .unwrap_or(&base_name);
 
@@ -206,7 +527,7 @@ impl PythonParser {
     fn walk_statements(
         &self,
         stmts: &[ast::Stmt],
-        context: &mut Vec<String>,
+        context: &mut Vec<Scope>,
         calls: &mut Vec<Call>,
         file_path: &str,
         converter: &LocationConverter,
@@ -219,24 +540,33 @@ impl PythonParser {
     fn walk_stmt(
         &self,
         stmt: &ast::Stmt,
-        context: &mut Vec<String>,
+        context: &mut Vec<Scope>,
         calls: &mut Vec<Call>,
         file_path: &str,
         converter: &LocationConverter,
     ) {
         match stmt {
             ast::Stmt::FunctionDef(func_def) => {
-                context.push(func_def.name.to_string());
+                context.push(Scope {
+                    name: func_def.name.to_string(),
+                    is_class: false,
+                });
                 self.walk_statements(&func_def.body, context, calls, file_path, converter);
                 context.pop();
             }
             ast::Stmt::AsyncFunctionDef(func_def) => {
-                context.push(func_def.name.to_string());
+                context.push(Scope {
+                    name: func_def.name.to_string(),
+                    is_class: false,
+                });
                 self.walk_statements(&func_def.body, context, calls, file_path, converter);
                 context.pop();
             }
             ast::Stmt::ClassDef(class_def) => {
-                context.push(class_def.name.to_string());
+                context.push(Scope {
+                    name: class_def.name.to_string(),
+                    is_class: true,
+                });
                 self.walk_statements(&class_def.body, context, calls, file_path, converter);
                 context.pop();
             }
@@ -322,10 +652,30 @@ impl PythonParser {
         }
     }
 
+    /// Заменяет `self.X` / `cls.X` в методе на `Класс.X`
+    ///
+    /// Переписываются только прямые вызовы метода; `self.repo.get()` и
+    /// вызовы во вложенных функциях метода остаются как есть.
+    fn bind_method_receiver(name: String, context: &[Scope]) -> String {
+        let [.., class, method] = context else {
+            return name;
+        };
+        if !class.is_class || method.is_class {
+            return name;
+        }
+        match name
+            .strip_prefix("self.")
+            .or_else(|| name.strip_prefix("cls."))
+        {
+            Some(attr) if !attr.contains('.') => format!("{}.{}", class.name, attr),
+            _ => name,
+        }
+    }
+
     fn walk_expr(
         &self,
         expr: &ast::Expr,
-        context: &mut Vec<String>,
+        context: &mut Vec<Scope>,
         calls: &mut Vec<Call>,
         file_path: &str,
         converter: &LocationConverter,
@@ -333,6 +683,7 @@ impl PythonParser {
         match expr {
             ast::Expr::Call(call_expr) => {
                 if let Some(name) = self.call_name(&call_expr.func) {
+                    let name = Self::bind_method_receiver(name, context);
                     let arguments = self.extract_call_arguments(call_expr);
                     let range = call_expr.range();
                     let (line, column) = converter.byte_offset_to_location(range.start().into());
@@ -344,7 +695,13 @@ impl PythonParser {
                     let caller = if context.is_empty() {
                         None
                     } else {
-                        Some(context.join("."))
+                        Some(
+                            context
+                                .iter()
+                                .map(|scope| scope.name.as_str())
+                                .collect::<Vec<_>>()
+                                .join("."),
+                        )
                     };
 
                     calls.push(Call {
@@ -404,10 +761,8 @@ impl PythonParser {
                 }
             }
             ast::Expr::Dict(dict) => {
-                for key in &dict.keys {
-                    if let Some(key_expr) = key {
-                        self.walk_expr(key_expr, context, calls, file_path, converter);
-                    }
+                for key_expr in dict.keys.iter().flatten() {
+                    self.walk_expr(key_expr, context, calls, file_path, converter);
                 }
                 for value in &dict.values {
                     self.walk_expr(value, context, calls, file_path, converter);
@@ -425,18 +780,71 @@ impl PythonParser {
             }
             ast::Expr::GeneratorExp(gen_expr) => {
                 self.walk_expr(&gen_expr.elt, context, calls, file_path, converter);
-                for comp in &gen_expr.generators {
-                    self.walk_expr(&comp.iter, context, calls, file_path, converter);
-                    self.walk_expr(&comp.target, context, calls, file_path, converter);
-                    for if_expr in &comp.ifs {
-                        self.walk_expr(if_expr, context, calls, file_path, converter);
-                    }
-                }
+                self.walk_comprehensions(
+                    &gen_expr.generators,
+                    context,
+                    calls,
+                    file_path,
+                    converter,
+                );
+            }
+            ast::Expr::ListComp(list_comp) => {
+                self.walk_expr(&list_comp.elt, context, calls, file_path, converter);
+                self.walk_comprehensions(
+                    &list_comp.generators,
+                    context,
+                    calls,
+                    file_path,
+                    converter,
+                );
+            }
+            ast::Expr::SetComp(set_comp) => {
+                self.walk_expr(&set_comp.elt, context, calls, file_path, converter);
+                self.walk_comprehensions(
+                    &set_comp.generators,
+                    context,
+                    calls,
+                    file_path,
+                    converter,
+                );
+            }
+            ast::Expr::DictComp(dict_comp) => {
+                self.walk_expr(&dict_comp.key, context, calls, file_path, converter);
+                self.walk_expr(&dict_comp.value, context, calls, file_path, converter);
+                self.walk_comprehensions(
+                    &dict_comp.generators,
+                    context,
+                    calls,
+                    file_path,
+                    converter,
+                );
+            }
+            ast::Expr::NamedExpr(named_expr) => {
+                // Walrus: `(result := process(data))`
+                self.walk_expr(&named_expr.value, context, calls, file_path, converter);
             }
             _ => {}
         }
     }
 
+    /// Walks `for ... in ... if ...` clauses of a comprehension
+    fn walk_comprehensions(
+        &self,
+        generators: &[ast::Comprehension],
+        context: &mut Vec<Scope>,
+        calls: &mut Vec<Call>,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) {
+        for comp in generators {
+            self.walk_expr(&comp.iter, context, calls, file_path, converter);
+            self.walk_expr(&comp.target, context, calls, file_path, converter);
+            for if_expr in &comp.ifs {
+                self.walk_expr(if_expr, context, calls, file_path, converter);
+            }
+        }
+    }
+
     fn call_name(&self, expr: &ast::Expr) -> Option<String> {
         match expr {
             ast::Expr::Name(name) => Some(name.id.to_string()),
@@ -501,6 +909,50 @@ impl PythonParser {
                 ast::Constant::Ellipsis => "...".to_string(),
                 ast::Constant::Tuple(_) => "tuple".to_string(),
             },
+            ast::Expr::BinOp(bin_op) if matches!(bin_op.op, ast::Operator::BitOr) => {
+                // PEP 604: `str | None`
+                format!(
+                    "{} | {}",
+                    self.expr_to_string(&bin_op.left),
+                    self.expr_to_string(&bin_op.right)
+                )
+            }
+            ast::Expr::List(list) => format!(
+                "[{}]",
+                list.elts
+                    .iter()
+                    .map(|elt| self.expr_to_string(elt))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            ast::Expr::Tuple(tuple) => format!(
+                "({})",
+                tuple
+                    .elts
+                    .iter()
+                    .map(|elt| self.expr_to_string(elt))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            // `responses={404: {"model": NotFound}}` -> `{404: {model: NotFound}}`
+            ast::Expr::Dict(dict) => format!(
+                "{{{}}}",
+                dict.keys
+                    .iter()
+                    .zip(&dict.values)
+                    .map(|(key, value)| match key {
+                        Some(key) => {
+                            format!(
+                                "{}: {}",
+                                self.expr_to_string(key),
+                                self.expr_to_string(value)
+                            )
+                        }
+                        None => format!("**{}", self.expr_to_string(value)),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
             ast::Expr::Call(call_expr) => {
                 if let Some(name) = self.call_name(&call_expr.func) {
                     format!("{}(...)", name)
@@ -588,13 +1040,14 @@ impl PythonParser {
         for decorator in decorator_list {
             if let Some(name) = self.get_decorator_name(decorator) {
                 if self.is_route_decorator(&name) {
-                    let args = self.extract_decorator_arguments(decorator);
+                    let (args, keyword_args) = self.extract_decorator_arguments(decorator);
                     // Извлекаем реальную location из decorator AST
                     let range = decorator.range();
                     let (line, column) = converter.byte_offset_to_location(range.start().into());
                     decorators.push(crate::call_graph::Decorator {
                         name,
                         arguments: args,
+                        keyword_arguments: keyword_args,
                         location: Location {
                             file: file_path.to_string(),
                             line,
@@ -609,35 +1062,41 @@ impl PythonParser {
 
     fn get_decorator_name(&self, decorator: &ast::Expr) -> Option<String> {
         match decorator {
-            ast::Expr::Attribute(attr) => {
-                if let Some(base) = self.get_decorator_name(&attr.value) {
-                    Some(format!("{}.{}", base, attr.attr))
-                } else {
-                    None
-                }
-            }
+            ast::Expr::Attribute(attr) => self
+                .get_decorator_name(&attr.value)
+                .map(|base| format!("{}.{}", base, attr.attr)),
             ast::Expr::Name(name) => Some(name.id.to_string()),
             ast::Expr::Call(call_expr) => self.get_decorator_name(&call_expr.func),
             _ => None,
         }
     }
 
-    fn extract_decorator_arguments(&self, decorator: &ast::Expr) -> Vec<String> {
-        if let ast::Expr::Call(call_expr) = decorator {
-            let mut args = Vec::new();
-            for arg in &call_expr.args {
-                args.push(self.expr_to_string(arg));
-            }
-            for kw in &call_expr.keywords {
-                args.push(self.expr_to_string(&kw.value));
-            }
-            args
-        } else {
-            Vec::new()
-        }
+    /// Позиционные и именованные аргументы декоратора
+    fn extract_decorator_arguments(
+        &self,
+        decorator: &ast::Expr,
+    ) -> (Vec<String>, std::collections::HashMap<String, String>) {
+        let ast::Expr::Call(call_expr) = decorator else {
+            return Default::default();
+        };
+        let args = call_expr
+            .args
+            .iter()
+            .map(|arg| self.expr_to_string(arg))
+            .collect();
+        let keyword_args = call_expr
+            .keywords
+            .iter()
+            .filter_map(|kw| {
+                let name = kw.arg.as_ref()?;
+                Some((name.to_string(), self.expr_to_string(&kw.value)))
+            })
+            .collect();
+        (args, keyword_args)
     }
 
+    /// FastAPI (`app.post`, `router.get`) и Flask (`app.route`, `bp.route`) декораторы маршрутов
     fn is_route_decorator(&self, name: &str) -> bool {
-        name.starts_with("app.") || name.starts_with("router.")
+        name.starts_with("app.") || name.starts_with("router.") || name.ends_with(".route")
     }
 }
@@ -1,14 +1,83 @@
-use crate::models::{Location, SchemaReference, SchemaType, TypeInfo};
+use crate::analyzers::SchemaParser;
+use crate::data_flow::{Variable, VariableSource};
+use crate::models::{
+    Constraint, ConstraintValue, InterfaceMethod, Location, MethodParameter, SchemaReference,
+    SchemaType, TypeInfo, DEPRECATED_FIELDS_METADATA, DEPRECATED_METADATA,
+    FIELD_CONSTRAINTS_METADATA, METHODS_METADATA,
+};
 use crate::parsers::{Call, CallArgument, Import, LocationConverter};
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use std::path::Path;
-use swc_common::{sync::Lrc, FileName, SourceMap};
+use swc_common::comments::{Comments, SingleThreadedComments};
+use swc_common::{sync::Lrc, BytePos, FileName, SourceMap};
 use swc_ecma_ast::*;
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsSyntax};
 
+/// Extensions of single-file components whose `<script>` blocks hold TypeScript
+pub const COMPONENT_EXTENSIONS: [&str; 2] = ["vue", "svelte"];
+
+/// Whether `path` is a Vue/Svelte single-file component
+pub fn is_component_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| COMPONENT_EXTENSIONS.contains(&ext))
+}
+
+/// Keeps only the contents of `<script>` blocks of a single-file component
+///
+/// Everything else (template, styles, the tags themselves) is blanked out with
+/// spaces while newlines are kept, so byte offsets and line numbers still point
+/// into the original file.
+pub fn component_script_source(source: &str) -> String {
+    let mut script_ranges = Vec::new();
+    let mut rest = 0;
+    while let Some(open) = source[rest..].find("<script") {
+        let tag_start = rest + open;
+        let Some(tag_end) = source[tag_start..].find('>') else {
+            break;
+        };
+        let content_start = tag_start + tag_end + 1;
+        let content_end = source[content_start..]
+            .find("</script")
+            .map_or(source.len(), |close| content_start + close);
+        script_ranges.push(content_start..content_end);
+        rest = content_end;
+    }
+
+    source
+        .char_indices()
+        .map(|(idx, ch)| {
+            if ch == '\n' || script_ranges.iter().any(|range| range.contains(&idx)) {
+                ch.to_string()
+            } else {
+                // Multi-byte characters are blanked byte for byte
+                " ".repeat(ch.len_utf8())
+            }
+        })
+        .collect()
+}
+
+/// Field of a Zod object schema
+#[derive(Debug, Clone)]
+struct ZodField {
+    name: String,
+    /// Type name in TypeScript notation (`string`, `number | null`, schema name)
+    field_type: String,
+    /// `.optional()`/`.nullish()`: the field may be missing
+    optional: bool,
+    /// Constraints of the validator chain (`.min(3)`, `.email()`)
+    constraints: Vec<Constraint>,
+}
+
+/// Fields of the Zod object schemas of a module by variable name
+type ZodFieldMap = std::collections::HashMap<String, Vec<ZodField>>;
+
 /// TypeScript code parser with call analysis (via swc)
 pub struct TypeScriptParser {
     source_map: SourceMap,
+    /// Comments of the parsed files (JSDoc tags), keyed by position in `source_map`
+    comments: SingleThreadedComments,
 }
 
 impl TypeScriptParser {
@@ -16,12 +85,18 @@ impl TypeScriptParser {
     pub fn new() -> Self {
         Self {
             source_map: SourceMap::default(),
+            comments: SingleThreadedComments::default(),
         }
     }
 
     /// Parses a file via swc
+    ///
+    /// For `.vue`/`.svelte` components only the `<script>` blocks are parsed.
     pub fn parse_file(&self, path: &Path) -> Result<(Module, String, LocationConverter)> {
-        let source = std::fs::read_to_string(path)?;
+        let mut source = std::fs::read_to_string(path)?;
+        if is_component_file(path) {
+            source = component_script_source(&source);
+        }
         let module = self.parse_source(&source, path)?;
         let converter = LocationConverter::new(source.clone());
         Ok((module, source, converter))
@@ -37,10 +112,16 @@ impl TypeScriptParser {
         let is_tsx = path.extension().and_then(|e| e.to_str()) == Some("tsx");
         let syntax = Syntax::Typescript(TsSyntax {
             tsx: is_tsx,
+            dts: path.to_string_lossy().ends_with(".d.ts"),
             ..Default::default()
         });
 
-        let lexer = Lexer::new(syntax, Default::default(), StringInput::from(&*fm), None);
+        let lexer = Lexer::new(
+            syntax,
+            Default::default(),
+            StringInput::from(&*fm),
+            Some(&self.comments),
+        );
         let mut parser = Parser::new_from(lexer);
 
         parser
@@ -71,14 +152,7 @@ impl TypeScriptParser {
                     match specifier {
                         ImportSpecifier::Named(named) => {
                             if let Some(imported) = &named.imported {
-                                match imported {
-                                    ModuleExportName::Ident(ident) => {
-                                        names.push(ident.sym.as_ref().to_string());
-                                    }
-                                    ModuleExportName::Str(str) => {
-                                        names.push(str.value.as_str().unwrap_or("").to_string());
-                                    }
-                                }
+                                names.push(Self::module_export_name(imported));
                             } else {
                                 names.push(named.local.sym.as_ref().to_string());
                             }
@@ -95,6 +169,7 @@ impl TypeScriptParser {
                 imports.push(Import {
                     path: import_path,
                     names,
+                    alias: None,
                     location: Location {
                         file: file_path.to_string(),
                         line,
@@ -104,9 +179,70 @@ impl TypeScriptParser {
             }
         }
 
+        imports.extend(self.extract_reexports(module, file_path, converter));
         imports
     }
 
+    /// Extracts re-exports of barrel files as imports of the re-exported modules
+    ///
+    /// `export { foo } from './foo'` yields `foo` (`export { foo as bar }` yields
+    /// the exported name `bar`), `export * from './bar'` and
+    /// `export * as bar from './bar'` yield no names: the referenced module is
+    /// parsed either way, so its symbols resolve through the barrel.
+    pub fn extract_reexports(
+        &self,
+        module: &Module,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) -> Vec<Import> {
+        module
+            .body
+            .iter()
+            .filter_map(|item| {
+                let (src, names, span) = match item {
+                    ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export)) => {
+                        let src = named_export.src.as_ref()?;
+                        let names = named_export
+                            .specifiers
+                            .iter()
+                            .filter_map(|specifier| match specifier {
+                                ExportSpecifier::Named(named) => Some(Self::module_export_name(
+                                    named.exported.as_ref().unwrap_or(&named.orig),
+                                )),
+                                ExportSpecifier::Default(_) => Some("default".to_string()),
+                                ExportSpecifier::Namespace(_) => None,
+                            })
+                            .collect();
+                        (src, names, named_export.span)
+                    }
+                    ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export_all)) => {
+                        (&export_all.src, Vec::new(), export_all.span)
+                    }
+                    _ => return None,
+                };
+                let (line, column) = converter.byte_offset_to_location(span.lo.0 as usize);
+                Some(Import {
+                    path: src.value.as_str().unwrap_or("").to_string(),
+                    names,
+                    alias: None,
+                    location: Location {
+                        file: file_path.to_string(),
+                        line,
+                        column: Some(column),
+                    },
+                })
+            })
+            .collect()
+    }
+
+    /// Name of an import/export specifier (`foo` or `"foo"`)
+    fn module_export_name(name: &ModuleExportName) -> String {
+        match name {
+            ModuleExportName::Ident(ident) => ident.sym.as_ref().to_string(),
+            ModuleExportName::Str(str) => str.value.as_str().unwrap_or("").to_string(),
+        }
+    }
+
     /// Extracts function calls from module
     pub fn extract_calls(
         &self,
@@ -146,6 +282,15 @@ impl TypeScriptParser {
                     context.pop();
                 }
             }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export_default)) => {
+                if let DefaultDecl::Fn(fn_expr) = &export_default.decl {
+                    context.push(Self::default_export_name(fn_expr.ident.as_ref()));
+                    if let Some(body) = &fn_expr.function.body {
+                        self.walk_block_stmt(body, context, calls, file_path, converter);
+                    }
+                    context.pop();
+                }
+            }
             _ => {}
         }
     }
@@ -433,6 +578,32 @@ impl TypeScriptParser {
                     "call(...)".to_string()
                 }
             }
+            // `/users/${id}` keeps its static parts; substitutions become `${...}`
+            Expr::Tpl(tpl) => {
+                let parts: Vec<&str> = tpl.quasis.iter().map(|quasi| quasi.raw.as_ref()).collect();
+                format!("`{}`", parts.join("${...}"))
+            }
+            Expr::Object(object) => {
+                let props: Vec<String> = object
+                    .props
+                    .iter()
+                    .map(|prop| match prop {
+                        PropOrSpread::Prop(prop) => match prop.as_ref() {
+                            Prop::KeyValue(key_value) => format!(
+                                "{}: {}",
+                                Self::prop_name(&key_value.key).unwrap_or_else(|| "?".to_string()),
+                                self.expr_to_string(&key_value.value)
+                            ),
+                            Prop::Shorthand(ident) => ident.sym.as_ref().to_string(),
+                            _ => "?".to_string(),
+                        },
+                        PropOrSpread::Spread(spread) => {
+                            format!("...{}", self.expr_to_string(&spread.expr))
+                        }
+                    })
+                    .collect();
+                format!("{{{}}}", props.join(", "))
+            }
             _ => "expr".to_string(),
         }
     }
@@ -453,8 +624,18 @@ impl TypeScriptParser {
             ts_schema_map.insert(ts_schema.name.clone(), ts_schema.clone());
         }
 
+        // Fields of object schemas seen so far, for `.extend`/`.merge`/`.pick`/`.omit`
+        let mut zod_fields = std::collections::HashMap::new();
+
         for item in &module.body {
-            self.walk_for_zod(item, &mut schemas, file_path, converter, &ts_schema_map);
+            self.walk_for_zod(
+                item,
+                &mut schemas,
+                file_path,
+                converter,
+                &ts_schema_map,
+                &mut zod_fields,
+            );
         }
 
         schemas
@@ -468,6 +649,7 @@ impl TypeScriptParser {
         file_path: &str,
         converter: &LocationConverter,
         ts_schema_map: &std::collections::HashMap<String, SchemaReference>,
+        zod_fields: &mut ZodFieldMap,
     ) {
         match item {
             ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
@@ -475,7 +657,8 @@ impl TypeScriptParser {
                     if let Some(init) = &decl.init {
                         if let Expr::Call(call_expr) = init.as_ref() {
                             if let Callee::Expr(callee_expr) = &call_expr.callee {
-                                if self.is_zod_call(callee_expr.as_ref()) {
+                                let own_fields = self.zod_object_fields(init, zod_fields);
+                                if self.is_zod_call(callee_expr.as_ref()) || own_fields.is_some() {
                                     let span = call_expr.span;
                                     let (line, column) =
                                         converter.byte_offset_to_location(span.lo.0 as usize);
@@ -500,6 +683,55 @@ impl TypeScriptParser {
                                         }
                                     }
 
+                                    // Fields of the object schema itself take precedence
+                                    if own_fields.is_none() && !metadata.contains_key("fields") {
+                                        // Non-object schema (`z.union([...])`): keep its type
+                                        metadata.insert(
+                                            "type".to_string(),
+                                            self.zod_field_type(init, zod_fields),
+                                        );
+                                    }
+                                    if let Some(fields) = own_fields {
+                                        metadata.insert(
+                                            "fields".to_string(),
+                                            fields
+                                                .iter()
+                                                .map(|field| {
+                                                    let mut entry = format!(
+                                                        "{}:{}",
+                                                        field.name, field.field_type
+                                                    );
+                                                    if field.optional {
+                                                        entry.push_str(":optional");
+                                                    }
+                                                    entry
+                                                })
+                                                .collect::<Vec<_>>()
+                                                .join(","),
+                                        );
+                                        let field_constraints: std::collections::HashMap<
+                                            &str,
+                                            &[Constraint],
+                                        > = fields
+                                            .iter()
+                                            .filter(|field| !field.constraints.is_empty())
+                                            .map(|field| {
+                                                (field.name.as_str(), field.constraints.as_slice())
+                                            })
+                                            .collect();
+                                        if !field_constraints.is_empty() {
+                                            if let Ok(constraints) =
+                                                serde_json::to_string(&field_constraints)
+                                            {
+                                                metadata.insert(
+                                                    FIELD_CONSTRAINTS_METADATA.to_string(),
+                                                    constraints,
+                                                );
+                                            }
+                                        }
+                                        zod_fields.insert(schema_name.clone(), fields);
+                                    }
+
                                     schemas.push(SchemaReference {
                                         name: schema_name,
                                         schema_type: SchemaType::Zod,
@@ -546,24 +778,308 @@ impl TypeScriptParser {
 
     /// Checks if expression is a Zod call
     fn is_zod_call(&self, expr: &Expr) -> bool {
+        if let Expr::Member(member_expr) = expr {
+            if let Expr::Ident(ident) = member_expr.obj.as_ref() {
+                if ident.sym.as_ref() == "z" {
+                    if let MemberProp::Ident(prop) = &member_expr.prop {
+                        let method = prop.sym.as_ref();
+                        return matches!(
+                            method,
+                            "object"
+                                | "string"
+                                | "number"
+                                | "boolean"
+                                | "array"
+                                | "enum"
+                                | "union"
+                                | "literal"
+                                | "optional"
+                        );
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    /// Resolves the fields of a Zod object schema expression
+    ///
+    /// Handles `z.object({...})` and compositions of known object schemas:
+    /// `Base.extend({...})`, `A.merge(B)`, `Base.pick({...})` and `Base.omit({...})`.
+    /// Other chained methods (`.strict()`, `.describe(...)`) keep the fields.
+    /// Returns `None` when the expression is not an object schema.
+    fn zod_object_fields(&self, expr: &Expr, known: &ZodFieldMap) -> Option<Vec<ZodField>> {
         match expr {
-            Expr::Member(member_expr) => {
-                if let Expr::Ident(ident) = member_expr.obj.as_ref() {
-                    if ident.sym.as_ref() == "z" {
-                        if let MemberProp::Ident(prop) = &member_expr.prop {
-                            let method = prop.sym.as_ref();
-                            return method == "object"
-                                || method == "string"
-                                || method == "number"
-                                || method == "boolean"
-                                || method == "array";
+            Expr::Ident(ident) => known.get(ident.sym.as_ref()).cloned(),
+            Expr::Paren(paren) => self.zod_object_fields(&paren.expr, known),
+            Expr::Call(call_expr) => {
+                let Callee::Expr(callee) = &call_expr.callee else {
+                    return None;
+                };
+                let Expr::Member(member) = callee.as_ref() else {
+                    return None;
+                };
+                let MemberProp::Ident(prop) = &member.prop else {
+                    return None;
+                };
+                let first_arg = call_expr.args.first().map(|arg| arg.expr.as_ref());
+
+                if matches!(member.obj.as_ref(), Expr::Ident(obj) if obj.sym.as_ref() == "z") {
+                    return match (prop.sym.as_ref(), first_arg) {
+                        ("object", Some(Expr::Object(shape))) => {
+                            Some(self.zod_shape_fields(shape, known))
                         }
+                        _ => None,
+                    };
+                }
+
+                let mut fields = self.zod_object_fields(&member.obj, known)?;
+                match (prop.sym.as_ref(), first_arg) {
+                    ("extend", Some(Expr::Object(shape))) => {
+                        for field in self.zod_shape_fields(shape, known) {
+                            fields.retain(|existing| existing.name != field.name);
+                            fields.push(field);
+                        }
+                    }
+                    ("merge", Some(other)) => {
+                        for field in self.zod_object_fields(other, known)? {
+                            fields.retain(|existing| existing.name != field.name);
+                            fields.push(field);
+                        }
+                    }
+                    ("pick", Some(Expr::Object(mask))) => {
+                        let keys = Self::object_keys(mask);
+                        fields.retain(|field| keys.contains(&field.name));
+                    }
+                    ("omit", Some(Expr::Object(mask))) => {
+                        let keys = Self::object_keys(mask);
+                        fields.retain(|field| !keys.contains(&field.name));
                     }
+                    _ => {}
                 }
+                Some(fields)
             }
-            _ => {}
+            _ => None,
+        }
+    }
+
+    /// Fields of a `z.object({...})` shape literal in declaration order
+    fn zod_shape_fields(&self, shape: &ObjectLit, known: &ZodFieldMap) -> Vec<ZodField> {
+        shape
+            .props
+            .iter()
+            .filter_map(|prop| match prop {
+                PropOrSpread::Prop(prop) => match prop.as_ref() {
+                    Prop::KeyValue(key_value) => {
+                        let (optional, nullable) = Self::zod_field_modifiers(&key_value.value);
+                        let field_type = self.zod_field_type(&key_value.value, known);
+                        Some(ZodField {
+                            name: Self::prop_name(&key_value.key)?,
+                            field_type: if nullable {
+                                format!("{} | null", field_type)
+                            } else {
+                                field_type
+                            },
+                            optional,
+                            constraints: Self::zod_field_constraints(&key_value.value),
+                        })
+                    }
+                    _ => None,
+                },
+                PropOrSpread::Spread(_) => None,
+            })
+            .collect()
+    }
+
+    /// Type name of a Zod field validator (`z.string().min(3)` → `string`)
+    ///
+    /// A reference to another known schema yields that schema's name;
+    /// `z.enum`/`z.literal` give `enum`, `z.union([a, b])` gives `a | b` and
+    /// `z.optional(a)` gives the type of `a`.
+    fn zod_field_type(&self, expr: &Expr, known: &ZodFieldMap) -> String {
+        match expr {
+            Expr::Ident(ident) if known.contains_key(ident.sym.as_ref()) => {
+                ident.sym.as_ref().to_string()
+            }
+            Expr::Call(call_expr) => {
+                let Callee::Expr(callee) = &call_expr.callee else {
+                    return "any".to_string();
+                };
+                let Expr::Member(member) = callee.as_ref() else {
+                    return "any".to_string();
+                };
+                let MemberProp::Ident(prop) = &member.prop else {
+                    return "any".to_string();
+                };
+                match member.obj.as_ref() {
+                    Expr::Ident(obj) if obj.sym.as_ref() == "z" => match prop.sym.as_ref() {
+                        "union" => {
+                            let Some(Expr::Array(members)) =
+                                call_expr.args.first().map(|arg| arg.expr.as_ref())
+                            else {
+                                return "any".to_string();
+                            };
+                            return members
+                                .elems
+                                .iter()
+                                .flatten()
+                                .map(|member| self.zod_field_type(&member.expr, known))
+                                .collect::<Vec<_>>()
+                                .join(" | ");
+                        }
+                        "optional" => {
+                            return match call_expr.args.first() {
+                                Some(inner) => self.zod_field_type(&inner.expr, known),
+                                None => "any".to_string(),
+                            };
+                        }
+                        "string" => "string",
+                        "number" | "bigint" => "number",
+                        "boolean" => "boolean",
+                        "date" => "Date",
+                        "array" => "array",
+                        "object" | "record" => "object",
+                        "enum" | "literal" => "enum",
+                        _ => "any",
+                    }
+                    .to_string(),
+                    // Chained validator: the type comes from the start of the chain
+                    obj => self.zod_field_type(obj, known),
+                }
+            }
+            _ => "any".to_string(),
+        }
+    }
+
+    /// Constraints of a Zod validator chain in call order
+    ///
+    /// `.min(n)`/`.max(n)` (`.length(n)` gives both), `.email()`, `.url()`,
+    /// `.regex(/.../)` and the values of `z.enum([...])`/`z.literal(...)`;
+    /// other methods of the chain are skipped.
+    fn zod_field_constraints(expr: &Expr) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        for (method, call_expr) in Self::zod_chain(expr) {
+            let first_arg = call_expr.args.first().map(|arg| arg.expr.as_ref());
+            match (method, first_arg) {
+                ("min", Some(arg)) => {
+                    constraints.extend(Self::constraint_value(arg).map(Constraint::Min))
+                }
+                ("max", Some(arg)) => {
+                    constraints.extend(Self::constraint_value(arg).map(Constraint::Max))
+                }
+                ("length", Some(arg)) => {
+                    if let Some(value) = Self::constraint_value(arg) {
+                        constraints.push(Constraint::Min(value.clone()));
+                        constraints.push(Constraint::Max(value));
+                    }
+                }
+                ("email", _) => constraints.push(Constraint::Email),
+                ("url", _) => constraints.push(Constraint::Url),
+                ("regex", Some(Expr::Lit(Lit::Regex(regex)))) => {
+                    constraints.push(Constraint::Pattern(regex.exp.to_string()))
+                }
+                ("enum", Some(Expr::Array(values))) => {
+                    let values = values
+                        .elems
+                        .iter()
+                        .flatten()
+                        .filter_map(|value| Self::literal_value(&value.expr))
+                        .collect();
+                    constraints.push(Constraint::Enum(values));
+                }
+                ("literal", Some(value)) => {
+                    constraints.extend(
+                        Self::literal_value(value).map(|value| Constraint::Enum(vec![value])),
+                    );
+                }
+                _ => {}
+            }
+        }
+        constraints
+    }
+
+    /// Whether a Zod validator chain accepts a missing value and `null`
+    ///
+    /// `.optional()` (or `z.optional(...)`) makes the field optional,
+    /// `.nullable()` lets it be `null` and `.nullish()` does both.
+    fn zod_field_modifiers(expr: &Expr) -> (bool, bool) {
+        let chain = Self::zod_chain(expr);
+        let optional = chain
+            .iter()
+            .any(|(method, _)| matches!(*method, "optional" | "nullish"));
+        let nullable = chain
+            .iter()
+            .any(|(method, _)| matches!(*method, "nullable" | "nullish"));
+        (optional, nullable)
+    }
+
+    /// Method calls of a Zod validator chain in call order
+    /// (`z.string().min(3)` → `string`, `min`)
+    fn zod_chain(expr: &Expr) -> Vec<(&str, &CallExpr)> {
+        let mut chain = Vec::new();
+        let mut current = expr;
+        while let Expr::Call(call_expr) = current {
+            let Callee::Expr(callee) = &call_expr.callee else {
+                break;
+            };
+            let Expr::Member(member) = callee.as_ref() else {
+                break;
+            };
+            let MemberProp::Ident(prop) = &member.prop else {
+                break;
+            };
+            chain.push((prop.sym.as_ref(), call_expr));
+            current = member.obj.as_ref();
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// Value of a string, number or boolean literal (`"admin"` → `admin`)
+    fn literal_value(expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Lit(Lit::Str(str)) => str.value.as_str().map(str::to_string),
+            Expr::Lit(Lit::Num(number)) => Some(number.value.to_string()),
+            Expr::Lit(Lit::Bool(value)) => Some(value.value.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Numeric argument of a Zod validator (`3`, `0.5`)
+    fn constraint_value(expr: &Expr) -> Option<ConstraintValue> {
+        let Expr::Lit(Lit::Num(number)) = expr else {
+            return None;
+        };
+        if number.value.fract() == 0.0 {
+            Some(ConstraintValue::Integer(number.value as i64))
+        } else {
+            Some(ConstraintValue::Float(number.value))
+        }
+    }
+
+    /// Keys of an object literal (`{ id: true, name: true }` → `id`, `name`)
+    fn object_keys(object: &ObjectLit) -> Vec<String> {
+        object
+            .props
+            .iter()
+            .filter_map(|prop| match prop {
+                PropOrSpread::Prop(prop) => match prop.as_ref() {
+                    Prop::KeyValue(key_value) => Self::prop_name(&key_value.key),
+                    Prop::Shorthand(ident) => Some(ident.sym.as_ref().to_string()),
+                    _ => None,
+                },
+                PropOrSpread::Spread(_) => None,
+            })
+            .collect()
+    }
+
+    /// Name of an identifier or string property key
+    fn prop_name(key: &PropName) -> Option<String> {
+        match key {
+            PropName::Ident(ident) => Some(ident.sym.as_ref().to_string()),
+            PropName::Str(str) => str.value.as_str().map(str::to_string),
+            _ => None,
         }
-        false
     }
 
     /// Extracts TypeScript types from module
@@ -598,6 +1114,163 @@ impl TypeScriptParser {
         schemas
     }
 
+    /// Extracts variables whose type is asserted on the initializer
+    ///
+    /// Handles `const user = { ... } satisfies User`, `const user = data as User`
+    /// and `<User>data`. The asserted type becomes the variable's schema reference;
+    /// interfaces and type aliases declared in the same module are resolved to
+    /// their full schema.
+    pub fn extract_typed_variables(
+        &self,
+        module: &Module,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) -> Vec<Variable> {
+        let schemas = self.extract_typescript_schemas(module, file_path, converter);
+        let mut variables = Vec::new();
+
+        for item in &module.body {
+            match item {
+                ModuleItem::Stmt(stmt) => self.walk_stmt_for_typed_variables(
+                    stmt,
+                    &schemas,
+                    &mut variables,
+                    file_path,
+                    converter,
+                ),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                    if let Decl::Var(var_decl) = &export_decl.decl {
+                        self.collect_typed_variables(
+                            var_decl,
+                            &schemas,
+                            &mut variables,
+                            file_path,
+                            converter,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        variables
+    }
+
+    /// Traverses statements looking for variable declarations with type assertions
+    fn walk_stmt_for_typed_variables(
+        &self,
+        stmt: &Stmt,
+        schemas: &[SchemaReference],
+        variables: &mut Vec<Variable>,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) {
+        match stmt {
+            Stmt::Decl(Decl::Var(var_decl)) => {
+                self.collect_typed_variables(var_decl, schemas, variables, file_path, converter);
+            }
+            Stmt::Decl(Decl::Fn(fn_decl)) => {
+                if let Some(body) = &fn_decl.function.body {
+                    for stmt in &body.stmts {
+                        self.walk_stmt_for_typed_variables(
+                            stmt, schemas, variables, file_path, converter,
+                        );
+                    }
+                }
+            }
+            Stmt::Block(block) => {
+                for stmt in &block.stmts {
+                    self.walk_stmt_for_typed_variables(
+                        stmt, schemas, variables, file_path, converter,
+                    );
+                }
+            }
+            Stmt::If(if_stmt) => {
+                self.walk_stmt_for_typed_variables(
+                    &if_stmt.cons,
+                    schemas,
+                    variables,
+                    file_path,
+                    converter,
+                );
+                if let Some(alt) = &if_stmt.alt {
+                    self.walk_stmt_for_typed_variables(
+                        alt, schemas, variables, file_path, converter,
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Records declarators initialized with `satisfies`/`as`/`<T>` expressions
+    fn collect_typed_variables(
+        &self,
+        var_decl: &VarDecl,
+        schemas: &[SchemaReference],
+        variables: &mut Vec<Variable>,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) {
+        for decl in &var_decl.decls {
+            let (Pat::Ident(ident), Some(init)) = (&decl.name, &decl.init) else {
+                continue;
+            };
+            let Some(asserted_type) = Self::asserted_type(init) else {
+                continue;
+            };
+
+            let (line, column) = converter.byte_offset_to_location(decl.span.lo.0 as usize);
+            let location = Location {
+                file: file_path.to_string(),
+                line,
+                column: Some(column),
+            };
+
+            let schema_ref = match asserted_type {
+                TsType::TsTypeRef(type_ref) => {
+                    let name = self.ts_entity_name_to_string(&type_ref.type_name);
+                    Some(
+                        schemas
+                            .iter()
+                            .find(|schema| schema.name == name)
+                            .cloned()
+                            .unwrap_or_else(|| SchemaReference {
+                                name,
+                                schema_type: SchemaType::TypeScript,
+                                location: location.clone(),
+                                metadata: std::collections::HashMap::new(),
+                            }),
+                    )
+                }
+                _ => None,
+            };
+
+            variables.push(Variable {
+                name: ident.id.sym.as_ref().to_string(),
+                type_info: TypeInfo {
+                    base_type: self.ts_type_to_base_type(asserted_type),
+                    schema_ref,
+                    constraints: Vec::new(),
+                    optional: false,
+                },
+                location,
+                source: VariableSource::Local,
+            });
+        }
+    }
+
+    /// Returns the type asserted by the outermost `satisfies`/`as`/`<T>` expression
+    fn asserted_type(expr: &Expr) -> Option<&TsType> {
+        match expr {
+            Expr::TsSatisfies(satisfies) => Some(&satisfies.type_ann),
+            Expr::TsAs(as_expr) => Some(&as_expr.type_ann),
+            Expr::TsTypeAssertion(assertion) => Some(&assertion.type_ann),
+            Expr::Paren(paren) => Self::asserted_type(&paren.expr),
+            _ => None,
+        }
+    }
+
     /// Traverses AST to find TypeScript types
     fn walk_for_types(
         &self,
@@ -677,6 +1350,14 @@ impl TypeScriptParser {
                             optional: false,
                         });
                     }
+                    Decl::TsEnum(ts_enum) => {
+                        types.push(self.enum_type_info(ts_enum, file_path, converter));
+                    }
+                    Decl::TsModule(ts_module) => {
+                        for item in Self::declared_module_items(ts_module) {
+                            self.walk_for_types(item, types, file_path, converter);
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -747,10 +1428,229 @@ impl TypeScriptParser {
                     optional: false,
                 });
             }
+            // `enum Role { ... }` and `const enum Role { ... }`
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsEnum(ts_enum))) => {
+                types.push(self.enum_type_info(ts_enum, file_path, converter));
+            }
+            // `declare module "x" { ... }`, `declare namespace Api { ... }`
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(ts_module))) => {
+                for item in Self::declared_module_items(ts_module) {
+                    self.walk_for_types(item, types, file_path, converter);
+                }
+            }
             _ => {}
         }
     }
 
+    /// Items of a `declare module`/`namespace` block (`namespace A.B {}` is unwrapped)
+    fn declared_module_items(ts_module: &TsModuleDecl) -> &[ModuleItem] {
+        let mut body = ts_module.body.as_ref();
+        while let Some(TsNamespaceBody::TsNamespaceDecl(nested)) = body {
+            body = Some(nested.body.as_ref());
+        }
+        match body {
+            Some(TsNamespaceBody::TsModuleBlock(block)) => &block.body,
+            _ => &[],
+        }
+    }
+
+    /// Values of enum members: string initializers, numeric initializers and
+    /// implicit numbers (counting on from the previous numeric member)
+    fn enum_member_values(ts_enum: &TsEnumDecl) -> Vec<String> {
+        let mut next_number = 0.0;
+        ts_enum
+            .members
+            .iter()
+            .map(|member| match member.init.as_deref() {
+                Some(Expr::Lit(Lit::Str(str))) => str.value.as_str().unwrap_or("").to_string(),
+                Some(Expr::Lit(Lit::Num(num))) => {
+                    next_number = num.value + 1.0;
+                    num.value.to_string()
+                }
+                Some(_) => match &member.id {
+                    TsEnumMemberId::Ident(ident) => ident.sym.as_ref().to_string(),
+                    TsEnumMemberId::Str(str) => str.value.as_str().unwrap_or("").to_string(),
+                },
+                None => {
+                    let value = next_number;
+                    next_number += 1.0;
+                    value.to_string()
+                }
+            })
+            .collect()
+    }
+
+    /// Type of an enum declaration: an enumeration with its member values
+    fn enum_type_info(
+        &self,
+        ts_enum: &TsEnumDecl,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) -> TypeInfo {
+        let (line, column) = converter.byte_offset_to_location(ts_enum.span.lo.0 as usize);
+        let mut metadata = std::collections::HashMap::new();
+        if ts_enum.is_const {
+            metadata.insert("const".to_string(), "true".to_string());
+        }
+        let values = Self::enum_member_values(ts_enum);
+        metadata.insert("type".to_string(), "enum".to_string());
+        metadata.insert("values".to_string(), values.join(","));
+
+        TypeInfo {
+            base_type: crate::models::BaseType::Enum,
+            schema_ref: Some(SchemaReference {
+                name: ts_enum.id.sym.as_ref().to_string(),
+                schema_type: SchemaType::TypeScript,
+                location: Location {
+                    file: file_path.to_string(),
+                    line,
+                    column: Some(column),
+                },
+                metadata,
+            }),
+            constraints: vec![crate::models::Constraint::Enum(values)],
+            optional: false,
+        }
+    }
+
+    /// Builds the schema of an interface declaration
+    ///
+    /// Properties marked with a JSDoc `@deprecated` tag are listed in the
+    /// `deprecated_fields` metadata; a deprecated interface gets `deprecated = "true"`.
+    /// `doc_pos` is where the interface's JSDoc is attached (the `export` keyword if any).
+    fn interface_schema(
+        &self,
+        ts_interface: &TsInterfaceDecl,
+        doc_pos: BytePos,
+        file_path: &str,
+        converter: &LocationConverter,
+    ) -> SchemaReference {
+        let span = ts_interface.span;
+        let (line, column) = converter.byte_offset_to_location(span.lo.0 as usize);
+
+        let name = ts_interface.id.sym.as_ref().to_string();
+        let mut metadata = std::collections::HashMap::new();
+        let mut fields = Vec::new();
+        let mut deprecated_fields = Vec::new();
+        let mut methods = Vec::new();
+
+        for member in &ts_interface.body.body {
+            if let swc_ecma_ast::TsTypeElement::TsMethodSignature(method) = member {
+                methods.push(self.interface_method(method));
+            }
+            if let swc_ecma_ast::TsTypeElement::TsPropertySignature(prop) = member {
+                let field_name = self.ts_property_key_to_string(&prop.key);
+                if let Some(type_ann) = &prop.type_ann {
+                    let field_type = self.ts_type_ann_to_string(type_ann);
+                    let optional = prop.optional;
+                    fields.push(format!(
+                        "{}:{}:{}",
+                        field_name,
+                        field_type,
+                        if optional { "optional" } else { "required" }
+                    ));
+                    if self.has_deprecated_tag(prop.span.lo) {
+                        deprecated_fields.push(field_name);
+                    }
+                }
+            }
+        }
+
+        if !fields.is_empty() {
+            metadata.insert("fields".to_string(), fields.join(","));
+        }
+        if !deprecated_fields.is_empty() {
+            metadata.insert(
+                DEPRECATED_FIELDS_METADATA.to_string(),
+                deprecated_fields.join(","),
+            );
+        }
+        if !methods.is_empty() {
+            if let Ok(methods) = serde_json::to_string(&methods) {
+                metadata.insert(METHODS_METADATA.to_string(), methods);
+            }
+        }
+        if self.has_deprecated_tag(doc_pos) || self.has_deprecated_tag(span.lo) {
+            metadata.insert(DEPRECATED_METADATA.to_string(), "true".to_string());
+        }
+
+        SchemaReference {
+            name,
+            schema_type: SchemaType::TypeScript,
+            location: Location {
+                file: file_path.to_string(),
+                line,
+                column: Some(column),
+            },
+            metadata,
+        }
+    }
+
+    /// Signature of an interface method (`save(user: User): void`)
+    fn interface_method(&self, method: &swc_ecma_ast::TsMethodSignature) -> InterfaceMethod {
+        let parameters = method
+            .params
+            .iter()
+            .map(|param| match param {
+                swc_ecma_ast::TsFnParam::Ident(ident) => MethodParameter {
+                    name: ident.id.sym.as_ref().to_string(),
+                    type_name: ident
+                        .type_ann
+                        .as_ref()
+                        .map(|type_ann| self.ts_type_ann_to_string(type_ann)),
+                    optional: ident.id.optional,
+                },
+                // `...args` keeps its name; destructured rest patterns have none
+                swc_ecma_ast::TsFnParam::Rest(rest) => MethodParameter {
+                    name: match rest.arg.as_ref() {
+                        Pat::Ident(ident) => ident.id.sym.as_ref().to_string(),
+                        _ => "...".to_string(),
+                    },
+                    type_name: rest
+                        .type_ann
+                        .as_ref()
+                        .map(|type_ann| self.ts_type_ann_to_string(type_ann)),
+                    optional: true,
+                },
+                swc_ecma_ast::TsFnParam::Array(array) => MethodParameter {
+                    name: "[]".to_string(),
+                    type_name: array
+                        .type_ann
+                        .as_ref()
+                        .map(|type_ann| self.ts_type_ann_to_string(type_ann)),
+                    optional: array.optional,
+                },
+                swc_ecma_ast::TsFnParam::Object(object) => MethodParameter {
+                    name: "{}".to_string(),
+                    type_name: object
+                        .type_ann
+                        .as_ref()
+                        .map(|type_ann| self.ts_type_ann_to_string(type_ann)),
+                    optional: object.optional,
+                },
+            })
+            .collect();
+
+        InterfaceMethod {
+            name: self.ts_property_key_to_string(&method.key),
+            parameters,
+            return_type: method
+                .type_ann
+                .as_ref()
+                .map(|type_ann| self.ts_type_ann_to_string(type_ann)),
+            optional: method.optional,
+        }
+    }
+
+    /// Whether a JSDoc comment attached at `pos` carries the `@deprecated` tag
+    fn has_deprecated_tag(&self, pos: BytePos) -> bool {
+        self.comments.get_leading(pos).is_some_and(|comments| {
+            comments
+                .iter()
+                .any(|comment| comment.text.contains("@deprecated"))
+        })
+    }
+
     /// Traverses AST to find TypeScript schemas
     fn walk_for_typescript_schemas(
         &self,
@@ -763,51 +1663,21 @@ impl TypeScriptParser {
             ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
                 match &export_decl.decl {
                     Decl::TsInterface(ts_interface) => {
-                        let span = ts_interface.span;
+                        // JSDoc of an exported interface precedes the `export` keyword
+                        schemas.push(self.interface_schema(
+                            ts_interface,
+                            export_decl.span.lo,
+                            file_path,
+                            converter,
+                        ));
+                    }
+                    Decl::TsTypeAlias(ts_type_alias) => {
+                        let span = ts_type_alias.span;
                         let (line, column) = converter.byte_offset_to_location(span.lo.0 as usize);
 
-                        let name = ts_interface.id.sym.as_ref().to_string();
-                        let mut metadata = std::collections::HashMap::new();
-                        let mut fields = Vec::new();
-
-                        for member in &ts_interface.body.body {
-                            if let swc_ecma_ast::TsTypeElement::TsPropertySignature(prop) = member {
-                                let field_name = self.ts_property_key_to_string(&prop.key);
-                                if let Some(type_ann) = &prop.type_ann {
-                                    let field_type = self.ts_type_ann_to_string(type_ann);
-                                    let optional = prop.optional;
-                                    fields.push(format!(
-                                        "{}:{}:{}",
-                                        field_name,
-                                        field_type,
-                                        if optional { "optional" } else { "required" }
-                                    ));
-                                }
-                            }
-                        }
-
-                        if !fields.is_empty() {
-                            metadata.insert("fields".to_string(), fields.join(","));
-                        }
-
-                        schemas.push(SchemaReference {
-                            name,
-                            schema_type: SchemaType::TypeScript,
-                            location: Location {
-                                file: file_path.to_string(),
-                                line,
-                                column: Some(column),
-                            },
-                            metadata,
-                        });
-                    }
-                    Decl::TsTypeAlias(ts_type_alias) => {
-                        let span = ts_type_alias.span;
-                        let (line, column) = converter.byte_offset_to_location(span.lo.0 as usize);
-
-                        let name = ts_type_alias.id.sym.as_ref().to_string();
-                        let type_str = self.ts_type_to_string(ts_type_alias.type_ann.as_ref());
-
+                        let name = ts_type_alias.id.sym.as_ref().to_string();
+                        let type_str = self.ts_type_to_string(ts_type_alias.type_ann.as_ref());
+
                         let mut metadata = std::collections::HashMap::new();
                         metadata.insert("type".to_string(), type_str);
 
@@ -822,47 +1692,33 @@ impl TypeScriptParser {
                             metadata,
                         });
                     }
+                    Decl::TsEnum(ts_enum) => {
+                        schemas.extend(
+                            self.enum_type_info(ts_enum, file_path, converter)
+                                .schema_ref,
+                        );
+                    }
+                    Decl::TsModule(ts_module) => {
+                        for item in Self::declared_module_items(ts_module) {
+                            self.walk_for_typescript_schemas(item, schemas, file_path, converter);
+                        }
+                    }
                     _ => {}
                 }
             }
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsEnum(ts_enum))) => {
+                schemas.extend(
+                    self.enum_type_info(ts_enum, file_path, converter)
+                        .schema_ref,
+                );
+            }
             ModuleItem::Stmt(Stmt::Decl(Decl::TsInterface(ts_interface))) => {
-                let span = ts_interface.span;
-                let (line, column) = converter.byte_offset_to_location(span.lo.0 as usize);
-
-                let name = ts_interface.id.sym.as_ref().to_string();
-                let mut metadata = std::collections::HashMap::new();
-                let mut fields = Vec::new();
-
-                for member in &ts_interface.body.body {
-                    if let swc_ecma_ast::TsTypeElement::TsPropertySignature(prop) = member {
-                        let field_name = self.ts_property_key_to_string(&prop.key);
-                        if let Some(type_ann) = &prop.type_ann {
-                            let field_type = self.ts_type_ann_to_string(type_ann);
-                            let optional = prop.optional;
-                            fields.push(format!(
-                                "{}:{}:{}",
-                                field_name,
-                                field_type,
-                                if optional { "optional" } else { "required" }
-                            ));
-                        }
-                    }
-                }
-
-                if !fields.is_empty() {
-                    metadata.insert("fields".to_string(), fields.join(","));
-                }
-
-                schemas.push(SchemaReference {
-                    name,
-                    schema_type: SchemaType::TypeScript,
-                    location: Location {
-                        file: file_path.to_string(),
-                        line,
-                        column: Some(column),
-                    },
-                    metadata,
-                });
+                schemas.push(self.interface_schema(
+                    ts_interface,
+                    ts_interface.span.lo,
+                    file_path,
+                    converter,
+                ));
             }
             ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(ts_type_alias))) => {
                 let span = ts_type_alias.span;
@@ -885,6 +1741,12 @@ impl TypeScriptParser {
                     metadata,
                 });
             }
+            // Ambient declarations (`declare module "api" { interface User {} }`, `.d.ts`)
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(ts_module))) => {
+                for item in Self::declared_module_items(ts_module) {
+                    self.walk_for_typescript_schemas(item, schemas, file_path, converter);
+                }
+            }
             _ => {}
         }
     }
@@ -915,7 +1777,7 @@ impl TypeScriptParser {
                     format!(
                         "{}.{}",
                         self.ts_entity_name_to_string(&qualified.left),
-                        qualified.right.sym.as_ref().to_string()
+                        qualified.right.sym.as_ref()
                     )
                 }
             },
@@ -925,6 +1787,17 @@ impl TypeScriptParser {
                     self.ts_type_to_string(array_type.elem_type.as_ref())
                 )
             }
+            swc_ecma_ast::TsType::TsTupleType(tuple_type) => {
+                let types: Vec<String> = tuple_type
+                    .elem_types
+                    .iter()
+                    .map(|elem| self.ts_type_to_string(&elem.ty))
+                    .collect();
+                format!("[{}]", types.join(", "))
+            }
+            swc_ecma_ast::TsType::TsOptionalType(optional_type) => {
+                format!("{}?", self.ts_type_to_string(&optional_type.type_ann))
+            }
             swc_ecma_ast::TsType::TsUnionOrIntersectionType(union) => {
                 // In SWC 18.0 structure may differ, use match on type
                 match union {
@@ -962,9 +1835,15 @@ impl TypeScriptParser {
                     crate::models::BaseType::Boolean
                 }
                 swc_ecma_ast::TsKeywordTypeKind::TsAnyKeyword => crate::models::BaseType::Any,
+                swc_ecma_ast::TsKeywordTypeKind::TsNullKeyword
+                | swc_ecma_ast::TsKeywordTypeKind::TsUndefinedKeyword => {
+                    crate::models::BaseType::Null
+                }
                 _ => crate::models::BaseType::Unknown,
             },
-            swc_ecma_ast::TsType::TsArrayType(_) => crate::models::BaseType::Array,
+            swc_ecma_ast::TsType::TsArrayType(_) | swc_ecma_ast::TsType::TsTupleType(_) => {
+                crate::models::BaseType::Array
+            }
             swc_ecma_ast::TsType::TsTypeRef(_) => crate::models::BaseType::Object,
             _ => crate::models::BaseType::Unknown,
         }
@@ -978,7 +1857,7 @@ impl TypeScriptParser {
                 format!(
                     "{}.{}",
                     self.ts_entity_name_to_string(&qualified.left),
-                    qualified.right.sym.as_ref().to_string()
+                    qualified.right.sym.as_ref()
                 )
             }
         }
@@ -1009,6 +1888,59 @@ impl TypeScriptParser {
         result
     }
 
+    /// Returned expression of a function body (the first `return <value>`)
+    fn function_return_value(&self, function: &Function) -> Option<String> {
+        function
+            .body
+            .as_ref()
+            .and_then(|body| self.block_return_value(&body.stmts))
+    }
+
+    /// Returned expression of an arrow function; a concise body is the returned value
+    fn arrow_return_value(&self, arrow_fn: &ArrowExpr) -> Option<String> {
+        match arrow_fn.body.as_ref() {
+            BlockStmtOrExpr::BlockStmt(block) => self.block_return_value(&block.stmts),
+            BlockStmtOrExpr::Expr(expr) => Some(self.expr_to_string(expr)),
+        }
+    }
+
+    /// First `return <value>` in statements, including nested blocks but not nested functions
+    fn block_return_value(&self, stmts: &[Stmt]) -> Option<String> {
+        stmts.iter().find_map(|stmt| match stmt {
+            Stmt::Return(ret_stmt) => ret_stmt.arg.as_ref().map(|arg| self.expr_to_string(arg)),
+            Stmt::Block(block) => self.block_return_value(&block.stmts),
+            Stmt::If(if_stmt) => self
+                .block_return_value(std::slice::from_ref(&*if_stmt.cons))
+                .or_else(|| {
+                    if_stmt
+                        .alt
+                        .as_ref()
+                        .and_then(|alt| self.block_return_value(std::slice::from_ref(&**alt)))
+                }),
+            Stmt::For(for_stmt) => self.block_return_value(std::slice::from_ref(&*for_stmt.body)),
+            Stmt::ForOf(for_stmt) => self.block_return_value(std::slice::from_ref(&*for_stmt.body)),
+            Stmt::ForIn(for_stmt) => self.block_return_value(std::slice::from_ref(&*for_stmt.body)),
+            Stmt::While(while_stmt) => {
+                self.block_return_value(std::slice::from_ref(&*while_stmt.body))
+            }
+            Stmt::Try(try_stmt) => self
+                .block_return_value(&try_stmt.block.stmts)
+                .or_else(|| {
+                    try_stmt
+                        .handler
+                        .as_ref()
+                        .and_then(|handler| self.block_return_value(&handler.body.stmts))
+                })
+                .or_else(|| {
+                    try_stmt
+                        .finalizer
+                        .as_ref()
+                        .and_then(|finalizer| self.block_return_value(&finalizer.stmts))
+                }),
+            _ => None,
+        })
+    }
+
     /// Traverses AST to find functions and classes
     fn walk_for_functions_and_classes(
         &self,
@@ -1034,6 +1966,7 @@ impl TypeScriptParser {
                     parameters,
                     return_type,
                     is_async,
+                    return_value: self.function_return_value(&fn_decl.function),
                 });
             }
             ModuleItem::Stmt(Stmt::Decl(Decl::Class(class_decl))) => {
@@ -1068,6 +2001,7 @@ impl TypeScriptParser {
                             parameters,
                             return_type,
                             is_async,
+                            return_value: self.function_return_value(&fn_decl.function),
                         });
                     }
                     Decl::Class(class_decl) => {
@@ -1085,9 +2019,66 @@ impl TypeScriptParser {
                             methods,
                         });
                     }
+                    Decl::TsModule(ts_module) => {
+                        for item in Self::declared_module_items(ts_module) {
+                            self.walk_for_functions_and_classes(item, result, file_path, converter);
+                        }
+                    }
                     _ => {}
                 }
             }
+            // `export default function` / `export default class`, possibly anonymous
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export_default)) => {
+                match &export_default.decl {
+                    DefaultDecl::Fn(fn_expr) => {
+                        let span = fn_expr
+                            .ident
+                            .as_ref()
+                            .map_or(fn_expr.function.span, |ident| ident.span);
+                        let (line, column) = converter.byte_offset_to_location(span.lo.0 as usize);
+
+                        let name = Self::default_export_name(fn_expr.ident.as_ref());
+                        let parameters = self.extract_function_parameters(&fn_expr.function);
+                        let return_type = self.extract_return_type(&fn_expr.function);
+                        let is_async = fn_expr.function.is_async;
+
+                        result.push(FunctionOrClass::Function {
+                            name,
+                            line,
+                            column,
+                            parameters,
+                            return_type,
+                            is_async,
+                            return_value: self.function_return_value(&fn_expr.function),
+                        });
+                    }
+                    DefaultDecl::Class(class_expr) => {
+                        let span = class_expr
+                            .ident
+                            .as_ref()
+                            .map_or(class_expr.class.span, |ident| ident.span);
+                        let (line, column) = converter.byte_offset_to_location(span.lo.0 as usize);
+
+                        let name = Self::default_export_name(class_expr.ident.as_ref());
+                        let methods =
+                            self.extract_class_methods(&class_expr.class, file_path, converter);
+
+                        result.push(FunctionOrClass::Class {
+                            name,
+                            line,
+                            column,
+                            methods,
+                        });
+                    }
+                    DefaultDecl::TsInterfaceDecl(_) => {}
+                }
+            }
+            // `declare function` inside `declare module`/`namespace` blocks
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(ts_module))) => {
+                for item in Self::declared_module_items(ts_module) {
+                    self.walk_for_functions_and_classes(item, result, file_path, converter);
+                }
+            }
             ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
                 // Handle const fn = () => {} and similar
                 for decl in &var_decl.decls {
@@ -1109,6 +2100,7 @@ impl TypeScriptParser {
                                     parameters,
                                     return_type,
                                     is_async: arrow_fn.is_async,
+                                    return_value: self.arrow_return_value(arrow_fn),
                                 });
                             }
                         }
@@ -1119,6 +2111,23 @@ impl TypeScriptParser {
         }
     }
 
+    /// Name of a class method or field (`unknown` for computed keys)
+    fn class_member_name(key: &PropName) -> String {
+        match key {
+            PropName::Ident(ident) => ident.sym.as_ref().to_string(),
+            PropName::Str(str) => str.value.as_str().unwrap_or("").to_string(),
+            _ => "unknown".to_string(),
+        }
+    }
+
+    /// Name of a default-exported function or class; `default` when anonymous
+    fn default_export_name(ident: Option<&Ident>) -> String {
+        ident.map_or_else(
+            || "default".to_string(),
+            |ident| ident.sym.as_ref().to_string(),
+        )
+    }
+
     /// Extracts function parameters
     fn extract_function_parameters(
         &self,
@@ -1186,16 +2195,40 @@ impl TypeScriptParser {
     }
 
     /// Converts TsTypeAnn to TypeInfo
+    ///
+    /// Union types (`string | number`) keep their members as a `Constraint::OneOf`.
     fn ts_type_ann_to_type_info(&self, type_ann: &swc_ecma_ast::TsTypeAnn) -> TypeInfo {
-        let base_type = self.ts_type_to_base_type(&type_ann.type_ann);
+        let (base_type, constraints) = match self.union_member_types(&type_ann.type_ann) {
+            Some(members) => SchemaParser::union_type_info(members),
+            None => (self.ts_type_to_base_type(&type_ann.type_ann), Vec::new()),
+        };
         TypeInfo {
             base_type,
             schema_ref: None,
-            constraints: Vec::new(),
+            constraints,
             optional: false,
         }
     }
 
+    /// Base types of the members of a union type (`None` for non-union types)
+    fn union_member_types(
+        &self,
+        ts_type: &swc_ecma_ast::TsType,
+    ) -> Option<Vec<crate::models::BaseType>> {
+        match ts_type {
+            swc_ecma_ast::TsType::TsUnionOrIntersectionType(
+                swc_ecma_ast::TsUnionOrIntersectionType::TsUnionType(union_type),
+            ) => Some(
+                union_type
+                    .types
+                    .iter()
+                    .map(|member| self.ts_type_to_base_type(member))
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
     fn parameter_from_binding_ident(
         &self,
         ident: &BindingIdent,
@@ -1257,14 +2290,7 @@ impl TypeScriptParser {
                     let span = method.span;
                     let (line, column) = converter.byte_offset_to_location(span.lo.0 as usize);
 
-                    let name = match &method.key {
-                        swc_ecma_ast::PropName::Ident(ident) => ident.sym.as_ref().to_string(),
-                        swc_ecma_ast::PropName::Str(str) => {
-                            str.value.as_str().unwrap_or("").to_string()
-                        }
-                        _ => "unknown".to_string(),
-                    };
-
+                    let name = Self::class_member_name(&method.key);
                     let parameters = self.extract_function_parameters(&method.function);
                     let return_type = self.extract_return_type(&method.function);
                     let is_async = method.function.is_async;
@@ -1278,6 +2304,26 @@ impl TypeScriptParser {
                         return_type,
                         is_async,
                         is_static,
+                        return_value: self.function_return_value(&method.function),
+                    });
+                }
+                // Arrow function fields (`handler = (req) => {...}`) bind `this`
+                swc_ecma_ast::ClassMember::ClassProp(prop) => {
+                    let Some(Expr::Arrow(arrow_fn)) = prop.value.as_deref() else {
+                        continue;
+                    };
+                    let span = prop.span;
+                    let (line, column) = converter.byte_offset_to_location(span.lo.0 as usize);
+
+                    methods.push(ClassMethod {
+                        name: Self::class_member_name(&prop.key),
+                        line,
+                        column,
+                        parameters: self.extract_arrow_function_parameters(arrow_fn),
+                        return_type: self.extract_arrow_return_type(arrow_fn),
+                        is_async: arrow_fn.is_async,
+                        is_static: prop.is_static,
+                        return_value: self.arrow_return_value(arrow_fn),
                     });
                 }
                 _ => {}
@@ -1289,7 +2335,7 @@ impl TypeScriptParser {
 }
 
 /// Function or class from TypeScript code
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum FunctionOrClass {
     Function {
         name: String,
@@ -1298,6 +2344,9 @@ pub enum FunctionOrClass {
         parameters: Vec<crate::call_graph::Parameter>,
         return_type: Option<TypeInfo>,
         is_async: bool,
+        /// Returned expression (`None` when the function returns nothing)
+        #[serde(default)]
+        return_value: Option<String>,
     },
     Class {
         name: String,
@@ -1308,7 +2357,7 @@ pub enum FunctionOrClass {
 }
 
 /// Class method
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClassMethod {
     pub name: String,
     pub line: usize,
@@ -1317,6 +2366,9 @@ pub struct ClassMethod {
     pub return_type: Option<TypeInfo>,
     pub is_async: bool,
     pub is_static: bool,
+    /// Returned expression (`None` when the method returns nothing)
+    #[serde(default)]
+    pub return_value: Option<String>,
 }
 
 impl Default for TypeScriptParser {
@@ -1349,6 +2401,23 @@ import express from 'express';
         assert_eq!(imports[1].path, "express");
     }
 
+    #[test]
+    fn test_reexport_records_exported_name() {
+        let parser = TypeScriptParser::new();
+        let source = "export { formatUser as format, parseUser } from './users';
+";
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("index.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let imports = parser.extract_reexports(&module, test_file.to_str().unwrap(), &converter);
+
+        assert_eq!(imports.len(), 1);
+        assert_eq!(imports[0].path, "./users");
+        assert_eq!(imports[0].names, vec!["format", "parseUser"]);
+    }
+
     #[test]
     fn test_extract_calls() {
         let parser = TypeScriptParser::new();
@@ -1435,6 +2504,211 @@ const userSchema = z.object({
         assert_eq!(schemas[0].schema_type, SchemaType::Zod);
     }
 
+    #[test]
+    fn test_zod_extend_keeps_base_fields() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+const Base = z.object({
+    id: z.number(),
+    name: z.string().min(1),
+});
+const Extended = Base.extend({ extra: z.string() });
+const Merged = Base.merge(z.object({ role: z.string() }));
+const NameOnly = Base.pick({ name: true });
+const WithoutName = Base.omit({ name: true });
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("test.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas = parser.extract_zod_schemas(&module, test_file.to_str().unwrap(), &converter);
+        let fields = |name: &str| {
+            let schema = schemas.iter().find(|s| s.name == name).unwrap();
+            let mut fields: Vec<String> = SchemaParser::parse(schema)
+                .unwrap()
+                .properties
+                .into_keys()
+                .collect();
+            fields.sort();
+            fields
+        };
+
+        assert_eq!(fields("Extended"), ["extra", "id", "name"]);
+        assert_eq!(fields("Merged"), ["id", "name", "role"]);
+        assert_eq!(fields("NameOnly"), ["name"]);
+        assert_eq!(fields("WithoutName"), ["id"]);
+    }
+
+    #[test]
+    fn test_zod_validator_chain_yields_constraints() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+const Signup = z.object({
+    email: z.string().email(),
+    login: z.string().min(3).max(50).regex(/^[a-z]+$/),
+    site: z.string().url().optional(),
+    age: z.number(),
+});
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("test.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas = parser.extract_zod_schemas(&module, test_file.to_str().unwrap(), &converter);
+        let schema = SchemaParser::parse(&schemas[0]).unwrap();
+
+        assert_eq!(schema.properties["email"].constraints, [Constraint::Email]);
+        assert_eq!(
+            schema.properties["login"].constraints,
+            [
+                Constraint::Min(ConstraintValue::Integer(3)),
+                Constraint::Max(ConstraintValue::Integer(50)),
+                Constraint::Pattern("^[a-z]+$".to_string()),
+            ]
+        );
+        assert_eq!(schema.properties["site"].constraints, [Constraint::Url]);
+        assert!(schema.properties["age"].constraints.is_empty());
+    }
+
+    #[test]
+    fn test_zod_enum_union_and_literal() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+const Status = z.union([z.string(), z.number()]);
+const Order = z.object({
+    role: z.enum(["admin", "user"]),
+    kind: z.literal("order"),
+    note: z.optional(z.string()),
+});
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("test.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas = parser.extract_zod_schemas(&module, test_file.to_str().unwrap(), &converter);
+        assert_eq!(schemas.len(), 2);
+        assert_eq!(schemas[0].metadata["type"], "string | number");
+
+        let order = SchemaParser::parse(&schemas[1]).unwrap();
+        let role = &order.properties["role"];
+        assert_eq!(role.base_type, crate::models::BaseType::Enum);
+        assert_eq!(
+            role.constraints,
+            [Constraint::Enum(vec![
+                "admin".to_string(),
+                "user".to_string()
+            ])]
+        );
+        assert_eq!(
+            order.properties["kind"].constraints,
+            [Constraint::Enum(vec!["order".to_string()])]
+        );
+        assert_eq!(order.properties["note"].field_type, "string");
+    }
+
+    #[test]
+    fn test_optional_zod_field_is_not_required() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+const Profile = z.object({
+    id: z.number(),
+    name: z.string().optional(),
+    bio: z.string().nullish(),
+    avatar: z.string().url().nullable(),
+});
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("test.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas = parser.extract_zod_schemas(&module, test_file.to_str().unwrap(), &converter);
+        let schema = SchemaParser::parse(&schemas[0]).unwrap();
+
+        let mut required = schema.required.clone();
+        required.sort();
+        assert_eq!(required, ["avatar", "id"]);
+        assert!(schema.properties["name"].optional);
+        let avatar = &schema.properties["avatar"];
+        assert_eq!(avatar.field_type, "string | null");
+        assert_eq!(avatar.base_type, crate::models::BaseType::String);
+    }
+
+    #[test]
+    fn test_string_enum_becomes_enum_schema() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+export enum Role {
+    Admin = "admin",
+    User = "user",
+}
+
+enum Level { Low, High }
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("test.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas =
+            parser.extract_typescript_schemas(&module, test_file.to_str().unwrap(), &converter);
+        assert_eq!(schemas.len(), 2);
+
+        let role = SchemaParser::parse(&schemas[0]).unwrap();
+        assert_eq!(schemas[0].name, "Role");
+        assert_eq!(role.schema_type, "enum");
+        assert_eq!(
+            role.constraints,
+            [Constraint::Enum(vec![
+                "admin".to_string(),
+                "user".to_string()
+            ])]
+        );
+        let level = SchemaParser::parse(&schemas[1]).unwrap();
+        assert_eq!(
+            level.constraints,
+            [Constraint::Enum(vec!["0".to_string(), "1".to_string()])]
+        );
+    }
+
+    #[test]
+    fn test_satisfies_links_variable_to_schema() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+export interface User {
+    name: string;
+    age: number;
+}
+
+const user = { name: "Ann", age: 30 } satisfies User;
+const other = JSON.parse(raw) as Order;
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("user.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let variables =
+            parser.extract_typed_variables(&module, test_file.to_str().unwrap(), &converter);
+        assert_eq!(variables.len(), 2);
+
+        let user = &variables[0];
+        assert_eq!(user.name, "user");
+        let schema = user.type_info.schema_ref.as_ref().unwrap();
+        assert_eq!(schema.name, "User");
+        assert_eq!(
+            schema.metadata.get("fields").map(String::as_str),
+            Some("name:string:required,age:number:required")
+        );
+
+        let other = &variables[1];
+        assert_eq!(other.name, "other");
+        assert_eq!(other.type_info.schema_ref.as_ref().unwrap().name, "Order");
+    }
+
     #[test]
     fn test_extract_functions_and_classes() {
         let parser = TypeScriptParser::new();
@@ -1476,6 +2750,322 @@ class UserService {
         assert!(has_class);
     }
 
+    #[test]
+    fn test_default_exported_function_is_extracted() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+export default function Page(id: number) {
+    loadUser(id);
+}
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("page.tsx");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let items =
+            parser.extract_functions_and_classes(&module, test_file.to_str().unwrap(), &converter);
+        let Some(FunctionOrClass::Function {
+            name, parameters, ..
+        }) = items.first()
+        else {
+            panic!("default-exported function not extracted");
+        };
+        assert_eq!(name, "Page");
+        assert_eq!(parameters.len(), 1);
+
+        let calls = parser.extract_calls(&module, test_file.to_str().unwrap(), &converter);
+        let call = calls.iter().find(|c| c.name == "loadUser").unwrap();
+        assert_eq!(call.caller.as_deref(), Some("Page"));
+
+        std::fs::write(&test_file, "export default class {}\n").unwrap();
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let items =
+            parser.extract_functions_and_classes(&module, test_file.to_str().unwrap(), &converter);
+        assert!(matches!(
+            items.first(),
+            Some(FunctionOrClass::Class { name, .. }) if name == "default"
+        ));
+    }
+
+    #[test]
+    fn test_arrow_class_fields_are_methods() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+class UserController {
+    label = "users";
+    handler = (req: Request): string => {
+        return req.url;
+    };
+    private fetch = async (id: number) => {
+        await load(id);
+    };
+}
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("controller.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let items =
+            parser.extract_functions_and_classes(&module, test_file.to_str().unwrap(), &converter);
+        let Some(FunctionOrClass::Class { methods, .. }) = items.first() else {
+            panic!("class not extracted");
+        };
+
+        assert_eq!(methods.len(), 2);
+        assert_eq!(methods[0].name, "handler");
+        assert_eq!(methods[0].parameters[0].name, "req");
+        assert_eq!(
+            methods[0].return_type.as_ref().map(|t| t.base_type),
+            Some(crate::models::BaseType::String)
+        );
+        assert!(!methods[0].is_async);
+        assert_eq!(methods[1].name, "fetch");
+        assert!(methods[1].is_async);
+    }
+
+    #[test]
+    fn test_union_parameter_keeps_members() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+export function format(value: string | number, label: string | null) {
+    return `${label}: ${value}`;
+}
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("format.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let items =
+            parser.extract_functions_and_classes(&module, test_file.to_str().unwrap(), &converter);
+        let Some(FunctionOrClass::Function { parameters, .. }) = items.first() else {
+            panic!("function not extracted");
+        };
+
+        let value = &parameters[0].type_info;
+        assert_eq!(value.base_type, crate::models::BaseType::Unknown);
+        assert_eq!(
+            value.constraints,
+            vec![crate::models::Constraint::OneOf(vec![
+                crate::models::BaseType::String,
+                crate::models::BaseType::Number,
+            ])]
+        );
+        // `T | null` collapses to `T`
+        let label = &parameters[1].type_info;
+        assert_eq!(label.base_type, crate::models::BaseType::String);
+        assert!(label.constraints.is_empty());
+    }
+
+    #[test]
+    fn test_tuple_type_is_typed_array() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+export type Pair = [string, number];
+
+export interface Marker {
+    label: string;
+    coords: [number, number];
+}
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("geo.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas =
+            parser.extract_typescript_schemas(&module, test_file.to_str().unwrap(), &converter);
+
+        let pair = schemas.iter().find(|s| s.name == "Pair").unwrap();
+        assert_eq!(
+            pair.metadata.get("type").map(String::as_str),
+            Some("[string, number]")
+        );
+        let pair = SchemaParser::parse(pair).unwrap();
+        assert_eq!(pair.schema_type, "array");
+        let item_types: Vec<&str> = pair
+            .tuple_items
+            .iter()
+            .map(|item| item.schema_type.as_str())
+            .collect();
+        assert_eq!(item_types, vec!["string", "number"]);
+
+        let marker = schemas.iter().find(|s| s.name == "Marker").unwrap();
+        let marker = SchemaParser::parse(marker).unwrap();
+        assert_eq!(marker.properties.len(), 2);
+        let coords = &marker.properties["coords"];
+        assert_eq!(coords.base_type, crate::models::BaseType::Array);
+        assert_eq!(
+            coords
+                .nested_schema
+                .as_ref()
+                .map(|tuple| tuple.tuple_items.len()),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_declaration_file_types_are_schemas() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+declare module "billing-api" {
+    export interface Invoice {
+        id: string;
+        total: number;
+    }
+    export function fetchInvoice(id: string): Promise<Invoice>;
+}
+
+declare namespace Api.Models {
+    interface Customer {
+        name: string;
+    }
+}
+
+export declare const enum Currency {
+    Usd = "USD",
+    Eur = "EUR",
+}
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("billing.d.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let file_path = test_file.to_str().unwrap();
+        let schemas = parser.extract_typescript_schemas(&module, file_path, &converter);
+        let invoice = schemas.iter().find(|s| s.name == "Invoice").unwrap();
+        assert_eq!(SchemaParser::parse(invoice).unwrap().properties.len(), 2);
+        assert!(schemas.iter().any(|s| s.name == "Customer"));
+
+        let functions = parser.extract_functions_and_classes(&module, file_path, &converter);
+        assert!(functions.iter().any(
+            |f| matches!(f, FunctionOrClass::Function { name, .. } if name == "fetchInvoice")
+        ));
+
+        let types = parser.extract_types(&module, file_path, &converter);
+        let currency = types
+            .iter()
+            .find(|t| t.schema_ref.as_ref().is_some_and(|s| s.name == "Currency"))
+            .unwrap();
+        assert_eq!(currency.base_type, crate::models::BaseType::Enum);
+        assert_eq!(
+            currency.constraints,
+            vec![crate::models::Constraint::Enum(vec![
+                "USD".to_string(),
+                "EUR".to_string()
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_jsdoc_deprecated_marks_fields_and_interfaces() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+export interface Profile {
+    name: string;
+    /** @deprecated use `name` instead */
+    nickname?: string;
+}
+
+/**
+ * @deprecated replaced by Profile
+ */
+export interface LegacyProfile {
+    login: string;
+}
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("profile.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas =
+            parser.extract_typescript_schemas(&module, test_file.to_str().unwrap(), &converter);
+
+        let profile = schemas.iter().find(|s| s.name == "Profile").unwrap();
+        assert!(!profile.is_deprecated());
+        let parsed = SchemaParser::parse(profile).unwrap();
+        assert!(parsed.properties["nickname"].deprecated);
+        assert!(!parsed.properties["name"].deprecated);
+
+        let legacy = schemas.iter().find(|s| s.name == "LegacyProfile").unwrap();
+        assert!(legacy.is_deprecated());
+    }
+
+    #[test]
+    fn test_interface_method_signature_is_captured() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+export interface UserRepo {
+    table: string;
+    save(user: User, force?: boolean): Promise<void>;
+    find?(id: number): User;
+    log(...messages: string[]): void;
+}
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("repo.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas =
+            parser.extract_typescript_schemas(&module, test_file.to_str().unwrap(), &converter);
+
+        let methods = schemas[0].methods();
+        assert_eq!(methods.len(), 3);
+        assert_eq!(methods[0].name, "save");
+        assert_eq!(
+            methods[0].parameters[0],
+            MethodParameter {
+                name: "user".to_string(),
+                type_name: Some("User".to_string()),
+                optional: false,
+            }
+        );
+        assert!(methods[0].parameters[1].optional);
+        assert!(methods[1].optional);
+        assert_eq!(methods[1].return_type.as_deref(), Some("User"));
+        assert_eq!(
+            methods[2].parameters[0],
+            MethodParameter {
+                name: "messages".to_string(),
+                type_name: Some("string[]".to_string()),
+                optional: true,
+            }
+        );
+        // Methods are not data fields
+        assert_eq!(schemas[0].metadata["fields"], "table:string:required");
+    }
+
+    #[test]
+    fn test_interface_to_json_schema() {
+        let parser = TypeScriptParser::new();
+        let source = r#"
+export interface Profile {
+    name: string;
+    age: number;
+    nickname?: string;
+}
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        let test_file = temp_dir.path().join("profile.ts");
+        std::fs::write(&test_file, source).unwrap();
+
+        let (module, _, converter) = parser.parse_file(&test_file).unwrap();
+        let schemas =
+            parser.extract_typescript_schemas(&module, test_file.to_str().unwrap(), &converter);
+        let profile = schemas.iter().find(|s| s.name == "Profile").unwrap();
+
+        let json_schema = profile.to_json_schema().unwrap();
+        assert_eq!(json_schema["type"], "object");
+        assert_eq!(json_schema["properties"]["name"]["type"], "string");
+        assert_eq!(json_schema["properties"]["age"]["type"], "number");
+        assert_eq!(json_schema["required"], serde_json::json!(["age", "name"]));
+    }
+
     #[test]
     fn test_zod_typescript_sync() {
         let parser = TypeScriptParser::new();
@@ -0,0 +1,63 @@
+//! Общие фикстуры для тестов анализаторов и отчётов
+//!
+//! Доступны в тестах `dc-core` и в других крейтах через фичу `test-support`.
+
+use crate::models::{
+    ChainDirection, ChainStatus, Contract, DataChain, Location, SchemaReference, SchemaType,
+    Severity,
+};
+use std::collections::HashMap;
+
+/// JSON Schema с именем `name` и телом `json_schema` в метаданных
+pub fn schema(name: &str, json_schema: serde_json::Value) -> SchemaReference {
+    SchemaReference {
+        name: name.to_string(),
+        schema_type: SchemaType::JsonSchema,
+        location: Location {
+            file: "schema.json".to_string(),
+            line: 1,
+            column: None,
+        },
+        metadata: HashMap::from([("json_schema".to_string(), json_schema.to_string())]),
+    }
+}
+
+/// Pydantic-модель без метаданных
+pub fn pydantic_schema(name: &str) -> SchemaReference {
+    SchemaReference {
+        name: name.to_string(),
+        schema_type: SchemaType::Pydantic,
+        location: Location {
+            file: "schemas.py".to_string(),
+            line: 1,
+            column: None,
+        },
+        metadata: HashMap::new(),
+    }
+}
+
+/// Контракт между двумя схемами без несоответствий
+pub fn contract(from: SchemaReference, to: SchemaReference) -> Contract {
+    Contract {
+        from_link_id: "from".to_string(),
+        to_link_id: "to".to_string(),
+        from_schema: from,
+        to_schema: to,
+        mismatches: Vec::new(),
+        severity: Severity::Info,
+        status_code: None,
+    }
+}
+
+/// Цепочка без звеньев с заданными контрактами
+pub fn chain(name: &str, contracts: Vec<Contract>) -> DataChain {
+    DataChain {
+        id: "chain-0".to_string(),
+        name: name.to_string(),
+        links: Vec::new(),
+        contracts,
+        direction: ChainDirection::FrontendToBackend,
+        status: ChainStatus::default(),
+        truncated: false,
+    }
+}
@@ -0,0 +1,50 @@
+use dc_core::analyzers::ChainBuilder;
+use dc_core::call_graph::{find_nodes, CallGraphBuilder, CallNode};
+use dc_core::data_flow::DataFlowTracker;
+use dc_core::parsers::{LocationConverter, PythonParser};
+use rustpython_parser::{parse, Mode};
+use tempfile::TempDir;
+
+#[path = "../benches/fixtures/mod.rs"]
+mod fixtures;
+
+#[test]
+fn test_synthetic_module_is_deterministic_and_parses() {
+    let source = fixtures::python_module("bench", 3, 20);
+    assert_eq!(source, fixtures::python_module("bench", 3, 20));
+
+    let module = parse(&source, Mode::Module, "bench.py").unwrap();
+    let converter = LocationConverter::new(source.clone());
+    let parser = PythonParser::new();
+
+    assert_eq!(
+        parser
+            .extract_pydantic_models(&module, "bench.py", &converter)
+            .len(),
+        3
+    );
+    assert_eq!(
+        parser.extract_calls(&module, "bench.py", &converter).len(),
+        19
+    );
+}
+
+#[test]
+fn test_synthetic_project_builds_one_route_per_module() {
+    let temp_dir = TempDir::new().unwrap();
+    let main_path = fixtures::write_project(temp_dir.path(), 4, 5).unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&main_path).unwrap();
+    let graph = builder.into_graph();
+
+    assert_eq!(
+        find_nodes(&graph, |node| matches!(node, CallNode::Route { .. })).len(),
+        4
+    );
+    let tracker = DataFlowTracker::new(&graph);
+    let chains = ChainBuilder::new(&graph, &tracker)
+        .find_all_chains()
+        .unwrap();
+    assert!(!chains.is_empty());
+}
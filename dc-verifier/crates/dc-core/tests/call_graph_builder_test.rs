@@ -0,0 +1,940 @@
+use dc_core::call_graph::{
+    find_nodes, imports_only, outgoing_nodes, CallEdge, CallGraphBuilder, CallNode, HttpMethod,
+    PathCache,
+};
+use dc_core::models::{BaseType, SchemaType};
+use tempfile::TempDir;
+
+#[test]
+fn test_same_named_functions_produce_distinct_nodes() {
+    let temp_dir = TempDir::new().unwrap();
+    let module_path = temp_dir.path().join("handlers.py");
+    std::fs::write(
+        &module_path,
+        r#"
+def handler(data):
+    return data
+
+
+def handler(data, extra):
+    return extra
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&module_path).unwrap();
+    let graph = builder.into_graph();
+
+    let handlers = find_nodes(
+        &graph,
+        |n| matches!(n, CallNode::Function { name, .. } if name == "handler"),
+    );
+    assert_eq!(handlers.len(), 2);
+
+    let mut lines: Vec<usize> = handlers
+        .iter()
+        .filter_map(|id| match graph.node_weight(**id) {
+            Some(CallNode::Function { line, .. }) => Some(*line),
+            _ => None,
+        })
+        .collect();
+    lines.sort();
+    assert_eq!(lines, vec![2, 6]);
+}
+
+#[test]
+fn test_call_inside_list_comprehension_produces_edge() {
+    let temp_dir = TempDir::new().unwrap();
+    let module_path = temp_dir.path().join("service.py");
+    std::fs::write(
+        &module_path,
+        r#"
+def normalize(item):
+    return item.strip()
+
+
+def process(items):
+    return [normalize(item) for item in items]
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&module_path).unwrap();
+    let graph = builder.into_graph();
+
+    let function = |target: &str| {
+        find_nodes(
+            &graph,
+            |n| matches!(n, CallNode::Function { name, .. } if name == target),
+        )[0]
+    };
+    let process = function("process");
+    let normalize = function("normalize");
+
+    assert!(graph
+        .edges_connecting(*process, *normalize)
+        .any(|edge| matches!(edge.weight(), CallEdge::Call { .. })));
+}
+
+#[test]
+fn test_return_produces_return_edge_to_caller() {
+    let temp_dir = TempDir::new().unwrap();
+    let module_path = temp_dir.path().join("service.py");
+    std::fs::write(
+        &module_path,
+        r#"
+def load_user(user_id):
+    user = fetch(user_id)
+    return user
+
+
+def log(message):
+    print(message)
+
+
+def get_user(user_id):
+    log(user_id)
+    return load_user(user_id)
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&module_path).unwrap();
+    let graph = builder.into_graph();
+
+    let function = |target: &str| {
+        find_nodes(
+            &graph,
+            |n| matches!(n, CallNode::Function { name, .. } if name == target),
+        )[0]
+    };
+    let get_user = function("get_user");
+    let load_user = function("load_user");
+    let log = function("log");
+
+    let return_values: Vec<_> = graph
+        .edges_connecting(*load_user, *get_user)
+        .filter_map(|edge| match edge.weight() {
+            CallEdge::Return { return_value, .. } => Some(return_value.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(return_values, vec!["user"]);
+
+    // `log` returns nothing, and return edges are not calls
+    assert_eq!(graph.edges_connecting(*log, *get_user).count(), 0);
+    assert!(!outgoing_nodes(&graph, load_user).contains(&get_user));
+}
+
+#[test]
+fn test_imports_only_keeps_modules_and_import_edges() {
+    let temp_dir = TempDir::new().unwrap();
+    let main_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        temp_dir.path().join("utils.py"),
+        r#"
+def helper(value):
+    return value
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        &main_path,
+        r#"
+import utils
+
+
+def run(value):
+    return utils.helper(value)
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&main_path).unwrap();
+    let graph = builder.into_graph();
+    assert!(graph
+        .node_weights()
+        .any(|n| matches!(n, CallNode::Function { .. })));
+
+    let filtered = imports_only(&graph);
+
+    assert_eq!(filtered.node_count(), 2);
+    assert!(filtered
+        .node_weights()
+        .all(|n| matches!(n, CallNode::Module { .. })));
+    assert_eq!(filtered.edge_count(), 1);
+    assert!(filtered
+        .edge_weights()
+        .all(|e| matches!(e, CallEdge::Import { import_path, .. } if import_path == "utils")));
+}
+
+#[test]
+fn test_unresolved_call_produces_external_node() {
+    let temp_dir = TempDir::new().unwrap();
+    let module_path = temp_dir.path().join("service.py");
+    std::fs::write(
+        &module_path,
+        r#"
+import json
+
+
+def dump(payload):
+    return json.dumps(payload)
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&module_path).unwrap();
+    let graph = builder.into_graph();
+
+    let external = find_nodes(
+        &graph,
+        |n| matches!(n, CallNode::ExternalCall { name } if name == "json.dumps"),
+    );
+    assert_eq!(external.len(), 1);
+    assert!(find_nodes(
+        &graph,
+        |n| matches!(n, CallNode::Function { name, .. } if name == "json.dumps")
+    )
+    .is_empty());
+}
+
+#[test]
+fn test_inherited_method_call_resolves_to_base_class() {
+    let temp_dir = TempDir::new().unwrap();
+    let main_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        temp_dir.path().join("base.py"),
+        r#"
+class Repository:
+    def save(self, item):
+        return item
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        &main_path,
+        r#"
+from base import Repository
+
+
+class UserRepository(Repository):
+    def find(self, user_id):
+        return user_id
+
+
+def create_user(user):
+    return UserRepository.save(user)
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&main_path).unwrap();
+    let graph = builder.into_graph();
+
+    let create_user = find_nodes(
+        &graph,
+        |n| matches!(n, CallNode::Function { name, .. } if name == "create_user"),
+    )[0];
+    let save = find_nodes(
+        &graph,
+        |n| matches!(n, CallNode::Method { name, .. } if name == "save"),
+    );
+    assert_eq!(save.len(), 1);
+
+    assert!(graph
+        .edges_connecting(*create_user, *save[0])
+        .any(|edge| matches!(edge.weight(), CallEdge::Call { .. })));
+    assert!(!graph.node_weights().any(
+        |n| matches!(n, CallNode::ExternalCall { name, .. } if name == "UserRepository.save")
+    ));
+}
+
+#[test]
+fn test_same_named_classes_keep_their_own_bases() {
+    let temp_dir = TempDir::new().unwrap();
+    let main_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        temp_dir.path().join("users.py"),
+        r#"
+class Repository:
+    def save(self, item):
+        return item
+
+
+class UserRepository(Repository):
+    def find(self, user_id):
+        return user_id
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        temp_dir.path().join("legacy.py"),
+        r#"
+class UserRepository:
+    def load(self, user_id):
+        return user_id
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        &main_path,
+        r#"
+from users import UserRepository
+import legacy
+
+
+def create_user(user):
+    return UserRepository.save(user)
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&main_path).unwrap();
+    let graph = builder.into_graph();
+
+    // The imported `UserRepository` resolves, not the later `legacy` one
+    let create_user = find_nodes(
+        &graph,
+        |n| matches!(n, CallNode::Function { name, .. } if name == "create_user"),
+    )[0];
+    let save = find_nodes(
+        &graph,
+        |n| matches!(n, CallNode::Method { name, .. } if name == "save"),
+    )[0];
+    assert!(graph
+        .edges_connecting(*create_user, *save)
+        .any(|edge| matches!(edge.weight(), CallEdge::Call { .. })));
+}
+
+#[test]
+fn test_depends_parameter_adds_edge_to_dependency() {
+    let temp_dir = TempDir::new().unwrap();
+    let main_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        temp_dir.path().join("database.py"),
+        r#"
+def get_db():
+    return None
+
+def get_user():
+    return None
+
+def get_settings():
+    return None
+
+def fallback():
+    return None
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        &main_path,
+        r#"
+from typing import Annotated
+from fastapi import Depends, FastAPI
+from database import fallback, get_db, get_settings, get_user
+
+app = FastAPI()
+
+
+@app.get("/items")
+def list_items(
+    user: Annotated[dict, Depends(get_user)],
+    db = Depends(get_db),
+    settings = Depends(dependency=get_settings, use_cache=False),
+    other = Depends(use_cache=fallback),
+):
+    return []
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&main_path).unwrap();
+    let graph = builder.into_graph();
+
+    let function = |function_name: &str| {
+        find_nodes(
+            &graph,
+            |n| matches!(n, CallNode::Function { name, .. } if name == function_name),
+        )[0]
+    };
+    let route = find_nodes(&graph, |n| matches!(n, CallNode::Route { .. }))[0];
+
+    assert!(graph
+        .edges_connecting(*route, *function("get_db"))
+        .any(|edge| matches!(edge.weight(), CallEdge::Call { .. })));
+    // The handler comes first, then the dependencies in declaration order;
+    // only the first positional argument or `dependency=` names a dependency
+    assert_eq!(
+        outgoing_nodes(&graph, route),
+        vec![
+            function("list_items"),
+            function("get_user"),
+            function("get_db"),
+            function("get_settings"),
+        ]
+    );
+}
+
+#[test]
+fn test_path_cache_canonicalizes_each_path_once() {
+    let temp_dir = TempDir::new().unwrap();
+    let main = temp_dir.path().join("main.py");
+    let models = temp_dir.path().join("models.py");
+    std::fs::write(&main, "").unwrap();
+    std::fs::write(&models, "").unwrap();
+
+    let cache = PathCache::new();
+    for _ in 0..100 {
+        assert_eq!(cache.normalize(&main), main.canonicalize().unwrap());
+        assert_eq!(cache.normalize(&models), models.canonicalize().unwrap());
+    }
+    // A missing file is kept as is and cached as well
+    let missing = temp_dir.path().join("missing.py");
+    assert_eq!(cache.normalize(&missing), missing);
+    assert_eq!(cache.normalize(&missing), missing);
+
+    assert_eq!(cache.canonicalize_calls(), 3);
+}
+
+#[test]
+fn test_non_route_decorators_are_recorded_on_function_node() {
+    let temp_dir = TempDir::new().unwrap();
+    let module_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        &module_path,
+        r#"
+from fastapi import FastAPI
+
+app = FastAPI()
+
+
+@app.get("/profile")
+@require_auth
+@cache(ttl=60)
+async def get_profile(user_id: int):
+    return user_id
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&module_path).unwrap();
+    let graph = builder.into_graph();
+
+    let decorators = graph.node_indices().find_map(|idx| match &graph[idx] {
+        CallNode::Function {
+            name, decorators, ..
+        } if name == "get_profile" => Some(decorators.clone()),
+        _ => None,
+    });
+    assert_eq!(
+        decorators,
+        Some(vec!["require_auth".to_string(), "cache".to_string()])
+    );
+}
+
+#[test]
+fn test_route_operation_id_comes_from_decorator() {
+    let temp_dir = TempDir::new().unwrap();
+    let module_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        &module_path,
+        r#"
+from fastapi import FastAPI
+
+app = FastAPI()
+
+
+@app.get("/users")
+def list_users():
+    return []
+
+
+@app.post("/users", operation_id="createUser")
+def create_user(name: str):
+    return name
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&module_path).unwrap();
+    let graph = builder.into_graph();
+
+    let mut operation_ids: Vec<Option<String>> = graph
+        .node_weights()
+        .filter_map(|node| match node {
+            CallNode::Route { operation_id, .. } => Some(operation_id.clone()),
+            _ => None,
+        })
+        .collect();
+    operation_ids.sort();
+    // Without `operation_id=` the id is generated from the final path later
+    assert_eq!(operation_ids, vec![None, Some("createUser".to_string())]);
+}
+
+#[test]
+fn test_aliased_imports_resolve_calls() {
+    let temp_dir = TempDir::new().unwrap();
+    let main_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        temp_dir.path().join("helpers.py"),
+        r#"
+def normalize(value):
+    return value
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        &main_path,
+        r#"
+import numpy as np
+import helpers as h
+from helpers import normalize as norm
+
+
+def run(value):
+    h.normalize(value)
+    norm(value)
+    return np.array(value)
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&main_path).unwrap();
+    let graph = builder.into_graph();
+
+    let run = find_nodes(
+        &graph,
+        |n| matches!(n, CallNode::Function { name, .. } if name == "run"),
+    )[0];
+    let normalize = find_nodes(
+        &graph,
+        |n| matches!(n, CallNode::Function { name, .. } if name == "normalize"),
+    )[0];
+    let calls_to_normalize = graph
+        .edges_connecting(*run, *normalize)
+        .filter(|edge| matches!(edge.weight(), CallEdge::Call { .. }))
+        .count();
+    assert_eq!(calls_to_normalize, 2);
+    assert_eq!(
+        find_nodes(
+            &graph,
+            |n| matches!(n, CallNode::ExternalCall { name } if name == "numpy.array")
+        )
+        .len(),
+        1
+    );
+}
+
+#[test]
+fn test_star_import_resolves_exported_names() {
+    let temp_dir = TempDir::new().unwrap();
+    let main_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        temp_dir.path().join("models.py"),
+        r#"
+__all__ = ["create_user"]
+
+
+def create_user(data):
+    return data
+
+
+def audit(data):
+    return data
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        temp_dir.path().join("services.py"),
+        r#"
+def create_user(data):
+    return data
+
+
+def audit(data):
+    return data
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        &main_path,
+        r#"
+from services import *
+from models import *
+
+
+def run(data):
+    create_user(data)
+    audit(data)
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&main_path).unwrap();
+    let graph = builder.into_graph();
+
+    let run = find_nodes(
+        &graph,
+        |n| matches!(n, CallNode::Function { name, .. } if name == "run"),
+    )[0];
+    let callees: Vec<(String, String)> = graph
+        .edges(*run)
+        .filter_map(|edge| match edge.weight() {
+            CallEdge::Call { callee, .. } => match &graph[**callee] {
+                CallNode::Function { name, file, .. } => Some((
+                    name.clone(),
+                    file.file_name()?.to_string_lossy().to_string(),
+                )),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    // `audit` is not in `models.__all__`, so it still comes from `services`
+    assert_eq!(callees.len(), 2);
+    assert!(callees.contains(&("create_user".to_string(), "models.py".to_string())));
+    assert!(callees.contains(&("audit".to_string(), "services.py".to_string())));
+}
+
+#[test]
+fn test_self_method_call_resolves_to_class_method() {
+    let temp_dir = TempDir::new().unwrap();
+    let main_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        &main_path,
+        r#"
+class UserService:
+    def create(self, data):
+        payload = self.validate(data)
+        return self.repo.save(payload)
+
+    def validate(self, data):
+        return data
+
+    @classmethod
+    def build(cls):
+        return cls.defaults()
+
+    @classmethod
+    def defaults(cls):
+        return {}
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&main_path).unwrap();
+    let graph = builder.into_graph();
+
+    let method = |wanted: &str| {
+        find_nodes(
+            &graph,
+            |n| matches!(n, CallNode::Method { name, .. } if name == wanted),
+        )[0]
+    };
+    for (caller, callee) in [("create", "validate"), ("build", "defaults")] {
+        assert!(
+            graph
+                .edges_connecting(*method(caller), *method(callee))
+                .any(|edge| matches!(edge.weight(), CallEdge::Call { .. })),
+            "{} -> {}",
+            caller,
+            callee
+        );
+    }
+    assert!(graph
+        .node_weights()
+        .any(|n| matches!(n, CallNode::ExternalCall { name } if name == "self.repo.save")));
+}
+
+#[test]
+fn test_model_annotation_yields_parameter_schema_ref() {
+    let temp_dir = TempDir::new().unwrap();
+    let main_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        temp_dir.path().join("schemas.py"),
+        r#"
+from pydantic import BaseModel
+
+
+class UserModel(BaseModel):
+    name: str
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        &main_path,
+        r#"
+from schemas import UserModel
+
+
+def create_user(user: UserModel, notify: bool = False):
+    return user
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&main_path).unwrap();
+    let graph = builder.into_graph();
+
+    let parameters = graph
+        .node_weights()
+        .find_map(|n| match n {
+            CallNode::Function {
+                name, parameters, ..
+            } if name == "create_user" => Some(parameters.clone()),
+            _ => None,
+        })
+        .unwrap();
+    let user_schema = parameters[0].type_info.schema_ref.as_ref().unwrap();
+    assert_eq!(user_schema.name, "UserModel");
+    assert_eq!(user_schema.schema_type, SchemaType::Pydantic);
+    assert_eq!(parameters[1].type_info.base_type, BaseType::Boolean);
+    assert!(parameters[1].type_info.schema_ref.is_none());
+}
+
+#[test]
+fn test_return_annotation_becomes_return_type() {
+    let temp_dir = TempDir::new().unwrap();
+    let main_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        &main_path,
+        r#"
+from typing import Optional
+
+from pydantic import BaseModel
+
+
+class UserModel(BaseModel):
+    name: str
+
+
+def find_user(name: str) -> Optional[UserModel]:
+    return None
+
+
+async def count_users() -> int:
+    return 0
+
+
+def reset() -> None:
+    pass
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&main_path).unwrap();
+    let graph = builder.into_graph();
+
+    let return_type = |wanted: &str| {
+        graph
+            .node_weights()
+            .find_map(|n| match n {
+                CallNode::Function {
+                    name, return_type, ..
+                } if name == wanted => Some(return_type.clone()),
+                _ => None,
+            })
+            .unwrap()
+    };
+    let find_user = return_type("find_user").unwrap();
+    assert!(find_user.optional);
+    assert_eq!(find_user.schema_ref.unwrap().name, "UserModel");
+    let count_users = return_type("count_users").unwrap();
+    assert_eq!(count_users.base_type, BaseType::Integer);
+    assert!(!count_users.optional);
+    assert!(return_type("reset").is_none());
+}
+
+#[test]
+fn test_flask_route_methods_come_from_keyword() {
+    let temp_dir = TempDir::new().unwrap();
+    let main_path = temp_dir.path().join("app.py");
+    std::fs::write(
+        &main_path,
+        r#"
+from flask import Blueprint, Flask
+
+app = Flask(__name__)
+bp = Blueprint("users", __name__)
+
+
+@app.route("/users", methods=["GET", "POST", "GET"])
+def users():
+    return []
+
+
+@bp.route("/health")
+def health():
+    return "ok"
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&main_path).unwrap();
+    let graph = builder.into_graph();
+
+    let mut routes: Vec<(String, HttpMethod)> = graph
+        .node_weights()
+        .filter_map(|n| match n {
+            CallNode::Route { path, method, .. } => Some((path.clone(), *method)),
+            _ => None,
+        })
+        .collect();
+    routes.sort_by_key(|(path, method)| (path.clone(), format!("{:?}", method)));
+    assert_eq!(
+        routes,
+        vec![
+            ("/health".to_string(), HttpMethod::Get),
+            ("/users".to_string(), HttpMethod::Get),
+            ("/users".to_string(), HttpMethod::Post),
+        ]
+    );
+}
+
+#[test]
+fn test_include_router_prefixes_router_routes() {
+    let temp_dir = TempDir::new().unwrap();
+    let main_path = temp_dir.path().join("main.py");
+    let routers_dir = temp_dir.path().join("routers");
+    std::fs::create_dir(&routers_dir).unwrap();
+    std::fs::write(routers_dir.join("__init__.py"), "").unwrap();
+    std::fs::write(
+        routers_dir.join("users.py"),
+        r#"
+from fastapi import APIRouter
+
+router = APIRouter()
+
+
+@router.get("/users")
+def list_users():
+    return []
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        routers_dir.join("items.py"),
+        r#"
+from fastapi import APIRouter
+
+router = APIRouter()
+
+
+@router.post("/items")
+def create_item(name: str):
+    return name
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        &main_path,
+        r#"
+from fastapi import FastAPI
+
+from routers import users
+from routers.items import router as items_router
+
+app = FastAPI()
+app.include_router(users.router, prefix="/api/v1")
+app.include_router(items_router, prefix="/api/v2")
+
+
+@app.get("/health")
+def health():
+    return "ok"
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&main_path).unwrap();
+    let graph = builder.into_graph();
+
+    let mut paths: Vec<String> = graph
+        .node_weights()
+        .filter_map(|n| match n {
+            CallNode::Route { path, .. } => Some(path.clone()),
+            _ => None,
+        })
+        .collect();
+    paths.sort();
+    assert_eq!(paths, vec!["/api/v1/users", "/api/v2/items", "/health"]);
+}
+
+#[test]
+fn test_router_included_twice_gets_a_route_per_include() {
+    let temp_dir = TempDir::new().unwrap();
+    let main_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        temp_dir.path().join("users.py"),
+        r#"
+from fastapi import APIRouter
+
+router = APIRouter()
+
+
+@router.get("/users")
+def list_users():
+    return []
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        &main_path,
+        r#"
+from fastapi import FastAPI
+
+from users import router
+
+app = FastAPI()
+app.include_router(router, prefix="/v1")
+app.include_router(router, prefix="/v2")
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&main_path).unwrap();
+    let graph = builder.into_graph();
+
+    let mut routes: Vec<(String, String)> = graph
+        .node_indices()
+        .filter_map(|idx| match &graph[idx] {
+            CallNode::Route { path, handler, .. } => {
+                // Every copy keeps the handler edge
+                assert!(graph.contains_edge(idx, handler.0));
+                match &graph[handler.0] {
+                    CallNode::Function { name, .. } => Some((path.clone(), name.clone())),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect();
+    routes.sort();
+    assert_eq!(
+        routes,
+        vec![
+            ("/v1/users".to_string(), "list_users".to_string()),
+            ("/v2/users".to_string(), "list_users".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,363 @@
+use dc_core::analyzers::{ChainBuilder, ContractChecker};
+use dc_core::call_graph::{CallGraph, CallGraphBuilder, CallNode, HttpMethod};
+use dc_core::data_flow::DataFlowTracker;
+use dc_core::models::{ChainDirection, Location, NodeId, SchemaReference, SchemaType};
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+#[test]
+fn test_iter_chains_matches_find_all_chains() {
+    let temp_dir = TempDir::new().unwrap();
+    let app_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        &app_path,
+        r#"
+from fastapi import FastAPI
+
+app = FastAPI()
+
+@app.get("/users/{user_id}")
+def get_user(user_id: int):
+    return {"user_id": user_id}
+
+@app.post("/orders")
+def create_order(item: str, quantity: int = 1):
+    return {"item": item}
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&app_path).unwrap();
+    let graph = builder.into_graph();
+
+    let tracker = DataFlowTracker::new(&graph);
+    let chain_builder = ChainBuilder::new(&graph, &tracker);
+
+    let collected: Vec<String> = chain_builder
+        .find_all_chains()
+        .unwrap()
+        .into_iter()
+        .map(|chain| chain.id)
+        .collect();
+    let iterated: Vec<String> = chain_builder
+        .iter_chains()
+        .filter_map(Result::ok)
+        .map(|chain| chain.id)
+        .collect();
+
+    assert!(!collected.is_empty());
+    assert_eq!(iterated, collected);
+}
+
+#[test]
+fn test_equivalent_route_templates_produce_same_chain_name() {
+    let mut graph = CallGraph::new();
+    let mut routes = Vec::new();
+
+    for path in ["/users/{user_id}", "/users/:user_id/"] {
+        let handler = NodeId::from(graph.add_node(CallNode::Function {
+            name: "get_user".to_string(),
+            file: PathBuf::from("main.py"),
+            line: 1,
+            parameters: Vec::new(),
+            return_type: None,
+            decorators: Vec::new(),
+        }));
+        let route = NodeId::from(graph.add_node(CallNode::Route {
+            path: path.to_string(),
+            method: HttpMethod::Get,
+            handler,
+            location: Location {
+                file: "main.py".to_string(),
+                line: 1,
+                column: None,
+            },
+            response_model: None,
+            operation_id: None,
+            status_code: None,
+            responses: Vec::new(),
+        }));
+        routes.push(route);
+    }
+
+    let tracker = DataFlowTracker::new(&graph);
+    let chain_builder = ChainBuilder::new(&graph, &tracker);
+    let names: Vec<String> = routes
+        .into_iter()
+        .map(|route| chain_builder.build_forward_chain(route).unwrap().name)
+        .collect();
+
+    assert_eq!(names[0], "GET /users/{user_id}");
+    assert_eq!(names[0], names[1]);
+}
+
+#[test]
+fn test_upload_handler_is_not_compared_as_json_body() {
+    let temp_dir = TempDir::new().unwrap();
+    let app_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        &app_path,
+        r#"
+from fastapi import FastAPI, File, UploadFile
+
+app = FastAPI()
+
+@app.post("/avatars")
+async def upload_avatar(file: UploadFile = File(...)):
+    return {"filename": file.filename}
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&app_path).unwrap();
+    let graph = builder.into_graph();
+
+    let tracker = DataFlowTracker::new(&graph);
+    let chain_builder = ChainBuilder::new(&graph, &tracker);
+    let chain = chain_builder
+        .iter_chains()
+        .filter_map(Result::ok)
+        .find(|chain| chain.direction == ChainDirection::FrontendToBackend)
+        .unwrap();
+    let request_schema = &chain.links[0].schema_ref;
+    assert!(request_schema.is_form_body());
+
+    // A JSON body sent by the client is not checked against the upload fields
+    let mut metadata = HashMap::new();
+    metadata.insert(
+        "json_schema".to_string(),
+        json!({
+            "type": "object",
+            "properties": {"name": {"type": "integer"}},
+            "required": ["name"]
+        })
+        .to_string(),
+    );
+    let client_schema = SchemaReference {
+        name: "AvatarForm".to_string(),
+        schema_type: SchemaType::JsonSchema,
+        location: request_schema.location.clone(),
+        metadata,
+    };
+    let checker = ContractChecker::new();
+    assert!(checker
+        .compare_schemas(&client_schema, request_schema)
+        .is_empty());
+}
+
+#[test]
+fn test_branching_handler_produces_chain_per_sink() {
+    let temp_dir = TempDir::new().unwrap();
+    let app_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        &app_path,
+        r#"
+from fastapi import FastAPI
+
+app = FastAPI()
+
+def save_user(name):
+    return name
+
+def send_welcome_email(name):
+    return name
+
+@app.post("/users")
+def create_user(name: str):
+    save_user(name)
+    send_welcome_email(name)
+    return {"name": name}
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&app_path).unwrap();
+    let graph = builder.into_graph();
+
+    let tracker = DataFlowTracker::new(&graph);
+    let chain_builder = ChainBuilder::new(&graph, &tracker);
+    let chains: Vec<_> = chain_builder
+        .find_all_chains()
+        .unwrap()
+        .into_iter()
+        .filter(|chain| chain.direction == ChainDirection::FrontendToBackend)
+        .collect();
+
+    assert_eq!(chains.len(), 2);
+    let sinks: Vec<&str> = chains
+        .iter()
+        .map(|chain| chain.links.last().unwrap().id.as_str())
+        .collect();
+    assert!(sinks.iter().any(|sink| sink.contains("save_user")));
+    assert!(sinks.iter().any(|sink| sink.contains("send_welcome_email")));
+    assert_ne!(chains[0].id, chains[1].id);
+    assert_ne!(chains[0].name, chains[1].name);
+}
+
+#[test]
+fn test_long_chain_is_capped_and_flagged_truncated() {
+    let temp_dir = TempDir::new().unwrap();
+    let app_path = temp_dir.path().join("main.py");
+    let mut source = String::from("from fastapi import FastAPI\n\napp = FastAPI()\n\n");
+    for step in 1..10 {
+        source.push_str(&format!(
+            "def step{}(data):\n    return step{}(data)\n\n",
+            step,
+            step + 1
+        ));
+    }
+    source.push_str("def step10(data):\n    return data\n\n");
+    source.push_str("@app.post(\"/users\")\ndef create_user(name: str):\n    return step1(name)\n");
+    std::fs::write(&app_path, source).unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&app_path).unwrap();
+    let graph = builder.into_graph();
+    let tracker = DataFlowTracker::new(&graph);
+
+    let forward_chain = |chain_builder: ChainBuilder| {
+        chain_builder
+            .find_all_chains()
+            .unwrap()
+            .into_iter()
+            .find(|chain| chain.direction == ChainDirection::FrontendToBackend)
+            .unwrap()
+    };
+
+    let capped = forward_chain(ChainBuilder::new(&graph, &tracker).with_max_chain_length(4));
+    assert_eq!(capped.links.len(), 4);
+    assert!(capped.truncated);
+
+    // Route, handler and ten steps fit into the default limit
+    let full = forward_chain(ChainBuilder::new(&graph, &tracker));
+    assert_eq!(full.links.len(), 12);
+    assert!(!full.truncated);
+}
+
+#[test]
+fn test_response_model_takes_precedence_over_return_annotation() {
+    let temp_dir = TempDir::new().unwrap();
+    let app_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        &app_path,
+        r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class UserRecord(BaseModel):
+    id: int
+    name: str
+    password_hash: str
+
+class UserOut(BaseModel):
+    id: int
+    name: str
+
+@app.post(path="/users", response_model=UserOut)
+def create_user(name: str) -> UserRecord:
+    return UserRecord(id=1, name=name, password_hash="")
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&app_path).unwrap();
+    let mut graph = builder.into_graph();
+
+    let tracker = DataFlowTracker::new(&graph);
+    let chain_builder = ChainBuilder::new(&graph, &tracker);
+    let route = dc_core::call_graph::find_nodes(&graph, |n| matches!(n, CallNode::Route { .. }))[0];
+    // The path is given as the `path=` keyword
+    assert!(matches!(
+        graph.node_weight(*route),
+        Some(CallNode::Route { path, .. }) if path == "/users"
+    ));
+
+    let response = chain_builder.extract_route_response_schema(route).unwrap();
+    assert_eq!(response.name, "UserOut");
+    assert_eq!(response.metadata["fields"], "id:int,name:str");
+
+    let reverse = chain_builder.build_reverse_chain(route).unwrap();
+    let route_link = reverse.links.last().unwrap();
+    assert_eq!(route_link.schema_ref.name, "UserOut");
+
+    // Without `response_model` the `-> UserRecord` annotation is used
+    if let Some(CallNode::Route { response_model, .. }) = graph.node_weight_mut(*route) {
+        *response_model = None;
+    }
+    let tracker = DataFlowTracker::new(&graph);
+    let chain_builder = ChainBuilder::new(&graph, &tracker);
+    let response = chain_builder.extract_route_response_schema(route).unwrap();
+    assert_eq!(response.name, "UserRecord");
+}
+
+#[test]
+fn test_response_model_list_resolves_to_item_model() {
+    let temp_dir = TempDir::new().unwrap();
+    let app_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        &app_path,
+        r#"
+from typing import List
+
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class UserOut(BaseModel):
+    id: int
+    name: str
+
+@app.get("/users", response_model=List[UserOut])
+def list_users():
+    return []
+
+@app.get("/users/stats", response_model=None)
+def user_stats() -> dict:
+    return {}
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&app_path).unwrap();
+    let graph = builder.into_graph();
+
+    let tracker = DataFlowTracker::new(&graph);
+    let chain_builder = ChainBuilder::new(&graph, &tracker);
+    let route = |wanted: &str| {
+        dc_core::call_graph::find_nodes(
+            &graph,
+            |n| matches!(n, CallNode::Route { path, .. } if path == wanted),
+        )[0]
+    };
+
+    let users = chain_builder
+        .extract_route_response_schema(route("/users"))
+        .unwrap();
+    assert_eq!(users.name, "UserOut");
+    // The route keeps the list shape of the response
+    assert!(matches!(
+        graph.node_weight(*route("/users")),
+        Some(CallNode::Route {
+            response_model: Some(dc_core::models::TypeInfo {
+                base_type: dc_core::models::BaseType::Array,
+                ..
+            }),
+            ..
+        })
+    ));
+    // `response_model=None` names no model: the return annotation is used
+    let stats = chain_builder
+        .extract_route_response_schema(route("/users/stats"))
+        .unwrap();
+    assert_eq!(stats.name, "Object");
+}
@@ -62,6 +62,7 @@ fn test_chain_with_typescript_function() {
             constraints: Vec::new(),
             optional: false,
         }),
+        decorators: Vec::new(),
     });
 
     let tracker = DataFlowTracker::new(&graph);
@@ -131,6 +132,7 @@ fn test_contract_checker_with_typescript_schemas() {
         to_schema: to_schema.clone(),
         mismatches: Vec::new(),
         severity: dc_core::models::Severity::Info,
+        status_code: None,
     };
 
     let checker = ContractChecker::new();
@@ -0,0 +1,287 @@
+use dc_core::analyzers::SchemaParser;
+use dc_core::models::{dedupe_schema_refs, BaseType, Constraint, ConstraintValue};
+use dc_core::parsers::{constrained_type, LocationConverter, PythonParser};
+use rustpython_parser::{ast, parse, Mode};
+use std::collections::HashMap;
+
+fn required_fields(source: &str) -> Vec<String> {
+    let ast = parse(source, Mode::Module, "models.py").unwrap();
+    let converter = LocationConverter::new(source.to_string());
+    let models = PythonParser::new().extract_pydantic_models(&ast, "models.py", &converter);
+    assert_eq!(models.len(), 1);
+
+    models[0]
+        .metadata
+        .get("required")
+        .map(|required| {
+            required
+                .split(',')
+                .filter(|field| !field.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_field_without_default_is_required() {
+    let required = required_fields(
+        r#"
+from pydantic import BaseModel, Field
+
+class User(BaseModel):
+    name: str
+    email: str = Field(..., description="Contact email")
+    age: int = ...
+"#,
+    );
+    assert_eq!(required, vec!["name", "email", "age"]);
+}
+
+#[test]
+fn test_optional_with_none_default_is_optional() {
+    let required = required_fields(
+        r#"
+from typing import Optional, Union
+from pydantic import BaseModel
+
+class User(BaseModel):
+    id: int
+    name: Optional[str] = None
+    nickname: str | None = None
+    bio: Union[str, None] = None
+"#,
+    );
+    assert_eq!(required, vec!["id"]);
+}
+
+#[test]
+fn test_field_with_default_value_is_optional() {
+    let required = required_fields(
+        r#"
+from pydantic import BaseModel, Field
+
+class User(BaseModel):
+    id: int
+    role: str = "member"
+    tags: list = Field(default_factory=list)
+    active: bool = Field(True)
+"#,
+    );
+    assert_eq!(required, vec!["id"]);
+}
+
+#[test]
+fn test_nested_model_reference_is_resolved() {
+    let source = r#"
+from typing import Optional
+from pydantic import BaseModel
+
+class Address(BaseModel):
+    city: str
+    zip_code: int
+
+class User(BaseModel):
+    name: str
+    address: Address
+    billing: Optional[Address] = None
+"#;
+    let ast = parse(source, Mode::Module, "models.py").unwrap();
+    let converter = LocationConverter::new(source.to_string());
+    let models = PythonParser::new().extract_pydantic_models(&ast, "models.py", &converter);
+    let user = models.iter().find(|model| model.name == "User").unwrap();
+
+    let schema = SchemaParser::parse_with_models(user, &models).unwrap();
+
+    let address = &schema.properties["address"];
+    assert_eq!(address.base_type, BaseType::Object);
+    let nested = address.nested_schema.as_ref().expect("nested schema");
+    assert_eq!(nested.properties["city"].base_type, BaseType::String);
+    assert_eq!(nested.properties["zip_code"].base_type, BaseType::Integer);
+    assert!(schema.properties["billing"].nested_schema.is_some());
+    assert!(schema.properties["name"].nested_schema.is_none());
+}
+
+#[test]
+fn test_duplicate_model_references_keep_json_schema() {
+    let source = r#"
+from pydantic import BaseModel
+
+class User(BaseModel):
+    name: str
+"#;
+    let ast = parse(source, Mode::Module, "models.py").unwrap();
+    let converter = LocationConverter::new(source.to_string());
+    let from_ast = PythonParser::new().extract_pydantic_models(&ast, "models.py", &converter);
+
+    // The same model as PyO3 sees it: full JSON schema, line unknown
+    let mut from_runtime = from_ast[0].clone();
+    from_runtime.location.line = 0;
+    from_runtime.location.column = None;
+    from_runtime.metadata = HashMap::from([(
+        "json_schema".to_string(),
+        r#"{"type":"object","properties":{"name":{"type":"string"}},"required":["name"]}"#
+            .to_string(),
+    )]);
+
+    let schemas = dedupe_schema_refs(
+        from_ast
+            .iter()
+            .cloned()
+            .chain([from_runtime])
+            .chain(from_ast.iter().cloned()),
+    );
+
+    assert_eq!(schemas.len(), 1);
+    assert!(schemas[0].metadata.contains_key("json_schema"));
+    assert_eq!(schemas[0].location, from_ast[0].location);
+}
+
+#[test]
+fn test_conint_annotation_produces_min_constraint() {
+    let source = r#"
+from pydantic import BaseModel, conint, constr
+
+class User(BaseModel):
+    age: conint(ge=0)
+    name: constr(max_length=10)
+"#;
+    let ast = parse(source, Mode::Module, "models.py").unwrap();
+    let converter = LocationConverter::new(source.to_string());
+    let models = PythonParser::new().extract_pydantic_models(&ast, "models.py", &converter);
+
+    let schema = SchemaParser::parse(&models[0]).unwrap();
+
+    let age = &schema.properties["age"];
+    assert_eq!(age.base_type, BaseType::Integer);
+    assert_eq!(
+        age.constraints,
+        vec![Constraint::Min(ConstraintValue::Integer(0))]
+    );
+    let name = &schema.properties["name"];
+    assert_eq!(name.base_type, BaseType::String);
+    assert_eq!(
+        name.constraints,
+        vec![Constraint::Max(ConstraintValue::Integer(10))]
+    );
+}
+
+#[test]
+fn test_constrained_type_edge_cases() {
+    let constrained = |annotation: &str| {
+        let ast::Mod::Expression(expression) =
+            parse(annotation, Mode::Expression, "models.py").unwrap()
+        else {
+            unreachable!()
+        };
+        constrained_type(&expression.body).unwrap().constraints
+    };
+
+    // Strict bounds at the ends of i64 do not overflow
+    assert!(constrained(&format!("conint(gt={})", i64::MAX)).is_empty());
+    assert_eq!(
+        constrained(&format!("conint(lt=-{})", i64::MAX)),
+        vec![Constraint::Max(ConstraintValue::Integer(i64::MIN))]
+    );
+    assert_eq!(
+        constrained("conint(gt=0, lt=10)"),
+        vec![
+            Constraint::Min(ConstraintValue::Integer(1)),
+            Constraint::Max(ConstraintValue::Integer(9))
+        ]
+    );
+    assert_eq!(
+        constrained("conset(int, max_items=3)"),
+        vec![
+            Constraint::UniqueItems,
+            Constraint::Max(ConstraintValue::Integer(3))
+        ]
+    );
+    assert!(!constrained("conlist(int)").contains(&Constraint::UniqueItems));
+}
+
+#[test]
+fn test_self_referential_model_is_marked_recursive() {
+    let source = r#"
+from typing import List, Optional
+from pydantic import BaseModel
+
+class Node(BaseModel):
+    name: str
+    children: List[Node]
+    parent: Optional["Node"] = None
+"#;
+    let ast = parse(source, Mode::Module, "models.py").unwrap();
+    let converter = LocationConverter::new(source.to_string());
+    let models = PythonParser::new().extract_pydantic_models(&ast, "models.py", &converter);
+
+    let schema = SchemaParser::parse_with_models(&models[0], &models).unwrap();
+
+    let children = &schema.properties["children"];
+    assert!(children.recursive);
+    assert_eq!(children.base_type, BaseType::Array);
+    assert!(children.nested_schema.is_none());
+    let parent = &schema.properties["parent"];
+    assert!(parent.recursive);
+    assert_eq!(parent.base_type, BaseType::Object);
+    assert!(!schema.properties["name"].recursive);
+    assert_eq!(children.to_json_value()["x-recursive"], true);
+}
+
+#[test]
+fn test_graph_model_schemas_resolve_nested_models() {
+    use dc_core::call_graph::{CallGraphBuilder, CallNode};
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let app_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        &app_path,
+        r#"
+from typing import List
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class Address(BaseModel):
+    city: str
+
+class Node(BaseModel):
+    name: str
+    children: List[Node]
+
+class UserCreate(BaseModel):
+    name: str
+    address: Address
+    tree: Node
+
+@app.post("/users")
+def create_user(user: UserCreate):
+    return user
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&app_path).unwrap();
+    let graph = builder.into_graph();
+
+    let user = graph
+        .node_weights()
+        .find_map(|node| match node {
+            CallNode::Function {
+                name, parameters, ..
+            } if name == "create_user" => parameters[0].type_info.schema_ref.clone(),
+            _ => None,
+        })
+        .unwrap();
+    assert!(user.metadata.contains_key("json_schema"));
+
+    // The production parser sees the resolved nested models
+    let schema = SchemaParser::parse(&user).unwrap();
+    let address = schema.properties["address"].nested_schema.as_ref().unwrap();
+    assert_eq!(address.properties["city"].base_type, BaseType::String);
+    let tree = schema.properties["tree"].nested_schema.as_ref().unwrap();
+    assert!(tree.properties["children"].recursive);
+    assert_eq!(schema.required, vec!["address", "name", "tree"]);
+}
@@ -0,0 +1,220 @@
+use dc_core::analyzers::{ChainBuilder, SchemaParser};
+use dc_core::call_graph::{find_nodes, CallGraph, CallGraphBuilder, CallNode};
+use dc_core::data_flow::DataFlowTracker;
+use dc_core::models::{Link, LinkType, Location, NodeId, SchemaReference, SchemaType};
+use std::collections::HashMap;
+
+fn build_graph(source: &str) -> CallGraph {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let app_path = temp_dir.path().join("main.py");
+    std::fs::write(&app_path, source).unwrap();
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&app_path).unwrap();
+    builder.into_graph()
+}
+
+fn handler(graph: &CallGraph, handler_name: &str) -> NodeId {
+    find_nodes(
+        graph,
+        |n| matches!(n, CallNode::Function { name, .. } if name == handler_name),
+    )[0]
+}
+
+#[test]
+fn test_response_contract_carries_route_status_code() {
+    let graph = build_graph(
+        r#"
+from fastapi import FastAPI, status
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class User(BaseModel):
+    id: int
+
+@app.post("/users", response_model=User, status_code=status.HTTP_201_CREATED)
+def create_user(name: str):
+    return save_user(name)
+
+@app.get("/users/{user_id}", response_model=User)
+def get_user(user_id: int):
+    return load_user(user_id)
+"#,
+    );
+    let tracker = DataFlowTracker::new(&graph);
+    let builder = ChainBuilder::new(&graph, &tracker);
+
+    let created = builder
+        .build_reverse_chain(handler(&graph, "create_user"))
+        .unwrap();
+    assert!(created.links[0].id.starts_with("route-"));
+    assert_eq!(created.contracts[0].status_code, Some(201));
+
+    // Without `status_code=` FastAPI answers with 200
+    let fetched = builder
+        .build_reverse_chain(handler(&graph, "get_user"))
+        .unwrap();
+    assert_eq!(fetched.contracts[0].status_code, Some(200));
+}
+
+fn link(id: &str, link_type: LinkType, schema_ref: SchemaReference) -> Link {
+    Link {
+        id: id.to_string(),
+        link_type,
+        location: schema_ref.location.clone(),
+        node_id: NodeId::from(petgraph::graph::NodeIndex::new(0)),
+        schema_ref,
+    }
+}
+
+fn pydantic_schema(name: &str) -> SchemaReference {
+    SchemaReference {
+        name: name.to_string(),
+        schema_type: SchemaType::Pydantic,
+        location: Location {
+            file: "schemas.py".to_string(),
+            line: 1,
+            column: None,
+        },
+        metadata: HashMap::new(),
+    }
+}
+
+#[test]
+fn test_response_contract_per_status_code() {
+    let graph = CallGraph::new();
+    let tracker = DataFlowTracker::new(&graph);
+    let builder = ChainBuilder::new(&graph, &tracker);
+
+    let route = link(
+        "route-users",
+        LinkType::Source,
+        pydantic_schema("UserResponse"),
+    );
+    let consumer = link("func-createUser", LinkType::Sink, pydantic_schema("User"));
+    let responses = vec![
+        (200, pydantic_schema("UserResponse")),
+        (422, pydantic_schema("HTTPValidationError")),
+    ];
+
+    let contracts = builder.build_response_contracts(&route, &consumer, &responses);
+
+    assert_eq!(contracts.len(), 2);
+    assert_eq!(contracts[0].status_code, Some(200));
+    assert_eq!(contracts[0].from_schema.name, "UserResponse");
+    assert_eq!(contracts[0].to_schema.name, "User");
+    assert_eq!(contracts[1].status_code, Some(422));
+    assert_eq!(contracts[1].from_schema.name, "HTTPValidationError");
+    assert_eq!(contracts[1].to_schema.name, "HTTPValidationError");
+    assert!(contracts
+        .iter()
+        .all(|c| c.from_link_id == "route-users" && c.to_link_id == "func-createUser"));
+}
+
+#[test]
+fn test_route_junction_has_contract_per_declared_response() {
+    let graph = build_graph(
+        r#"
+from fastapi import FastAPI, Depends
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class User(BaseModel):
+    id: int
+
+class NotFound(BaseModel):
+    detail: str
+
+@app.get("/users/{user_id}", response_model=User, responses={404: {"model": NotFound}})
+def get_user(user_id: int):
+    return load_user(user_id)
+
+@app.get("/health", response_model=User)
+def health(db = Depends(get_db)):
+    return db.ping()
+"#,
+    );
+    let tracker = DataFlowTracker::new(&graph);
+    let builder = ChainBuilder::new(&graph, &tracker);
+
+    let chain = builder
+        .build_reverse_chain(handler(&graph, "get_user"))
+        .unwrap();
+    let status = |code: u16| {
+        chain
+            .contracts
+            .iter()
+            .find(|contract| contract.status_code == Some(code))
+            .unwrap_or_else(|| panic!("no contract for HTTP {}", code))
+    };
+    assert_eq!(status(200).from_schema.name, "User");
+    assert_eq!(status(404).from_schema.name, "NotFound");
+    // FastAPI validates `user_id` and answers 422 when it is not an integer
+    let validation = status(422);
+    assert_eq!(validation.from_schema.name, "HTTPValidationError");
+    let detail = SchemaParser::parse(&validation.from_schema).unwrap();
+    assert!(detail.properties.contains_key("detail"));
+
+    // Only `Depends()` parameters: nothing to validate, no 422
+    let chain = builder
+        .build_reverse_chain(handler(&graph, "health"))
+        .unwrap();
+    assert!(chain
+        .contracts
+        .iter()
+        .all(|contract| contract.status_code != Some(422)));
+}
+
+#[test]
+fn test_response_envelope_compares_inner_schema() {
+    let graph = build_graph(
+        r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class User(BaseModel):
+    id: int
+    name: str
+
+class Meta(BaseModel):
+    page: int
+
+class UserEnvelope(BaseModel):
+    data: User
+    meta: Meta
+
+@app.get("/users/{user_id}", response_model=UserEnvelope)
+def get_user(user_id: int):
+    return load_user(user_id)
+"#,
+    );
+    let tracker = DataFlowTracker::new(&graph);
+
+    let chain = ChainBuilder::new(&graph, &tracker)
+        .with_response_envelope(Some("data".to_string()))
+        .build_reverse_chain(handler(&graph, "get_user"))
+        .unwrap();
+
+    // The handler returns into its route, which answers with the payload of the envelope
+    let route_link = &chain.links[0];
+    assert!(route_link.id.starts_with("route-"));
+    assert_eq!(route_link.schema_ref.name, "UserEnvelope.data");
+    let inner = SchemaParser::parse(&route_link.schema_ref).unwrap();
+    assert!(inner.properties.contains_key("id"));
+    assert!(!inner.properties.contains_key("meta"));
+    let contract = chain
+        .contracts
+        .iter()
+        .find(|contract| contract.from_link_id == route_link.id)
+        .unwrap();
+    assert_eq!(contract.from_schema.name, "UserEnvelope.data");
+
+    // Without an envelope the whole response model is compared
+    let chain = ChainBuilder::new(&graph, &tracker)
+        .build_reverse_chain(handler(&graph, "get_user"))
+        .unwrap();
+    assert_eq!(chain.links[0].schema_ref.name, "UserEnvelope");
+}
@@ -0,0 +1,66 @@
+use dc_core::analyzers::{ChainBuilder, ContractChecker};
+use dc_core::call_graph::{find_nodes, CallGraphBuilder, CallNode};
+use dc_core::data_flow::DataFlowTracker;
+use dc_core::models::{Location, MismatchType, SchemaReference, SchemaType};
+use std::collections::HashMap;
+use tempfile::TempDir;
+
+#[test]
+fn test_required_query_parameter_in_route_parameter_schema() {
+    let temp_dir = TempDir::new().unwrap();
+    let app_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        &app_path,
+        r#"
+from fastapi import FastAPI, Query
+
+app = FastAPI()
+
+@app.get("/items/{item_id}")
+def read_item(item_id: int, q: str = Query(...), limit: int = 10):
+    return {"item_id": item_id}
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&app_path).unwrap();
+    let graph = builder.into_graph();
+
+    let routes = find_nodes(&graph, |n| matches!(n, CallNode::Route { .. }));
+    assert_eq!(routes.len(), 1);
+
+    let tracker = DataFlowTracker::new(&graph);
+    let chain_builder = ChainBuilder::new(&graph, &tracker);
+    let schema = chain_builder
+        .extract_route_parameters_schema(routes[0])
+        .expect("route should have a parameter schema");
+
+    assert_eq!(schema.name, "read_itemParameters");
+    assert_eq!(schema.metadata["fields"], "item_id:int,q:str,limit:int");
+    assert_eq!(schema.metadata["required"], "item_id,q");
+    assert_eq!(
+        schema.metadata["parameter_locations"],
+        "item_id:path,q:query,limit:query"
+    );
+
+    // Вызов с фронтенда без обязательного query-параметра `q`
+    let mut metadata = HashMap::new();
+    metadata.insert("fields".to_string(), "item_id:int".to_string());
+    metadata.insert("required".to_string(), "item_id".to_string());
+    let frontend_call = SchemaReference {
+        name: "getItemParams".to_string(),
+        schema_type: SchemaType::Zod,
+        location: Location {
+            file: "api.ts".to_string(),
+            line: 1,
+            column: None,
+        },
+        metadata,
+    };
+
+    let mismatches = ContractChecker::new().compare_schemas(&frontend_call, &schema);
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].mismatch_type, MismatchType::MissingField);
+    assert_eq!(mismatches[0].path, "q");
+}
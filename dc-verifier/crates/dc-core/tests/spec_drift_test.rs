@@ -0,0 +1,215 @@
+use dc_core::analyzers::{compare_with_spec, fastapi_operation_id, ContractChecker, SpecDrift};
+use dc_core::call_graph::{CallGraphBuilder, HttpMethod};
+use dc_core::parsers::OpenApiParser;
+use serde_json::json;
+use tempfile::TempDir;
+
+#[test]
+fn test_route_missing_from_spec_is_reported() {
+    let temp_dir = TempDir::new().unwrap();
+    let app_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        &app_path,
+        r#"
+from fastapi import FastAPI
+
+app = FastAPI()
+
+@app.get("/users/{user_id}")
+def get_user(user_id: int):
+    return {"user_id": user_id}
+
+@app.get("/health")
+def health():
+    return {"ok": True}
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&app_path).unwrap();
+    let graph = builder.into_graph();
+
+    let spec = json!({
+        "openapi": "3.0.0",
+        "paths": {
+            "/users/{id}": {"get": {"responses": {"200": {"description": "User"}}}}
+        }
+    });
+    let endpoints = OpenApiParser::from_json(&spec.to_string())
+        .unwrap()
+        .extract_endpoints();
+
+    let drifts = compare_with_spec(&graph, &endpoints, &ContractChecker::new());
+
+    assert_eq!(drifts.len(), 1, "{:?}", drifts);
+    assert!(matches!(
+        &drifts[0],
+        SpecDrift::MissingInSpec { method, path } if method == "GET" && path == "/health"
+    ));
+}
+
+#[test]
+fn test_route_is_matched_by_operation_id_before_path() {
+    let temp_dir = TempDir::new().unwrap();
+    let app_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        &app_path,
+        r#"
+from fastapi import FastAPI
+
+app = FastAPI()
+
+@app.get("/v2/users", operation_id="listUsers")
+def list_users():
+    return []
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&app_path).unwrap();
+    let graph = builder.into_graph();
+
+    let spec = json!({
+        "openapi": "3.0.0",
+        "paths": {
+            "/users": {"get": {"operationId": "listUsers", "responses": {"200": {"description": "Users"}}}}
+        }
+    });
+    let endpoints = OpenApiParser::from_json(&spec.to_string())
+        .unwrap()
+        .extract_endpoints();
+
+    let drifts = compare_with_spec(&graph, &endpoints, &ContractChecker::new());
+
+    assert!(drifts.is_empty(), "{:?}", drifts);
+}
+
+#[test]
+fn test_inline_spec_schema_is_compared_by_fields_only() {
+    let temp_dir = TempDir::new().unwrap();
+    let app_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        &app_path,
+        r#"
+from fastapi import FastAPI
+from pydantic import BaseModel
+
+app = FastAPI()
+
+class UserIn(BaseModel):
+    name: str
+    age: int
+
+@app.post("/users")
+def create_user(user: UserIn):
+    return None
+"#,
+    )
+    .unwrap();
+
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&app_path).unwrap();
+    let graph = builder.into_graph();
+
+    let spec = |age_type: &str| {
+        json!({
+            "openapi": "3.0.0",
+            "paths": {
+                "/users": {
+                    "post": {
+                        "operationId": "create_user",
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "type": "object",
+                                        "properties": {
+                                            "name": {"type": "string"},
+                                            "age": {"type": age_type}
+                                        },
+                                        "required": ["name", "age"]
+                                    }
+                                }
+                            }
+                        },
+                        "responses": {"204": {"description": "Created"}}
+                    }
+                }
+            }
+        })
+    };
+    let drifts_for = |age_type: &str| {
+        let endpoints = OpenApiParser::from_json(&spec(age_type).to_string())
+            .unwrap()
+            .extract_endpoints();
+        compare_with_spec(&graph, &endpoints, &ContractChecker::new())
+    };
+
+    assert!(
+        drifts_for("integer").is_empty(),
+        "{:?}",
+        drifts_for("integer")
+    );
+    let drifts = drifts_for("string");
+    assert_eq!(drifts.len(), 1, "{:?}", drifts);
+    assert!(matches!(
+        &drifts[0],
+        SpecDrift::SchemaDrift { part, spec_schema, .. }
+            if *part == "request" && spec_schema == "create_userRequest"
+    ));
+}
+
+#[test]
+fn test_default_operation_id_matches_fastapi() {
+    assert_eq!(
+        fastapi_operation_id("get_user", "/users/{user_id}", HttpMethod::Get),
+        "get_user_users__user_id__get"
+    );
+    assert_eq!(
+        fastapi_operation_id("create_item", "/api/v1/items-list", HttpMethod::Post),
+        "create_item_api_v1_items_list_post"
+    );
+
+    let temp_dir = TempDir::new().unwrap();
+    let app_path = temp_dir.path().join("main.py");
+    std::fs::write(
+        &app_path,
+        r#"
+from fastapi import FastAPI
+
+app = FastAPI()
+
+@app.get("/users/{user_id}")
+def get_user(user_id: int):
+    return {"user_id": user_id}
+"#,
+    )
+    .unwrap();
+    let mut builder = CallGraphBuilder::new();
+    builder.build_from_entry(&app_path).unwrap();
+    let graph = builder.into_graph();
+
+    // Paths as FastAPI writes them, with the operationId it generated; the spec
+    // path was later renamed, so only the operationId can match the route
+    let spec = json!({
+        "openapi": "3.1.0",
+        "paths": {
+            "/members/{user_id}": {
+                "get": {
+                    "summary": "Get User",
+                    "operationId": "get_user_users__user_id__get",
+                    "responses": {"200": {"description": "Successful Response"}}
+                }
+            }
+        }
+    });
+    let endpoints = OpenApiParser::from_json(&spec.to_string())
+        .unwrap()
+        .extract_endpoints();
+
+    let drifts = compare_with_spec(&graph, &endpoints, &ContractChecker::new());
+
+    assert!(drifts.is_empty(), "{:?}", drifts);
+}
@@ -1,7 +1,13 @@
+use crate::tsconfig::TsConfig;
+use crate::workspace::Workspace;
 use anyhow::{Context, Result};
-use dc_core::call_graph::{CallEdge, CallGraph, CallNode};
+use dc_core::cache::CacheStore;
+use dc_core::call_graph::{CallEdge, CallGraph, CallNode, PathCache};
 use dc_core::models::NodeId;
-use dc_core::parsers::TypeScriptParser;
+use dc_core::parsers::{
+    is_component_file, is_test_file, Call, FunctionOrClass, Import, TypeScriptParser,
+};
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
@@ -13,11 +19,35 @@ pub struct TypeScriptCallGraphBuilder {
     processed_files: HashSet<PathBuf>,
     module_nodes: HashMap<PathBuf, NodeId>,
     function_nodes: HashMap<String, NodeId>,
+    /// Nodes for calls without a source definition, by callee name
+    external_nodes: HashMap<String, NodeId>,
+    /// Returned expression of each function that returns a value
+    return_values: HashMap<NodeId, String>,
     project_root: Option<PathBuf>,
+    /// Memoized `canonicalize` results
+    canonical_paths: PathCache,
     /// Maximum recursion depth (None = unlimited)
     max_depth: Option<usize>,
     /// Current recursion depth
     current_depth: usize,
+    /// Monorepo workspace used to resolve cross-package imports
+    workspace: Option<Workspace>,
+    /// `tsconfig.json` whose `paths`/`baseUrl` resolve aliased imports
+    tsconfig_path: Option<PathBuf>,
+    /// Resolution settings loaded from `tsconfig_path` for the current build
+    tsconfig: Option<TsConfig>,
+    /// Per-file extraction cache keyed by content hash
+    cache: Option<CacheStore>,
+    /// Analyze test files (`*.test.ts`, `__tests__/`), skipped by default
+    include_tests: bool,
+}
+
+/// Everything extracted from a single file, cached between runs
+#[derive(Serialize, Deserialize)]
+struct FileExtraction {
+    imports: Vec<Import>,
+    calls: Vec<Call>,
+    functions_and_classes: Vec<FunctionOrClass>,
 }
 
 impl TypeScriptCallGraphBuilder {
@@ -30,9 +60,17 @@ impl TypeScriptCallGraphBuilder {
             processed_files: HashSet::new(),
             module_nodes: HashMap::new(),
             function_nodes: HashMap::new(),
+            external_nodes: HashMap::new(),
+            return_values: HashMap::new(),
             project_root: None,
+            canonical_paths: PathCache::new(),
             max_depth: None,
             current_depth: 0,
+            workspace: None,
+            tsconfig_path: None,
+            tsconfig: None,
+            cache: None,
+            include_tests: false,
         }
     }
 
@@ -42,9 +80,60 @@ impl TypeScriptCallGraphBuilder {
         self
     }
 
+    /// Analyzes test files (`*.test.ts`, `*.spec.ts`, `__tests__/`), which are skipped by default
+    pub fn with_include_tests(mut self, include_tests: bool) -> Self {
+        self.include_tests = include_tests;
+        self
+    }
+
+    /// Sets the monorepo workspace explicitly (otherwise it is discovered from src_paths)
+    pub fn with_workspace(mut self, workspace: Workspace) -> Self {
+        self.workspace = Some(workspace);
+        self
+    }
+
+    /// Resolves aliased imports (`@/components/Button`) through the `compilerOptions.paths`
+    /// and `baseUrl` of a `tsconfig.json`; the file is read when the graph is built
+    pub fn with_tsconfig(mut self, path: PathBuf) -> Self {
+        self.tsconfig_path = Some(path);
+        self
+    }
+
+    /// Enables per-file caching: unchanged files are not re-parsed on the next build
+    pub fn with_cache(mut self, cache: CacheStore) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
     /// Builds graph for TypeScript project
-    pub fn build_graph(mut self) -> Result<CallGraph> {
-        // 1. Find all .ts/.tsx files in src_paths
+    ///
+    /// The builder is not consumed: every call starts from a clean state, so a single
+    /// configured instance can be reused across analyses (e.g. in a long-running process).
+    pub fn build_graph(&self) -> Result<CallGraph> {
+        self.build_graph_from(&self.src_paths)
+    }
+
+    /// Builds graph for the given source paths using this builder's configuration
+    pub fn build_graph_from(&self, src_paths: &[PathBuf]) -> Result<CallGraph> {
+        let mut run = Self::new(src_paths.to_vec()).with_max_depth(self.max_depth);
+        run.workspace = self.workspace.clone();
+        run.tsconfig_path = self.tsconfig_path.clone();
+        run.cache = self.cache.clone();
+        run.include_tests = self.include_tests;
+        run.build()
+    }
+
+    /// Runs a single build on a fresh builder state
+    fn build(mut self) -> Result<CallGraph> {
+        // 0. Detect monorepo workspace (pnpm-workspace.yaml / package.json workspaces)
+        if self.workspace.is_none() {
+            self.workspace = self.src_paths.iter().find_map(|p| Workspace::discover(p));
+        }
+        if let Some(tsconfig_path) = &self.tsconfig_path {
+            self.tsconfig = Some(TsConfig::load(tsconfig_path)?);
+        }
+
+        // 1. Find all .ts/.tsx files (and .vue/.svelte components) in src_paths
         let mut files = Vec::new();
         for src_path in &self.src_paths {
             self.find_ts_files(src_path, &mut files)?;
@@ -70,7 +159,7 @@ impl TypeScriptCallGraphBuilder {
 
     /// Processes a single TypeScript file
     fn process_file(&mut self, file: &Path) -> Result<()> {
-        let normalized = Self::normalize_path(file);
+        let normalized = self.normalize_path(file);
 
         if self.processed_files.contains(&normalized) {
             return Ok(()); // Already processed
@@ -88,10 +177,11 @@ impl TypeScriptCallGraphBuilder {
         self.current_depth += 1;
 
         let result = (|| -> Result<()> {
-            let (module, _source, converter) = self
-                .parser
-                .parse_file(&normalized)
-                .with_context(|| format!("Failed to parse {:?}", normalized))?;
+            let FileExtraction {
+                imports,
+                calls,
+                functions_and_classes,
+            } = self.extract_file(&normalized)?;
 
             // Create module node
             let module_node = self.get_or_create_module_node(&normalized)?;
@@ -99,10 +189,7 @@ impl TypeScriptCallGraphBuilder {
 
             let file_path_str = normalized.to_string_lossy().to_string();
 
-            // Extract imports
-            let imports = self
-                .parser
-                .extract_imports(&module, &file_path_str, &converter);
+            // Process imports
             for import in imports {
                 if let Err(err) = self.process_import(module_node, &import, &normalized) {
                     eprintln!(
@@ -112,31 +199,16 @@ impl TypeScriptCallGraphBuilder {
                 }
             }
 
-            // Extract calls
-            let calls = self
-                .parser
-                .extract_calls(&module, &file_path_str, &converter);
-            for call in calls {
-                if let Err(err) = self.process_call(module_node, &call, &normalized) {
-                    eprintln!(
-                        "Error processing call '{}' from {:?}: {}",
-                        call.name, normalized, err
-                    );
-                }
-            }
-
-            // Extract functions and classes
-            let functions_and_classes =
-                self.parser
-                    .extract_functions_and_classes(&module, &file_path_str, &converter);
+            // Process functions and classes
             for item in functions_and_classes {
                 match item {
-                    dc_core::parsers::FunctionOrClass::Function {
+                    FunctionOrClass::Function {
                         name,
                         line,
                         parameters,
                         return_type,
                         is_async,
+                        return_value,
                         ..
                     } => {
                         let function_node = self.get_or_create_function_node_with_details(
@@ -147,6 +219,9 @@ impl TypeScriptCallGraphBuilder {
                             return_type,
                             is_async,
                         );
+                        if let Some(return_value) = return_value {
+                            self.return_values.insert(function_node, return_value);
+                        }
                         self.graph.add_edge(
                             *module_node,
                             *function_node,
@@ -162,7 +237,7 @@ impl TypeScriptCallGraphBuilder {
                             },
                         );
                     }
-                    dc_core::parsers::FunctionOrClass::Class {
+                    FunctionOrClass::Class {
                         name,
                         line,
                         methods,
@@ -195,6 +270,9 @@ impl TypeScriptCallGraphBuilder {
                                 method.is_async,
                                 method.is_static,
                             );
+                            if let Some(return_value) = method.return_value {
+                                self.return_values.insert(method_node, return_value);
+                            }
                             self.graph.add_edge(
                                 *class_node,
                                 *method_node,
@@ -214,6 +292,16 @@ impl TypeScriptCallGraphBuilder {
                 }
             }
 
+            // Process calls once the file's own definitions are known
+            for call in calls {
+                if let Err(err) = self.process_call(module_node, &call, &normalized) {
+                    eprintln!(
+                        "Error processing call '{}' from {:?}: {}",
+                        call.name, normalized, err
+                    );
+                }
+            }
+
             Ok(())
         })();
 
@@ -221,11 +309,60 @@ impl TypeScriptCallGraphBuilder {
         result
     }
 
+    /// Parses a file and extracts its imports, calls and definitions
+    ///
+    /// With a cache configured, a file whose content hash is unchanged is taken
+    /// from the cache instead of being re-parsed; the graph is then reassembled
+    /// from the per-file results.
+    fn extract_file(&self, file: &Path) -> Result<FileExtraction> {
+        let file_path_str = file.to_string_lossy().to_string();
+        let content = match &self.cache {
+            Some(_) => {
+                Some(std::fs::read(file).with_context(|| format!("Failed to read {:?}", file))?)
+            }
+            None => None,
+        };
+
+        if let (Some(cache), Some(content)) = (&self.cache, &content) {
+            match cache.load_file_entry(&file_path_str, content) {
+                Ok(Some(extraction)) => return Ok(extraction),
+                Ok(None) => {}
+                Err(err) => eprintln!("Ignoring cache entry for {:?}: {}", file, err),
+            }
+        }
+
+        let (module, _source, converter) = self
+            .parser
+            .parse_file(file)
+            .with_context(|| format!("Failed to parse {:?}", file))?;
+        let extraction = FileExtraction {
+            imports: self
+                .parser
+                .extract_imports(&module, &file_path_str, &converter),
+            calls: self
+                .parser
+                .extract_calls(&module, &file_path_str, &converter),
+            functions_and_classes: self.parser.extract_functions_and_classes(
+                &module,
+                &file_path_str,
+                &converter,
+            ),
+        };
+
+        if let (Some(cache), Some(content)) = (&self.cache, &content) {
+            if let Err(err) = cache.save_file_entry(&file_path_str, content, &extraction) {
+                eprintln!("Failed to cache {:?}: {}", file, err);
+            }
+        }
+
+        Ok(extraction)
+    }
+
     /// Processes an import
     fn process_import(
         &mut self,
         from: NodeId,
-        import: &dc_core::parsers::Import,
+        import: &Import,
         current_file: &Path,
     ) -> Result<NodeId> {
         let import_path = match self.resolve_import_path(&import.path, current_file) {
@@ -264,19 +401,13 @@ impl TypeScriptCallGraphBuilder {
     }
 
     /// Processes a function call
-    fn process_call(
-        &mut self,
-        caller: NodeId,
-        call: &dc_core::parsers::Call,
-        current_file: &Path,
-    ) -> Result<NodeId> {
+    fn process_call(&mut self, caller: NodeId, call: &Call, current_file: &Path) -> Result<NodeId> {
         // Try to find function in current file or other processed files
+        // No definition in the sources: library or global call (e.g. `console.log`)
         let callee_node = self
             .find_function_node(&call.name, current_file)
-            .unwrap_or_else(|| {
-                // If function not found, create virtual node
-                self.get_or_create_function_node(&call.name, current_file)
-            });
+            .or_else(|| self.find_method_node(&call.name))
+            .unwrap_or_else(|| self.get_or_create_external_node(&call.name));
 
         let argument_mapping = call
             .arguments
@@ -301,13 +432,36 @@ impl TypeScriptCallGraphBuilder {
                 location: call.location.clone(),
             },
         );
+        self.add_return_edge(callee_node, caller);
 
         Ok(callee_node)
     }
 
+    /// Adds a `Return` edge when `callee` returns a value to `caller` (once per pair)
+    fn add_return_edge(&mut self, callee: NodeId, caller: NodeId) {
+        let Some(return_value) = self.return_values.get(&callee).cloned() else {
+            return;
+        };
+        let exists = self
+            .graph
+            .edges_connecting(*callee, *caller)
+            .any(|edge| matches!(edge.weight(), CallEdge::Return { .. }));
+        if !exists {
+            self.graph.add_edge(
+                *callee,
+                *caller,
+                CallEdge::Return {
+                    from: callee,
+                    to: caller,
+                    return_value,
+                },
+            );
+        }
+    }
+
     /// Gets or creates a module node
-    fn get_or_create_module_node(&mut self, path: &PathBuf) -> Result<NodeId> {
-        let normalized = Self::normalize_path(path);
+    fn get_or_create_module_node(&mut self, path: &Path) -> Result<NodeId> {
+        let normalized = self.normalize_path(path);
 
         if let Some(node) = self.module_nodes.get(&normalized) {
             return Ok(*node);
@@ -320,9 +474,17 @@ impl TypeScriptCallGraphBuilder {
         Ok(node)
     }
 
-    /// Gets or creates a function node
-    fn get_or_create_function_node(&mut self, name: &str, file: &Path) -> NodeId {
-        self.get_or_create_function_node_with_details(name, file, 0, Vec::new(), None, false)
+    /// Gets or creates a node for a call without a source definition
+    fn get_or_create_external_node(&mut self, name: &str) -> NodeId {
+        if let Some(node) = self.external_nodes.get(name) {
+            return *node;
+        }
+
+        let node = NodeId::from(self.graph.add_node(CallNode::ExternalCall {
+            name: name.to_string(),
+        }));
+        self.external_nodes.insert(name.to_string(), node);
+        node
     }
 
     /// Gets or creates a function node with details
@@ -335,7 +497,7 @@ impl TypeScriptCallGraphBuilder {
         return_type: Option<dc_core::models::TypeInfo>,
         _is_async: bool,
     ) -> NodeId {
-        let key = Self::function_key(file, name);
+        let key = self.function_key(file, name, line);
 
         if let Some(node) = self.function_nodes.get(&key) {
             return *node;
@@ -347,6 +509,7 @@ impl TypeScriptCallGraphBuilder {
             line,
             parameters,
             return_type,
+            decorators: Vec::new(),
         }));
         self.function_nodes.insert(key, node);
         node
@@ -356,7 +519,7 @@ impl TypeScriptCallGraphBuilder {
     fn get_or_create_class_node(&mut self, name: &str, file: &Path, _line: usize) -> NodeId {
         let _key = format!(
             "{}::class::{}",
-            Self::normalize_path(file).to_string_lossy(),
+            self.normalize_path(file).to_string_lossy(),
             name
         );
 
@@ -372,15 +535,15 @@ impl TypeScriptCallGraphBuilder {
             }
         }
 
-        let node = NodeId::from(self.graph.add_node(CallNode::Class {
+        NodeId::from(self.graph.add_node(CallNode::Class {
             name: name.to_string(),
             file: file.to_path_buf(),
             methods: Vec::new(),
-        }));
-        node
+        }))
     }
 
     /// Gets or creates a method node
+    #[allow(clippy::too_many_arguments)]
     fn get_or_create_method_node(
         &mut self,
         name: &str,
@@ -394,7 +557,7 @@ impl TypeScriptCallGraphBuilder {
     ) -> NodeId {
         let _key = format!(
             "{}::method::{}",
-            Self::normalize_path(file).to_string_lossy(),
+            self.normalize_path(file).to_string_lossy(),
             name
         );
 
@@ -420,10 +583,8 @@ impl TypeScriptCallGraphBuilder {
         }));
 
         // Update class methods list
-        if let Some(class_node) = self.graph.node_weight_mut(*class) {
-            if let CallNode::Class { methods, .. } = class_node {
-                methods.push(node);
-            }
+        if let Some(CallNode::Class { methods, .. }) = self.graph.node_weight_mut(*class) {
+            methods.push(node);
         }
 
         node
@@ -431,22 +592,51 @@ impl TypeScriptCallGraphBuilder {
 
     /// Finds a function node
     fn find_function_node(&self, name: &str, current_file: &Path) -> Option<NodeId> {
-        let normalized = Self::normalize_path(current_file);
-        let direct_key = Self::function_key(&normalized, name);
-        if let Some(node) = self.function_nodes.get(&direct_key) {
-            return Some(*node);
+        let normalized = self.normalize_path(current_file);
+        if let Some(node) = self.find_function_in_file(name, &normalized) {
+            return Some(node);
         }
 
-        // Search by name across all files
+        // Search by name across all files (deterministically, by key)
         self.function_nodes
             .iter()
-            .find(|(key, _)| key.ends_with(&format!("::{}", name)))
+            .filter(|(key, _)| Self::split_function_key(key).is_some_and(|(n, _)| n == name))
+            .min_by(|(a, _), (b, _)| a.cmp(b))
             .map(|(_, node)| *node)
     }
 
+    /// Finds the method node of a `Class.method` call
+    fn find_method_node(&self, name: &str) -> Option<NodeId> {
+        let (class_name, method_name) = name.rsplit_once('.')?;
+        self.graph.node_indices().find_map(|node_idx| {
+            let CallNode::Method { name, class, .. } = self.graph.node_weight(node_idx)? else {
+                return None;
+            };
+            let is_class = matches!(
+                self.graph.node_weight(**class),
+                Some(CallNode::Class { name, .. }) if name == class_name
+            );
+            (name == method_name && is_class).then(|| NodeId::from(node_idx))
+        })
+    }
+
+    /// Finds the latest definition of `name` in a file
+    fn find_function_in_file(&self, name: &str, normalized_file: &Path) -> Option<NodeId> {
+        let file_prefix = format!("{}::", normalized_file.to_string_lossy());
+        self.function_nodes
+            .iter()
+            .filter(|(key, _)| key.starts_with(&file_prefix))
+            .filter_map(|(key, node)| {
+                let (key_name, line) = Self::split_function_key(key)?;
+                (key_name == name).then_some((line, *node))
+            })
+            .max_by_key(|(line, _)| *line)
+            .map(|(_, node)| node)
+    }
+
     /// Resolves import path
     fn resolve_import_path(&self, import_path: &str, current_file: &Path) -> Result<PathBuf> {
-        let normalized_current = Self::normalize_path(current_file);
+        let normalized_current = self.normalize_path(current_file);
         let base_dir = normalized_current
             .parent()
             .map(|p| p.to_path_buf())
@@ -455,22 +645,28 @@ impl TypeScriptCallGraphBuilder {
 
         let candidate = if import_path.starts_with('.') {
             self.resolve_relative_import(import_path, &base_dir)
+        } else if let Some(resolved) = self.tsconfig.as_ref().and_then(|tsconfig| {
+            tsconfig
+                .candidates(import_path)
+                .iter()
+                .find_map(|candidate| Self::resolve_module_file(candidate))
+        }) {
+            // tsconfig `paths` alias or a path under `baseUrl`
+            resolved
+        } else if let Some(resolved) = self
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.resolve(import_path))
+        {
+            // Workspace package - resolve to its source file
+            resolved
         } else {
             // Absolute imports - skip external modules for now
             return Err(anyhow::anyhow!("External module: {}", import_path));
         };
 
-        if candidate.exists() {
-            return Ok(candidate);
-        }
-
-        // Try adding extensions
-        for ext in &["ts", "tsx", "js", "jsx"] {
-            let mut with_ext = candidate.clone();
-            with_ext.set_extension(ext);
-            if with_ext.exists() {
-                return Ok(with_ext);
-            }
+        if let Some(resolved) = Self::resolve_module_file(&candidate) {
+            return Ok(resolved);
         }
 
         anyhow::bail!(
@@ -480,47 +676,70 @@ impl TypeScriptCallGraphBuilder {
         )
     }
 
-    /// Resolves relative import
+    /// Resolves relative import (`./user`, `../lib/api`) against the importing file's directory
     fn resolve_relative_import(&self, import_path: &str, base_dir: &Path) -> PathBuf {
-        let mut level = 0;
-        for ch in import_path.chars() {
-            if ch == '.' {
-                level += 1;
-            } else {
-                break;
-            }
-        }
-
         let mut path = base_dir.to_path_buf();
-        for _ in 1..level {
-            if let Some(parent) = path.parent() {
-                path = parent.to_path_buf();
+        for segment in import_path.split('/') {
+            match segment {
+                "" | "." => {}
+                ".." => {
+                    path.pop();
+                }
+                segment => path.push(segment),
             }
         }
+        path
+    }
 
-        let remaining = import_path.trim_start_matches('.');
-        if !remaining.is_empty() {
-            let replaced = remaining.replace('/', &std::path::MAIN_SEPARATOR.to_string());
-            path = path.join(replaced);
+    /// Source file of a module path: the file itself, the path with a TS/JS
+    /// extension appended, or the `index` file of a directory (barrel)
+    fn resolve_module_file(candidate: &Path) -> Option<PathBuf> {
+        const EXTENSIONS: [&str; 4] = ["ts", "tsx", "js", "jsx"];
+
+        if candidate.is_file() {
+            return Some(candidate.to_path_buf());
         }
+        let file_name = candidate.file_name()?.to_string_lossy();
+        EXTENSIONS
+            .iter()
+            .map(|ext| candidate.with_file_name(format!("{}.{}", file_name, ext)))
+            .chain(
+                EXTENSIONS
+                    .iter()
+                    .map(|ext| candidate.join(format!("index.{}", ext))),
+            )
+            .find(|path| path.is_file())
+    }
 
-        path
+    /// Normalizes path (canonicalization is memoized per builder)
+    fn normalize_path(&self, path: &Path) -> PathBuf {
+        self.canonical_paths.normalize(path)
     }
 
-    /// Normalizes path
-    fn normalize_path(path: &Path) -> PathBuf {
-        path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+    /// Creates key for function: `path::name@line`
+    ///
+    /// The definition line keeps same-named functions of one file apart.
+    fn function_key(&self, path: &Path, name: &str, line: usize) -> String {
+        format!(
+            "{}::{}@{}",
+            self.normalize_path(path).to_string_lossy(),
+            name,
+            line
+        )
     }
 
-    /// Creates key for function
-    fn function_key(path: &Path, name: &str) -> String {
-        format!("{}::{}", Self::normalize_path(path).to_string_lossy(), name)
+    /// Splits a function key into the function name and line
+    fn split_function_key(key: &str) -> Option<(&str, usize)> {
+        let (_, definition) = key.rsplit_once("::")?;
+        let (name, line) = definition.rsplit_once('@')?;
+        Some((name, line.parse().ok()?))
     }
 
     fn find_ts_files(&self, dir: &PathBuf, files: &mut Vec<PathBuf>) -> Result<()> {
         if dir.is_file() {
             if let Some(ext) = dir.extension() {
-                if ext == "ts" || ext == "tsx" {
+                // Vue/Svelte components are analyzed through their `<script>` blocks
+                if ext == "ts" || ext == "tsx" || is_component_file(dir) {
                     files.push(dir.clone());
                 }
             }
@@ -531,6 +750,9 @@ impl TypeScriptCallGraphBuilder {
             for entry in std::fs::read_dir(dir)? {
                 let entry = entry?;
                 let path = entry.path();
+                if !self.include_tests && is_test_file(&path) {
+                    continue;
+                }
                 self.find_ts_files(&path, files)?;
             }
         }
@@ -1,7 +1,11 @@
 pub mod call_graph;
 pub mod swc_parser;
+pub mod tsconfig;
+pub mod workspace;
 pub mod zod;
 
 pub use call_graph::*;
 pub use swc_parser::*;
+pub use tsconfig::*;
+pub use workspace::*;
 pub use zod::*;
@@ -19,7 +19,10 @@ impl SwcParser {
 
     /// Parses a TypeScript/JavaScript file
     pub fn parse_file(&self, path: &Path) -> Result<Module> {
-        let source = std::fs::read_to_string(path)?;
+        let mut source = std::fs::read_to_string(path)?;
+        if dc_core::parsers::is_component_file(path) {
+            source = dc_core::parsers::component_script_source(&source);
+        }
         self.parse_source(&source, path)
     }
 
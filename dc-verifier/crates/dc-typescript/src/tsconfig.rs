@@ -0,0 +1,195 @@
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// Module resolution settings of a `tsconfig.json`
+///
+/// Maps aliased imports (`@/components/Button`) to project paths through
+/// `compilerOptions.paths`; with `compilerOptions.baseUrl` set, bare imports
+/// (`utils/format`) are also looked up relative to it.
+#[derive(Debug, Clone, Default)]
+pub struct TsConfig {
+    /// Directory `paths` targets are relative to: `baseUrl` or the tsconfig directory
+    base_dir: PathBuf,
+    /// Whether `baseUrl` is set explicitly
+    has_base_url: bool,
+    /// `paths` patterns with their targets
+    paths: Vec<(String, Vec<String>)>,
+}
+
+impl TsConfig {
+    /// Loads `compilerOptions.baseUrl` and `compilerOptions.paths` from a tsconfig file
+    ///
+    /// Comments and trailing commas, which `tsc` accepts, are allowed.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read tsconfig {:?}", path))?;
+        let value: Value = serde_json::from_str(&strip_jsonc(&content))
+            .with_context(|| format!("Invalid tsconfig {:?}", path))?;
+        let config_dir = path.parent().unwrap_or_else(|| Path::new("."));
+        Ok(Self::from_value(config_dir, &value))
+    }
+
+    /// Builds the settings from parsed tsconfig content located in `config_dir`
+    pub fn from_value(config_dir: &Path, value: &Value) -> Self {
+        let options = value.get("compilerOptions");
+        let base_url = options
+            .and_then(|options| options.get("baseUrl"))
+            .and_then(Value::as_str);
+        let paths = options
+            .and_then(|options| options.get("paths"))
+            .and_then(Value::as_object)
+            .map(|paths| {
+                paths
+                    .iter()
+                    .map(|(pattern, targets)| {
+                        let targets = targets
+                            .as_array()
+                            .into_iter()
+                            .flatten()
+                            .filter_map(Value::as_str)
+                            .map(str::to_string)
+                            .collect();
+                        (pattern.clone(), targets)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            base_dir: match base_url {
+                Some(base_url) => config_dir.join(base_url),
+                None => config_dir.to_path_buf(),
+            },
+            has_base_url: base_url.is_some(),
+            paths,
+        }
+    }
+
+    /// Candidate paths of a non-relative import, most specific first (without extensions)
+    ///
+    /// As in `tsc`, the pattern with the longest prefix before `*` wins; exact
+    /// patterns take precedence over wildcard ones.
+    pub fn candidates(&self, import_path: &str) -> Vec<PathBuf> {
+        let matched = self
+            .paths
+            .iter()
+            .filter_map(|(pattern, targets)| {
+                let captured = match pattern.split_once('*') {
+                    Some((prefix, suffix)) => import_path
+                        .strip_prefix(prefix)
+                        .and_then(|rest| rest.strip_suffix(suffix))
+                        .map(|captured| (prefix.len(), captured)),
+                    None => (pattern == import_path).then_some((usize::MAX, "")),
+                }?;
+                Some((captured, targets))
+            })
+            .max_by_key(|((specificity, _), _)| *specificity);
+
+        let mut candidates: Vec<PathBuf> = matched
+            .map(|((_, captured), targets)| {
+                targets
+                    .iter()
+                    .map(|target| self.base_dir.join(target.replacen('*', captured, 1)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        if self.has_base_url {
+            candidates.push(self.base_dir.join(import_path));
+        }
+        candidates
+    }
+}
+
+/// Removes `//` and `/* */` comments and trailing commas outside of strings
+fn strip_jsonc(content: &str) -> String {
+    let mut without_comments = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+    while let Some(ch) = chars.next() {
+        if in_string {
+            without_comments.push(ch);
+            match ch {
+                '\\' => without_comments.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (ch, chars.peek()) {
+            ('/', Some('/')) => while chars.next_if(|&next| next != '\n').is_some() {},
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for next in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            _ => {
+                in_string = ch == '"';
+                without_comments.push(ch);
+            }
+        }
+    }
+
+    let mut result = String::with_capacity(without_comments.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for (idx, ch) in without_comments.char_indices() {
+        if in_string {
+            in_string = escaped || ch != '"';
+            escaped = !escaped && ch == '\\';
+        } else if ch == '"' {
+            in_string = true;
+        } else if ch == ',' {
+            let next = without_comments[idx + 1..].trim_start().chars().next();
+            if matches!(next, Some('}' | ']')) {
+                continue;
+            }
+        }
+        result.push(ch);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wildcard_alias_and_jsonc() {
+        let content = r#"{
+            // Path aliases
+            "compilerOptions": {
+                "baseUrl": "./",
+                "paths": {
+                    "@/*": ["src/*"], /* app code */
+                    "@ui/*": ["src/ui/*", "vendor/ui/*"],
+                    "config": ["src/config/index.ts"],
+                },
+            },
+        }"#;
+        let value: Value = serde_json::from_str(&strip_jsonc(content)).unwrap();
+        let tsconfig = TsConfig::from_value(Path::new("/app"), &value);
+
+        assert_eq!(
+            tsconfig.candidates("@ui/Button"),
+            vec![
+                PathBuf::from("/app/src/ui/Button"),
+                PathBuf::from("/app/vendor/ui/Button"),
+                PathBuf::from("/app/@ui/Button"),
+            ]
+        );
+        assert_eq!(
+            tsconfig.candidates("config")[0],
+            PathBuf::from("/app/src/config/index.ts")
+        );
+        assert_eq!(
+            strip_jsonc(r#"{"url": "http://x", }"#),
+            r#"{"url": "http://x" }"#
+        );
+    }
+}
@@ -0,0 +1,306 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Source file extensions probed when resolving workspace entries
+const SOURCE_EXTENSIONS: [&str; 4] = ["ts", "tsx", "js", "jsx"];
+
+/// Monorepo workspace (pnpm / npm / yarn workspaces)
+///
+/// Maps package names declared in the workspace to their source directories,
+/// so that imports like `@myorg/shared` resolve to the package sources instead
+/// of being treated as external modules.
+#[derive(Debug, Clone, Default)]
+pub struct Workspace {
+    root: PathBuf,
+    packages: HashMap<String, WorkspacePackage>,
+}
+
+/// A single package of a workspace
+#[derive(Debug, Clone)]
+pub struct WorkspacePackage {
+    /// Package name from `package.json`
+    pub name: String,
+    /// Package directory
+    pub dir: PathBuf,
+    /// Source entry file (if it could be determined)
+    pub entry: Option<PathBuf>,
+}
+
+impl Workspace {
+    /// Looks for a workspace root starting at `start` and walking up the directory tree
+    ///
+    /// A directory is a workspace root if it contains `pnpm-workspace.yaml`
+    /// or a `package.json` with a `workspaces` field.
+    pub fn discover(start: &Path) -> Option<Self> {
+        let start = start.canonicalize().unwrap_or_else(|_| start.to_path_buf());
+        let start_dir = if start.is_file() {
+            start.parent()?.to_path_buf()
+        } else {
+            start
+        };
+
+        for dir in start_dir.ancestors() {
+            if let Some(patterns) = Self::read_workspace_patterns(dir) {
+                return Some(Self::from_patterns(dir, &patterns));
+            }
+        }
+
+        None
+    }
+
+    /// Builds a workspace from its root and package globs
+    pub fn from_patterns(root: &Path, patterns: &[String]) -> Self {
+        let mut packages = HashMap::new();
+
+        for pattern in patterns {
+            if pattern.starts_with('!') {
+                continue;
+            }
+            for dir in Self::expand_pattern(root, pattern) {
+                if let Some(package) = Self::load_package(&dir) {
+                    packages.insert(package.name.clone(), package);
+                }
+            }
+        }
+
+        Self {
+            root: root.to_path_buf(),
+            packages,
+        }
+    }
+
+    /// Workspace root directory
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns a package by name
+    pub fn package(&self, name: &str) -> Option<&WorkspacePackage> {
+        self.packages.get(name)
+    }
+
+    /// Resolves a bare import (`@myorg/shared`, `@myorg/shared/utils`) to a source file
+    pub fn resolve(&self, import_path: &str) -> Option<PathBuf> {
+        if let Some(package) = self.packages.get(import_path) {
+            return package.entry.clone();
+        }
+
+        // Deep import into a package: `<name>/<subpath>`
+        let package = self
+            .packages
+            .values()
+            .filter(|p| {
+                import_path.len() > p.name.len()
+                    && import_path.starts_with(&p.name)
+                    && import_path.as_bytes()[p.name.len()] == b'/'
+            })
+            .max_by_key(|p| p.name.len())?;
+
+        let subpath = &import_path[package.name.len() + 1..];
+        [
+            package.dir.join("src").join(subpath),
+            package.dir.join(subpath),
+        ]
+        .iter()
+        .find_map(|candidate| Self::probe_source(candidate))
+    }
+
+    /// Reads package globs from `pnpm-workspace.yaml` or `package.json`
+    fn read_workspace_patterns(dir: &Path) -> Option<Vec<String>> {
+        let pnpm = dir.join("pnpm-workspace.yaml");
+        if let Ok(content) = std::fs::read_to_string(&pnpm) {
+            return Some(Self::parse_pnpm_workspace(&content));
+        }
+
+        let manifest = Self::read_package_json(dir)?;
+        let workspaces = manifest.get("workspaces")?;
+        // npm/yarn: `"workspaces": [...]` or `"workspaces": { "packages": [...] }`
+        let list = workspaces
+            .as_array()
+            .or_else(|| workspaces.get("packages").and_then(|p| p.as_array()))?;
+
+        Some(
+            list.iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect(),
+        )
+    }
+
+    /// Minimal parser for the `packages:` list of `pnpm-workspace.yaml`
+    fn parse_pnpm_workspace(content: &str) -> Vec<String> {
+        let mut patterns = Vec::new();
+        let mut in_packages = false;
+
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            if !line.starts_with(' ') && !line.starts_with('-') {
+                in_packages = trimmed == "packages:";
+                continue;
+            }
+
+            if in_packages {
+                if let Some(item) = trimmed.strip_prefix('-') {
+                    let item = item.trim().trim_matches(|c| c == '\'' || c == '"');
+                    if !item.is_empty() {
+                        patterns.push(item.to_string());
+                    }
+                }
+            }
+        }
+
+        patterns
+    }
+
+    /// Expands a workspace glob (`packages/*`, `apps/**`, `tools/cli`) into directories
+    fn expand_pattern(root: &Path, pattern: &str) -> Vec<PathBuf> {
+        let pattern = pattern.trim_end_matches('/');
+        let base = pattern
+            .strip_suffix("/**")
+            .or_else(|| pattern.strip_suffix("/*"));
+
+        match base {
+            Some(base) => {
+                let dir = root.join(base);
+                let mut dirs: Vec<PathBuf> = std::fs::read_dir(&dir)
+                    .map(|entries| {
+                        entries
+                            .filter_map(|e| e.ok())
+                            .map(|e| e.path())
+                            .filter(|p| p.is_dir())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                dirs.sort();
+                dirs
+            }
+            None => vec![root.join(pattern)],
+        }
+    }
+
+    /// Loads a package description from its `package.json`
+    fn load_package(dir: &Path) -> Option<WorkspacePackage> {
+        let manifest = Self::read_package_json(dir)?;
+        let name = manifest.get("name")?.as_str()?.to_string();
+        let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+        let entry = Self::resolve_entry(&dir, &manifest);
+
+        Some(WorkspacePackage { name, dir, entry })
+    }
+
+    /// Determines the source entry of a package from `exports`/`main`
+    ///
+    /// Build outputs (`dist/index.js`, `lib/index.js`) are mapped back to `src/`,
+    /// falling back to `src/index.ts` when nothing else matches.
+    fn resolve_entry(dir: &Path, manifest: &Value) -> Option<PathBuf> {
+        let declared = ["exports", "source", "types", "main", "module"]
+            .iter()
+            .filter_map(|key| manifest.get(*key))
+            .filter_map(Self::entry_from_value);
+
+        for entry in declared {
+            let entry = entry.trim_start_matches("./");
+            let candidate = dir.join(entry);
+            if let Some(found) = Self::probe_source(&candidate) {
+                return Some(found);
+            }
+
+            // dist/index.js -> src/index.ts
+            let mut parts = Path::new(entry).components();
+            parts.next();
+            let src_candidate = dir.join("src").join(parts.as_path());
+            if let Some(found) = Self::probe_source(&src_candidate) {
+                return Some(found);
+            }
+        }
+
+        Self::probe_source(&dir.join("src").join("index"))
+            .or_else(|| Self::probe_source(&dir.join("index")))
+    }
+
+    /// Extracts an entry path from `exports`/`main`-like values
+    fn entry_from_value(value: &Value) -> Option<String> {
+        match value {
+            Value::String(s) => Some(s.clone()),
+            Value::Object(map) => map
+                .get(".")
+                .or_else(|| map.get("source"))
+                .or_else(|| map.get("types"))
+                .or_else(|| map.get("import"))
+                .or_else(|| map.get("default"))
+                .or_else(|| map.get("require"))
+                .and_then(Self::entry_from_value),
+            _ => None,
+        }
+    }
+
+    /// Returns the TypeScript source for a path, trying known extensions and `index` files
+    ///
+    /// Declaration files (`.d.ts`) are used only when no source file exists.
+    fn probe_source(candidate: &Path) -> Option<PathBuf> {
+        let extension = candidate.extension().and_then(|e| e.to_str());
+        let is_declaration = candidate.to_string_lossy().ends_with(".d.ts");
+        if matches!(extension, Some("ts" | "tsx")) && !is_declaration && candidate.is_file() {
+            return Some(candidate.to_path_buf());
+        }
+
+        // Strip `.js`/`.d.ts`-style build extensions before probing
+        let stem = match extension {
+            Some("js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx") => candidate.with_extension(""),
+            _ => candidate.to_path_buf(),
+        };
+        let stem = match stem.to_str().and_then(|s| s.strip_suffix(".d")) {
+            Some(s) => PathBuf::from(s),
+            None => stem,
+        };
+
+        for ext in SOURCE_EXTENSIONS {
+            let with_ext = PathBuf::from(format!("{}.{}", stem.to_string_lossy(), ext));
+            if with_ext.is_file() {
+                return Some(with_ext);
+            }
+        }
+
+        if stem.is_dir() {
+            for ext in SOURCE_EXTENSIONS {
+                let index = stem.join(format!("index.{}", ext));
+                if index.is_file() {
+                    return Some(index);
+                }
+            }
+        }
+
+        // Type-only packages ship declarations only
+        let declaration = PathBuf::from(format!("{}.d.ts", stem.to_string_lossy()));
+        if declaration.is_file() {
+            return Some(declaration);
+        }
+        let index_declaration = stem.join("index.d.ts");
+        if index_declaration.is_file() {
+            return Some(index_declaration);
+        }
+
+        None
+    }
+
+    fn read_package_json(dir: &Path) -> Option<Value> {
+        let content = std::fs::read_to_string(dir.join("package.json")).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pnpm_workspace() {
+        let content = "packages:\n  - 'packages/*'\n  - \"apps/web\"\n  # comment\n  - '!**/test/**'\nother: true\n";
+        let patterns = Workspace::parse_pnpm_workspace(content);
+        assert_eq!(patterns, vec!["packages/*", "apps/web", "!**/test/**"]);
+    }
+}
@@ -1,3 +1,4 @@
+use dc_core::call_graph::{CallEdge, CallNode};
 use dc_typescript::TypeScriptCallGraphBuilder;
 use tempfile::TempDir;
 
@@ -78,8 +79,8 @@ export function createService(): UserService {
         .filter(|node| matches!(node, dc_core::call_graph::CallNode::Class { .. }))
         .collect();
 
-    assert!(function_nodes.len() > 0);
-    assert!(class_nodes.len() > 0);
+    assert!(!function_nodes.is_empty());
+    assert!(!class_nodes.is_empty());
 }
 
 #[test]
@@ -135,7 +136,7 @@ export function validate(data: any): void {
         .filter(|edge| matches!(edge, dc_core::call_graph::CallEdge::Call { .. }))
         .collect();
 
-    assert!(call_edges.len() > 0);
+    assert!(!call_edges.is_empty());
 }
 
 #[test]
@@ -172,5 +173,432 @@ export function getUser(id: UserId): User {
         .filter(|node| matches!(node, dc_core::call_graph::CallNode::Function { .. }))
         .collect();
 
-    assert!(function_nodes.len() > 0);
+    assert!(!function_nodes.is_empty());
+}
+
+#[test]
+fn test_workspace_package_import_resolves_to_source() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    std::fs::write(
+        root.join("package.json"),
+        r#"{ "name": "monorepo", "private": true, "workspaces": ["packages/*"] }"#,
+    )
+    .unwrap();
+
+    let shared_dir = root.join("packages/shared");
+    std::fs::create_dir_all(shared_dir.join("src")).unwrap();
+    std::fs::write(
+        shared_dir.join("package.json"),
+        r#"{ "name": "@myorg/shared", "main": "dist/index.js" }"#,
+    )
+    .unwrap();
+    let shared_entry = shared_dir.join("src/index.ts");
+    std::fs::write(
+        &shared_entry,
+        r#"
+export function formatUser(name: string): string {
+    return name.trim();
+}
+"#,
+    )
+    .unwrap();
+
+    let app_src = root.join("packages/app/src");
+    std::fs::create_dir_all(&app_src).unwrap();
+    std::fs::write(
+        root.join("packages/app/package.json"),
+        r#"{ "name": "@myorg/app" }"#,
+    )
+    .unwrap();
+    std::fs::write(
+        app_src.join("main.ts"),
+        r#"
+import { formatUser } from '@myorg/shared';
+
+export function main() {
+    return formatUser("Test");
+}
+"#,
+    )
+    .unwrap();
+
+    let builder = TypeScriptCallGraphBuilder::new(vec![app_src]);
+    let graph = builder.build_graph().unwrap();
+
+    let expected = shared_entry.canonicalize().unwrap();
+    let resolved = graph
+        .edge_indices()
+        .filter_map(|idx| graph.edge_weight(idx))
+        .any(|edge| match edge {
+            dc_core::call_graph::CallEdge::Import {
+                import_path, file, ..
+            } => import_path == "@myorg/shared" && *file == expected,
+            _ => false,
+        });
+
+    assert!(
+        resolved,
+        "@myorg/shared should resolve to packages/shared/src/index.ts"
+    );
+}
+
+#[test]
+fn test_builder_reused_for_consecutive_builds() {
+    let first_dir = TempDir::new().unwrap();
+    std::fs::write(
+        first_dir.path().join("users.ts"),
+        "export function listUsers(): string[] {\n    return [];\n}\n",
+    )
+    .unwrap();
+
+    let second_dir = TempDir::new().unwrap();
+    std::fs::write(
+        second_dir.path().join("orders.ts"),
+        "export function listOrders(): string[] {\n    return [];\n}\n",
+    )
+    .unwrap();
+
+    let function_names = |graph: &dc_core::call_graph::CallGraph| -> Vec<String> {
+        graph
+            .node_weights()
+            .filter_map(|node| match node {
+                dc_core::call_graph::CallNode::Function { name, .. } => Some(name.clone()),
+                _ => None,
+            })
+            .collect()
+    };
+
+    let builder = TypeScriptCallGraphBuilder::new(vec![first_dir.path().to_path_buf()]);
+    let first = builder.build_graph().unwrap();
+    let second = builder
+        .build_graph_from(&[second_dir.path().to_path_buf()])
+        .unwrap();
+    let again = builder.build_graph().unwrap();
+
+    assert_eq!(function_names(&first), vec!["listUsers".to_string()]);
+    assert_eq!(function_names(&second), vec!["listOrders".to_string()]);
+    assert_eq!(first.node_count(), again.node_count());
+    assert_eq!(first.edge_count(), again.edge_count());
+}
+
+#[test]
+fn test_cache_reparses_only_changed_file() {
+    use dc_core::cache::{CacheStats, CacheStore};
+    use dc_core::call_graph::CallNode;
+
+    let temp_dir = TempDir::new().unwrap();
+    let src_dir = temp_dir.path().join("src");
+    std::fs::create_dir(&src_dir).unwrap();
+    for name in ["a", "b", "c"] {
+        std::fs::write(
+            src_dir.join(format!("{}.ts", name)),
+            format!("export function {}Fn(): void {{}}\n", name),
+        )
+        .unwrap();
+    }
+
+    let cache = CacheStore::new(temp_dir.path().join("cache").to_str().unwrap()).unwrap();
+    let builder = TypeScriptCallGraphBuilder::new(vec![src_dir.clone()]).with_cache(cache.clone());
+
+    builder.build_graph().unwrap();
+    assert_eq!(cache.stats(), CacheStats { hits: 0, misses: 3 });
+
+    std::fs::write(
+        src_dir.join("b.ts"),
+        "export function bRenamed(): void {}\n",
+    )
+    .unwrap();
+    let graph = builder.build_graph().unwrap();
+
+    // Only b.ts is parsed again, a.ts and c.ts come from the cache
+    assert_eq!(cache.stats(), CacheStats { hits: 2, misses: 4 });
+    let functions: Vec<&str> = graph
+        .node_weights()
+        .filter_map(|n| match n {
+            CallNode::Function { name, .. } => Some(name.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert!(functions.contains(&"aFn"));
+    assert!(functions.contains(&"bRenamed"));
+    assert!(!functions.contains(&"bFn"));
+}
+
+#[test]
+fn test_unknown_call_produces_external_node() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("app.ts"),
+        r#"
+export function save(user: string) {
+    return user;
+}
+
+save("alice");
+console.log("saved");
+"#,
+    )
+    .unwrap();
+
+    let builder = TypeScriptCallGraphBuilder::new(vec![temp_dir.path().to_path_buf()]);
+    let graph = builder.build_graph().unwrap();
+
+    let nodes: Vec<_> = graph
+        .node_indices()
+        .filter_map(|idx| graph.node_weight(idx))
+        .collect();
+
+    assert!(nodes.iter().any(|node| matches!(
+        node,
+        CallNode::ExternalCall { name } if name == "console.log"
+    )));
+    assert!(!nodes.iter().any(|node| matches!(
+        node,
+        CallNode::Function { name, .. } if name == "console.log"
+    )));
+    // The locally defined function stays a single regular node
+    let save_nodes = nodes
+        .iter()
+        .filter(|node| matches!(node, CallNode::Function { name, .. } if name == "save"))
+        .count();
+    assert_eq!(save_nodes, 1);
+}
+
+#[test]
+fn test_class_method_returns_to_caller() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("service.ts"),
+        r#"
+export class UserService {
+    static getUser(id: number) {
+        return id;
+    }
+}
+
+const user = UserService.getUser(1);
+"#,
+    )
+    .unwrap();
+
+    let builder = TypeScriptCallGraphBuilder::new(vec![temp_dir.path().to_path_buf()]);
+    let graph = builder.build_graph().unwrap();
+
+    let method = graph
+        .node_indices()
+        .find(|idx| matches!(graph.node_weight(*idx), Some(CallNode::Method { name, .. }) if name == "getUser"))
+        .expect("method node");
+    let return_value = graph.edges(method).find_map(|edge| match edge.weight() {
+        CallEdge::Return { return_value, .. } => Some(return_value.clone()),
+        _ => None,
+    });
+    assert_eq!(return_value.as_deref(), Some("id"));
+}
+
+#[test]
+fn test_vue_script_block_calls_are_detected() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("UserList.vue"),
+        r#"<template>
+  <ul>
+    <li v-for="user in users" :key="user.id">{{ user.name }}</li>
+  </ul>
+</template>
+
+<script setup lang="ts">
+import axios from "axios";
+
+export function loadUsers() {
+    return axios.get("/api/users");
+}
+</script>
+
+<style scoped>
+ul { margin: 0; }
+</style>
+"#,
+    )
+    .unwrap();
+
+    let builder = TypeScriptCallGraphBuilder::new(vec![temp_dir.path().to_path_buf()]);
+    let graph = builder.build_graph().unwrap();
+
+    let nodes: Vec<_> = graph
+        .node_indices()
+        .filter_map(|idx| graph.node_weight(idx))
+        .collect();
+    assert!(nodes.iter().any(|node| matches!(
+        node,
+        CallNode::ExternalCall { name } if name == "axios.get"
+    )));
+    // Line numbers point into the original component file
+    assert!(nodes.iter().any(|node| matches!(
+        node,
+        CallNode::Function { name, line, .. } if name == "loadUsers" && *line == 10
+    )));
+}
+
+#[test]
+fn test_test_files_are_skipped_unless_included() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("api.ts"),
+        r#"
+export function fetchUser(id: string) {
+    return id;
+}
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        temp_dir.path().join("api.test.ts"),
+        r#"
+export function mockFetchUser() {
+    return "alice";
+}
+"#,
+    )
+    .unwrap();
+
+    let has_function = |graph: &dc_core::call_graph::CallGraph, target: &str| {
+        graph
+            .node_weights()
+            .any(|node| matches!(node, CallNode::Function { name, .. } if name == target))
+    };
+
+    let builder = TypeScriptCallGraphBuilder::new(vec![temp_dir.path().to_path_buf()]);
+    let graph = builder.build_graph().unwrap();
+    assert!(has_function(&graph, "fetchUser"));
+    assert!(!has_function(&graph, "mockFetchUser"));
+
+    let builder = TypeScriptCallGraphBuilder::new(vec![temp_dir.path().to_path_buf()])
+        .with_include_tests(true);
+    let graph = builder.build_graph().unwrap();
+    assert!(has_function(&graph, "mockFetchUser"));
+}
+
+#[test]
+fn test_barrel_reexport_resolves_function() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    let lib_dir = root.join("lib");
+    std::fs::create_dir_all(&lib_dir).unwrap();
+    std::fs::write(
+        lib_dir.join("index.ts"),
+        "export { formatUser } from './users';\nexport * from './orders';\n",
+    )
+    .unwrap();
+    std::fs::write(
+        lib_dir.join("users.ts"),
+        r#"
+export function formatUser(name: string): string {
+    return name.trim();
+}
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        lib_dir.join("orders.ts"),
+        r#"
+export function loadOrders() {
+    return [];
+}
+"#,
+    )
+    .unwrap();
+
+    let app_dir = root.join("app");
+    std::fs::create_dir_all(&app_dir).unwrap();
+    std::fs::write(
+        app_dir.join("main.ts"),
+        r#"
+import { formatUser, loadOrders } from '../lib';
+
+export function main() {
+    loadOrders();
+    return formatUser("Test");
+}
+"#,
+    )
+    .unwrap();
+
+    // Only the app is analyzed: `lib` is reached through the barrel
+    let builder = TypeScriptCallGraphBuilder::new(vec![app_dir]);
+    let graph = builder.build_graph().unwrap();
+
+    for target in ["formatUser", "loadOrders"] {
+        assert!(
+            graph
+                .node_weights()
+                .any(|node| matches!(node, CallNode::Function { name, .. } if name == target)),
+            "{target} should resolve through lib/index.ts"
+        );
+        assert!(!graph
+            .node_weights()
+            .any(|node| matches!(node, CallNode::ExternalCall { name } if name == target)));
+    }
+}
+
+#[test]
+fn test_tsconfig_path_alias_resolves_function() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    std::fs::write(
+        root.join("tsconfig.json"),
+        r#"{
+    "compilerOptions": {
+        // `@/` points at the sources
+        "baseUrl": ".",
+        "paths": { "@/*": ["src/*"] },
+    }
+}"#,
+    )
+    .unwrap();
+    let components = root.join("src/components");
+    std::fs::create_dir_all(&components).unwrap();
+    let button_file = components.join("Button.tsx");
+    std::fs::write(
+        &button_file,
+        r#"
+export function renderButton(label: string) {
+    return label;
+}
+"#,
+    )
+    .unwrap();
+    let pages = root.join("src/pages");
+    std::fs::create_dir_all(&pages).unwrap();
+    std::fs::write(
+        pages.join("home.ts"),
+        r#"
+import { renderButton } from '@/components/Button';
+
+export function home() {
+    return renderButton("Home");
+}
+"#,
+    )
+    .unwrap();
+
+    let builder =
+        TypeScriptCallGraphBuilder::new(vec![pages]).with_tsconfig(root.join("tsconfig.json"));
+    let graph = builder.build_graph().unwrap();
+
+    let expected = button_file.canonicalize().unwrap();
+    assert!(graph
+        .edge_indices()
+        .filter_map(|idx| graph.edge_weight(idx))
+        .any(|edge| matches!(
+            edge,
+            dc_core::call_graph::CallEdge::Import { import_path, file, .. }
+                if import_path == "@/components/Button" && *file == expected
+        )));
+    assert!(graph
+        .node_weights()
+        .any(|node| matches!(node, CallNode::Function { name, .. } if name == "renderButton")));
 }